@@ -50,7 +50,7 @@ class DefaultForegroundNotificationBuilder(
 
     // Build the notification's content based on the trip state.
     // When navigating, show the visual instruction, icon and formatted progress items.
-    // When complete, show the arrival title and description.
+    // When arrived or complete, show the arrival title and description.
     // Otherwise, show the preparing title (this typically only happens on launch).
     when (tripState) {
       is TripState.Navigating -> {
@@ -64,6 +64,7 @@ class DefaultForegroundNotificationBuilder(
           builder.setCustomBigContentView(expandedView)
         }
       }
+      is TripState.Arrived,
       is TripState.Complete -> {
         builder.setContentTitle(context.getString(R.string.arrived_title))
         builder.setContentText(context.getString(R.string.arrived_description))
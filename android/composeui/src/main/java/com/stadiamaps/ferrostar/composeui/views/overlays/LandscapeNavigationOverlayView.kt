@@ -35,6 +35,7 @@ import com.stadiamaps.ferrostar.composeui.theme.NavigationUITheme
 import com.stadiamaps.ferrostar.composeui.views.components.gridviews.NavigatingInnerGridView
 import com.stadiamaps.ferrostar.core.NavigationUiState
 import com.stadiamaps.ferrostar.core.NavigationViewModel
+import com.stadiamaps.ferrostar.core.extensions.speedLimit
 import com.stadiamaps.ferrostar.core.mock.MockNavigationViewModel
 import com.stadiamaps.ferrostar.core.mock.pedestrianExample
 import kotlinx.coroutines.flow.MutableStateFlow
@@ -92,7 +93,7 @@ fun LandscapeNavigationOverlayView(
     Column(modifier = Modifier.fillMaxHeight()) {
       NavigatingInnerGridView(
           modifier = Modifier.fillMaxSize(),
-          speedLimit = uiState.currentAnnotation?.speedLimit,
+          speedLimit = uiState.currentAnnotation?.speedLimit(),
           speedLimitStyle = config.speedLimitStyle,
           showMute = config.showMute,
           isMuted = uiState.isMuted,
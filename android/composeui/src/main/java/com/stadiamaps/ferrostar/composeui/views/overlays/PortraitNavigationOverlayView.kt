@@ -29,6 +29,7 @@ import com.stadiamaps.ferrostar.composeui.theme.NavigationUITheme
 import com.stadiamaps.ferrostar.composeui.views.components.gridviews.NavigatingInnerGridView
 import com.stadiamaps.ferrostar.core.NavigationUiState
 import com.stadiamaps.ferrostar.core.NavigationViewModel
+import com.stadiamaps.ferrostar.core.extensions.speedLimit
 import com.stadiamaps.ferrostar.core.mock.MockNavigationViewModel
 import com.stadiamaps.ferrostar.core.mock.pedestrianExample
 import kotlinx.coroutines.flow.MutableStateFlow
@@ -73,7 +74,7 @@ fun PortraitNavigationOverlayView(
 
     NavigatingInnerGridView(
         modifier = Modifier.fillMaxSize().weight(1f).padding(bottom = 16.dp, top = 16.dp),
-        speedLimit = uiState.currentAnnotation?.speedLimit,
+        speedLimit = uiState.currentAnnotation?.speedLimit(),
         speedLimitStyle = config.speedLimitStyle,
         showMute = config.showMute,
         isMuted = uiState.isMuted,
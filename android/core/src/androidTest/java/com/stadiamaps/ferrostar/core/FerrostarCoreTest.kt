@@ -21,7 +21,6 @@ import uniffi.ferrostar.CourseFiltering
 import uniffi.ferrostar.GeographicCoordinate
 import uniffi.ferrostar.ManeuverModifier
 import uniffi.ferrostar.ManeuverType
-import uniffi.ferrostar.NavigationControllerConfig
 import uniffi.ferrostar.Route
 import uniffi.ferrostar.RouteAdapter
 import uniffi.ferrostar.RouteDeviation
@@ -38,6 +37,7 @@ import uniffi.ferrostar.VisualInstruction
 import uniffi.ferrostar.VisualInstructionContent
 import uniffi.ferrostar.Waypoint
 import uniffi.ferrostar.WaypointKind
+import uniffi.ferrostar.createNavigationControllerConfig
 
 private val valhallaEndpointUrl = "https://api.stadiamaps.com/navigate/v1"
 
@@ -147,8 +147,8 @@ class FerrostarCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
 
     try {
       // Tests that the core generates a request and attempts to process it, but throws due to the
@@ -195,8 +195,8 @@ class FerrostarCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
     val routes =
         core.getRoutes(
             initialLocation =
@@ -238,8 +238,8 @@ class FerrostarCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
     val routes =
         core.getRoutes(
             initialLocation =
@@ -289,8 +289,8 @@ class FerrostarCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
     val routes =
         core.getRoutes(
             initialLocation =
@@ -360,8 +360,8 @@ class FerrostarCoreTest {
             locationProvider = locationProvider,
             foregroundServiceManager = foregroundServiceManager,
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
 
     val deviationHandler = DeviationHandler()
     core.deviationHandler = deviationHandler
@@ -397,7 +397,7 @@ class FerrostarCoreTest {
             speed = null)
     core.startNavigation(
         routes.first(),
-        NavigationControllerConfig(
+        createNavigationControllerConfig(
             stepAdvance =
                 StepAdvanceMode.RelativeLineStringDistance(
                     16U,
@@ -415,7 +415,7 @@ class FerrostarCoreTest {
                             return RouteDeviation.OffRoute(42.0)
                           }
                         }),
-            CourseFiltering.RAW))
+            CourseFiltering.Raw))
 
     assert(foregroundServiceManager.startCalled)
     assert(deviationHandler.called)
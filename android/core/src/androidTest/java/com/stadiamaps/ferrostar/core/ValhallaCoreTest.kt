@@ -25,12 +25,12 @@ import org.junit.Assert.assertEquals
 import org.junit.Test
 import uniffi.ferrostar.CourseFiltering
 import uniffi.ferrostar.GeographicCoordinate
-import uniffi.ferrostar.NavigationControllerConfig
 import uniffi.ferrostar.RouteDeviationTracking
 import uniffi.ferrostar.StepAdvanceMode
 import uniffi.ferrostar.UserLocation
 import uniffi.ferrostar.Waypoint
 import uniffi.ferrostar.WaypointKind
+import uniffi.ferrostar.createNavigationControllerConfig
 
 const val simpleRoute =
     """
@@ -253,8 +253,8 @@ class ValhallaCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW))
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw))
 
     return runTest {
       val routes =
@@ -301,8 +301,8 @@ class ValhallaCoreTest {
             locationProvider = SimulatedLocationProvider(),
             foregroundServiceManager = MockForegroundNotificationManager(),
             navigationControllerConfig =
-                NavigationControllerConfig(
-                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.RAW),
+                createNavigationControllerConfig(
+                    StepAdvanceMode.Manual, RouteDeviationTracking.None, CourseFiltering.Raw),
             options = mapOf("costing_options" to mapOf("auto" to mapOf("useTolls" to 0))))
 
     return runTest {
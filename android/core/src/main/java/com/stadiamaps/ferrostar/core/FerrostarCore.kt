@@ -40,9 +40,10 @@ data class NavigationState(
 
 fun NavigationState.isNavigating(): Boolean =
     when (tripState) {
-      TripState.Complete,
+      is TripState.Complete,
       TripState.Idle -> false
-      is TripState.Navigating -> true
+      is TripState.Navigating,
+      is TripState.Arrived -> true
     }
 
 private val moshi: Moshi = Moshi.Builder().build()
@@ -299,6 +300,29 @@ class FerrostarCore(
     }
   }
 
+  /**
+   * Resumes navigation after the user has arrived at a waypoint.
+   *
+   * This only has an effect when the trip state is [TripState.Arrived] (see the arrival detection
+   * in the navigation controller config). Navigation continues with the next leg of the route, or
+   * completes the trip at the final destination.
+   */
+  fun resumeNavigation() {
+    val controller = _navigationController
+    val location = _lastLocation
+
+    if (controller != null && location != null) {
+      _state.update { currentValue ->
+        val newState =
+            controller.resumeNavigation(location = location, state = currentValue.tripState)
+
+        handleStateUpdate(newState, location)
+
+        NavigationState(tripState = newState, currentValue.routeGeometry, isCalculatingNewRoute)
+      }
+    }
+  }
+
   fun stopNavigation(stopLocationUpdates: Boolean = true) {
     foregroundServiceManager?.stopService()
     if (stopLocationUpdates) {
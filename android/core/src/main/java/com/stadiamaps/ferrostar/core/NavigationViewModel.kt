@@ -3,9 +3,7 @@ package com.stadiamaps.ferrostar.core
 import android.util.Log
 import androidx.lifecycle.ViewModel
 import androidx.lifecycle.viewModelScope
-import com.stadiamaps.ferrostar.core.annotation.AnnotationPublisher
-import com.stadiamaps.ferrostar.core.annotation.AnnotationWrapper
-import com.stadiamaps.ferrostar.core.annotation.NoOpAnnotationPublisher
+import com.stadiamaps.ferrostar.core.extensions.annotation
 import com.stadiamaps.ferrostar.core.extensions.currentRoadName
 import com.stadiamaps.ferrostar.core.extensions.deviation
 import com.stadiamaps.ferrostar.core.extensions.progress
@@ -20,6 +18,7 @@ import kotlinx.coroutines.flow.stateIn
 import uniffi.ferrostar.GeographicCoordinate
 import uniffi.ferrostar.RouteDeviation
 import uniffi.ferrostar.RouteStep
+import uniffi.ferrostar.SegmentAnnotation
 import uniffi.ferrostar.SpokenInstruction
 import uniffi.ferrostar.TripProgress
 import uniffi.ferrostar.TripState
@@ -60,15 +59,14 @@ data class NavigationUiState(
     /** The remaining steps in the trip (including the current step). */
     val remainingSteps: List<RouteStep>?,
     /** The route annotation object at the current location. */
-    val currentAnnotation: AnnotationWrapper<*>?
+    val currentAnnotation: SegmentAnnotation?
 ) {
   companion object {
     fun fromFerrostar(
         coreState: NavigationState,
         isMuted: Boolean?,
         location: UserLocation?,
-        snappedLocation: UserLocation?,
-        annotation: AnnotationWrapper<*>? = null
+        snappedLocation: UserLocation?
     ): NavigationUiState =
         NavigationUiState(
             snappedLocation = snappedLocation,
@@ -84,7 +82,7 @@ data class NavigationUiState(
             isMuted = isMuted,
             currentStepRoadName = coreState.tripState.currentRoadName(),
             remainingSteps = coreState.tripState.remainingSteps(),
-            currentAnnotation = annotation)
+            currentAnnotation = coreState.tripState.annotation())
   }
 
   fun isNavigating(): Boolean = progress != null
@@ -109,10 +107,8 @@ interface NavigationViewModel {
  * interface that is reused across navigation sessions will probably need to craft their own view
  * model.
  */
-open class DefaultNavigationViewModel(
-    private val ferrostarCore: FerrostarCore,
-    private val annotationPublisher: AnnotationPublisher<*> = NoOpAnnotationPublisher()
-) : ViewModel(), NavigationViewModel {
+open class DefaultNavigationViewModel(private val ferrostarCore: FerrostarCore) :
+    ViewModel(), NavigationViewModel {
 
   private val muteState: StateFlow<Boolean?> =
       ferrostarCore.spokenInstructionObserver?.muteState ?: MutableStateFlow(null)
@@ -120,18 +116,15 @@ open class DefaultNavigationViewModel(
   override val navigationUiState =
       combine(ferrostarCore.state, muteState) { a, b -> a to b }
           .map { (coreState, muteState) ->
-            Triple(coreState, muteState, annotationPublisher.map(coreState))
-          }
-          // The following converts coreState into an annotations wrapped state.
-          .map { (coreState, muteState, annotationWrapper) ->
             val location = ferrostarCore.locationProvider.lastLocation
             val userLocation =
                 when (coreState.tripState) {
                   is TripState.Navigating -> coreState.tripState.snappedUserLocation
+                  is TripState.Arrived -> coreState.tripState.snappedUserLocation
                   is TripState.Complete,
                   TripState.Idle -> ferrostarCore.locationProvider.lastLocation
                 }
-            uiState(coreState, muteState, location, userLocation, annotationWrapper)
+            uiState(coreState, muteState, location, userLocation)
             // This awkward dance is required because Kotlin doesn't have a way to map over
             // StateFlows
             // without converting to a generic Flow in the process.
@@ -144,8 +137,7 @@ open class DefaultNavigationViewModel(
                       ferrostarCore.state.value,
                       ferrostarCore.spokenInstructionObserver?.isMuted,
                       ferrostarCore.locationProvider.lastLocation,
-                      ferrostarCore.locationProvider.lastLocation,
-                      null))
+                      ferrostarCore.locationProvider.lastLocation))
 
   override fun stopNavigation(stopLocationUpdates: Boolean) {
     ferrostarCore.stopNavigation(stopLocationUpdates = stopLocationUpdates)
@@ -166,9 +158,6 @@ open class DefaultNavigationViewModel(
       coreState: NavigationState,
       isMuted: Boolean?,
       location: UserLocation?,
-      snappedLocation: UserLocation?,
-      annotationWrapper: AnnotationWrapper<*>?
-  ) =
-      NavigationUiState.fromFerrostar(
-          coreState, isMuted, location, snappedLocation, annotationWrapper)
+      snappedLocation: UserLocation?
+  ) = NavigationUiState.fromFerrostar(coreState, isMuted, location, snappedLocation)
 }
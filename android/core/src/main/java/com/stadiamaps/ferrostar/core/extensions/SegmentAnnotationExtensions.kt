@@ -0,0 +1,27 @@
+package com.stadiamaps.ferrostar.core.extensions
+
+import com.stadiamaps.ferrostar.core.measurement.MeasurementSpeed
+import com.stadiamaps.ferrostar.core.measurement.MeasurementSpeedUnit
+import uniffi.ferrostar.SegmentAnnotation
+import uniffi.ferrostar.SpeedLimit
+import uniffi.ferrostar.SpeedUnit
+
+/**
+ * Get the posted speed limit for the segment.
+ *
+ * @return The speed limit, or null if the segment has no speed limit or it is unknown.
+ */
+fun SegmentAnnotation.speedLimit(): MeasurementSpeed? =
+    when (val maxSpeed = this.maxSpeed) {
+      is SpeedLimit.Limited ->
+          MeasurementSpeed(
+              maxSpeed.value,
+              when (maxSpeed.unit) {
+                SpeedUnit.KILOMETERS_PER_HOUR -> MeasurementSpeedUnit.KilometersPerHour
+                SpeedUnit.MILES_PER_HOUR -> MeasurementSpeedUnit.MilesPerHour
+                SpeedUnit.KNOTS -> MeasurementSpeedUnit.Knots
+              })
+      SpeedLimit.NoLimit,
+      SpeedLimit.Unknown,
+      null -> null
+    }
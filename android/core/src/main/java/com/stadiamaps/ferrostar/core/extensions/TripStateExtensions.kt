@@ -10,6 +10,7 @@ import uniffi.ferrostar.TripState
 fun TripState.progress() =
     when (this) {
       is TripState.Navigating -> this.progress
+      is TripState.Arrived,
       is TripState.Complete,
       TripState.Idle -> null
     }
@@ -23,6 +24,7 @@ fun TripState.visualInstruction() =
     try {
       when (this) {
         is TripState.Navigating -> this.visualInstruction
+        is TripState.Arrived,
         is TripState.Complete,
         TripState.Idle -> null
       }
@@ -38,6 +40,7 @@ fun TripState.visualInstruction() =
 fun TripState.deviation() =
     when (this) {
       is TripState.Navigating -> this.deviation
+      is TripState.Arrived,
       is TripState.Complete,
       TripState.Idle -> null
     }
@@ -57,6 +60,7 @@ fun TripState.currentRoadName() =
               it
             }
           }
+      is TripState.Arrived,
       is TripState.Complete,
       TripState.Idle -> null
     }
@@ -69,6 +73,20 @@ fun TripState.currentRoadName() =
 fun TripState.remainingSteps() =
     when (this) {
       is TripState.Navigating -> this.remainingSteps
+      is TripState.Arrived -> this.remainingSteps
+      is TripState.Complete,
+      TripState.Idle -> null
+    }
+
+/**
+ * Get the annotation for the route segment at the user's current location.
+ *
+ * @return The annotation (if available and navigating).
+ */
+fun TripState.annotation() =
+    when (this) {
+      is TripState.Navigating -> this.annotation
+      is TripState.Arrived,
       is TripState.Complete,
       TripState.Idle -> null
     }
@@ -4,10 +4,6 @@ import androidx.lifecycle.ViewModel
 import com.stadiamaps.ferrostar.core.NavigationState
 import com.stadiamaps.ferrostar.core.NavigationUiState
 import com.stadiamaps.ferrostar.core.NavigationViewModel
-import com.stadiamaps.ferrostar.core.annotation.AnnotationWrapper
-import com.stadiamaps.ferrostar.core.annotation.Speed as SpeedLimit
-import com.stadiamaps.ferrostar.core.annotation.SpeedUnit
-import com.stadiamaps.ferrostar.core.annotation.valhalla.ValhallaOSRMExtendedAnnotation
 import java.time.Instant
 import kotlinx.coroutines.flow.StateFlow
 import uniffi.ferrostar.CourseOverGround
@@ -15,9 +11,14 @@ import uniffi.ferrostar.GeographicCoordinate
 import uniffi.ferrostar.ManeuverModifier
 import uniffi.ferrostar.ManeuverType
 import uniffi.ferrostar.RouteDeviation
+import uniffi.ferrostar.SegmentAnnotation
 import uniffi.ferrostar.Speed
+import uniffi.ferrostar.SpeedLimit
+import uniffi.ferrostar.SpeedUnit
+import uniffi.ferrostar.TripBookkeeping
 import uniffi.ferrostar.TripProgress
 import uniffi.ferrostar.TripState
+import uniffi.ferrostar.TripStatistics
 import uniffi.ferrostar.UserLocation
 import uniffi.ferrostar.VisualInstruction
 import uniffi.ferrostar.VisualInstructionContent
@@ -32,14 +33,16 @@ fun UserLocation.Companion.pedestrianExample(): UserLocation {
       speed = Speed(1.0, 1.0))
 }
 
-fun AnnotationWrapper.Companion.pedestrianExample():
-    AnnotationWrapper<ValhallaOSRMExtendedAnnotation> {
-  return AnnotationWrapper(
-      ValhallaOSRMExtendedAnnotation(
-          speedLimit = SpeedLimit.Value(40.0, SpeedUnit.KILOMETERS_PER_HOUR),
-          speed = 1.0,
-          distance = 1.0,
-          duration = 1.0))
+/** Mocked example for UI testing. */
+fun SegmentAnnotation.Companion.pedestrianExample(): SegmentAnnotation {
+  return SegmentAnnotation(
+      distance = 1.0,
+      duration = 1.0,
+      speed = 1.0,
+      maxSpeed = SpeedLimit.Limited(40.0, SpeedUnit.KILOMETERS_PER_HOUR),
+      congestion = null,
+      wayId = null,
+      extensions = emptyMap())
 }
 
 /** Mocked example for UI testing. */
@@ -72,7 +75,14 @@ fun NavigationState.Companion.pedestrianExample(): NavigationState {
                       triggerDistanceBeforeManeuver = 0.0,
                   ),
               spokenInstruction = null,
-              annotationJson = null),
+              annotation = SegmentAnnotation.pedestrianExample(),
+              speedLimit = null,
+              isSpeeding = false,
+              isLocationEstimated = false,
+              upcomingEvents = null,
+              completedWaypoints = listOf(),
+              statistics = TripStatistics(),
+              bookkeeping = TripBookkeeping()),
       routeGeometry = listOf(),
       isCalculatingNewRoute = false)
 }
@@ -82,8 +92,7 @@ fun NavigationUiState.Companion.pedestrianExample(): NavigationUiState =
         NavigationState.pedestrianExample(),
         false,
         UserLocation.pedestrianExample(),
-        UserLocation.pedestrianExample(),
-        AnnotationWrapper.pedestrianExample())
+        UserLocation.pedestrianExample())
 
 class MockNavigationViewModel(override val navigationUiState: StateFlow<NavigationUiState>) :
     ViewModel(), NavigationViewModel {
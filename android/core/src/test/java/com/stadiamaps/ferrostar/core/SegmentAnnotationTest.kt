@@ -0,0 +1,39 @@
+package com.stadiamaps.ferrostar.core
+
+import com.stadiamaps.ferrostar.core.extensions.speedLimit
+import com.stadiamaps.ferrostar.core.measurement.MeasurementSpeedUnit
+import org.junit.Assert.assertEquals
+import org.junit.Assert.assertNull
+import org.junit.Test
+import uniffi.ferrostar.SegmentAnnotation
+import uniffi.ferrostar.SpeedLimit
+import uniffi.ferrostar.SpeedUnit
+
+class SegmentAnnotationTest {
+
+  private fun annotation(maxSpeed: SpeedLimit?) =
+      SegmentAnnotation(
+          distance = null,
+          duration = null,
+          speed = null,
+          maxSpeed = maxSpeed,
+          congestion = null,
+          wayId = null,
+          extensions = emptyMap())
+
+  @Test
+  fun testSpeedLimit() {
+    val speedLimit =
+        annotation(SpeedLimit.Limited(15.0, SpeedUnit.MILES_PER_HOUR)).speedLimit()
+
+    assertEquals(15.0, speedLimit?.value)
+    assertEquals(MeasurementSpeedUnit.MilesPerHour, speedLimit?.unit)
+  }
+
+  @Test
+  fun testNoSpeedLimit() {
+    assertNull(annotation(SpeedLimit.NoLimit).speedLimit())
+    assertNull(annotation(SpeedLimit.Unknown).speedLimit())
+    assertNull(annotation(null).speedLimit())
+  }
+}
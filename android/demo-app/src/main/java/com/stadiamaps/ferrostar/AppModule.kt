@@ -16,10 +16,10 @@ import java.net.URL
 import java.time.Duration
 import okhttp3.OkHttpClient
 import uniffi.ferrostar.CourseFiltering
-import uniffi.ferrostar.NavigationControllerConfig
 import uniffi.ferrostar.RouteDeviationTracking
 import uniffi.ferrostar.SpecialAdvanceConditions
 import uniffi.ferrostar.StepAdvanceMode
+import uniffi.ferrostar.createNavigationControllerConfig
 
 /**
  * A basic sample of a dependency injection module for the demo app. This is only used to
@@ -85,7 +85,7 @@ object AppModule {
             locationProvider = locationProvider,
             foregroundServiceManager = foregroundServiceManager,
             navigationControllerConfig =
-                NavigationControllerConfig(
+                createNavigationControllerConfig(
                     StepAdvanceMode.RelativeLineStringDistance(
                         minimumHorizontalAccuracy = 25U,
                         specialAdvanceConditions =
@@ -93,7 +93,7 @@ object AppModule {
                             // testing
                             SpecialAdvanceConditions.MinimumDistanceFromCurrentStepLine(10U)),
                     RouteDeviationTracking.StaticThreshold(15U, 50.0),
-                    CourseFiltering.SNAP_TO_ROUTE),
+                    CourseFiltering.SnapToRoute),
             options =
                 mapOf(
                     "costingOptions" to
@@ -7,8 +7,6 @@ import com.stadiamaps.ferrostar.core.FerrostarCore
 import com.stadiamaps.ferrostar.core.LocationProvider
 import com.stadiamaps.ferrostar.core.LocationUpdateListener
 import com.stadiamaps.ferrostar.core.NavigationUiState
-import com.stadiamaps.ferrostar.core.annotation.AnnotationPublisher
-import com.stadiamaps.ferrostar.core.annotation.valhalla.valhallaExtendedOSRMAnnotationPublisher
 import com.stadiamaps.ferrostar.core.isNavigating
 import java.util.concurrent.Executors
 import kotlinx.coroutines.flow.MutableStateFlow
@@ -24,9 +22,8 @@ import uniffi.ferrostar.UserLocation
 class DemoNavigationViewModel(
     // This is a simple example, but these would typically be dependency injected
     val ferrostarCore: FerrostarCore = AppModule.ferrostarCore,
-    val locationProvider: LocationProvider = AppModule.locationProvider,
-    annotationPublisher: AnnotationPublisher<*> = valhallaExtendedOSRMAnnotationPublisher()
-) : DefaultNavigationViewModel(ferrostarCore, annotationPublisher), LocationUpdateListener {
+    val locationProvider: LocationProvider = AppModule.locationProvider
+) : DefaultNavigationViewModel(ferrostarCore), LocationUpdateListener {
   private val locationStateFlow = MutableStateFlow<UserLocation?>(null)
   private val executor = Executors.newSingleThreadScheduledExecutor()
 
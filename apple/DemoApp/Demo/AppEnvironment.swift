@@ -49,11 +49,7 @@ class AppEnvironment: ObservableObject {
             profile: "bicycle",
             locationProvider: locationProvider,
             navigationControllerConfig: config,
-            options: ["costing_options": ["bicycle": ["use_roads": 0.2]]],
-            // This is how you can set up annotation publishing;
-            // We provide "extended OSRM" support out of the box,
-            // but this is fully extendable!
-            annotation: AnnotationPublisher<ValhallaExtendedOSRMAnnotation>.valhallaExtendedOSRM()
+            options: ["costing_options": ["bicycle": ["use_roads": 0.2]]]
         )
 
         // NOTE: Not all applications will need a delegate. Read the NavigationDelegate documentation for details.
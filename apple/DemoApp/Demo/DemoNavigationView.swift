@@ -50,7 +50,7 @@ struct DemoNavigationView: View {
             )
             .navigationSpeedLimit(
                 // Configure speed limit signage based on user preference or location
-                speedLimit: ferrostarCore.annotation?.speedLimit,
+                speedLimit: ferrostarCore.state?.currentSpeedLimit,
                 speedLimitStyle: .mutcdStyle
             )
             .innerGrid(
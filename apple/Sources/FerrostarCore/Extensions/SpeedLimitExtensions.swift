@@ -0,0 +1,24 @@
+import FerrostarCoreFFI
+import Foundation
+
+public extension SpeedLimit {
+    /// The speed limit as a measurement.
+    ///
+    /// Segments with no speed limit are reported as an infinite speed,
+    /// and unknown speed limits are `nil`.
+    var measurementValue: Measurement<UnitSpeed>? {
+        switch self {
+        case .noLimit: .init(value: .infinity, unit: .kilometersPerHour)
+        case .unknown: nil
+        case let .limited(value, unit):
+            switch unit {
+            case .kilometersPerHour:
+                .init(value: value, unit: .kilometersPerHour)
+            case .milesPerHour:
+                .init(value: value, unit: .milesPerHour)
+            case .knots:
+                .init(value: value, unit: .knots)
+            }
+        }
+    }
+}
@@ -81,8 +81,6 @@ public protocol FerrostarCoreDelegate: AnyObject {
     /// The observable state of the model (for easy binding in SwiftUI views).
     @Published public private(set) var state: NavigationState?
 
-    public let annotation: (any AnnotationPublishing)?
-
     private let networkSession: URLRequestLoading
     private let routeProvider: RouteProvider
     private let locationProvider: LocationProviding
@@ -106,28 +104,21 @@ public protocol FerrostarCoreDelegate: AnyObject {
     /// updates.
     ///   - navigationControllerConfig: Configure the behavior of the navigation controller.
     ///   - networkSession: The network session to run route fetches on. A custom ``RouteProvider`` may not use this.
-    ///   - annotation: An implementation of the annotation publisher that transforms custom annotation JSON into
-    /// published values of defined swift types.
     public init(
         routeProvider: RouteProvider,
         locationProvider: LocationProviding,
         navigationControllerConfig: SwiftNavigationControllerConfig,
-        networkSession: URLRequestLoading,
-        annotation: (any AnnotationPublishing)? = nil
+        networkSession: URLRequestLoading
     ) {
         self.routeProvider = routeProvider
         self.locationProvider = locationProvider
         config = navigationControllerConfig
         self.networkSession = networkSession
-        self.annotation = annotation
 
         super.init()
 
         // Location provider setup
         locationProvider.delegate = self
-
-        // Annotation publisher setup
-        self.annotation?.configure($state)
     }
 
     /// Initializes a core instance for a Valhalla API accessed over HTTP.
@@ -141,16 +132,13 @@ public protocol FerrostarCoreDelegate: AnyObject {
     /// automatically (like `format`), but this lets you add arbitrary options so you can access the full API.
     ///   - networkSession: The network session to use. Don't set this unless you need to replace the networking stack
     /// (ex: for testing).
-    ///   - annotation: An implementation of the annotation publisher that transforms custom annotation JSON into
-    /// published values of defined swift types.
     public convenience init(
         valhallaEndpointUrl: URL,
         profile: String,
         locationProvider: LocationProviding,
         navigationControllerConfig: SwiftNavigationControllerConfig,
         options: [String: Any] = [:],
-        networkSession: URLRequestLoading = URLSession.shared,
-        annotation: (any AnnotationPublishing)? = nil
+        networkSession: URLRequestLoading = URLSession.shared
     ) throws {
         guard let jsonOptions = try String(
             data: JSONSerialization.data(withJSONObject: options),
@@ -168,8 +156,7 @@ public protocol FerrostarCoreDelegate: AnyObject {
             routeProvider: .routeAdapter(adapter),
             locationProvider: locationProvider,
             navigationControllerConfig: navigationControllerConfig,
-            networkSession: networkSession,
-            annotation: annotation
+            networkSession: networkSession
         )
     }
 
@@ -177,15 +164,13 @@ public protocol FerrostarCoreDelegate: AnyObject {
         routeAdapter: RouteAdapterProtocol,
         locationProvider: LocationProviding,
         navigationControllerConfig: SwiftNavigationControllerConfig,
-        networkSession: URLRequestLoading = URLSession.shared,
-        annotation: (any AnnotationPublishing)? = nil
+        networkSession: URLRequestLoading = URLSession.shared
     ) {
         self.init(
             routeProvider: .routeAdapter(routeAdapter),
             locationProvider: locationProvider,
             navigationControllerConfig: navigationControllerConfig,
-            networkSession: networkSession,
-            annotation: annotation
+            networkSession: networkSession
         )
     }
 
@@ -193,15 +178,13 @@ public protocol FerrostarCoreDelegate: AnyObject {
         customRouteProvider: CustomRouteProvider,
         locationProvider: LocationProviding,
         navigationControllerConfig: SwiftNavigationControllerConfig,
-        networkSession: URLRequestLoading = URLSession.shared,
-        annotation: (any AnnotationPublishing)? = nil
+        networkSession: URLRequestLoading = URLSession.shared
     ) {
         self.init(
             routeProvider: .customProvider(customRouteProvider),
             locationProvider: locationProvider,
             navigationControllerConfig: navigationControllerConfig,
-            networkSession: networkSession,
-            annotation: annotation
+            networkSession: networkSession
         )
     }
 
@@ -279,6 +262,20 @@ public protocol FerrostarCoreDelegate: AnyObject {
         update(newState: newState, location: lastLocation)
     }
 
+    /// Resumes navigation after the user has arrived at a waypoint.
+    ///
+    /// This only has an effect when the trip state is `.arrived`
+    /// (see the arrival detection in the navigation controller config).
+    /// Navigation continues with the next leg of the route, or completes the trip at the final destination.
+    public func resumeNavigation() {
+        guard let controller = navigationController, let tripState = state?.tripState, let lastLocation else {
+            return
+        }
+
+        let newState = controller.resumeNavigation(location: lastLocation, state: tripState)
+        update(newState: newState, location: lastLocation)
+    }
+
     // TODO: Ability to pause without totally stopping and clearing state
 
     /// Stops navigation and stops requesting location updates (to save battery).
@@ -307,7 +304,14 @@ public protocol FerrostarCoreDelegate: AnyObject {
                 deviation: deviation,
                 visualInstruction: _,
                 spokenInstruction: spokenInstruction,
-                annotationJson: _
+                annotation: _,
+                speedLimit: _,
+                isSpeeding: _,
+                isLocationEstimated: _,
+                upcomingEvents: _,
+                completedWaypoints: _,
+                statistics: _,
+                bookkeeping: _
             ):
                 switch deviation {
                 case .noDeviation:
@@ -26,7 +26,14 @@ public extension NavigationState {
             deviation: .noDeviation,
             visualInstruction: nil,
             spokenInstruction: nil,
-            annotationJson: nil
+            annotation: nil,
+            speedLimit: nil,
+            isSpeeding: false,
+            isLocationEstimated: false,
+            upcomingEvents: nil,
+            completedWaypoints: [],
+            statistics: TripStatistics(),
+            bookkeeping: TripBookkeeping()
         ),
         routeGeometry: samplePedestrianWaypoints,
         isCalculatingNewRoute: false
@@ -83,7 +90,14 @@ public extension NavigationState {
                 deviation: .noDeviation,
                 visualInstruction: nil,
                 spokenInstruction: nil,
-                annotationJson: nil
+                annotation: nil,
+                speedLimit: nil,
+                isSpeeding: false,
+                isLocationEstimated: false,
+                upcomingEvents: nil,
+                completedWaypoints: [],
+                statistics: TripStatistics(),
+                bookkeeping: TripBookkeeping()
             ),
             routeGeometry: samplePedestrianWaypoints,
             isCalculatingNewRoute: false
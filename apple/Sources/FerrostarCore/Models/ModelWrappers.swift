@@ -46,7 +46,7 @@ public struct SwiftNavigationControllerConfig {
                 routeDeviationTracking: SwiftRouteDeviationTracking,
                 snappedLocationCourseFiltering: CourseFiltering)
     {
-        ffiValue = FerrostarCoreFFI.NavigationControllerConfig(
+        ffiValue = FerrostarCoreFFI.createNavigationControllerConfig(
             stepAdvance: stepAdvance,
             routeDeviationTracking: routeDeviationTracking.ffiValue,
             snappedLocationCourseFiltering: snappedLocationCourseFiltering
@@ -23,7 +23,7 @@ public struct NavigationState: Hashable {
 
     public var currentProgress: TripProgress? {
         guard case let .navigating(_, _, _, _, progress, _, _,
-                                   _, _) = tripState
+                                   _, _, _, _, _, _, _, _, _) = tripState
         else {
             return nil
         }
@@ -32,7 +32,7 @@ public struct NavigationState: Hashable {
     }
 
     public var currentVisualInstruction: VisualInstruction? {
-        guard case let .navigating(_, _, _, _, _, _, visualInstruction, _, _) = tripState else {
+        guard case let .navigating(_, _, _, _, _, _, visualInstruction, _, _, _, _, _, _, _, _, _) = tripState else {
             return nil
         }
 
@@ -40,27 +40,32 @@ public struct NavigationState: Hashable {
     }
 
     public var remainingSteps: [RouteStep]? {
-        guard case let .navigating(_, _, remainingSteps, _, _, _, _, _, _) = tripState else {
+        guard case let .navigating(_, _, remainingSteps, _, _, _, _, _, _, _, _, _, _, _, _, _) = tripState else {
             return nil
         }
 
         return remainingSteps
     }
 
-    /// The current geometry segment's annotations in a JSON string.
+    /// The annotation for the current geometry segment.
     ///
     /// A segment is the line between two coordinates on the geometry.
-    public var currentAnnotationJSON: String? {
-        guard case let .navigating(_, _, _, _, _, _, _, _, annotationJson) = tripState else {
+    public var currentAnnotation: SegmentAnnotation? {
+        guard case let .navigating(_, _, _, _, _, _, _, _, annotation, _, _, _, _, _, _, _) = tripState else {
             return nil
         }
 
-        return annotationJson
+        return annotation
+    }
+
+    /// The speed limit of the current geometry segment (if known).
+    public var currentSpeedLimit: Measurement<UnitSpeed>? {
+        currentAnnotation?.maxSpeed?.measurementValue
     }
 
     public var isNavigating: Bool {
         switch tripState {
-        case .navigating:
+        case .navigating, .arrived:
             true
         case .complete, .idle:
             false
@@ -68,7 +73,7 @@ public struct NavigationState: Hashable {
     }
 
     public var currentRoadName: String? {
-        guard case let .navigating(_, _, remainingSteps, _, _, _, _, _, _) = tripState else {
+        guard case let .navigating(_, _, remainingSteps, _, _, _, _, _, _, _, _, _, _, _, _, _) = tripState else {
             return nil
         }
 
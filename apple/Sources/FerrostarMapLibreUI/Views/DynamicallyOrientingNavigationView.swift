@@ -164,7 +164,8 @@ public struct DynamicallyOrientingNavigationView: View, CustomizableNavigatingIn
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .imperial
 
-    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _, _) = state.tripState else {
+    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _,
+                               _, _, _, _, _, _, _, _) = state.tripState else {
         return EmptyView()
     }
 
@@ -185,7 +186,8 @@ public struct DynamicallyOrientingNavigationView: View, CustomizableNavigatingIn
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .metric
 
-    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _, _) = state.tripState else {
+    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _,
+                               _, _, _, _, _, _, _, _) = state.tripState else {
         return EmptyView()
     }
 
@@ -127,7 +127,8 @@ public struct LandscapeNavigationView: View, CustomizableNavigatingInnerGridView
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .imperial
 
-    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _, _) = state.tripState else {
+    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _,
+                               _, _, _, _, _, _, _, _) = state.tripState else {
         return EmptyView()
     }
 
@@ -150,7 +151,8 @@ public struct LandscapeNavigationView: View, CustomizableNavigatingInnerGridView
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .metric
 
-    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _, _) = state.tripState else {
+    guard case let .navigating(_, snappedUserLocation: userLocation, _, _, _, _, _, _,
+                               _, _, _, _, _, _, _, _) = state.tripState else {
         return EmptyView()
     }
 
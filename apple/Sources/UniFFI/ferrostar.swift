@@ -621,7 +621,8 @@ fileprivate struct FfiConverterTimestamp: FfiConverterRustBuffer {
  * returning an updated state given inputs like user location.
  *
  * Notes for implementing a new platform:
- * - A controller is bound to a single route; if you want recalculation, create a new instance.
+ * - A controller is bound to a single route; if you want recalculation, create a new instance
+ * for the new route and use [`NavigationController::get_rerouted_state`] to carry over the trip context.
  * - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
  */
 public protocol NavigationControllerProtocol : AnyObject {
@@ -643,6 +644,93 @@ public protocol NavigationControllerProtocol : AnyObject {
      */
     func getInitialState(location: UserLocation)  -> TripState
     
+    /**
+     * Returns the initial trip state for this controller's route,
+     * carrying over the trip context from a state that was produced while navigating a previous route.
+     *
+     * Use this instead of [`NavigationController::get_initial_state`] when the route of an in-progress trip
+     * is replaced (ex: after rerouting, or when the user accepts an alternative route).
+     * Create a new controller for the replacement route,
+     * and pass it the last state from the controller for the route being replaced.
+     *
+     * The completed waypoints, the [`TripStatistics`] so far
+     * (counting this as one more reroute),
+     * and the utterance IDs of spoken instructions which have already been triggered
+     * are carried over from `previous_state`.
+     * Since routes are parsed with fresh utterance IDs, spoken instructions for the first step
+     * of the new route are also considered triggered if an instruction with the same text
+     * and trigger distance was triggered for the current step of the previous route.
+     * Everything else (remaining steps and waypoints, progress, instructions, etc.)
+     * is computed fresh for the new route.
+     *
+     * NOTE: The first waypoint of the replacement route is assumed to be
+     * the user's location at the time of the route request,
+     * and is not added to the list of completed waypoints.
+     *
+     * If `previous_state` is not [`TripState::Navigating`] or [`TripState::Arrived`],
+     * this is equivalent to [`NavigationController::get_initial_state`].
+     */
+    func getReroutedState(location: UserLocation, previousState: TripState)  -> TripState
+    
+    /**
+     * Predicts the trip state at a later time by dead reckoning,
+     * for when location updates stop or degrade (ex: in tunnels or urban canyons).
+     *
+     * The user is assumed to keep moving along the route from the snapped location in `state`
+     * at their last known speed
+     * (or the speed the routing engine expects on the current step, if the user's speed is unknown).
+     * Everything which follows from the location (progress, instructions, the speed limit, etc.)
+     * is updated for the predicted location, and the resulting state has `is_location_estimated` set.
+     * Spoken instructions are surfaced as usual, but not recorded as announced
+     * (the utterance ID stays the same, so it can be used to avoid repeating them).
+     *
+     * The rest of the trip is left alone:
+     * the current step never advances (the prediction stops at the end of it),
+     * and the statistics and bookkeeping are unchanged,
+     * as only real location updates should affect these.
+     * Call this frequently (ex: every second) while location updates are unavailable.
+     * Predictions can be chained by passing a predicted state back in.
+     * The next real location update (see [`NavigationController::update_user_location`])
+     * replaces the estimate.
+     *
+     * The state is returned unchanged if it is not [`TripState::Navigating`],
+     * if the user is off route, if `timestamp` is not after the last location,
+     * or if there is no speed to go on.
+     */
+    func predictStateAt(state: TripState, timestamp: Date)  -> TripState
+    
+    /**
+     * Resumes navigation after the user has arrived at a waypoint.
+     *
+     * Navigation continues from the start of the next route leg.
+     * If the user arrived at the final destination, the trip is complete.
+     *
+     * If `state` is not [`TripState::Arrived`], it is returned unchanged.
+     */
+    func resumeNavigation(location: UserLocation, state: TripState)  -> TripState
+    
+    /**
+     * Returns up to `max_count` of the nearest incidents (and separately, congestion spans)
+     * on the route ahead of the user, within `max_distance` meters.
+     *
+     * Distances are measured along the route from the snapped user location.
+     * See [`find_upcoming_events`](crate::algorithms::find_upcoming_events) for details.
+     *
+     * Returns [`None`] if `state` is not [`TripState::Navigating`].
+     */
+    func upcomingEvents(state: TripState, maxCount: UInt16, maxDistance: Double)  -> UpcomingEvents?
+    
+    /**
+     * Updates the compass heading of the user's device.
+     *
+     * With [`CourseFiltering::FuseHeading`], the course of the snapped user location
+     * is updated right away, so that it stays current while the user is stationary.
+     * Otherwise, the heading is only recorded in the trip state's bookkeeping.
+     *
+     * Headings older than the current one are ignored.
+     */
+    func updateHeading(heading: Heading, state: TripState)  -> TripState
+    
     /**
      * Updates the user's current location and updates the navigation state accordingly.
      *
@@ -653,6 +741,12 @@ public protocol NavigationControllerProtocol : AnyObject {
      */
     func updateUserLocation(location: UserLocation, state: TripState)  -> TripState
     
+    /**
+     * Updates the user's current location (see [`NavigationController::update_user_location`]),
+     * and reports the [`NavigationEvent`]s which occurred as a result.
+     */
+    func updateUserLocationWithEvents(location: UserLocation, state: TripState)  -> NavigationUpdate
+    
 }
 
 /**
@@ -660,7 +754,8 @@ public protocol NavigationControllerProtocol : AnyObject {
  * returning an updated state given inputs like user location.
  *
  * Notes for implementing a new platform:
- * - A controller is bound to a single route; if you want recalculation, create a new instance.
+ * - A controller is bound to a single route; if you want recalculation, create a new instance
+ * for the new route and use [`NavigationController::get_rerouted_state`] to carry over the trip context.
  * - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
  */
 open class NavigationController:
@@ -752,6 +847,129 @@ open func getInitialState(location: UserLocation) -> TripState {
         FfiConverterTypeUserLocation.lower(location),$0
     )
 })
+}
+    
+    /**
+     * Returns the initial trip state for this controller's route,
+     * carrying over the trip context from a state that was produced while navigating a previous route.
+     *
+     * Use this instead of [`NavigationController::get_initial_state`] when the route of an in-progress trip
+     * is replaced (ex: after rerouting, or when the user accepts an alternative route).
+     * Create a new controller for the replacement route,
+     * and pass it the last state from the controller for the route being replaced.
+     *
+     * The completed waypoints, the [`TripStatistics`] so far
+     * (counting this as one more reroute),
+     * and the utterance IDs of spoken instructions which have already been triggered
+     * are carried over from `previous_state`.
+     * Since routes are parsed with fresh utterance IDs, spoken instructions for the first step
+     * of the new route are also considered triggered if an instruction with the same text
+     * and trigger distance was triggered for the current step of the previous route.
+     * Everything else (remaining steps and waypoints, progress, instructions, etc.)
+     * is computed fresh for the new route.
+     *
+     * NOTE: The first waypoint of the replacement route is assumed to be
+     * the user's location at the time of the route request,
+     * and is not added to the list of completed waypoints.
+     *
+     * If `previous_state` is not [`TripState::Navigating`] or [`TripState::Arrived`],
+     * this is equivalent to [`NavigationController::get_initial_state`].
+     */
+open func getReroutedState(location: UserLocation, previousState: TripState) -> TripState {
+    return try!  FfiConverterTypeTripState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_get_rerouted_state(self.uniffiClonePointer(),
+        FfiConverterTypeUserLocation.lower(location),
+        FfiConverterTypeTripState.lower(previousState),$0
+    )
+})
+}
+    
+    /**
+     * Predicts the trip state at a later time by dead reckoning,
+     * for when location updates stop or degrade (ex: in tunnels or urban canyons).
+     *
+     * The user is assumed to keep moving along the route from the snapped location in `state`
+     * at their last known speed
+     * (or the speed the routing engine expects on the current step, if the user's speed is unknown).
+     * Everything which follows from the location (progress, instructions, the speed limit, etc.)
+     * is updated for the predicted location, and the resulting state has `is_location_estimated` set.
+     * Spoken instructions are surfaced as usual, but not recorded as announced
+     * (the utterance ID stays the same, so it can be used to avoid repeating them).
+     *
+     * The rest of the trip is left alone:
+     * the current step never advances (the prediction stops at the end of it),
+     * and the statistics and bookkeeping are unchanged,
+     * as only real location updates should affect these.
+     * Call this frequently (ex: every second) while location updates are unavailable.
+     * Predictions can be chained by passing a predicted state back in.
+     * The next real location update (see [`NavigationController::update_user_location`])
+     * replaces the estimate.
+     *
+     * The state is returned unchanged if it is not [`TripState::Navigating`],
+     * if the user is off route, if `timestamp` is not after the last location,
+     * or if there is no speed to go on.
+     */
+open func predictStateAt(state: TripState, timestamp: Date) -> TripState {
+    return try!  FfiConverterTypeTripState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_predict_state_at(self.uniffiClonePointer(),
+        FfiConverterTypeTripState.lower(state),
+        FfiConverterTimestamp.lower(timestamp),$0
+    )
+})
+}
+    
+    /**
+     * Resumes navigation after the user has arrived at a waypoint.
+     *
+     * Navigation continues from the start of the next route leg.
+     * If the user arrived at the final destination, the trip is complete.
+     *
+     * If `state` is not [`TripState::Arrived`], it is returned unchanged.
+     */
+open func resumeNavigation(location: UserLocation, state: TripState) -> TripState {
+    return try!  FfiConverterTypeTripState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_resume_navigation(self.uniffiClonePointer(),
+        FfiConverterTypeUserLocation.lower(location),
+        FfiConverterTypeTripState.lower(state),$0
+    )
+})
+}
+    
+    /**
+     * Returns up to `max_count` of the nearest incidents (and separately, congestion spans)
+     * on the route ahead of the user, within `max_distance` meters.
+     *
+     * Distances are measured along the route from the snapped user location.
+     * See [`find_upcoming_events`](crate::algorithms::find_upcoming_events) for details.
+     *
+     * Returns [`None`] if `state` is not [`TripState::Navigating`].
+     */
+open func upcomingEvents(state: TripState, maxCount: UInt16, maxDistance: Double) -> UpcomingEvents? {
+    return try!  FfiConverterOptionTypeUpcomingEvents.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_upcoming_events(self.uniffiClonePointer(),
+        FfiConverterTypeTripState.lower(state),
+        FfiConverterUInt16.lower(maxCount),
+        FfiConverterDouble.lower(maxDistance),$0
+    )
+})
+}
+    
+    /**
+     * Updates the compass heading of the user's device.
+     *
+     * With [`CourseFiltering::FuseHeading`], the course of the snapped user location
+     * is updated right away, so that it stays current while the user is stationary.
+     * Otherwise, the heading is only recorded in the trip state's bookkeeping.
+     *
+     * Headings older than the current one are ignored.
+     */
+open func updateHeading(heading: Heading, state: TripState) -> TripState {
+    return try!  FfiConverterTypeTripState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_update_heading(self.uniffiClonePointer(),
+        FfiConverterTypeHeading.lower(heading),
+        FfiConverterTypeTripState.lower(state),$0
+    )
+})
 }
     
     /**
@@ -771,6 +989,19 @@ open func updateUserLocation(location: UserLocation, state: TripState) -> TripSt
 })
 }
     
+    /**
+     * Updates the user's current location (see [`NavigationController::update_user_location`]),
+     * and reports the [`NavigationEvent`]s which occurred as a result.
+     */
+open func updateUserLocationWithEvents(location: UserLocation, state: TripState) -> NavigationUpdate {
+    return try!  FfiConverterTypeNavigationUpdate.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationcontroller_update_user_location_with_events(self.uniffiClonePointer(),
+        FfiConverterTypeUserLocation.lower(location),
+        FfiConverterTypeTripState.lower(state),$0
+    )
+})
+}
+    
 
 }
 
@@ -828,6 +1059,188 @@ public func FfiConverterTypeNavigationController_lower(_ value: NavigationContro
 
 
 
+/**
+ * Decides when a new route should be requested, given a sequence of trip states.
+ *
+ * Create one instance per trip, and keep using it across reroutes
+ * (ex: when the trip state is carried over via
+ * [`NavigationController::get_rerouted_state`](crate::navigation_controller::NavigationController::get_rerouted_state)).
+ */
+public protocol ReroutePolicyProtocol : AnyObject {
+    
+    /**
+     * Returns the state of the policy at the start of a trip.
+     */
+    func getInitialState()  -> ReroutePolicyState
+    
+    /**
+     * Evaluates a new trip state, returning the updated policy state.
+     *
+     * The [`ReroutePolicyState::decision`] field of the result says whether a new route
+     * should be requested now.
+     * When it is [`RerouteDecision::RequestReroute`],
+     * the time and distance of the request are recorded immediately,
+     * so subsequent updates will wait for the configured time and distance
+     * before requesting another one.
+     */
+    func update(tripState: TripState, previousState: ReroutePolicyState)  -> ReroutePolicyState
+    
+}
+
+/**
+ * Decides when a new route should be requested, given a sequence of trip states.
+ *
+ * Create one instance per trip, and keep using it across reroutes
+ * (ex: when the trip state is carried over via
+ * [`NavigationController::get_rerouted_state`](crate::navigation_controller::NavigationController::get_rerouted_state)).
+ */
+open class ReroutePolicy:
+    ReroutePolicyProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_ferrostar_fn_clone_reroutepolicy(self.pointer, $0) }
+    }
+    /**
+     * Create a reroute policy with the given configuration.
+     */
+public convenience init(config: ReroutePolicyConfig) {
+    let pointer =
+        try! rustCall() {
+    uniffi_ferrostar_fn_constructor_reroutepolicy_new(
+        FfiConverterTypeReroutePolicyConfig.lower(config),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_ferrostar_fn_free_reroutepolicy(pointer, $0) }
+    }
+
+    
+
+    
+    /**
+     * Returns the state of the policy at the start of a trip.
+     */
+open func getInitialState() -> ReroutePolicyState {
+    return try!  FfiConverterTypeReroutePolicyState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_reroutepolicy_get_initial_state(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+    /**
+     * Evaluates a new trip state, returning the updated policy state.
+     *
+     * The [`ReroutePolicyState::decision`] field of the result says whether a new route
+     * should be requested now.
+     * When it is [`RerouteDecision::RequestReroute`],
+     * the time and distance of the request are recorded immediately,
+     * so subsequent updates will wait for the configured time and distance
+     * before requesting another one.
+     */
+open func update(tripState: TripState, previousState: ReroutePolicyState) -> ReroutePolicyState {
+    return try!  FfiConverterTypeReroutePolicyState.lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_reroutepolicy_update(self.uniffiClonePointer(),
+        FfiConverterTypeTripState.lower(tripState),
+        FfiConverterTypeReroutePolicyState.lower(previousState),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReroutePolicy: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = ReroutePolicy
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ReroutePolicy {
+        return ReroutePolicy(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: ReroutePolicy) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReroutePolicy {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: ReroutePolicy, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReroutePolicy_lift(_ pointer: UnsafeMutableRawPointer) throws -> ReroutePolicy {
+    return try FfiConverterTypeReroutePolicy.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReroutePolicy_lower(_ value: ReroutePolicy) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeReroutePolicy.lower(value)
+}
+
+
+
+
 /**
  * The route adapter bridges between the common core and a routing backend where interaction takes place
  * over a generic request/response flow (typically over a network;
@@ -937,6 +1350,22 @@ public convenience init(requestGenerator: RouteRequestGenerator, responseParser:
     }
 
     
+    /**
+     * Creates a route adapter for a Valhalla server, like [`RouteAdapter::new_valhalla_http`],
+     * which requests instructions in the given language and units.
+     */
+public static func newLocalizedValhallaHttp(endpointUrl: String, profile: String, optionsJson: String?, language: String, units: UnitSystem)throws  -> RouteAdapter {
+    return try  FfiConverterTypeRouteAdapter.lift(try rustCallWithError(FfiConverterTypeInstantiationError.lift) {
+    uniffi_ferrostar_fn_constructor_routeadapter_new_localized_valhalla_http(
+        FfiConverterString.lower(endpointUrl),
+        FfiConverterString.lower(profile),
+        FfiConverterOptionString.lower(optionsJson),
+        FfiConverterString.lower(language),
+        FfiConverterTypeUnitSystem.lower(units),$0
+    )
+})
+}
+    
 public static func newValhallaHttp(endpointUrl: String, profile: String, optionsJson: String?)throws  -> RouteAdapter {
     return try  FfiConverterTypeRouteAdapter.lift(try rustCallWithError(FfiConverterTypeInstantiationError.lift) {
     uniffi_ferrostar_fn_constructor_routeadapter_new_valhalla_http(
@@ -1788,50 +2217,64 @@ public func FfiConverterTypeCongestion_lower(_ value: Congestion) -> RustBuffer
 
 
 /**
- * The direction in which the user/device is observed to be traveling.
+ * A continuous stretch of the route ahead of the user with the same level of traffic congestion.
  */
-public struct CourseOverGround {
+public struct CongestionSpan {
     /**
-     * The direction in which the user's device is traveling, measured in clockwise degrees from
-     * true north (N = 0, E = 90, S = 180, W = 270).
+     * The level of congestion along the span.
      */
-    public var degrees: UInt16
+    public var level: CongestionLevel
     /**
-     * The accuracy of the course value, measured in degrees.
+     * The distance along the route from the user's snapped location to the start of the span, in meters.
+     *
+     * This is zero if the user is already within the span.
      */
-    public var accuracy: UInt16?
+    public var distanceToStart: Double
+    /**
+     * The length of the span ahead of the user, in meters.
+     */
+    public var length: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The direction in which the user's device is traveling, measured in clockwise degrees from
-         * true north (N = 0, E = 90, S = 180, W = 270).
-         */degrees: UInt16, 
+         * The level of congestion along the span.
+         */level: CongestionLevel, 
         /**
-         * The accuracy of the course value, measured in degrees.
-         */accuracy: UInt16?) {
-        self.degrees = degrees
-        self.accuracy = accuracy
+         * The distance along the route from the user's snapped location to the start of the span, in meters.
+         *
+         * This is zero if the user is already within the span.
+         */distanceToStart: Double, 
+        /**
+         * The length of the span ahead of the user, in meters.
+         */length: Double) {
+        self.level = level
+        self.distanceToStart = distanceToStart
+        self.length = length
     }
 }
 
 
 
-extension CourseOverGround: Equatable, Hashable {
-    public static func ==(lhs: CourseOverGround, rhs: CourseOverGround) -> Bool {
-        if lhs.degrees != rhs.degrees {
+extension CongestionSpan: Equatable, Hashable {
+    public static func ==(lhs: CongestionSpan, rhs: CongestionSpan) -> Bool {
+        if lhs.level != rhs.level {
             return false
         }
-        if lhs.accuracy != rhs.accuracy {
+        if lhs.distanceToStart != rhs.distanceToStart {
+            return false
+        }
+        if lhs.length != rhs.length {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(degrees)
-        hasher.combine(accuracy)
+        hasher.combine(level)
+        hasher.combine(distanceToStart)
+        hasher.combine(length)
     }
 }
 
@@ -1839,8 +2282,93 @@ extension CourseOverGround: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCourseOverGround: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseOverGround {
+public struct FfiConverterTypeCongestionSpan: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CongestionSpan {
+        return
+            try CongestionSpan(
+                level: FfiConverterTypeCongestionLevel.read(from: &buf), 
+                distanceToStart: FfiConverterDouble.read(from: &buf), 
+                length: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CongestionSpan, into buf: inout [UInt8]) {
+        FfiConverterTypeCongestionLevel.write(value.level, into: &buf)
+        FfiConverterDouble.write(value.distanceToStart, into: &buf)
+        FfiConverterDouble.write(value.length, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCongestionSpan_lift(_ buf: RustBuffer) throws -> CongestionSpan {
+    return try FfiConverterTypeCongestionSpan.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCongestionSpan_lower(_ value: CongestionSpan) -> RustBuffer {
+    return FfiConverterTypeCongestionSpan.lower(value)
+}
+
+
+/**
+ * The direction in which the user/device is observed to be traveling.
+ */
+public struct CourseOverGround {
+    /**
+     * The direction in which the user's device is traveling, measured in clockwise degrees from
+     * true north (N = 0, E = 90, S = 180, W = 270).
+     */
+    public var degrees: UInt16
+    /**
+     * The accuracy of the course value, measured in degrees.
+     */
+    public var accuracy: UInt16?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The direction in which the user's device is traveling, measured in clockwise degrees from
+         * true north (N = 0, E = 90, S = 180, W = 270).
+         */degrees: UInt16, 
+        /**
+         * The accuracy of the course value, measured in degrees.
+         */accuracy: UInt16?) {
+        self.degrees = degrees
+        self.accuracy = accuracy
+    }
+}
+
+
+
+extension CourseOverGround: Equatable, Hashable {
+    public static func ==(lhs: CourseOverGround, rhs: CourseOverGround) -> Bool {
+        if lhs.degrees != rhs.degrees {
+            return false
+        }
+        if lhs.accuracy != rhs.accuracy {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(degrees)
+        hasher.combine(accuracy)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCourseOverGround: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseOverGround {
         return
             try CourseOverGround(
                 degrees: FfiConverterUInt16.read(from: &buf), 
@@ -2371,42 +2899,51 @@ public func FfiConverterTypeIncident_lower(_ value: Incident) -> RustBuffer {
 
 
 /**
- * The content of a visual instruction.
+ * A table of phrases for synthesizing instructions in a given language.
+ *
+ * Phrases are looked up by key (ex: `turn.name`),
+ * and may contain placeholders in braces (ex: `Turn {modifier} onto {way_name}`).
+ * Maneuver phrases are keyed by maneuver type, an optional modifier, and a variant
+ * (`default`, `name`, `destination`, and `exit_*` for numbered exits),
+ * with more specific keys taking precedence.
+ * See [`InstructionLanguage::english`] for the full set of keys and placeholders.
+ *
+ * Phrases which embed another instruction (ex: `spoken.in_distance`) can use either
+ * `{instruction}`, which is inserted as-is,
+ * or `{lowercase_instruction}`, which starts with a lowercase letter,
+ * depending on the grammar of the language.
+ *
+ * Phrases which are missing from the table fall back to English,
+ * so a table only needs to contain the phrases that differ.
  */
-public struct LaneInfo {
-    public var active: Bool
-    public var directions: [String]
-    public var activeDirection: String?
+public struct InstructionLanguage {
+    /**
+     * Phrases by key.
+     */
+    public var phrases: [String: String]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(active: Bool, directions: [String], activeDirection: String?) {
-        self.active = active
-        self.directions = directions
-        self.activeDirection = activeDirection
+    public init(
+        /**
+         * Phrases by key.
+         */phrases: [String: String]) {
+        self.phrases = phrases
     }
 }
 
 
 
-extension LaneInfo: Equatable, Hashable {
-    public static func ==(lhs: LaneInfo, rhs: LaneInfo) -> Bool {
-        if lhs.active != rhs.active {
-            return false
-        }
-        if lhs.directions != rhs.directions {
-            return false
-        }
-        if lhs.activeDirection != rhs.activeDirection {
+extension InstructionLanguage: Equatable, Hashable {
+    public static func ==(lhs: InstructionLanguage, rhs: InstructionLanguage) -> Bool {
+        if lhs.phrases != rhs.phrases {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(active)
-        hasher.combine(directions)
-        hasher.combine(activeDirection)
+        hasher.combine(phrases)
     }
 }
 
@@ -2414,20 +2951,16 @@ extension LaneInfo: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLaneInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LaneInfo {
+public struct FfiConverterTypeInstructionLanguage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstructionLanguage {
         return
-            try LaneInfo(
-                active: FfiConverterBool.read(from: &buf), 
-                directions: FfiConverterSequenceString.read(from: &buf), 
-                activeDirection: FfiConverterOptionString.read(from: &buf)
+            try InstructionLanguage(
+                phrases: FfiConverterDictionaryStringString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: LaneInfo, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.active, into: &buf)
-        FfiConverterSequenceString.write(value.directions, into: &buf)
-        FfiConverterOptionString.write(value.activeDirection, into: &buf)
+    public static func write(_ value: InstructionLanguage, into buf: inout [UInt8]) {
+        FfiConverterDictionaryStringString.write(value.phrases, into: &buf)
     }
 }
 
@@ -2435,55 +2968,145 @@ public struct FfiConverterTypeLaneInfo: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLaneInfo_lift(_ buf: RustBuffer) throws -> LaneInfo {
-    return try FfiConverterTypeLaneInfo.lift(buf)
+public func FfiConverterTypeInstructionLanguage_lift(_ buf: RustBuffer) throws -> InstructionLanguage {
+    return try FfiConverterTypeInstructionLanguage.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLaneInfo_lower(_ value: LaneInfo) -> RustBuffer {
-    return FfiConverterTypeLaneInfo.lower(value)
+public func FfiConverterTypeInstructionLanguage_lower(_ value: InstructionLanguage) -> RustBuffer {
+    return FfiConverterTypeInstructionLanguage.lower(value)
 }
 
 
 /**
- * The current state of the simulation.
+ * An intersection along a [`RouteStep`].
  */
-public struct LocationSimulationState {
-    public var currentLocation: UserLocation
-    public var remainingLocations: [GeographicCoordinate]
-    public var bias: LocationBias
+public struct Intersection {
+    /**
+     * The location of the intersection.
+     */
+    public var location: GeographicCoordinate
+    /**
+     * The clockwise angles from true north of all roads at the intersection.
+     */
+    public var bearings: [UInt16]
+    /**
+     * Whether each of the roads (corresponding 1:1 with `bearings`)
+     * may be entered on a valid route.
+     */
+    public var entry: [Bool]
+    /**
+     * The index into `bearings` of the road along which the route enters the intersection.
+     *
+     * NOTE: The bearing of this road points *away* from the intersection,
+     * so it must be rotated by 180 degrees to get the direction of travel.
+     * This is [`None`] for the intersection where the route departs.
+     */
+    public var inBearingIndex: UInt64?
+    /**
+     * The index into `bearings` of the road along which the route exits the intersection.
+     *
+     * This is [`None`] for the intersection where the route arrives.
+     */
+    public var outBearingIndex: UInt64?
+    /**
+     * The classes of the road along which the route exits the intersection
+     * (ex: `toll`, `motorway`, `ferry`, `tunnel`, or `restricted`).
+     *
+     * The available classes depend on the routing engine and profile.
+     */
+    public var classes: [String]
+    /**
+     * The turn lanes at the intersection, in left-to-right order.
+     *
+     * This is empty if the routing engine has no lane information for the intersection.
+     */
+    public var lanes: [IntersectionLane]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(currentLocation: UserLocation, remainingLocations: [GeographicCoordinate], bias: LocationBias) {
-        self.currentLocation = currentLocation
-        self.remainingLocations = remainingLocations
-        self.bias = bias
+    public init(
+        /**
+         * The location of the intersection.
+         */location: GeographicCoordinate, 
+        /**
+         * The clockwise angles from true north of all roads at the intersection.
+         */bearings: [UInt16], 
+        /**
+         * Whether each of the roads (corresponding 1:1 with `bearings`)
+         * may be entered on a valid route.
+         */entry: [Bool], 
+        /**
+         * The index into `bearings` of the road along which the route enters the intersection.
+         *
+         * NOTE: The bearing of this road points *away* from the intersection,
+         * so it must be rotated by 180 degrees to get the direction of travel.
+         * This is [`None`] for the intersection where the route departs.
+         */inBearingIndex: UInt64?, 
+        /**
+         * The index into `bearings` of the road along which the route exits the intersection.
+         *
+         * This is [`None`] for the intersection where the route arrives.
+         */outBearingIndex: UInt64?, 
+        /**
+         * The classes of the road along which the route exits the intersection
+         * (ex: `toll`, `motorway`, `ferry`, `tunnel`, or `restricted`).
+         *
+         * The available classes depend on the routing engine and profile.
+         */classes: [String], 
+        /**
+         * The turn lanes at the intersection, in left-to-right order.
+         *
+         * This is empty if the routing engine has no lane information for the intersection.
+         */lanes: [IntersectionLane]) {
+        self.location = location
+        self.bearings = bearings
+        self.entry = entry
+        self.inBearingIndex = inBearingIndex
+        self.outBearingIndex = outBearingIndex
+        self.classes = classes
+        self.lanes = lanes
     }
 }
 
 
 
-extension LocationSimulationState: Equatable, Hashable {
-    public static func ==(lhs: LocationSimulationState, rhs: LocationSimulationState) -> Bool {
-        if lhs.currentLocation != rhs.currentLocation {
+extension Intersection: Equatable, Hashable {
+    public static func ==(lhs: Intersection, rhs: Intersection) -> Bool {
+        if lhs.location != rhs.location {
             return false
         }
-        if lhs.remainingLocations != rhs.remainingLocations {
+        if lhs.bearings != rhs.bearings {
             return false
         }
-        if lhs.bias != rhs.bias {
+        if lhs.entry != rhs.entry {
+            return false
+        }
+        if lhs.inBearingIndex != rhs.inBearingIndex {
+            return false
+        }
+        if lhs.outBearingIndex != rhs.outBearingIndex {
+            return false
+        }
+        if lhs.classes != rhs.classes {
+            return false
+        }
+        if lhs.lanes != rhs.lanes {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(currentLocation)
-        hasher.combine(remainingLocations)
-        hasher.combine(bias)
+        hasher.combine(location)
+        hasher.combine(bearings)
+        hasher.combine(entry)
+        hasher.combine(inBearingIndex)
+        hasher.combine(outBearingIndex)
+        hasher.combine(classes)
+        hasher.combine(lanes)
     }
 }
 
@@ -2491,20 +3114,28 @@ extension LocationSimulationState: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSimulationState {
+public struct FfiConverterTypeIntersection: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Intersection {
         return
-            try LocationSimulationState(
-                currentLocation: FfiConverterTypeUserLocation.read(from: &buf), 
-                remainingLocations: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                bias: FfiConverterTypeLocationBias.read(from: &buf)
+            try Intersection(
+                location: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                bearings: FfiConverterSequenceUInt16.read(from: &buf), 
+                entry: FfiConverterSequenceBool.read(from: &buf), 
+                inBearingIndex: FfiConverterOptionUInt64.read(from: &buf), 
+                outBearingIndex: FfiConverterOptionUInt64.read(from: &buf), 
+                classes: FfiConverterSequenceString.read(from: &buf), 
+                lanes: FfiConverterSequenceTypeIntersectionLane.read(from: &buf)
         )
     }
 
-    public static func write(_ value: LocationSimulationState, into buf: inout [UInt8]) {
-        FfiConverterTypeUserLocation.write(value.currentLocation, into: &buf)
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.remainingLocations, into: &buf)
-        FfiConverterTypeLocationBias.write(value.bias, into: &buf)
+    public static func write(_ value: Intersection, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.location, into: &buf)
+        FfiConverterSequenceUInt16.write(value.bearings, into: &buf)
+        FfiConverterSequenceBool.write(value.entry, into: &buf)
+        FfiConverterOptionUInt64.write(value.inBearingIndex, into: &buf)
+        FfiConverterOptionUInt64.write(value.outBearingIndex, into: &buf)
+        FfiConverterSequenceString.write(value.classes, into: &buf)
+        FfiConverterSequenceTypeIntersectionLane.write(value.lanes, into: &buf)
     }
 }
 
@@ -2512,75 +3143,120 @@ public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationSimulationState_lift(_ buf: RustBuffer) throws -> LocationSimulationState {
-    return try FfiConverterTypeLocationSimulationState.lift(buf)
+public func FfiConverterTypeIntersection_lift(_ buf: RustBuffer) throws -> Intersection {
+    return try FfiConverterTypeIntersection.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationSimulationState_lower(_ value: LocationSimulationState) -> RustBuffer {
-    return FfiConverterTypeLocationSimulationState.lower(value)
+public func FfiConverterTypeIntersection_lower(_ value: Intersection) -> RustBuffer {
+    return FfiConverterTypeIntersection.lower(value)
 }
 
 
-public struct NavigationControllerConfig {
+/**
+ * A turn lane at an [`Intersection`].
+ */
+public struct IntersectionLane {
     /**
-     * Configures when navigation advances to the next step in the route.
+     * The indications (ex: markings on the road or signs) for the lane, such as `left` or `straight`.
+     *
+     * A lane may have multiple indications.
      */
-    public var stepAdvance: StepAdvanceMode
+    public var indications: [String]
     /**
-     * Configures when the user is deemed to be off course.
+     * Whether the lane can be used to complete the maneuver.
+     */
+    public var valid: Bool
+    /**
+     * Whether the lane is a preferred choice for the maneuver.
      *
-     * NOTE: This is distinct from the action that is taken.
-     * It is only the determination that the user has deviated from the expected route.
+     * This is an extension supported by Mapbox and Valhalla.
      */
-    public var routeDeviationTracking: RouteDeviationTracking
+    public var active: Bool?
     /**
-     * Configures how the heading component of the snapped location is reported in [`TripState`].
+     * Which of the `indications` applies to the maneuver.
+     *
+     * This is an extension supported by Mapbox and Valhalla.
      */
-    public var snappedLocationCourseFiltering: CourseFiltering
+    public var validIndication: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Configures when navigation advances to the next step in the route.
-         */stepAdvance: StepAdvanceMode, 
+         * The indications (ex: markings on the road or signs) for the lane, such as `left` or `straight`.
+         *
+         * A lane may have multiple indications.
+         */indications: [String], 
         /**
-         * Configures when the user is deemed to be off course.
+         * Whether the lane can be used to complete the maneuver.
+         */valid: Bool, 
+        /**
+         * Whether the lane is a preferred choice for the maneuver.
          *
-         * NOTE: This is distinct from the action that is taken.
-         * It is only the determination that the user has deviated from the expected route.
-         */routeDeviationTracking: RouteDeviationTracking, 
+         * This is an extension supported by Mapbox and Valhalla.
+         */active: Bool?, 
         /**
-         * Configures how the heading component of the snapped location is reported in [`TripState`].
-         */snappedLocationCourseFiltering: CourseFiltering) {
-        self.stepAdvance = stepAdvance
-        self.routeDeviationTracking = routeDeviationTracking
-        self.snappedLocationCourseFiltering = snappedLocationCourseFiltering
+         * Which of the `indications` applies to the maneuver.
+         *
+         * This is an extension supported by Mapbox and Valhalla.
+         */validIndication: String?) {
+        self.indications = indications
+        self.valid = valid
+        self.active = active
+        self.validIndication = validIndication
     }
 }
 
 
 
+extension IntersectionLane: Equatable, Hashable {
+    public static func ==(lhs: IntersectionLane, rhs: IntersectionLane) -> Bool {
+        if lhs.indications != rhs.indications {
+            return false
+        }
+        if lhs.valid != rhs.valid {
+            return false
+        }
+        if lhs.active != rhs.active {
+            return false
+        }
+        if lhs.validIndication != rhs.validIndication {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(indications)
+        hasher.combine(valid)
+        hasher.combine(active)
+        hasher.combine(validIndication)
+    }
+}
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationControllerConfig {
+public struct FfiConverterTypeIntersectionLane: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IntersectionLane {
         return
-            try NavigationControllerConfig(
-                stepAdvance: FfiConverterTypeStepAdvanceMode.read(from: &buf), 
-                routeDeviationTracking: FfiConverterTypeRouteDeviationTracking.read(from: &buf), 
-                snappedLocationCourseFiltering: FfiConverterTypeCourseFiltering.read(from: &buf)
+            try IntersectionLane(
+                indications: FfiConverterSequenceString.read(from: &buf), 
+                valid: FfiConverterBool.read(from: &buf), 
+                active: FfiConverterOptionBool.read(from: &buf), 
+                validIndication: FfiConverterOptionString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavigationControllerConfig, into buf: inout [UInt8]) {
-        FfiConverterTypeStepAdvanceMode.write(value.stepAdvance, into: &buf)
-        FfiConverterTypeRouteDeviationTracking.write(value.routeDeviationTracking, into: &buf)
-        FfiConverterTypeCourseFiltering.write(value.snappedLocationCourseFiltering, into: &buf)
+    public static func write(_ value: IntersectionLane, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.indications, into: &buf)
+        FfiConverterBool.write(value.valid, into: &buf)
+        FfiConverterOptionBool.write(value.active, into: &buf)
+        FfiConverterOptionString.write(value.validIndication, into: &buf)
     }
 }
 
@@ -2588,86 +3264,55 @@ public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationControllerConfig_lift(_ buf: RustBuffer) throws -> NavigationControllerConfig {
-    return try FfiConverterTypeNavigationControllerConfig.lift(buf)
+public func FfiConverterTypeIntersectionLane_lift(_ buf: RustBuffer) throws -> IntersectionLane {
+    return try FfiConverterTypeIntersectionLane.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationControllerConfig_lower(_ value: NavigationControllerConfig) -> RustBuffer {
-    return FfiConverterTypeNavigationControllerConfig.lower(value)
+public func FfiConverterTypeIntersectionLane_lower(_ value: IntersectionLane) -> RustBuffer {
+    return FfiConverterTypeIntersectionLane.lower(value)
 }
 
 
 /**
- * Information describing the series of steps needed to travel between two or more points.
- *
- * NOTE: This type is unstable and is still under active development and should be
- * considered unstable.
+ * The content of a visual instruction.
  */
-public struct Route {
-    public var geometry: [GeographicCoordinate]
-    public var bbox: BoundingBox
-    /**
-     * The total route distance, in meters.
-     */
-    public var distance: Double
-    /**
-     * The ordered list of waypoints to visit, including the starting point.
-     * Note that this is distinct from the *geometry* which includes all points visited.
-     * A waypoint represents a start/end point for a route leg.
-     */
-    public var waypoints: [Waypoint]
-    public var steps: [RouteStep]
+public struct LaneInfo {
+    public var active: Bool
+    public var directions: [String]
+    public var activeDirection: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(geometry: [GeographicCoordinate], bbox: BoundingBox, 
-        /**
-         * The total route distance, in meters.
-         */distance: Double, 
-        /**
-         * The ordered list of waypoints to visit, including the starting point.
-         * Note that this is distinct from the *geometry* which includes all points visited.
-         * A waypoint represents a start/end point for a route leg.
-         */waypoints: [Waypoint], steps: [RouteStep]) {
-        self.geometry = geometry
-        self.bbox = bbox
-        self.distance = distance
-        self.waypoints = waypoints
-        self.steps = steps
+    public init(active: Bool, directions: [String], activeDirection: String?) {
+        self.active = active
+        self.directions = directions
+        self.activeDirection = activeDirection
     }
 }
 
 
 
-extension Route: Equatable, Hashable {
-    public static func ==(lhs: Route, rhs: Route) -> Bool {
-        if lhs.geometry != rhs.geometry {
-            return false
-        }
-        if lhs.bbox != rhs.bbox {
-            return false
-        }
-        if lhs.distance != rhs.distance {
+extension LaneInfo: Equatable, Hashable {
+    public static func ==(lhs: LaneInfo, rhs: LaneInfo) -> Bool {
+        if lhs.active != rhs.active {
             return false
         }
-        if lhs.waypoints != rhs.waypoints {
+        if lhs.directions != rhs.directions {
             return false
         }
-        if lhs.steps != rhs.steps {
+        if lhs.activeDirection != rhs.activeDirection {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(geometry)
-        hasher.combine(bbox)
-        hasher.combine(distance)
-        hasher.combine(waypoints)
-        hasher.combine(steps)
+        hasher.combine(active)
+        hasher.combine(directions)
+        hasher.combine(activeDirection)
     }
 }
 
@@ -2675,24 +3320,20 @@ extension Route: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Route {
+public struct FfiConverterTypeLaneInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LaneInfo {
         return
-            try Route(
-                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                bbox: FfiConverterTypeBoundingBox.read(from: &buf), 
-                distance: FfiConverterDouble.read(from: &buf), 
-                waypoints: FfiConverterSequenceTypeWaypoint.read(from: &buf), 
-                steps: FfiConverterSequenceTypeRouteStep.read(from: &buf)
+            try LaneInfo(
+                active: FfiConverterBool.read(from: &buf), 
+                directions: FfiConverterSequenceString.read(from: &buf), 
+                activeDirection: FfiConverterOptionString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Route, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
-        FfiConverterTypeBoundingBox.write(value.bbox, into: &buf)
-        FfiConverterDouble.write(value.distance, into: &buf)
-        FfiConverterSequenceTypeWaypoint.write(value.waypoints, into: &buf)
-        FfiConverterSequenceTypeRouteStep.write(value.steps, into: &buf)
+    public static func write(_ value: LaneInfo, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.active, into: &buf)
+        FfiConverterSequenceString.write(value.directions, into: &buf)
+        FfiConverterOptionString.write(value.activeDirection, into: &buf)
     }
 }
 
@@ -2700,166 +3341,73 @@ public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoute_lift(_ buf: RustBuffer) throws -> Route {
-    return try FfiConverterTypeRoute.lift(buf)
+public func FfiConverterTypeLaneInfo_lift(_ buf: RustBuffer) throws -> LaneInfo {
+    return try FfiConverterTypeLaneInfo.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoute_lower(_ value: Route) -> RustBuffer {
-    return FfiConverterTypeRoute.lower(value)
+public func FfiConverterTypeLaneInfo_lower(_ value: LaneInfo) -> RustBuffer {
+    return FfiConverterTypeLaneInfo.lower(value)
 }
 
 
 /**
- * A maneuver (such as a turn or merge) followed by travel of a certain distance until reaching
- * the next step.
+ * A filtered estimate of the user's location and velocity (see [`LocationFiltering`]).
  */
-public struct RouteStep {
-    /**
-     * The full route geometry for this step.
-     */
-    public var geometry: [GeographicCoordinate]
-    /**
-     * The distance, in meters, to travel along the route after the maneuver to reach the next step.
-     */
-    public var distance: Double
-    /**
-     * The estimated duration, in seconds, that it will take to complete this step.
-     */
-    public var duration: Double
-    /**
-     * The name of the road being traveled on (useful for certain UI styles).
-     */
-    public var roadName: String?
-    /**
-     * A list of exits (name or number).
-     */
-    public var exits: [String]
-    /**
-     * A description of the maneuver (ex: "Turn wright onto main street").
-     *
-     * Note for UI implementers: the context this appears in (or doesn't)
-     * depends somewhat on your use case and routing engine.
-     * For example, this field is useful as a written instruction in Valhalla.
-     */
-    public var instruction: String
-    /**
-     * A list of instructions for visual display (usually as banners) at specific points along the step.
-     */
-    public var visualInstructions: [VisualInstruction]
+public struct LocationEstimate {
     /**
-     * A list of prompts to announce (via speech synthesis) at specific points along the step.
+     * The filtered location.
      */
-    public var spokenInstructions: [SpokenInstruction]
+    public var location: UserLocation
     /**
-     * A list of json encoded strings representing annotations between each coordinate along the step.
+     * The estimated velocity toward the east, in meters per second.
      */
-    public var annotations: [String]?
+    public var velocityEast: Double
     /**
-     * A list of incidents that occur along the step.
+     * The estimated velocity toward the north, in meters per second.
      */
-    public var incidents: [Incident]
+    public var velocityNorth: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The full route geometry for this step.
-         */geometry: [GeographicCoordinate], 
-        /**
-         * The distance, in meters, to travel along the route after the maneuver to reach the next step.
-         */distance: Double, 
-        /**
-         * The estimated duration, in seconds, that it will take to complete this step.
-         */duration: Double, 
-        /**
-         * The name of the road being traveled on (useful for certain UI styles).
-         */roadName: String?, 
-        /**
-         * A list of exits (name or number).
-         */exits: [String], 
-        /**
-         * A description of the maneuver (ex: "Turn wright onto main street").
-         *
-         * Note for UI implementers: the context this appears in (or doesn't)
-         * depends somewhat on your use case and routing engine.
-         * For example, this field is useful as a written instruction in Valhalla.
-         */instruction: String, 
-        /**
-         * A list of instructions for visual display (usually as banners) at specific points along the step.
-         */visualInstructions: [VisualInstruction], 
-        /**
-         * A list of prompts to announce (via speech synthesis) at specific points along the step.
-         */spokenInstructions: [SpokenInstruction], 
+         * The filtered location.
+         */location: UserLocation, 
         /**
-         * A list of json encoded strings representing annotations between each coordinate along the step.
-         */annotations: [String]?, 
+         * The estimated velocity toward the east, in meters per second.
+         */velocityEast: Double, 
         /**
-         * A list of incidents that occur along the step.
-         */incidents: [Incident]) {
-        self.geometry = geometry
-        self.distance = distance
-        self.duration = duration
-        self.roadName = roadName
-        self.exits = exits
-        self.instruction = instruction
-        self.visualInstructions = visualInstructions
-        self.spokenInstructions = spokenInstructions
-        self.annotations = annotations
-        self.incidents = incidents
+         * The estimated velocity toward the north, in meters per second.
+         */velocityNorth: Double) {
+        self.location = location
+        self.velocityEast = velocityEast
+        self.velocityNorth = velocityNorth
     }
 }
 
 
 
-extension RouteStep: Equatable, Hashable {
-    public static func ==(lhs: RouteStep, rhs: RouteStep) -> Bool {
-        if lhs.geometry != rhs.geometry {
-            return false
-        }
-        if lhs.distance != rhs.distance {
-            return false
-        }
-        if lhs.duration != rhs.duration {
-            return false
-        }
-        if lhs.roadName != rhs.roadName {
-            return false
-        }
-        if lhs.exits != rhs.exits {
-            return false
-        }
-        if lhs.instruction != rhs.instruction {
-            return false
-        }
-        if lhs.visualInstructions != rhs.visualInstructions {
-            return false
-        }
-        if lhs.spokenInstructions != rhs.spokenInstructions {
+extension LocationEstimate: Equatable, Hashable {
+    public static func ==(lhs: LocationEstimate, rhs: LocationEstimate) -> Bool {
+        if lhs.location != rhs.location {
             return false
         }
-        if lhs.annotations != rhs.annotations {
+        if lhs.velocityEast != rhs.velocityEast {
             return false
         }
-        if lhs.incidents != rhs.incidents {
+        if lhs.velocityNorth != rhs.velocityNorth {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(geometry)
-        hasher.combine(distance)
-        hasher.combine(duration)
-        hasher.combine(roadName)
-        hasher.combine(exits)
-        hasher.combine(instruction)
-        hasher.combine(visualInstructions)
-        hasher.combine(spokenInstructions)
-        hasher.combine(annotations)
-        hasher.combine(incidents)
+        hasher.combine(location)
+        hasher.combine(velocityEast)
+        hasher.combine(velocityNorth)
     }
 }
 
@@ -2867,34 +3415,20 @@ extension RouteStep: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteStep {
+public struct FfiConverterTypeLocationEstimate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationEstimate {
         return
-            try RouteStep(
-                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                distance: FfiConverterDouble.read(from: &buf), 
-                duration: FfiConverterDouble.read(from: &buf), 
-                roadName: FfiConverterOptionString.read(from: &buf), 
-                exits: FfiConverterSequenceString.read(from: &buf), 
-                instruction: FfiConverterString.read(from: &buf), 
-                visualInstructions: FfiConverterSequenceTypeVisualInstruction.read(from: &buf), 
-                spokenInstructions: FfiConverterSequenceTypeSpokenInstruction.read(from: &buf), 
-                annotations: FfiConverterOptionSequenceString.read(from: &buf), 
-                incidents: FfiConverterSequenceTypeIncident.read(from: &buf)
+            try LocationEstimate(
+                location: FfiConverterTypeUserLocation.read(from: &buf), 
+                velocityEast: FfiConverterDouble.read(from: &buf), 
+                velocityNorth: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: RouteStep, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
-        FfiConverterDouble.write(value.distance, into: &buf)
-        FfiConverterDouble.write(value.duration, into: &buf)
-        FfiConverterOptionString.write(value.roadName, into: &buf)
-        FfiConverterSequenceString.write(value.exits, into: &buf)
-        FfiConverterString.write(value.instruction, into: &buf)
-        FfiConverterSequenceTypeVisualInstruction.write(value.visualInstructions, into: &buf)
-        FfiConverterSequenceTypeSpokenInstruction.write(value.spokenInstructions, into: &buf)
-        FfiConverterOptionSequenceString.write(value.annotations, into: &buf)
-        FfiConverterSequenceTypeIncident.write(value.incidents, into: &buf)
+    public static func write(_ value: LocationEstimate, into buf: inout [UInt8]) {
+        FfiConverterTypeUserLocation.write(value.location, into: &buf)
+        FfiConverterDouble.write(value.velocityEast, into: &buf)
+        FfiConverterDouble.write(value.velocityNorth, into: &buf)
     }
 }
 
@@ -2902,61 +3436,73 @@ public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteStep_lift(_ buf: RustBuffer) throws -> RouteStep {
-    return try FfiConverterTypeRouteStep.lift(buf)
+public func FfiConverterTypeLocationEstimate_lift(_ buf: RustBuffer) throws -> LocationEstimate {
+    return try FfiConverterTypeLocationEstimate.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteStep_lower(_ value: RouteStep) -> RustBuffer {
-    return FfiConverterTypeRouteStep.lower(value)
+public func FfiConverterTypeLocationEstimate_lower(_ value: LocationEstimate) -> RustBuffer {
+    return FfiConverterTypeLocationEstimate.lower(value)
 }
 
 
 /**
- * The speed of the user from the location provider.
+ * A location update which was matched to the route with [`LocationSnapping::MapMatching`].
  */
-public struct Speed {
+public struct LocationMatch {
     /**
-     * The user's speed in meters per second.
+     * The (raw) location reported by the location provider.
      */
-    public var value: Double
+    public var location: UserLocation
     /**
-     * The accuracy of the speed value, measured in meters per second.
+     * The positions along the route which were considered for this location.
      */
-    public var accuracy: Double?
+    public var candidates: [MatchCandidate]
+    /**
+     * The distance along the route of the position the location was matched to, in meters.
+     */
+    public var distanceAlongRoute: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The user's speed in meters per second.
-         */value: Double, 
+         * The (raw) location reported by the location provider.
+         */location: UserLocation, 
         /**
-         * The accuracy of the speed value, measured in meters per second.
-         */accuracy: Double?) {
-        self.value = value
-        self.accuracy = accuracy
+         * The positions along the route which were considered for this location.
+         */candidates: [MatchCandidate], 
+        /**
+         * The distance along the route of the position the location was matched to, in meters.
+         */distanceAlongRoute: Double) {
+        self.location = location
+        self.candidates = candidates
+        self.distanceAlongRoute = distanceAlongRoute
     }
 }
 
 
 
-extension Speed: Equatable, Hashable {
-    public static func ==(lhs: Speed, rhs: Speed) -> Bool {
-        if lhs.value != rhs.value {
+extension LocationMatch: Equatable, Hashable {
+    public static func ==(lhs: LocationMatch, rhs: LocationMatch) -> Bool {
+        if lhs.location != rhs.location {
             return false
         }
-        if lhs.accuracy != rhs.accuracy {
+        if lhs.candidates != rhs.candidates {
+            return false
+        }
+        if lhs.distanceAlongRoute != rhs.distanceAlongRoute {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(value)
-        hasher.combine(accuracy)
+        hasher.combine(location)
+        hasher.combine(candidates)
+        hasher.combine(distanceAlongRoute)
     }
 }
 
@@ -2964,18 +3510,20 @@ extension Speed: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Speed {
+public struct FfiConverterTypeLocationMatch: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationMatch {
         return
-            try Speed(
-                value: FfiConverterDouble.read(from: &buf), 
-                accuracy: FfiConverterOptionDouble.read(from: &buf)
+            try LocationMatch(
+                location: FfiConverterTypeUserLocation.read(from: &buf), 
+                candidates: FfiConverterSequenceTypeMatchCandidate.read(from: &buf), 
+                distanceAlongRoute: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Speed, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.value, into: &buf)
-        FfiConverterOptionDouble.write(value.accuracy, into: &buf)
+    public static func write(_ value: LocationMatch, into buf: inout [UInt8]) {
+        FfiConverterTypeUserLocation.write(value.location, into: &buf)
+        FfiConverterSequenceTypeMatchCandidate.write(value.candidates, into: &buf)
+        FfiConverterDouble.write(value.distanceAlongRoute, into: &buf)
     }
 }
 
@@ -2983,101 +3531,55 @@ public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpeed_lift(_ buf: RustBuffer) throws -> Speed {
-    return try FfiConverterTypeSpeed.lift(buf)
+public func FfiConverterTypeLocationMatch_lift(_ buf: RustBuffer) throws -> LocationMatch {
+    return try FfiConverterTypeLocationMatch.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpeed_lower(_ value: Speed) -> RustBuffer {
-    return FfiConverterTypeSpeed.lower(value)
+public func FfiConverterTypeLocationMatch_lower(_ value: LocationMatch) -> RustBuffer {
+    return FfiConverterTypeLocationMatch.lower(value)
 }
 
 
 /**
- * An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
- *
- * Note that these do not have any locale information attached.
+ * The current state of the simulation.
  */
-public struct SpokenInstruction {
-    /**
-     * Plain-text instruction which can be synthesized with a TTS engine.
-     */
-    public var text: String
-    /**
-     * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
-     */
-    public var ssml: String?
-    /**
-     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
-     */
-    public var triggerDistanceBeforeManeuver: Double
-    /**
-     * A unique identifier for this instruction.
-     *
-     * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
-     * which may have the same textual content.
-     * UUIDs conveniently fill this purpose.
-     *
-     * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
-     * This should be theoretically possible though if someone cares to write up a proposal and a PR.
-     */
-    public var utteranceId: Uuid
+public struct LocationSimulationState {
+    public var currentLocation: UserLocation
+    public var remainingLocations: [GeographicCoordinate]
+    public var bias: LocationBias
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(
-        /**
-         * Plain-text instruction which can be synthesized with a TTS engine.
-         */text: String, 
-        /**
-         * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
-         */ssml: String?, 
-        /**
-         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
-         */triggerDistanceBeforeManeuver: Double, 
-        /**
-         * A unique identifier for this instruction.
-         *
-         * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
-         * which may have the same textual content.
-         * UUIDs conveniently fill this purpose.
-         *
-         * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
-         * This should be theoretically possible though if someone cares to write up a proposal and a PR.
-         */utteranceId: Uuid) {
-        self.text = text
-        self.ssml = ssml
-        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
-        self.utteranceId = utteranceId
+    public init(currentLocation: UserLocation, remainingLocations: [GeographicCoordinate], bias: LocationBias) {
+        self.currentLocation = currentLocation
+        self.remainingLocations = remainingLocations
+        self.bias = bias
     }
 }
 
 
 
-extension SpokenInstruction: Equatable, Hashable {
-    public static func ==(lhs: SpokenInstruction, rhs: SpokenInstruction) -> Bool {
-        if lhs.text != rhs.text {
-            return false
-        }
-        if lhs.ssml != rhs.ssml {
+extension LocationSimulationState: Equatable, Hashable {
+    public static func ==(lhs: LocationSimulationState, rhs: LocationSimulationState) -> Bool {
+        if lhs.currentLocation != rhs.currentLocation {
             return false
         }
-        if lhs.triggerDistanceBeforeManeuver != rhs.triggerDistanceBeforeManeuver {
+        if lhs.remainingLocations != rhs.remainingLocations {
             return false
         }
-        if lhs.utteranceId != rhs.utteranceId {
+        if lhs.bias != rhs.bias {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(text)
-        hasher.combine(ssml)
-        hasher.combine(triggerDistanceBeforeManeuver)
-        hasher.combine(utteranceId)
+        hasher.combine(currentLocation)
+        hasher.combine(remainingLocations)
+        hasher.combine(bias)
     }
 }
 
@@ -3085,22 +3587,20 @@ extension SpokenInstruction: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpokenInstruction {
+public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSimulationState {
         return
-            try SpokenInstruction(
-                text: FfiConverterString.read(from: &buf), 
-                ssml: FfiConverterOptionString.read(from: &buf), 
-                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf), 
-                utteranceId: FfiConverterTypeUuid.read(from: &buf)
+            try LocationSimulationState(
+                currentLocation: FfiConverterTypeUserLocation.read(from: &buf), 
+                remainingLocations: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                bias: FfiConverterTypeLocationBias.read(from: &buf)
         )
     }
 
-    public static func write(_ value: SpokenInstruction, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterOptionString.write(value.ssml, into: &buf)
-        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
-        FfiConverterTypeUuid.write(value.utteranceId, into: &buf)
+    public static func write(_ value: LocationSimulationState, into buf: inout [UInt8]) {
+        FfiConverterTypeUserLocation.write(value.currentLocation, into: &buf)
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.remainingLocations, into: &buf)
+        FfiConverterTypeLocationBias.write(value.bias, into: &buf)
     }
 }
 
@@ -3108,77 +3608,97 @@ public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpokenInstruction_lift(_ buf: RustBuffer) throws -> SpokenInstruction {
-    return try FfiConverterTypeSpokenInstruction.lift(buf)
+public func FfiConverterTypeLocationSimulationState_lift(_ buf: RustBuffer) throws -> LocationSimulationState {
+    return try FfiConverterTypeLocationSimulationState.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpokenInstruction_lower(_ value: SpokenInstruction) -> RustBuffer {
-    return FfiConverterTypeSpokenInstruction.lower(value)
+public func FfiConverterTypeLocationSimulationState_lower(_ value: LocationSimulationState) -> RustBuffer {
+    return FfiConverterTypeLocationSimulationState.lower(value)
 }
 
 
 /**
- * High-level state describing progress through a route.
+ * The maneuver at the start of a [`RouteStep`].
  */
-public struct TripProgress {
+public struct Maneuver {
     /**
-     * The distance to the next maneuver, in meters.
+     * The location where the maneuver takes place.
      */
-    public var distanceToNextManeuver: Double
+    public var location: GeographicCoordinate
     /**
-     * The total distance remaining in the trip, in meters.
-     *
-     * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+     * The clockwise angle from true north to the direction of travel immediately *before* the maneuver.
      */
-    public var distanceRemaining: Double
+    public var bearingBefore: UInt16
     /**
-     * The total duration remaining in the trip, in seconds.
+     * The clockwise angle from true north to the direction of travel immediately *after* the maneuver.
      */
-    public var durationRemaining: Double
+    public var bearingAfter: UInt16
+    /**
+     * The broad class of maneuver.
+     */
+    public var maneuverType: ManeuverType
+    /**
+     * Additional information to further specify the maneuver type (if any).
+     */
+    public var maneuverModifier: ManeuverModifier?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The distance to the next maneuver, in meters.
-         */distanceToNextManeuver: Double, 
+         * The location where the maneuver takes place.
+         */location: GeographicCoordinate, 
         /**
-         * The total distance remaining in the trip, in meters.
-         *
-         * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
-         */distanceRemaining: Double, 
+         * The clockwise angle from true north to the direction of travel immediately *before* the maneuver.
+         */bearingBefore: UInt16, 
         /**
-         * The total duration remaining in the trip, in seconds.
-         */durationRemaining: Double) {
-        self.distanceToNextManeuver = distanceToNextManeuver
-        self.distanceRemaining = distanceRemaining
-        self.durationRemaining = durationRemaining
+         * The clockwise angle from true north to the direction of travel immediately *after* the maneuver.
+         */bearingAfter: UInt16, 
+        /**
+         * The broad class of maneuver.
+         */maneuverType: ManeuverType, 
+        /**
+         * Additional information to further specify the maneuver type (if any).
+         */maneuverModifier: ManeuverModifier?) {
+        self.location = location
+        self.bearingBefore = bearingBefore
+        self.bearingAfter = bearingAfter
+        self.maneuverType = maneuverType
+        self.maneuverModifier = maneuverModifier
     }
 }
 
 
 
-extension TripProgress: Equatable, Hashable {
-    public static func ==(lhs: TripProgress, rhs: TripProgress) -> Bool {
-        if lhs.distanceToNextManeuver != rhs.distanceToNextManeuver {
+extension Maneuver: Equatable, Hashable {
+    public static func ==(lhs: Maneuver, rhs: Maneuver) -> Bool {
+        if lhs.location != rhs.location {
             return false
         }
-        if lhs.distanceRemaining != rhs.distanceRemaining {
+        if lhs.bearingBefore != rhs.bearingBefore {
             return false
         }
-        if lhs.durationRemaining != rhs.durationRemaining {
+        if lhs.bearingAfter != rhs.bearingAfter {
+            return false
+        }
+        if lhs.maneuverType != rhs.maneuverType {
+            return false
+        }
+        if lhs.maneuverModifier != rhs.maneuverModifier {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(distanceToNextManeuver)
-        hasher.combine(distanceRemaining)
-        hasher.combine(durationRemaining)
+        hasher.combine(location)
+        hasher.combine(bearingBefore)
+        hasher.combine(bearingAfter)
+        hasher.combine(maneuverType)
+        hasher.combine(maneuverModifier)
     }
 }
 
@@ -3186,20 +3706,24 @@ extension TripProgress: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripProgress {
+public struct FfiConverterTypeManeuver: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Maneuver {
         return
-            try TripProgress(
-                distanceToNextManeuver: FfiConverterDouble.read(from: &buf), 
-                distanceRemaining: FfiConverterDouble.read(from: &buf), 
-                durationRemaining: FfiConverterDouble.read(from: &buf)
+            try Maneuver(
+                location: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                bearingBefore: FfiConverterUInt16.read(from: &buf), 
+                bearingAfter: FfiConverterUInt16.read(from: &buf), 
+                maneuverType: FfiConverterTypeManeuverType.read(from: &buf), 
+                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TripProgress, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.distanceToNextManeuver, into: &buf)
-        FfiConverterDouble.write(value.distanceRemaining, into: &buf)
-        FfiConverterDouble.write(value.durationRemaining, into: &buf)
+    public static func write(_ value: Maneuver, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.location, into: &buf)
+        FfiConverterUInt16.write(value.bearingBefore, into: &buf)
+        FfiConverterUInt16.write(value.bearingAfter, into: &buf)
+        FfiConverterTypeManeuverType.write(value.maneuverType, into: &buf)
+        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
     }
 }
 
@@ -3207,79 +3731,61 @@ public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripProgress_lift(_ buf: RustBuffer) throws -> TripProgress {
-    return try FfiConverterTypeTripProgress.lift(buf)
+public func FfiConverterTypeManeuver_lift(_ buf: RustBuffer) throws -> Maneuver {
+    return try FfiConverterTypeManeuver.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripProgress_lower(_ value: TripProgress) -> RustBuffer {
-    return FfiConverterTypeTripProgress.lower(value)
+public func FfiConverterTypeManeuver_lower(_ value: Maneuver) -> RustBuffer {
+    return FfiConverterTypeManeuver.lower(value)
 }
 
 
 /**
- * The location of the user that is navigating.
- *
- * In addition to coordinates, this includes estimated accuracy and course information,
- * which can influence navigation logic and UI.
- *
- * NOTE: Heading is absent on purpose.
- * Heading updates are not related to a change in the user's location.
+ * A possible position of the user along the route.
  */
-public struct UserLocation {
-    public var coordinates: GeographicCoordinate
+public struct MatchCandidate {
     /**
-     * The estimated accuracy of the coordinate (in meters)
+     * The distance along the route from its start, in meters.
      */
-    public var horizontalAccuracy: Double
-    public var courseOverGround: CourseOverGround?
-    public var timestamp: Date
-    public var speed: Speed?
+    public var distanceAlongRoute: Double
+    /**
+     * The log probability of observing the location if the user were at this position.
+     */
+    public var logLikelihood: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(coordinates: GeographicCoordinate, 
+    public init(
         /**
-         * The estimated accuracy of the coordinate (in meters)
-         */horizontalAccuracy: Double, courseOverGround: CourseOverGround?, timestamp: Date, speed: Speed?) {
-        self.coordinates = coordinates
-        self.horizontalAccuracy = horizontalAccuracy
-        self.courseOverGround = courseOverGround
-        self.timestamp = timestamp
-        self.speed = speed
+         * The distance along the route from its start, in meters.
+         */distanceAlongRoute: Double, 
+        /**
+         * The log probability of observing the location if the user were at this position.
+         */logLikelihood: Double) {
+        self.distanceAlongRoute = distanceAlongRoute
+        self.logLikelihood = logLikelihood
     }
 }
 
 
 
-extension UserLocation: Equatable, Hashable {
-    public static func ==(lhs: UserLocation, rhs: UserLocation) -> Bool {
-        if lhs.coordinates != rhs.coordinates {
-            return false
-        }
-        if lhs.horizontalAccuracy != rhs.horizontalAccuracy {
-            return false
-        }
-        if lhs.courseOverGround != rhs.courseOverGround {
-            return false
-        }
-        if lhs.timestamp != rhs.timestamp {
+extension MatchCandidate: Equatable, Hashable {
+    public static func ==(lhs: MatchCandidate, rhs: MatchCandidate) -> Bool {
+        if lhs.distanceAlongRoute != rhs.distanceAlongRoute {
             return false
         }
-        if lhs.speed != rhs.speed {
+        if lhs.logLikelihood != rhs.logLikelihood {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(coordinates)
-        hasher.combine(horizontalAccuracy)
-        hasher.combine(courseOverGround)
-        hasher.combine(timestamp)
-        hasher.combine(speed)
+        hasher.combine(distanceAlongRoute)
+        hasher.combine(logLikelihood)
     }
 }
 
@@ -3287,24 +3793,18 @@ extension UserLocation: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserLocation {
+public struct FfiConverterTypeMatchCandidate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MatchCandidate {
         return
-            try UserLocation(
-                coordinates: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
-                horizontalAccuracy: FfiConverterDouble.read(from: &buf), 
-                courseOverGround: FfiConverterOptionTypeCourseOverGround.read(from: &buf), 
-                timestamp: FfiConverterTimestamp.read(from: &buf), 
-                speed: FfiConverterOptionTypeSpeed.read(from: &buf)
+            try MatchCandidate(
+                distanceAlongRoute: FfiConverterDouble.read(from: &buf), 
+                logLikelihood: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: UserLocation, into buf: inout [UInt8]) {
-        FfiConverterTypeGeographicCoordinate.write(value.coordinates, into: &buf)
-        FfiConverterDouble.write(value.horizontalAccuracy, into: &buf)
-        FfiConverterOptionTypeCourseOverGround.write(value.courseOverGround, into: &buf)
-        FfiConverterTimestamp.write(value.timestamp, into: &buf)
-        FfiConverterOptionTypeSpeed.write(value.speed, into: &buf)
+    public static func write(_ value: MatchCandidate, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distanceAlongRoute, into: &buf)
+        FfiConverterDouble.write(value.logLikelihood, into: &buf)
     }
 }
 
@@ -3312,112 +3812,155 @@ public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeUserLocation_lift(_ buf: RustBuffer) throws -> UserLocation {
-    return try FfiConverterTypeUserLocation.lift(buf)
+public func FfiConverterTypeMatchCandidate_lift(_ buf: RustBuffer) throws -> MatchCandidate {
+    return try FfiConverterTypeMatchCandidate.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeUserLocation_lower(_ value: UserLocation) -> RustBuffer {
-    return FfiConverterTypeUserLocation.lower(value)
+public func FfiConverterTypeMatchCandidate_lower(_ value: MatchCandidate) -> RustBuffer {
+    return FfiConverterTypeMatchCandidate.lower(value)
 }
 
 
-/**
- * An instruction for visual display (usually as banners) at a specific point along a [`RouteStep`].
- */
-public struct VisualInstruction {
+public struct NavigationControllerConfig {
     /**
-     * The primary instruction content.
+     * Configures when navigation advances to the next step in the route.
+     */
+    public var stepAdvance: StepAdvanceMode
+    /**
+     * Configures when the user is deemed to be off course.
      *
-     * This is usually given more visual weight.
+     * NOTE: This is distinct from the action that is taken.
+     * It is only the determination that the user has deviated from the expected route.
      */
-    public var primaryContent: VisualInstructionContent
+    public var routeDeviationTracking: RouteDeviationTracking
     /**
-     * Optional secondary instruction content.
+     * Configures how the heading component of the snapped location is reported in [`TripState`].
      */
-    public var secondaryContent: VisualInstructionContent?
+    public var snappedLocationCourseFiltering: CourseFiltering
     /**
-     * Optional sub-maneuver instruction content.
+     * Configures how raw location updates are cleaned up before they are used.
      */
-    public var subContent: VisualInstructionContent?
+    public var locationFiltering: LocationFiltering
     /**
-     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+     * Configures how the user's location is snapped to the route.
      */
-    public var triggerDistanceBeforeManeuver: Double
+    public var locationSnapping: LocationSnapping
+    /**
+     * Configures whether upcoming steps are considered when matching the user's location to the route.
+     */
+    public var stepLookAhead: StepLookAhead
+    /**
+     * Configures whether the user's arrival at waypoints is detected.
+     */
+    public var arrivalDetection: ArrivalDetection
+    /**
+     * Configures when visual and spoken instructions are triggered.
+     */
+    public var instructionTriggerMode: InstructionTriggerMode
+    /**
+     * Configures whether the estimated remaining duration is adjusted for the user's observed progress.
+     */
+    public var etaBlending: EtaBlending
+    /**
+     * Configures when the user is flagged as exceeding the speed limit.
+     */
+    public var speedingDetection: SpeedingDetection
+    /**
+     * Configures whether upcoming incidents and congestion are included in [`TripState`].
+     */
+    public var eventLookAhead: EventLookAhead
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The primary instruction content.
+         * Configures when navigation advances to the next step in the route.
+         */stepAdvance: StepAdvanceMode, 
+        /**
+         * Configures when the user is deemed to be off course.
          *
-         * This is usually given more visual weight.
-         */primaryContent: VisualInstructionContent, 
+         * NOTE: This is distinct from the action that is taken.
+         * It is only the determination that the user has deviated from the expected route.
+         */routeDeviationTracking: RouteDeviationTracking, 
         /**
-         * Optional secondary instruction content.
-         */secondaryContent: VisualInstructionContent?, 
+         * Configures how the heading component of the snapped location is reported in [`TripState`].
+         */snappedLocationCourseFiltering: CourseFiltering, 
         /**
-         * Optional sub-maneuver instruction content.
-         */subContent: VisualInstructionContent?, 
+         * Configures how raw location updates are cleaned up before they are used.
+         */locationFiltering: LocationFiltering, 
         /**
-         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
-         */triggerDistanceBeforeManeuver: Double) {
-        self.primaryContent = primaryContent
-        self.secondaryContent = secondaryContent
-        self.subContent = subContent
-        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
+         * Configures how the user's location is snapped to the route.
+         */locationSnapping: LocationSnapping, 
+        /**
+         * Configures whether upcoming steps are considered when matching the user's location to the route.
+         */stepLookAhead: StepLookAhead, 
+        /**
+         * Configures whether the user's arrival at waypoints is detected.
+         */arrivalDetection: ArrivalDetection, 
+        /**
+         * Configures when visual and spoken instructions are triggered.
+         */instructionTriggerMode: InstructionTriggerMode, 
+        /**
+         * Configures whether the estimated remaining duration is adjusted for the user's observed progress.
+         */etaBlending: EtaBlending, 
+        /**
+         * Configures when the user is flagged as exceeding the speed limit.
+         */speedingDetection: SpeedingDetection, 
+        /**
+         * Configures whether upcoming incidents and congestion are included in [`TripState`].
+         */eventLookAhead: EventLookAhead) {
+        self.stepAdvance = stepAdvance
+        self.routeDeviationTracking = routeDeviationTracking
+        self.snappedLocationCourseFiltering = snappedLocationCourseFiltering
+        self.locationFiltering = locationFiltering
+        self.locationSnapping = locationSnapping
+        self.stepLookAhead = stepLookAhead
+        self.arrivalDetection = arrivalDetection
+        self.instructionTriggerMode = instructionTriggerMode
+        self.etaBlending = etaBlending
+        self.speedingDetection = speedingDetection
+        self.eventLookAhead = eventLookAhead
     }
 }
 
 
 
-extension VisualInstruction: Equatable, Hashable {
-    public static func ==(lhs: VisualInstruction, rhs: VisualInstruction) -> Bool {
-        if lhs.primaryContent != rhs.primaryContent {
-            return false
-        }
-        if lhs.secondaryContent != rhs.secondaryContent {
-            return false
-        }
-        if lhs.subContent != rhs.subContent {
-            return false
-        }
-        if lhs.triggerDistanceBeforeManeuver != rhs.triggerDistanceBeforeManeuver {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(primaryContent)
-        hasher.combine(secondaryContent)
-        hasher.combine(subContent)
-        hasher.combine(triggerDistanceBeforeManeuver)
-    }
-}
-
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstruction {
+public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationControllerConfig {
         return
-            try VisualInstruction(
-                primaryContent: FfiConverterTypeVisualInstructionContent.read(from: &buf), 
-                secondaryContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
-                subContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
-                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf)
+            try NavigationControllerConfig(
+                stepAdvance: FfiConverterTypeStepAdvanceMode.read(from: &buf), 
+                routeDeviationTracking: FfiConverterTypeRouteDeviationTracking.read(from: &buf), 
+                snappedLocationCourseFiltering: FfiConverterTypeCourseFiltering.read(from: &buf), 
+                locationFiltering: FfiConverterTypeLocationFiltering.read(from: &buf), 
+                locationSnapping: FfiConverterTypeLocationSnapping.read(from: &buf), 
+                stepLookAhead: FfiConverterTypeStepLookAhead.read(from: &buf), 
+                arrivalDetection: FfiConverterTypeArrivalDetection.read(from: &buf), 
+                instructionTriggerMode: FfiConverterTypeInstructionTriggerMode.read(from: &buf), 
+                etaBlending: FfiConverterTypeEtaBlending.read(from: &buf), 
+                speedingDetection: FfiConverterTypeSpeedingDetection.read(from: &buf), 
+                eventLookAhead: FfiConverterTypeEventLookAhead.read(from: &buf)
         )
     }
 
-    public static func write(_ value: VisualInstruction, into buf: inout [UInt8]) {
-        FfiConverterTypeVisualInstructionContent.write(value.primaryContent, into: &buf)
-        FfiConverterOptionTypeVisualInstructionContent.write(value.secondaryContent, into: &buf)
-        FfiConverterOptionTypeVisualInstructionContent.write(value.subContent, into: &buf)
-        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+    public static func write(_ value: NavigationControllerConfig, into buf: inout [UInt8]) {
+        FfiConverterTypeStepAdvanceMode.write(value.stepAdvance, into: &buf)
+        FfiConverterTypeRouteDeviationTracking.write(value.routeDeviationTracking, into: &buf)
+        FfiConverterTypeCourseFiltering.write(value.snappedLocationCourseFiltering, into: &buf)
+        FfiConverterTypeLocationFiltering.write(value.locationFiltering, into: &buf)
+        FfiConverterTypeLocationSnapping.write(value.locationSnapping, into: &buf)
+        FfiConverterTypeStepLookAhead.write(value.stepLookAhead, into: &buf)
+        FfiConverterTypeArrivalDetection.write(value.arrivalDetection, into: &buf)
+        FfiConverterTypeInstructionTriggerMode.write(value.instructionTriggerMode, into: &buf)
+        FfiConverterTypeEtaBlending.write(value.etaBlending, into: &buf)
+        FfiConverterTypeSpeedingDetection.write(value.speedingDetection, into: &buf)
+        FfiConverterTypeEventLookAhead.write(value.eventLookAhead, into: &buf)
     }
 }
 
@@ -3425,117 +3968,61 @@ public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstruction_lift(_ buf: RustBuffer) throws -> VisualInstruction {
-    return try FfiConverterTypeVisualInstruction.lift(buf)
+public func FfiConverterTypeNavigationControllerConfig_lift(_ buf: RustBuffer) throws -> NavigationControllerConfig {
+    return try FfiConverterTypeNavigationControllerConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstruction_lower(_ value: VisualInstruction) -> RustBuffer {
-    return FfiConverterTypeVisualInstruction.lower(value)
+public func FfiConverterTypeNavigationControllerConfig_lower(_ value: NavigationControllerConfig) -> RustBuffer {
+    return FfiConverterTypeNavigationControllerConfig.lower(value)
 }
 
 
 /**
- * The content of a visual instruction.
+ * The result of a location update which also reports the [`NavigationEvent`]s that occurred.
  */
-public struct VisualInstructionContent {
-    /**
-     * The text to display.
-     */
-    public var text: String
-    /**
-     * A standardized maneuver type (if any).
-     */
-    public var maneuverType: ManeuverType?
-    /**
-     * A standardized maneuver modifier (if any).
-     */
-    public var maneuverModifier: ManeuverModifier?
+public struct NavigationUpdate {
     /**
-     * If applicable, the number of degrees you need to go around the roundabout before exiting.
-     *
-     * For example, entering and exiting the roundabout in the same direction of travel
-     * (as if you had gone straight, apart from the detour)
-     * would be an exit angle of 180 degrees.
-     */
-    public var roundaboutExitDegrees: UInt16?
-    /**
-     * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
+     * The updated trip state.
      */
-    public var laneInfo: [LaneInfo]?
+    public var tripState: TripState
     /**
-     * The exit number (or similar identifier like "8B").
+     * The events which occurred during the update, in the order they should be handled.
      */
-    public var exitNumbers: [String]
+    public var events: [NavigationEvent]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The text to display.
-         */text: String, 
-        /**
-         * A standardized maneuver type (if any).
-         */maneuverType: ManeuverType?, 
-        /**
-         * A standardized maneuver modifier (if any).
-         */maneuverModifier: ManeuverModifier?, 
-        /**
-         * If applicable, the number of degrees you need to go around the roundabout before exiting.
-         *
-         * For example, entering and exiting the roundabout in the same direction of travel
-         * (as if you had gone straight, apart from the detour)
-         * would be an exit angle of 180 degrees.
-         */roundaboutExitDegrees: UInt16?, 
-        /**
-         * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
-         */laneInfo: [LaneInfo]?, 
+         * The updated trip state.
+         */tripState: TripState, 
         /**
-         * The exit number (or similar identifier like "8B").
-         */exitNumbers: [String]) {
-        self.text = text
-        self.maneuverType = maneuverType
-        self.maneuverModifier = maneuverModifier
-        self.roundaboutExitDegrees = roundaboutExitDegrees
-        self.laneInfo = laneInfo
-        self.exitNumbers = exitNumbers
+         * The events which occurred during the update, in the order they should be handled.
+         */events: [NavigationEvent]) {
+        self.tripState = tripState
+        self.events = events
     }
 }
 
 
 
-extension VisualInstructionContent: Equatable, Hashable {
-    public static func ==(lhs: VisualInstructionContent, rhs: VisualInstructionContent) -> Bool {
-        if lhs.text != rhs.text {
-            return false
-        }
-        if lhs.maneuverType != rhs.maneuverType {
-            return false
-        }
-        if lhs.maneuverModifier != rhs.maneuverModifier {
-            return false
-        }
-        if lhs.roundaboutExitDegrees != rhs.roundaboutExitDegrees {
-            return false
-        }
-        if lhs.laneInfo != rhs.laneInfo {
+extension NavigationUpdate: Equatable, Hashable {
+    public static func ==(lhs: NavigationUpdate, rhs: NavigationUpdate) -> Bool {
+        if lhs.tripState != rhs.tripState {
             return false
         }
-        if lhs.exitNumbers != rhs.exitNumbers {
+        if lhs.events != rhs.events {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(text)
-        hasher.combine(maneuverType)
-        hasher.combine(maneuverModifier)
-        hasher.combine(roundaboutExitDegrees)
-        hasher.combine(laneInfo)
-        hasher.combine(exitNumbers)
+        hasher.combine(tripState)
+        hasher.combine(events)
     }
 }
 
@@ -3543,26 +4030,18 @@ extension VisualInstructionContent: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstructionContent {
+public struct FfiConverterTypeNavigationUpdate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationUpdate {
         return
-            try VisualInstructionContent(
-                text: FfiConverterString.read(from: &buf), 
-                maneuverType: FfiConverterOptionTypeManeuverType.read(from: &buf), 
-                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf), 
-                roundaboutExitDegrees: FfiConverterOptionUInt16.read(from: &buf), 
-                laneInfo: FfiConverterOptionSequenceTypeLaneInfo.read(from: &buf), 
-                exitNumbers: FfiConverterSequenceString.read(from: &buf)
+            try NavigationUpdate(
+                tripState: FfiConverterTypeTripState.read(from: &buf), 
+                events: FfiConverterSequenceTypeNavigationEvent.read(from: &buf)
         )
     }
 
-    public static func write(_ value: VisualInstructionContent, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterOptionTypeManeuverType.write(value.maneuverType, into: &buf)
-        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
-        FfiConverterOptionUInt16.write(value.roundaboutExitDegrees, into: &buf)
-        FfiConverterOptionSequenceTypeLaneInfo.write(value.laneInfo, into: &buf)
-        FfiConverterSequenceString.write(value.exitNumbers, into: &buf)
+    public static func write(_ value: NavigationUpdate, into buf: inout [UInt8]) {
+        FfiConverterTypeTripState.write(value.tripState, into: &buf)
+        FfiConverterSequenceTypeNavigationEvent.write(value.events, into: &buf)
     }
 }
 
@@ -3570,59 +4049,65 @@ public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstructionContent_lift(_ buf: RustBuffer) throws -> VisualInstructionContent {
-    return try FfiConverterTypeVisualInstructionContent.lift(buf)
+public func FfiConverterTypeNavigationUpdate_lift(_ buf: RustBuffer) throws -> NavigationUpdate {
+    return try FfiConverterTypeNavigationUpdate.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstructionContent_lower(_ value: VisualInstructionContent) -> RustBuffer {
-    return FfiConverterTypeVisualInstructionContent.lower(value)
+public func FfiConverterTypeNavigationUpdate_lower(_ value: NavigationUpdate) -> RustBuffer {
+    return FfiConverterTypeNavigationUpdate.lower(value)
 }
 
 
 /**
- * A waypoint along a route.
- *
- * Within the context of Ferrostar, a route request consists of exactly one [`UserLocation`]
- * and at least one [`Waypoint`]. The route starts from the user's location (which may
- * contain other useful information like their current course for the [`crate::routing_adapters::RouteRequestGenerator`]
- * to use) and proceeds through one or more waypoints.
- *
- * Waypoints are used during route calculation, are tracked throughout the lifecycle of a trip,
- * and are used for recalculating when the user deviates from the expected route.
- *
- * Note that support for properties beyond basic geographic coordinates varies by routing engine.
+ * A sample of the user's progress along the route at a point in time.
  */
-public struct Waypoint {
-    public var coordinate: GeographicCoordinate
-    public var kind: WaypointKind
+public struct ProgressSample {
+    /**
+     * The elapsed trip time when the sample was taken, in seconds
+     * (see [`TripStatistics::elapsed_time`]).
+     */
+    public var elapsedTime: Double
+    /**
+     * The duration remaining in the trip according to the route, in seconds
+     * (see [`TripProgress::duration_remaining`]).
+     */
+    public var durationRemaining: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(coordinate: GeographicCoordinate, kind: WaypointKind) {
-        self.coordinate = coordinate
-        self.kind = kind
+    public init(
+        /**
+         * The elapsed trip time when the sample was taken, in seconds
+         * (see [`TripStatistics::elapsed_time`]).
+         */elapsedTime: Double, 
+        /**
+         * The duration remaining in the trip according to the route, in seconds
+         * (see [`TripProgress::duration_remaining`]).
+         */durationRemaining: Double) {
+        self.elapsedTime = elapsedTime
+        self.durationRemaining = durationRemaining
     }
 }
 
 
 
-extension Waypoint: Equatable, Hashable {
-    public static func ==(lhs: Waypoint, rhs: Waypoint) -> Bool {
-        if lhs.coordinate != rhs.coordinate {
+extension ProgressSample: Equatable, Hashable {
+    public static func ==(lhs: ProgressSample, rhs: ProgressSample) -> Bool {
+        if lhs.elapsedTime != rhs.elapsedTime {
             return false
         }
-        if lhs.kind != rhs.kind {
+        if lhs.durationRemaining != rhs.durationRemaining {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(coordinate)
-        hasher.combine(kind)
+        hasher.combine(elapsedTime)
+        hasher.combine(durationRemaining)
     }
 }
 
@@ -3630,18 +4115,18 @@ extension Waypoint: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Waypoint {
+public struct FfiConverterTypeProgressSample: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProgressSample {
         return
-            try Waypoint(
-                coordinate: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
-                kind: FfiConverterTypeWaypointKind.read(from: &buf)
+            try ProgressSample(
+                elapsedTime: FfiConverterDouble.read(from: &buf), 
+                durationRemaining: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Waypoint, into buf: inout [UInt8]) {
-        FfiConverterTypeGeographicCoordinate.write(value.coordinate, into: &buf)
-        FfiConverterTypeWaypointKind.write(value.kind, into: &buf)
+    public static func write(_ value: ProgressSample, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.elapsedTime, into: &buf)
+        FfiConverterDouble.write(value.durationRemaining, into: &buf)
     }
 }
 
@@ -3649,102 +4134,61 @@ public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypoint_lift(_ buf: RustBuffer) throws -> Waypoint {
-    return try FfiConverterTypeWaypoint.lift(buf)
+public func FfiConverterTypeProgressSample_lift(_ buf: RustBuffer) throws -> ProgressSample {
+    return try FfiConverterTypeProgressSample.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypoint_lower(_ value: Waypoint) -> RustBuffer {
-    return FfiConverterTypeWaypoint.lower(value)
+public func FfiConverterTypeProgressSample_lower(_ value: ProgressSample) -> RustBuffer {
+    return FfiConverterTypeProgressSample.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
 /**
- * The lane type blocked by the incident.
+ * A pronunciation for a word or phrase in an instruction.
  */
+public struct Pronunciation {
+    /**
+     * The text as written in the instruction.
+     */
+    public var text: String
+    /**
+     * The pronunciation in the International Phonetic Alphabet.
+     */
+    public var ipa: String
 
-public enum BlockedLane {
-    
-    case left
-    case leftCenter
-    case leftTurnLane
-    case center
-    case right
-    case rightCenter
-    case rightTurnLane
-    case hov
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The text as written in the instruction.
+         */text: String, 
+        /**
+         * The pronunciation in the International Phonetic Alphabet.
+         */ipa: String) {
+        self.text = text
+        self.ipa = ipa
+    }
 }
 
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
-    typealias SwiftType = BlockedLane
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BlockedLane {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .left
-        
-        case 2: return .leftCenter
-        
-        case 3: return .leftTurnLane
-        
-        case 4: return .center
-        
-        case 5: return .right
-        
-        case 6: return .rightCenter
-        
-        case 7: return .rightTurnLane
-        
-        case 8: return .hov
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+extension Pronunciation: Equatable, Hashable {
+    public static func ==(lhs: Pronunciation, rhs: Pronunciation) -> Bool {
+        if lhs.text != rhs.text {
+            return false
         }
+        if lhs.ipa != rhs.ipa {
+            return false
+        }
+        return true
     }
 
-    public static func write(_ value: BlockedLane, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .left:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .leftCenter:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .leftTurnLane:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .center:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .right:
-            writeInt(&buf, Int32(5))
-        
-        
-        case .rightCenter:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .rightTurnLane:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .hov:
-            writeInt(&buf, Int32(8))
-        
-        }
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(text)
+        hasher.combine(ipa)
     }
 }
 
@@ -3752,73 +4196,151 @@ public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBlockedLane_lift(_ buf: RustBuffer) throws -> BlockedLane {
-    return try FfiConverterTypeBlockedLane.lift(buf)
+public struct FfiConverterTypePronunciation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Pronunciation {
+        return
+            try Pronunciation(
+                text: FfiConverterString.read(from: &buf), 
+                ipa: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Pronunciation, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterString.write(value.ipa, into: &buf)
+    }
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBlockedLane_lower(_ value: BlockedLane) -> RustBuffer {
-    return FfiConverterTypeBlockedLane.lower(value)
+public func FfiConverterTypePronunciation_lift(_ buf: RustBuffer) throws -> Pronunciation {
+    return try FfiConverterTypePronunciation.lift(buf)
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePronunciation_lower(_ value: Pronunciation) -> RustBuffer {
+    return FfiConverterTypePronunciation.lower(value)
+}
 
 
-extension BlockedLane: Equatable, Hashable {}
-
-
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Controls filtering/post-processing of user course by the [`NavigationController`].
+ * Configures when a [`ReroutePolicy`] decides that a new route should be requested.
+ *
+ * All conditions must be met before a reroute is requested.
  */
-
-public enum CourseFiltering {
-    
+public struct ReroutePolicyConfig {
     /**
-     * Snap the user's course to the current step's linestring using the next index in the step's geometry.
-
+     * The minimum amount of time (in seconds) that must elapse between two reroute requests.
+     *
+     * Time is measured using [`TripStatistics::elapsed_time`],
+     * so it is based on location timestamps rather than the wall clock.
      */
-    case snapToRoute
+    public var minimumTimeBetweenReroutes: Double
     /**
-     * Use the raw course as reported by the location provider with no processing.
+     * The number of consecutive location updates that must be off the route
+     * before a reroute is requested.
+     *
+     * This helps to avoid rerouting due to a single noisy location fix.
+     * Values of zero are treated the same as one.
      */
-    case raw
+    public var minimumConsecutiveOffRouteUpdates: UInt32
+    /**
+     * The minimum distance (in meters) that the user must travel between two reroute requests.
+     */
+    public var minimumDistanceBetweenReroutes: Double
+    /**
+     * The maximum number of reroutes to request over the course of a trip.
+     *
+     * Reroutes are counted by [`TripStatistics::reroute_count`],
+     * so they only count once the new route has been applied.
+     * When this is `None`, there is no limit.
+     */
+    public var maxReroutesPerTrip: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The minimum amount of time (in seconds) that must elapse between two reroute requests.
+         *
+         * Time is measured using [`TripStatistics::elapsed_time`],
+         * so it is based on location timestamps rather than the wall clock.
+         */minimumTimeBetweenReroutes: Double, 
+        /**
+         * The number of consecutive location updates that must be off the route
+         * before a reroute is requested.
+         *
+         * This helps to avoid rerouting due to a single noisy location fix.
+         * Values of zero are treated the same as one.
+         */minimumConsecutiveOffRouteUpdates: UInt32, 
+        /**
+         * The minimum distance (in meters) that the user must travel between two reroute requests.
+         */minimumDistanceBetweenReroutes: Double, 
+        /**
+         * The maximum number of reroutes to request over the course of a trip.
+         *
+         * Reroutes are counted by [`TripStatistics::reroute_count`],
+         * so they only count once the new route has been applied.
+         * When this is `None`, there is no limit.
+         */maxReroutesPerTrip: UInt32?) {
+        self.minimumTimeBetweenReroutes = minimumTimeBetweenReroutes
+        self.minimumConsecutiveOffRouteUpdates = minimumConsecutiveOffRouteUpdates
+        self.minimumDistanceBetweenReroutes = minimumDistanceBetweenReroutes
+        self.maxReroutesPerTrip = maxReroutesPerTrip
+    }
+}
+
+
+
+extension ReroutePolicyConfig: Equatable, Hashable {
+    public static func ==(lhs: ReroutePolicyConfig, rhs: ReroutePolicyConfig) -> Bool {
+        if lhs.minimumTimeBetweenReroutes != rhs.minimumTimeBetweenReroutes {
+            return false
+        }
+        if lhs.minimumConsecutiveOffRouteUpdates != rhs.minimumConsecutiveOffRouteUpdates {
+            return false
+        }
+        if lhs.minimumDistanceBetweenReroutes != rhs.minimumDistanceBetweenReroutes {
+            return false
+        }
+        if lhs.maxReroutesPerTrip != rhs.maxReroutesPerTrip {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(minimumTimeBetweenReroutes)
+        hasher.combine(minimumConsecutiveOffRouteUpdates)
+        hasher.combine(minimumDistanceBetweenReroutes)
+        hasher.combine(maxReroutesPerTrip)
+    }
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
-    typealias SwiftType = CourseFiltering
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseFiltering {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .snapToRoute
-        
-        case 2: return .raw
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+public struct FfiConverterTypeReroutePolicyConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReroutePolicyConfig {
+        return
+            try ReroutePolicyConfig(
+                minimumTimeBetweenReroutes: FfiConverterDouble.read(from: &buf), 
+                minimumConsecutiveOffRouteUpdates: FfiConverterUInt32.read(from: &buf), 
+                minimumDistanceBetweenReroutes: FfiConverterDouble.read(from: &buf), 
+                maxReroutesPerTrip: FfiConverterOptionUInt32.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: CourseFiltering, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .snapToRoute:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .raw:
-            writeInt(&buf, Int32(2))
-        
-        }
+    public static func write(_ value: ReroutePolicyConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.minimumTimeBetweenReroutes, into: &buf)
+        FfiConverterUInt32.write(value.minimumConsecutiveOffRouteUpdates, into: &buf)
+        FfiConverterDouble.write(value.minimumDistanceBetweenReroutes, into: &buf)
+        FfiConverterOptionUInt32.write(value.maxReroutesPerTrip, into: &buf)
     }
 }
 
@@ -3826,224 +4348,179 @@ public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseFiltering_lift(_ buf: RustBuffer) throws -> CourseFiltering {
-    return try FfiConverterTypeCourseFiltering.lift(buf)
+public func FfiConverterTypeReroutePolicyConfig_lift(_ buf: RustBuffer) throws -> ReroutePolicyConfig {
+    return try FfiConverterTypeReroutePolicyConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseFiltering_lower(_ value: CourseFiltering) -> RustBuffer {
-    return FfiConverterTypeCourseFiltering.lower(value)
+public func FfiConverterTypeReroutePolicyConfig_lower(_ value: ReroutePolicyConfig) -> RustBuffer {
+    return FfiConverterTypeReroutePolicyConfig.lower(value)
 }
 
 
+/**
+ * The state of a [`ReroutePolicy`], which must be passed back in with each trip state update.
+ */
+public struct ReroutePolicyState {
+    /**
+     * The decision for the most recent trip state.
+     */
+    public var decision: RerouteDecision
+    /**
+     * The number of consecutive trip states in which the user was off the route
+     * since the last reroute request.
+     */
+    public var consecutiveOffRouteUpdates: UInt32
+    /**
+     * The elapsed trip time (in seconds) when the last reroute was requested.
+     */
+    public var lastRerouteElapsedTime: Double?
+    /**
+     * The distance traveled (in meters) when the last reroute was requested.
+     */
+    public var lastRerouteDistanceTraveled: Double?
 
-extension CourseFiltering: Equatable, Hashable {}
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The decision for the most recent trip state.
+         */decision: RerouteDecision, 
+        /**
+         * The number of consecutive trip states in which the user was off the route
+         * since the last reroute request.
+         */consecutiveOffRouteUpdates: UInt32, 
+        /**
+         * The elapsed trip time (in seconds) when the last reroute was requested.
+         */lastRerouteElapsedTime: Double?, 
+        /**
+         * The distance traveled (in meters) when the last reroute was requested.
+         */lastRerouteDistanceTraveled: Double?) {
+        self.decision = decision
+        self.consecutiveOffRouteUpdates = consecutiveOffRouteUpdates
+        self.lastRerouteElapsedTime = lastRerouteElapsedTime
+        self.lastRerouteDistanceTraveled = lastRerouteDistanceTraveled
+    }
+}
 
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The impact of the incident that has occurred.
- */
+extension ReroutePolicyState: Equatable, Hashable {
+    public static func ==(lhs: ReroutePolicyState, rhs: ReroutePolicyState) -> Bool {
+        if lhs.decision != rhs.decision {
+            return false
+        }
+        if lhs.consecutiveOffRouteUpdates != rhs.consecutiveOffRouteUpdates {
+            return false
+        }
+        if lhs.lastRerouteElapsedTime != rhs.lastRerouteElapsedTime {
+            return false
+        }
+        if lhs.lastRerouteDistanceTraveled != rhs.lastRerouteDistanceTraveled {
+            return false
+        }
+        return true
+    }
 
-public enum Impact {
-    
-    case unknown
-    case critical
-    case major
-    case minor
-    case low
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(decision)
+        hasher.combine(consecutiveOffRouteUpdates)
+        hasher.combine(lastRerouteElapsedTime)
+        hasher.combine(lastRerouteDistanceTraveled)
+    }
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeImpact: FfiConverterRustBuffer {
-    typealias SwiftType = Impact
+public struct FfiConverterTypeReroutePolicyState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReroutePolicyState {
+        return
+            try ReroutePolicyState(
+                decision: FfiConverterTypeRerouteDecision.read(from: &buf), 
+                consecutiveOffRouteUpdates: FfiConverterUInt32.read(from: &buf), 
+                lastRerouteElapsedTime: FfiConverterOptionDouble.read(from: &buf), 
+                lastRerouteDistanceTraveled: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Impact {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .unknown
-        
-        case 2: return .critical
-        
-        case 3: return .major
-        
-        case 4: return .minor
-        
-        case 5: return .low
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+    public static func write(_ value: ReroutePolicyState, into buf: inout [UInt8]) {
+        FfiConverterTypeRerouteDecision.write(value.decision, into: &buf)
+        FfiConverterUInt32.write(value.consecutiveOffRouteUpdates, into: &buf)
+        FfiConverterOptionDouble.write(value.lastRerouteElapsedTime, into: &buf)
+        FfiConverterOptionDouble.write(value.lastRerouteDistanceTraveled, into: &buf)
     }
-
-    public static func write(_ value: Impact, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .unknown:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .critical:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .major:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .minor:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .low:
-            writeInt(&buf, Int32(5))
-        
-        }
-    }
-}
+}
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeImpact_lift(_ buf: RustBuffer) throws -> Impact {
-    return try FfiConverterTypeImpact.lift(buf)
+public func FfiConverterTypeReroutePolicyState_lift(_ buf: RustBuffer) throws -> ReroutePolicyState {
+    return try FfiConverterTypeReroutePolicyState.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeImpact_lower(_ value: Impact) -> RustBuffer {
-    return FfiConverterTypeImpact.lower(value)
+public func FfiConverterTypeReroutePolicyState_lower(_ value: ReroutePolicyState) -> RustBuffer {
+    return FfiConverterTypeReroutePolicyState.lower(value)
 }
 
 
+/**
+ * A distance which has been converted and rounded for presentation to the user.
+ */
+public struct RoundedDistance {
+    public var value: Double
+    public var unit: DistanceUnit
 
-extension Impact: Equatable, Hashable {}
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(value: Double, unit: DistanceUnit) {
+        self.value = value
+        self.unit = unit
+    }
+}
 
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The type of incident that has occurred.
- */
+extension RoundedDistance: Equatable, Hashable {
+    public static func ==(lhs: RoundedDistance, rhs: RoundedDistance) -> Bool {
+        if lhs.value != rhs.value {
+            return false
+        }
+        if lhs.unit != rhs.unit {
+            return false
+        }
+        return true
+    }
 
-public enum IncidentType {
-    
-    case accident
-    case congestion
-    case construction
-    case disabledVehicle
-    case laneRestriction
-    case massTransit
-    case miscellaneous
-    case otherNews
-    case plannedEvent
-    case roadClosure
-    case roadHazard
-    case weather
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(value)
+        hasher.combine(unit)
+    }
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
-    typealias SwiftType = IncidentType
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IncidentType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .accident
-        
-        case 2: return .congestion
-        
-        case 3: return .construction
-        
-        case 4: return .disabledVehicle
-        
-        case 5: return .laneRestriction
-        
-        case 6: return .massTransit
-        
-        case 7: return .miscellaneous
-        
-        case 8: return .otherNews
-        
-        case 9: return .plannedEvent
-        
-        case 10: return .roadClosure
-        
-        case 11: return .roadHazard
-        
-        case 12: return .weather
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+public struct FfiConverterTypeRoundedDistance: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoundedDistance {
+        return
+            try RoundedDistance(
+                value: FfiConverterDouble.read(from: &buf), 
+                unit: FfiConverterTypeDistanceUnit.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: IncidentType, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .accident:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .congestion:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .construction:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .disabledVehicle:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .laneRestriction:
-            writeInt(&buf, Int32(5))
-        
-        
-        case .massTransit:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .miscellaneous:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .otherNews:
-            writeInt(&buf, Int32(8))
-        
-        
-        case .plannedEvent:
-            writeInt(&buf, Int32(9))
-        
-        
-        case .roadClosure:
-            writeInt(&buf, Int32(10))
-        
-        
-        case .roadHazard:
-            writeInt(&buf, Int32(11))
-        
-        
-        case .weather:
-            writeInt(&buf, Int32(12))
-        
-        }
+    public static func write(_ value: RoundedDistance, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.value, into: &buf)
+        FfiConverterTypeDistanceUnit.write(value.unit, into: &buf)
     }
 }
 
@@ -4051,162 +4528,4351 @@ public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncidentType_lift(_ buf: RustBuffer) throws -> IncidentType {
-    return try FfiConverterTypeIncidentType.lift(buf)
+public func FfiConverterTypeRoundedDistance_lift(_ buf: RustBuffer) throws -> RoundedDistance {
+    return try FfiConverterTypeRoundedDistance.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncidentType_lower(_ value: IncidentType) -> RustBuffer {
-    return FfiConverterTypeIncidentType.lower(value)
+public func FfiConverterTypeRoundedDistance_lower(_ value: RoundedDistance) -> RustBuffer {
+    return FfiConverterTypeRoundedDistance.lower(value)
 }
 
 
+/**
+ * Information describing the series of steps needed to travel between two or more points.
+ *
+ * NOTE: This type is unstable and is still under active development and should be
+ * considered unstable.
+ */
+public struct Route {
+    public var geometry: [GeographicCoordinate]
+    public var bbox: BoundingBox
+    /**
+     * The total route distance, in meters.
+     */
+    public var distance: Double
+    /**
+     * The ordered list of waypoints to visit, including the starting point.
+     * Note that this is distinct from the *geometry* which includes all points visited.
+     * A waypoint represents a start/end point for a route leg.
+     */
+    public var waypoints: [Waypoint]
+    public var steps: [RouteStep]
+    /**
+     * The legs of the route, in order.
+     *
+     * The [`RouteStep::leg_index`] of each step is an index into this list.
+     * This may be empty if the route was built from a source without leg information.
+     */
+    public var legs: [RouteLeg]
 
-extension IncidentType: Equatable, Hashable {}
-
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(geometry: [GeographicCoordinate], bbox: BoundingBox, 
+        /**
+         * The total route distance, in meters.
+         */distance: Double, 
+        /**
+         * The ordered list of waypoints to visit, including the starting point.
+         * Note that this is distinct from the *geometry* which includes all points visited.
+         * A waypoint represents a start/end point for a route leg.
+         */waypoints: [Waypoint], steps: [RouteStep], 
+        /**
+         * The legs of the route, in order.
+         *
+         * The [`RouteStep::leg_index`] of each step is an index into this list.
+         * This may be empty if the route was built from a source without leg information.
+         */legs: [RouteLeg] = []) {
+        self.geometry = geometry
+        self.bbox = bbox
+        self.distance = distance
+        self.waypoints = waypoints
+        self.steps = steps
+        self.legs = legs
+    }
+}
 
 
 
-public enum InstantiationError {
+extension Route: Equatable, Hashable {
+    public static func ==(lhs: Route, rhs: Route) -> Bool {
+        if lhs.geometry != rhs.geometry {
+            return false
+        }
+        if lhs.bbox != rhs.bbox {
+            return false
+        }
+        if lhs.distance != rhs.distance {
+            return false
+        }
+        if lhs.waypoints != rhs.waypoints {
+            return false
+        }
+        if lhs.steps != rhs.steps {
+            return false
+        }
+        if lhs.legs != rhs.legs {
+            return false
+        }
+        return true
+    }
 
-    
-    
-    case OptionsJsonParseError
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(geometry)
+        hasher.combine(bbox)
+        hasher.combine(distance)
+        hasher.combine(waypoints)
+        hasher.combine(steps)
+        hasher.combine(legs)
+    }
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeInstantiationError: FfiConverterRustBuffer {
-    typealias SwiftType = InstantiationError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstantiationError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Route {
+        return
+            try Route(
+                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                bbox: FfiConverterTypeBoundingBox.read(from: &buf), 
+                distance: FfiConverterDouble.read(from: &buf), 
+                waypoints: FfiConverterSequenceTypeWaypoint.read(from: &buf), 
+                steps: FfiConverterSequenceTypeRouteStep.read(from: &buf), 
+                legs: FfiConverterSequenceTypeRouteLeg.read(from: &buf)
+        )
+    }
 
-        
+    public static func write(_ value: Route, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
+        FfiConverterTypeBoundingBox.write(value.bbox, into: &buf)
+        FfiConverterDouble.write(value.distance, into: &buf)
+        FfiConverterSequenceTypeWaypoint.write(value.waypoints, into: &buf)
+        FfiConverterSequenceTypeRouteStep.write(value.steps, into: &buf)
+        FfiConverterSequenceTypeRouteLeg.write(value.legs, into: &buf)
+    }
+}
 
-        
-        case 1: return .OptionsJsonParseError
 
-         default: throw UniffiInternalError.unexpectedEnumCase
-        }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoute_lift(_ buf: RustBuffer) throws -> Route {
+    return try FfiConverterTypeRoute.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoute_lower(_ value: Route) -> RustBuffer {
+    return FfiConverterTypeRoute.lower(value)
+}
+
+
+/**
+ * A portion of a route which ends at a waypoint where the user stops
+ * (ex: an intermediate stop or the final destination).
+ *
+ * [`WaypointKind::Via`] waypoints are passed through, and do not split a route into legs.
+ */
+public struct RouteLeg {
+    /**
+     * The distance of the leg, in meters.
+     */
+    public var distance: Double
+    /**
+     * The estimated duration of the leg, in seconds.
+     */
+    public var duration: Double
+    /**
+     * The index of the waypoint in [`Route::waypoints`] where this leg ends.
+     */
+    public var destinationWaypointIndex: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The distance of the leg, in meters.
+         */distance: Double, 
+        /**
+         * The estimated duration of the leg, in seconds.
+         */duration: Double, 
+        /**
+         * The index of the waypoint in [`Route::waypoints`] where this leg ends.
+         */destinationWaypointIndex: UInt64) {
+        self.distance = distance
+        self.duration = duration
+        self.destinationWaypointIndex = destinationWaypointIndex
+    }
+}
+
+
+
+extension RouteLeg: Equatable, Hashable {
+    public static func ==(lhs: RouteLeg, rhs: RouteLeg) -> Bool {
+        if lhs.distance != rhs.distance {
+            return false
+        }
+        if lhs.duration != rhs.duration {
+            return false
+        }
+        if lhs.destinationWaypointIndex != rhs.destinationWaypointIndex {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(distance)
+        hasher.combine(duration)
+        hasher.combine(destinationWaypointIndex)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRouteLeg: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteLeg {
+        return
+            try RouteLeg(
+                distance: FfiConverterDouble.read(from: &buf), 
+                duration: FfiConverterDouble.read(from: &buf), 
+                destinationWaypointIndex: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RouteLeg, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distance, into: &buf)
+        FfiConverterDouble.write(value.duration, into: &buf)
+        FfiConverterUInt64.write(value.destinationWaypointIndex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteLeg_lift(_ buf: RustBuffer) throws -> RouteLeg {
+    return try FfiConverterTypeRouteLeg.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteLeg_lower(_ value: RouteLeg) -> RustBuffer {
+    return FfiConverterTypeRouteLeg.lower(value)
+}
+
+
+/**
+ * A maneuver (such as a turn or merge) followed by travel of a certain distance until reaching
+ * the next step.
+ */
+public struct RouteStep {
+    /**
+     * The full route geometry for this step.
+     */
+    public var geometry: [GeographicCoordinate]
+    /**
+     * The distance, in meters, to travel along the route after the maneuver to reach the next step.
+     */
+    public var distance: Double
+    /**
+     * The estimated duration, in seconds, that it will take to complete this step.
+     */
+    public var duration: Double
+    /**
+     * The name of the road being traveled on (useful for certain UI styles).
+     */
+    public var roadName: String?
+    /**
+     * A list of exits (name or number).
+     */
+    public var exits: [String]
+    /**
+     * A description of the maneuver (ex: "Turn wright onto main street").
+     *
+     * Note for UI implementers: the context this appears in (or doesn't)
+     * depends somewhat on your use case and routing engine.
+     * For example, this field is useful as a written instruction in Valhalla.
+     */
+    public var instruction: String
+    /**
+     * A list of instructions for visual display (usually as banners) at specific points along the step.
+     */
+    public var visualInstructions: [VisualInstruction]
+    /**
+     * A list of prompts to announce (via speech synthesis) at specific points along the step.
+     */
+    public var spokenInstructions: [SpokenInstruction]
+    /**
+     * Annotations for each segment (between successive coordinates) along the step.
+     */
+    public var annotations: [SegmentAnnotation]?
+    /**
+     * A list of incidents that occur along the step.
+     */
+    public var incidents: [Incident]
+    /**
+     * The maneuver at the start of the step (if known).
+     */
+    public var maneuver: Maneuver?
+    /**
+     * The intersections along the step, starting with the one where the maneuver takes place.
+     */
+    public var intersections: [Intersection]
+    /**
+     * The index of the leg in [`Route::legs`] which this step is a part of.
+     */
+    public var legIndex: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The full route geometry for this step.
+         */geometry: [GeographicCoordinate], 
+        /**
+         * The distance, in meters, to travel along the route after the maneuver to reach the next step.
+         */distance: Double, 
+        /**
+         * The estimated duration, in seconds, that it will take to complete this step.
+         */duration: Double, 
+        /**
+         * The name of the road being traveled on (useful for certain UI styles).
+         */roadName: String?, 
+        /**
+         * A list of exits (name or number).
+         */exits: [String], 
+        /**
+         * A description of the maneuver (ex: "Turn wright onto main street").
+         *
+         * Note for UI implementers: the context this appears in (or doesn't)
+         * depends somewhat on your use case and routing engine.
+         * For example, this field is useful as a written instruction in Valhalla.
+         */instruction: String, 
+        /**
+         * A list of instructions for visual display (usually as banners) at specific points along the step.
+         */visualInstructions: [VisualInstruction], 
+        /**
+         * A list of prompts to announce (via speech synthesis) at specific points along the step.
+         */spokenInstructions: [SpokenInstruction], 
+        /**
+         * Annotations for each segment (between successive coordinates) along the step.
+         */annotations: [SegmentAnnotation]?, 
+        /**
+         * A list of incidents that occur along the step.
+         */incidents: [Incident], 
+        /**
+         * The maneuver at the start of the step (if known).
+         */maneuver: Maneuver? = nil, 
+        /**
+         * The intersections along the step, starting with the one where the maneuver takes place.
+         */intersections: [Intersection] = [], 
+        /**
+         * The index of the leg in [`Route::legs`] which this step is a part of.
+         */legIndex: UInt64 = UInt64(0)) {
+        self.geometry = geometry
+        self.distance = distance
+        self.duration = duration
+        self.roadName = roadName
+        self.exits = exits
+        self.instruction = instruction
+        self.visualInstructions = visualInstructions
+        self.spokenInstructions = spokenInstructions
+        self.annotations = annotations
+        self.incidents = incidents
+        self.maneuver = maneuver
+        self.intersections = intersections
+        self.legIndex = legIndex
+    }
+}
+
+
+
+extension RouteStep: Equatable, Hashable {
+    public static func ==(lhs: RouteStep, rhs: RouteStep) -> Bool {
+        if lhs.geometry != rhs.geometry {
+            return false
+        }
+        if lhs.distance != rhs.distance {
+            return false
+        }
+        if lhs.duration != rhs.duration {
+            return false
+        }
+        if lhs.roadName != rhs.roadName {
+            return false
+        }
+        if lhs.exits != rhs.exits {
+            return false
+        }
+        if lhs.instruction != rhs.instruction {
+            return false
+        }
+        if lhs.visualInstructions != rhs.visualInstructions {
+            return false
+        }
+        if lhs.spokenInstructions != rhs.spokenInstructions {
+            return false
+        }
+        if lhs.annotations != rhs.annotations {
+            return false
+        }
+        if lhs.incidents != rhs.incidents {
+            return false
+        }
+        if lhs.maneuver != rhs.maneuver {
+            return false
+        }
+        if lhs.intersections != rhs.intersections {
+            return false
+        }
+        if lhs.legIndex != rhs.legIndex {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(geometry)
+        hasher.combine(distance)
+        hasher.combine(duration)
+        hasher.combine(roadName)
+        hasher.combine(exits)
+        hasher.combine(instruction)
+        hasher.combine(visualInstructions)
+        hasher.combine(spokenInstructions)
+        hasher.combine(annotations)
+        hasher.combine(incidents)
+        hasher.combine(maneuver)
+        hasher.combine(intersections)
+        hasher.combine(legIndex)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteStep {
+        return
+            try RouteStep(
+                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                distance: FfiConverterDouble.read(from: &buf), 
+                duration: FfiConverterDouble.read(from: &buf), 
+                roadName: FfiConverterOptionString.read(from: &buf), 
+                exits: FfiConverterSequenceString.read(from: &buf), 
+                instruction: FfiConverterString.read(from: &buf), 
+                visualInstructions: FfiConverterSequenceTypeVisualInstruction.read(from: &buf), 
+                spokenInstructions: FfiConverterSequenceTypeSpokenInstruction.read(from: &buf), 
+                annotations: FfiConverterOptionSequenceTypeSegmentAnnotation.read(from: &buf), 
+                incidents: FfiConverterSequenceTypeIncident.read(from: &buf), 
+                maneuver: FfiConverterOptionTypeManeuver.read(from: &buf), 
+                intersections: FfiConverterSequenceTypeIntersection.read(from: &buf), 
+                legIndex: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RouteStep, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
+        FfiConverterDouble.write(value.distance, into: &buf)
+        FfiConverterDouble.write(value.duration, into: &buf)
+        FfiConverterOptionString.write(value.roadName, into: &buf)
+        FfiConverterSequenceString.write(value.exits, into: &buf)
+        FfiConverterString.write(value.instruction, into: &buf)
+        FfiConverterSequenceTypeVisualInstruction.write(value.visualInstructions, into: &buf)
+        FfiConverterSequenceTypeSpokenInstruction.write(value.spokenInstructions, into: &buf)
+        FfiConverterOptionSequenceTypeSegmentAnnotation.write(value.annotations, into: &buf)
+        FfiConverterSequenceTypeIncident.write(value.incidents, into: &buf)
+        FfiConverterOptionTypeManeuver.write(value.maneuver, into: &buf)
+        FfiConverterSequenceTypeIntersection.write(value.intersections, into: &buf)
+        FfiConverterUInt64.write(value.legIndex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteStep_lift(_ buf: RustBuffer) throws -> RouteStep {
+    return try FfiConverterTypeRouteStep.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteStep_lower(_ value: RouteStep) -> RustBuffer {
+    return FfiConverterTypeRouteStep.lower(value)
+}
+
+
+/**
+ * Annotations for a segment of a route (between two successive coordinates).
+ *
+ * Routing engines vary in which annotations they support (if any),
+ * so all of the well-known values are optional.
+ */
+public struct SegmentAnnotation {
+    /**
+     * The length of the segment, in meters.
+     */
+    public var distance: Double?
+    /**
+     * The expected travel time along the segment, in seconds.
+     */
+    public var duration: Double?
+    /**
+     * The expected speed of travel along the segment, in meters per second.
+     */
+    public var speed: Double?
+    /**
+     * The speed limit along the segment.
+     */
+    public var maxSpeed: SpeedLimit?
+    /**
+     * The level of traffic congestion along the segment.
+     */
+    public var congestion: CongestionLevel?
+    /**
+     * The ID of the way (ex: in OpenStreetMap) which the segment is a part of.
+     */
+    public var wayId: UInt64?
+    /**
+     * Any other annotations from the routing engine,
+     * with values encoded as JSON strings.
+     */
+    public var extensions: [String: String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The length of the segment, in meters.
+         */distance: Double?, 
+        /**
+         * The expected travel time along the segment, in seconds.
+         */duration: Double?, 
+        /**
+         * The expected speed of travel along the segment, in meters per second.
+         */speed: Double?, 
+        /**
+         * The speed limit along the segment.
+         */maxSpeed: SpeedLimit?, 
+        /**
+         * The level of traffic congestion along the segment.
+         */congestion: CongestionLevel?, 
+        /**
+         * The ID of the way (ex: in OpenStreetMap) which the segment is a part of.
+         */wayId: UInt64?, 
+        /**
+         * Any other annotations from the routing engine,
+         * with values encoded as JSON strings.
+         */extensions: [String: String]) {
+        self.distance = distance
+        self.duration = duration
+        self.speed = speed
+        self.maxSpeed = maxSpeed
+        self.congestion = congestion
+        self.wayId = wayId
+        self.extensions = extensions
+    }
+}
+
+
+
+extension SegmentAnnotation: Equatable, Hashable {
+    public static func ==(lhs: SegmentAnnotation, rhs: SegmentAnnotation) -> Bool {
+        if lhs.distance != rhs.distance {
+            return false
+        }
+        if lhs.duration != rhs.duration {
+            return false
+        }
+        if lhs.speed != rhs.speed {
+            return false
+        }
+        if lhs.maxSpeed != rhs.maxSpeed {
+            return false
+        }
+        if lhs.congestion != rhs.congestion {
+            return false
+        }
+        if lhs.wayId != rhs.wayId {
+            return false
+        }
+        if lhs.extensions != rhs.extensions {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(distance)
+        hasher.combine(duration)
+        hasher.combine(speed)
+        hasher.combine(maxSpeed)
+        hasher.combine(congestion)
+        hasher.combine(wayId)
+        hasher.combine(extensions)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSegmentAnnotation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SegmentAnnotation {
+        return
+            try SegmentAnnotation(
+                distance: FfiConverterOptionDouble.read(from: &buf), 
+                duration: FfiConverterOptionDouble.read(from: &buf), 
+                speed: FfiConverterOptionDouble.read(from: &buf), 
+                maxSpeed: FfiConverterOptionTypeSpeedLimit.read(from: &buf), 
+                congestion: FfiConverterOptionTypeCongestionLevel.read(from: &buf), 
+                wayId: FfiConverterOptionUInt64.read(from: &buf), 
+                extensions: FfiConverterDictionaryStringString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SegmentAnnotation, into buf: inout [UInt8]) {
+        FfiConverterOptionDouble.write(value.distance, into: &buf)
+        FfiConverterOptionDouble.write(value.duration, into: &buf)
+        FfiConverterOptionDouble.write(value.speed, into: &buf)
+        FfiConverterOptionTypeSpeedLimit.write(value.maxSpeed, into: &buf)
+        FfiConverterOptionTypeCongestionLevel.write(value.congestion, into: &buf)
+        FfiConverterOptionUInt64.write(value.wayId, into: &buf)
+        FfiConverterDictionaryStringString.write(value.extensions, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentAnnotation_lift(_ buf: RustBuffer) throws -> SegmentAnnotation {
+    return try FfiConverterTypeSegmentAnnotation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentAnnotation_lower(_ value: SegmentAnnotation) -> RustBuffer {
+    return FfiConverterTypeSegmentAnnotation.lower(value)
+}
+
+
+/**
+ * The speed of the user from the location provider.
+ */
+public struct Speed {
+    /**
+     * The user's speed in meters per second.
+     */
+    public var value: Double
+    /**
+     * The accuracy of the speed value, measured in meters per second.
+     */
+    public var accuracy: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The user's speed in meters per second.
+         */value: Double, 
+        /**
+         * The accuracy of the speed value, measured in meters per second.
+         */accuracy: Double?) {
+        self.value = value
+        self.accuracy = accuracy
+    }
+}
+
+
+
+extension Speed: Equatable, Hashable {
+    public static func ==(lhs: Speed, rhs: Speed) -> Bool {
+        if lhs.value != rhs.value {
+            return false
+        }
+        if lhs.accuracy != rhs.accuracy {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(value)
+        hasher.combine(accuracy)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Speed {
+        return
+            try Speed(
+                value: FfiConverterDouble.read(from: &buf), 
+                accuracy: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Speed, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.value, into: &buf)
+        FfiConverterOptionDouble.write(value.accuracy, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeed_lift(_ buf: RustBuffer) throws -> Speed {
+    return try FfiConverterTypeSpeed.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeed_lower(_ value: Speed) -> RustBuffer {
+    return FfiConverterTypeSpeed.lower(value)
+}
+
+
+/**
+ * An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
+ *
+ * Note that these do not have any locale information attached.
+ */
+public struct SpokenInstruction {
+    /**
+     * Plain-text instruction which can be synthesized with a TTS engine.
+     */
+    public var text: String
+    /**
+     * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
+     */
+    public var ssml: String?
+    /**
+     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+     */
+    public var triggerDistanceBeforeManeuver: Double
+    /**
+     * A unique identifier for this instruction.
+     *
+     * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
+     * which may have the same textual content.
+     * UUIDs conveniently fill this purpose.
+     *
+     * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
+     * This should be theoretically possible though if someone cares to write up a proposal and a PR.
+     */
+    public var utteranceId: Uuid
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Plain-text instruction which can be synthesized with a TTS engine.
+         */text: String, 
+        /**
+         * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
+         */ssml: String?, 
+        /**
+         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+         */triggerDistanceBeforeManeuver: Double, 
+        /**
+         * A unique identifier for this instruction.
+         *
+         * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
+         * which may have the same textual content.
+         * UUIDs conveniently fill this purpose.
+         *
+         * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
+         * This should be theoretically possible though if someone cares to write up a proposal and a PR.
+         */utteranceId: Uuid) {
+        self.text = text
+        self.ssml = ssml
+        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
+        self.utteranceId = utteranceId
+    }
+}
+
+
+
+extension SpokenInstruction: Equatable, Hashable {
+    public static func ==(lhs: SpokenInstruction, rhs: SpokenInstruction) -> Bool {
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.ssml != rhs.ssml {
+            return false
+        }
+        if lhs.triggerDistanceBeforeManeuver != rhs.triggerDistanceBeforeManeuver {
+            return false
+        }
+        if lhs.utteranceId != rhs.utteranceId {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(text)
+        hasher.combine(ssml)
+        hasher.combine(triggerDistanceBeforeManeuver)
+        hasher.combine(utteranceId)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpokenInstruction {
+        return
+            try SpokenInstruction(
+                text: FfiConverterString.read(from: &buf), 
+                ssml: FfiConverterOptionString.read(from: &buf), 
+                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf), 
+                utteranceId: FfiConverterTypeUuid.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpokenInstruction, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionString.write(value.ssml, into: &buf)
+        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+        FfiConverterTypeUuid.write(value.utteranceId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpokenInstruction_lift(_ buf: RustBuffer) throws -> SpokenInstruction {
+    return try FfiConverterTypeSpokenInstruction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpokenInstruction_lower(_ value: SpokenInstruction) -> RustBuffer {
+    return FfiConverterTypeSpokenInstruction.lower(value)
+}
+
+
+/**
+ * Hints about the parts of an instruction which need special treatment when spoken.
+ */
+public struct SsmlHints {
+    /**
+     * Pronunciations of names (typically roads).
+     */
+    public var pronunciations: [Pronunciation]
+    /**
+     * Route numbers (ex: `I 84`), which are read like an address.
+     */
+    public var roadRefs: [String]
+    /**
+     * Exit numbers (ex: `10A`).
+     */
+    public var exitNumbers: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Pronunciations of names (typically roads).
+         */pronunciations: [Pronunciation], 
+        /**
+         * Route numbers (ex: `I 84`), which are read like an address.
+         */roadRefs: [String], 
+        /**
+         * Exit numbers (ex: `10A`).
+         */exitNumbers: [String]) {
+        self.pronunciations = pronunciations
+        self.roadRefs = roadRefs
+        self.exitNumbers = exitNumbers
+    }
+}
+
+
+
+extension SsmlHints: Equatable, Hashable {
+    public static func ==(lhs: SsmlHints, rhs: SsmlHints) -> Bool {
+        if lhs.pronunciations != rhs.pronunciations {
+            return false
+        }
+        if lhs.roadRefs != rhs.roadRefs {
+            return false
+        }
+        if lhs.exitNumbers != rhs.exitNumbers {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(pronunciations)
+        hasher.combine(roadRefs)
+        hasher.combine(exitNumbers)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSsmlHints: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SsmlHints {
+        return
+            try SsmlHints(
+                pronunciations: FfiConverterSequenceTypePronunciation.read(from: &buf), 
+                roadRefs: FfiConverterSequenceString.read(from: &buf), 
+                exitNumbers: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SsmlHints, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypePronunciation.write(value.pronunciations, into: &buf)
+        FfiConverterSequenceString.write(value.roadRefs, into: &buf)
+        FfiConverterSequenceString.write(value.exitNumbers, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSsmlHints_lift(_ buf: RustBuffer) throws -> SsmlHints {
+    return try FfiConverterTypeSsmlHints.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSsmlHints_lower(_ value: SsmlHints) -> RustBuffer {
+    return FfiConverterTypeSsmlHints.lower(value)
+}
+
+
+/**
+ * Working state which the navigation controller carries between updates of a [`TripState`].
+ *
+ * None of this is intended for display;
+ * it is part of the trip state only so that the navigation controller can remain a pure type.
+ */
+public struct TripBookkeeping {
+    /**
+     * The utterance IDs of all spoken instructions which have been triggered during the trip.
+     */
+    public var announcedUtteranceIds: [Uuid]
+    /**
+     * The elapsed trip time, in seconds, at which the user most recently came within
+     * the arrival radius of the current leg's destination.
+     *
+     * This is used to measure dwell time for [`ArrivalDetection::Enabled`],
+     * and is [`None`] while the user is outside the radius.
+     */
+    public var arrivalRadiusEnteredAt: Double?
+    /**
+     * Samples of the trip progress from recent location updates, oldest first.
+     *
+     * This is used to observe the user's rate of progress for [`EtaBlending::ObservedProgress`],
+     * and is empty when ETA blending is disabled.
+     */
+    public var progressSamples: [ProgressSample]
+    /**
+     * Recent location updates and how they were matched to the route, oldest first.
+     *
+     * This is used to match the user's location with [`LocationSnapping::MapMatching`],
+     * and is empty when snapping to the nearest point.
+     */
+    public var recentMatches: [LocationMatch]
+    /**
+     * The filtered estimate of the user's location and velocity as of the last accepted update.
+     *
+     * This is used to clean up location updates with [`LocationFiltering::AlphaBeta`],
+     * and is [`None`] when location filtering is disabled.
+     */
+    public var locationEstimate: LocationEstimate?
+    /**
+     * The most recent compass heading (see [`NavigationController::update_heading`](super::NavigationController::update_heading)).
+     */
+    public var heading: Heading?
+    /**
+     * The course over ground reported with the most recent location update,
+     * before it was fused with the heading for [`CourseFiltering::FuseHeading`].
+     */
+    public var reportedCourseOverGround: CourseOverGround?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The utterance IDs of all spoken instructions which have been triggered during the trip.
+         */announcedUtteranceIds: [Uuid] = [], 
+        /**
+         * The elapsed trip time, in seconds, at which the user most recently came within
+         * the arrival radius of the current leg's destination.
+         *
+         * This is used to measure dwell time for [`ArrivalDetection::Enabled`],
+         * and is [`None`] while the user is outside the radius.
+         */arrivalRadiusEnteredAt: Double? = nil, 
+        /**
+         * Samples of the trip progress from recent location updates, oldest first.
+         *
+         * This is used to observe the user's rate of progress for [`EtaBlending::ObservedProgress`],
+         * and is empty when ETA blending is disabled.
+         */progressSamples: [ProgressSample] = [], 
+        /**
+         * Recent location updates and how they were matched to the route, oldest first.
+         *
+         * This is used to match the user's location with [`LocationSnapping::MapMatching`],
+         * and is empty when snapping to the nearest point.
+         */recentMatches: [LocationMatch] = [], 
+        /**
+         * The filtered estimate of the user's location and velocity as of the last accepted update.
+         *
+         * This is used to clean up location updates with [`LocationFiltering::AlphaBeta`],
+         * and is [`None`] when location filtering is disabled.
+         */locationEstimate: LocationEstimate? = nil, 
+        /**
+         * The most recent compass heading (see [`NavigationController::update_heading`](super::NavigationController::update_heading)).
+         */heading: Heading? = nil, 
+        /**
+         * The course over ground reported with the most recent location update,
+         * before it was fused with the heading for [`CourseFiltering::FuseHeading`].
+         */reportedCourseOverGround: CourseOverGround? = nil) {
+        self.announcedUtteranceIds = announcedUtteranceIds
+        self.arrivalRadiusEnteredAt = arrivalRadiusEnteredAt
+        self.progressSamples = progressSamples
+        self.recentMatches = recentMatches
+        self.locationEstimate = locationEstimate
+        self.heading = heading
+        self.reportedCourseOverGround = reportedCourseOverGround
+    }
+}
+
+
+
+extension TripBookkeeping: Equatable, Hashable {
+    public static func ==(lhs: TripBookkeeping, rhs: TripBookkeeping) -> Bool {
+        if lhs.announcedUtteranceIds != rhs.announcedUtteranceIds {
+            return false
+        }
+        if lhs.arrivalRadiusEnteredAt != rhs.arrivalRadiusEnteredAt {
+            return false
+        }
+        if lhs.progressSamples != rhs.progressSamples {
+            return false
+        }
+        if lhs.recentMatches != rhs.recentMatches {
+            return false
+        }
+        if lhs.locationEstimate != rhs.locationEstimate {
+            return false
+        }
+        if lhs.heading != rhs.heading {
+            return false
+        }
+        if lhs.reportedCourseOverGround != rhs.reportedCourseOverGround {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(announcedUtteranceIds)
+        hasher.combine(arrivalRadiusEnteredAt)
+        hasher.combine(progressSamples)
+        hasher.combine(recentMatches)
+        hasher.combine(locationEstimate)
+        hasher.combine(heading)
+        hasher.combine(reportedCourseOverGround)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTripBookkeeping: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripBookkeeping {
+        return
+            try TripBookkeeping(
+                announcedUtteranceIds: FfiConverterSequenceTypeUuid.read(from: &buf), 
+                arrivalRadiusEnteredAt: FfiConverterOptionDouble.read(from: &buf), 
+                progressSamples: FfiConverterSequenceTypeProgressSample.read(from: &buf), 
+                recentMatches: FfiConverterSequenceTypeLocationMatch.read(from: &buf), 
+                locationEstimate: FfiConverterOptionTypeLocationEstimate.read(from: &buf), 
+                heading: FfiConverterOptionTypeHeading.read(from: &buf), 
+                reportedCourseOverGround: FfiConverterOptionTypeCourseOverGround.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TripBookkeeping, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeUuid.write(value.announcedUtteranceIds, into: &buf)
+        FfiConverterOptionDouble.write(value.arrivalRadiusEnteredAt, into: &buf)
+        FfiConverterSequenceTypeProgressSample.write(value.progressSamples, into: &buf)
+        FfiConverterSequenceTypeLocationMatch.write(value.recentMatches, into: &buf)
+        FfiConverterOptionTypeLocationEstimate.write(value.locationEstimate, into: &buf)
+        FfiConverterOptionTypeHeading.write(value.heading, into: &buf)
+        FfiConverterOptionTypeCourseOverGround.write(value.reportedCourseOverGround, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripBookkeeping_lift(_ buf: RustBuffer) throws -> TripBookkeeping {
+    return try FfiConverterTypeTripBookkeeping.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripBookkeeping_lower(_ value: TripBookkeeping) -> RustBuffer {
+    return FfiConverterTypeTripBookkeeping.lower(value)
+}
+
+
+/**
+ * High-level state describing progress through a route.
+ */
+public struct TripProgress {
+    /**
+     * The distance to the next maneuver, in meters.
+     */
+    public var distanceToNextManeuver: Double
+    /**
+     * The total distance remaining in the trip, in meters.
+     *
+     * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+     */
+    public var distanceRemaining: Double
+    /**
+     * The total duration remaining in the trip, in seconds.
+     */
+    public var durationRemaining: Double
+    /**
+     * The total duration remaining in the trip, in seconds,
+     * adjusted for the rate at which the user has actually been progressing along the route.
+     *
+     * This is the same as `duration_remaining` unless [`EtaBlending`] is enabled
+     * and enough location updates have been observed.
+     */
+    public var adjustedDurationRemaining: Double
+    /**
+     * The distance to the end of the current leg
+     * (the next waypoint where the user stops), in meters.
+     */
+    public var distanceToNextWaypoint: Double
+    /**
+     * The duration remaining until the end of the current leg
+     * (the next waypoint where the user stops), in seconds.
+     */
+    public var durationToNextWaypoint: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The distance to the next maneuver, in meters.
+         */distanceToNextManeuver: Double, 
+        /**
+         * The total distance remaining in the trip, in meters.
+         *
+         * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+         */distanceRemaining: Double, 
+        /**
+         * The total duration remaining in the trip, in seconds.
+         */durationRemaining: Double, 
+        /**
+         * The total duration remaining in the trip, in seconds,
+         * adjusted for the rate at which the user has actually been progressing along the route.
+         *
+         * This is the same as `duration_remaining` unless [`EtaBlending`] is enabled
+         * and enough location updates have been observed.
+         */adjustedDurationRemaining: Double = Double(0.0), 
+        /**
+         * The distance to the end of the current leg
+         * (the next waypoint where the user stops), in meters.
+         */distanceToNextWaypoint: Double = Double(0.0), 
+        /**
+         * The duration remaining until the end of the current leg
+         * (the next waypoint where the user stops), in seconds.
+         */durationToNextWaypoint: Double = Double(0.0)) {
+        self.distanceToNextManeuver = distanceToNextManeuver
+        self.distanceRemaining = distanceRemaining
+        self.durationRemaining = durationRemaining
+        self.adjustedDurationRemaining = adjustedDurationRemaining
+        self.distanceToNextWaypoint = distanceToNextWaypoint
+        self.durationToNextWaypoint = durationToNextWaypoint
+    }
+}
+
+
+
+extension TripProgress: Equatable, Hashable {
+    public static func ==(lhs: TripProgress, rhs: TripProgress) -> Bool {
+        if lhs.distanceToNextManeuver != rhs.distanceToNextManeuver {
+            return false
+        }
+        if lhs.distanceRemaining != rhs.distanceRemaining {
+            return false
+        }
+        if lhs.durationRemaining != rhs.durationRemaining {
+            return false
+        }
+        if lhs.adjustedDurationRemaining != rhs.adjustedDurationRemaining {
+            return false
+        }
+        if lhs.distanceToNextWaypoint != rhs.distanceToNextWaypoint {
+            return false
+        }
+        if lhs.durationToNextWaypoint != rhs.durationToNextWaypoint {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(distanceToNextManeuver)
+        hasher.combine(distanceRemaining)
+        hasher.combine(durationRemaining)
+        hasher.combine(adjustedDurationRemaining)
+        hasher.combine(distanceToNextWaypoint)
+        hasher.combine(durationToNextWaypoint)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripProgress {
+        return
+            try TripProgress(
+                distanceToNextManeuver: FfiConverterDouble.read(from: &buf), 
+                distanceRemaining: FfiConverterDouble.read(from: &buf), 
+                durationRemaining: FfiConverterDouble.read(from: &buf), 
+                adjustedDurationRemaining: FfiConverterDouble.read(from: &buf), 
+                distanceToNextWaypoint: FfiConverterDouble.read(from: &buf), 
+                durationToNextWaypoint: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TripProgress, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distanceToNextManeuver, into: &buf)
+        FfiConverterDouble.write(value.distanceRemaining, into: &buf)
+        FfiConverterDouble.write(value.durationRemaining, into: &buf)
+        FfiConverterDouble.write(value.adjustedDurationRemaining, into: &buf)
+        FfiConverterDouble.write(value.distanceToNextWaypoint, into: &buf)
+        FfiConverterDouble.write(value.durationToNextWaypoint, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripProgress_lift(_ buf: RustBuffer) throws -> TripProgress {
+    return try FfiConverterTypeTripProgress.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripProgress_lower(_ value: TripProgress) -> RustBuffer {
+    return FfiConverterTypeTripProgress.lower(value)
+}
+
+
+/**
+ * Statistics about a trip, accumulated by the [`NavigationController`](super::NavigationController)
+ * as user locations arrive.
+ *
+ * These cover the whole trip, including any replaced routes.
+ */
+public struct TripStatistics {
+    /**
+     * The distance traveled since the start of the trip, in meters.
+     *
+     * This is accumulated from successive snapped user locations.
+     */
+    public var distanceTraveled: Double
+    /**
+     * The time elapsed since the start of the trip, in seconds.
+     *
+     * This is accumulated from the timestamps of successive user locations.
+     */
+    public var elapsedTime: Double
+    /**
+     * The average speed over the trip, in meters per second.
+     */
+    public var averageSpeed: Double
+    /**
+     * The maximum speed reached during the trip, in meters per second.
+     *
+     * This uses the speed reported with each user location when available,
+     * and the speed between successive locations otherwise.
+     */
+    public var maxSpeed: Double
+    /**
+     * The time spent off the route, in seconds.
+     */
+    public var timeOffRoute: Double
+    /**
+     * The number of times that the route was replaced
+     * (see [`get_rerouted_state`](super::NavigationController::get_rerouted_state)).
+     */
+    public var rerouteCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The distance traveled since the start of the trip, in meters.
+         *
+         * This is accumulated from successive snapped user locations.
+         */distanceTraveled: Double = Double(0.0), 
+        /**
+         * The time elapsed since the start of the trip, in seconds.
+         *
+         * This is accumulated from the timestamps of successive user locations.
+         */elapsedTime: Double = Double(0.0), 
+        /**
+         * The average speed over the trip, in meters per second.
+         */averageSpeed: Double = Double(0.0), 
+        /**
+         * The maximum speed reached during the trip, in meters per second.
+         *
+         * This uses the speed reported with each user location when available,
+         * and the speed between successive locations otherwise.
+         */maxSpeed: Double = Double(0.0), 
+        /**
+         * The time spent off the route, in seconds.
+         */timeOffRoute: Double = Double(0.0), 
+        /**
+         * The number of times that the route was replaced
+         * (see [`get_rerouted_state`](super::NavigationController::get_rerouted_state)).
+         */rerouteCount: UInt32 = UInt32(0)) {
+        self.distanceTraveled = distanceTraveled
+        self.elapsedTime = elapsedTime
+        self.averageSpeed = averageSpeed
+        self.maxSpeed = maxSpeed
+        self.timeOffRoute = timeOffRoute
+        self.rerouteCount = rerouteCount
+    }
+}
+
+
+
+extension TripStatistics: Equatable, Hashable {
+    public static func ==(lhs: TripStatistics, rhs: TripStatistics) -> Bool {
+        if lhs.distanceTraveled != rhs.distanceTraveled {
+            return false
+        }
+        if lhs.elapsedTime != rhs.elapsedTime {
+            return false
+        }
+        if lhs.averageSpeed != rhs.averageSpeed {
+            return false
+        }
+        if lhs.maxSpeed != rhs.maxSpeed {
+            return false
+        }
+        if lhs.timeOffRoute != rhs.timeOffRoute {
+            return false
+        }
+        if lhs.rerouteCount != rhs.rerouteCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(distanceTraveled)
+        hasher.combine(elapsedTime)
+        hasher.combine(averageSpeed)
+        hasher.combine(maxSpeed)
+        hasher.combine(timeOffRoute)
+        hasher.combine(rerouteCount)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTripStatistics: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripStatistics {
+        return
+            try TripStatistics(
+                distanceTraveled: FfiConverterDouble.read(from: &buf), 
+                elapsedTime: FfiConverterDouble.read(from: &buf), 
+                averageSpeed: FfiConverterDouble.read(from: &buf), 
+                maxSpeed: FfiConverterDouble.read(from: &buf), 
+                timeOffRoute: FfiConverterDouble.read(from: &buf), 
+                rerouteCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TripStatistics, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distanceTraveled, into: &buf)
+        FfiConverterDouble.write(value.elapsedTime, into: &buf)
+        FfiConverterDouble.write(value.averageSpeed, into: &buf)
+        FfiConverterDouble.write(value.maxSpeed, into: &buf)
+        FfiConverterDouble.write(value.timeOffRoute, into: &buf)
+        FfiConverterUInt32.write(value.rerouteCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripStatistics_lift(_ buf: RustBuffer) throws -> TripStatistics {
+    return try FfiConverterTypeTripStatistics.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripStatistics_lower(_ value: TripStatistics) -> RustBuffer {
+    return FfiConverterTypeTripStatistics.lower(value)
+}
+
+
+/**
+ * Incidents and traffic congestion on the route ahead of the user, nearest first.
+ */
+public struct UpcomingEvents {
+    /**
+     * Incidents which the user has not yet passed the end of.
+     */
+    public var incidents: [UpcomingIncident]
+    /**
+     * Spans of moderate or worse congestion
+     * (see [`CongestionLevel`]) which the user has not yet passed the end of.
+     */
+    public var congestion: [CongestionSpan]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Incidents which the user has not yet passed the end of.
+         */incidents: [UpcomingIncident], 
+        /**
+         * Spans of moderate or worse congestion
+         * (see [`CongestionLevel`]) which the user has not yet passed the end of.
+         */congestion: [CongestionSpan]) {
+        self.incidents = incidents
+        self.congestion = congestion
+    }
+}
+
+
+
+extension UpcomingEvents: Equatable, Hashable {
+    public static func ==(lhs: UpcomingEvents, rhs: UpcomingEvents) -> Bool {
+        if lhs.incidents != rhs.incidents {
+            return false
+        }
+        if lhs.congestion != rhs.congestion {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(incidents)
+        hasher.combine(congestion)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUpcomingEvents: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UpcomingEvents {
+        return
+            try UpcomingEvents(
+                incidents: FfiConverterSequenceTypeUpcomingIncident.read(from: &buf), 
+                congestion: FfiConverterSequenceTypeCongestionSpan.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UpcomingEvents, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeUpcomingIncident.write(value.incidents, into: &buf)
+        FfiConverterSequenceTypeCongestionSpan.write(value.congestion, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingEvents_lift(_ buf: RustBuffer) throws -> UpcomingEvents {
+    return try FfiConverterTypeUpcomingEvents.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingEvents_lower(_ value: UpcomingEvents) -> RustBuffer {
+    return FfiConverterTypeUpcomingEvents.lower(value)
+}
+
+
+/**
+ * An incident on the route ahead of the user.
+ */
+public struct UpcomingIncident {
+    /**
+     * The incident.
+     *
+     * NOTE: The geometry indices are relative to the step that the incident was found on.
+     */
+    public var incident: Incident
+    /**
+     * The distance along the route from the user's snapped location to the start of the incident, in meters.
+     *
+     * This is zero if the user is already within the affected stretch of road.
+     */
+    public var distanceToStart: Double
+    /**
+     * The distance along the route from the user's snapped location to the end of the incident, in meters.
+     */
+    public var distanceToEnd: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The incident.
+         *
+         * NOTE: The geometry indices are relative to the step that the incident was found on.
+         */incident: Incident, 
+        /**
+         * The distance along the route from the user's snapped location to the start of the incident, in meters.
+         *
+         * This is zero if the user is already within the affected stretch of road.
+         */distanceToStart: Double, 
+        /**
+         * The distance along the route from the user's snapped location to the end of the incident, in meters.
+         */distanceToEnd: Double) {
+        self.incident = incident
+        self.distanceToStart = distanceToStart
+        self.distanceToEnd = distanceToEnd
+    }
+}
+
+
+
+extension UpcomingIncident: Equatable, Hashable {
+    public static func ==(lhs: UpcomingIncident, rhs: UpcomingIncident) -> Bool {
+        if lhs.incident != rhs.incident {
+            return false
+        }
+        if lhs.distanceToStart != rhs.distanceToStart {
+            return false
+        }
+        if lhs.distanceToEnd != rhs.distanceToEnd {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(incident)
+        hasher.combine(distanceToStart)
+        hasher.combine(distanceToEnd)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUpcomingIncident: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UpcomingIncident {
+        return
+            try UpcomingIncident(
+                incident: FfiConverterTypeIncident.read(from: &buf), 
+                distanceToStart: FfiConverterDouble.read(from: &buf), 
+                distanceToEnd: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UpcomingIncident, into buf: inout [UInt8]) {
+        FfiConverterTypeIncident.write(value.incident, into: &buf)
+        FfiConverterDouble.write(value.distanceToStart, into: &buf)
+        FfiConverterDouble.write(value.distanceToEnd, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingIncident_lift(_ buf: RustBuffer) throws -> UpcomingIncident {
+    return try FfiConverterTypeUpcomingIncident.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingIncident_lower(_ value: UpcomingIncident) -> RustBuffer {
+    return FfiConverterTypeUpcomingIncident.lower(value)
+}
+
+
+/**
+ * The location of the user that is navigating.
+ *
+ * In addition to coordinates, this includes estimated accuracy and course information,
+ * which can influence navigation logic and UI.
+ *
+ * NOTE: Heading is absent on purpose.
+ * Heading updates are not related to a change in the user's location.
+ */
+public struct UserLocation {
+    public var coordinates: GeographicCoordinate
+    /**
+     * The estimated accuracy of the coordinate (in meters)
+     */
+    public var horizontalAccuracy: Double
+    public var courseOverGround: CourseOverGround?
+    public var timestamp: Date
+    public var speed: Speed?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(coordinates: GeographicCoordinate, 
+        /**
+         * The estimated accuracy of the coordinate (in meters)
+         */horizontalAccuracy: Double, courseOverGround: CourseOverGround?, timestamp: Date, speed: Speed?) {
+        self.coordinates = coordinates
+        self.horizontalAccuracy = horizontalAccuracy
+        self.courseOverGround = courseOverGround
+        self.timestamp = timestamp
+        self.speed = speed
+    }
+}
+
+
+
+extension UserLocation: Equatable, Hashable {
+    public static func ==(lhs: UserLocation, rhs: UserLocation) -> Bool {
+        if lhs.coordinates != rhs.coordinates {
+            return false
+        }
+        if lhs.horizontalAccuracy != rhs.horizontalAccuracy {
+            return false
+        }
+        if lhs.courseOverGround != rhs.courseOverGround {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.speed != rhs.speed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(coordinates)
+        hasher.combine(horizontalAccuracy)
+        hasher.combine(courseOverGround)
+        hasher.combine(timestamp)
+        hasher.combine(speed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserLocation {
+        return
+            try UserLocation(
+                coordinates: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                horizontalAccuracy: FfiConverterDouble.read(from: &buf), 
+                courseOverGround: FfiConverterOptionTypeCourseOverGround.read(from: &buf), 
+                timestamp: FfiConverterTimestamp.read(from: &buf), 
+                speed: FfiConverterOptionTypeSpeed.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserLocation, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.coordinates, into: &buf)
+        FfiConverterDouble.write(value.horizontalAccuracy, into: &buf)
+        FfiConverterOptionTypeCourseOverGround.write(value.courseOverGround, into: &buf)
+        FfiConverterTimestamp.write(value.timestamp, into: &buf)
+        FfiConverterOptionTypeSpeed.write(value.speed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserLocation_lift(_ buf: RustBuffer) throws -> UserLocation {
+    return try FfiConverterTypeUserLocation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserLocation_lower(_ value: UserLocation) -> RustBuffer {
+    return FfiConverterTypeUserLocation.lower(value)
+}
+
+
+/**
+ * An instruction for visual display (usually as banners) at a specific point along a [`RouteStep`].
+ */
+public struct VisualInstruction {
+    /**
+     * The primary instruction content.
+     *
+     * This is usually given more visual weight.
+     */
+    public var primaryContent: VisualInstructionContent
+    /**
+     * Optional secondary instruction content.
+     */
+    public var secondaryContent: VisualInstructionContent?
+    /**
+     * Optional sub-maneuver instruction content.
+     */
+    public var subContent: VisualInstructionContent?
+    /**
+     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+     */
+    public var triggerDistanceBeforeManeuver: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The primary instruction content.
+         *
+         * This is usually given more visual weight.
+         */primaryContent: VisualInstructionContent, 
+        /**
+         * Optional secondary instruction content.
+         */secondaryContent: VisualInstructionContent?, 
+        /**
+         * Optional sub-maneuver instruction content.
+         */subContent: VisualInstructionContent?, 
+        /**
+         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+         */triggerDistanceBeforeManeuver: Double) {
+        self.primaryContent = primaryContent
+        self.secondaryContent = secondaryContent
+        self.subContent = subContent
+        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
+    }
+}
+
+
+
+extension VisualInstruction: Equatable, Hashable {
+    public static func ==(lhs: VisualInstruction, rhs: VisualInstruction) -> Bool {
+        if lhs.primaryContent != rhs.primaryContent {
+            return false
+        }
+        if lhs.secondaryContent != rhs.secondaryContent {
+            return false
+        }
+        if lhs.subContent != rhs.subContent {
+            return false
+        }
+        if lhs.triggerDistanceBeforeManeuver != rhs.triggerDistanceBeforeManeuver {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(primaryContent)
+        hasher.combine(secondaryContent)
+        hasher.combine(subContent)
+        hasher.combine(triggerDistanceBeforeManeuver)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstruction {
+        return
+            try VisualInstruction(
+                primaryContent: FfiConverterTypeVisualInstructionContent.read(from: &buf), 
+                secondaryContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
+                subContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
+                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VisualInstruction, into buf: inout [UInt8]) {
+        FfiConverterTypeVisualInstructionContent.write(value.primaryContent, into: &buf)
+        FfiConverterOptionTypeVisualInstructionContent.write(value.secondaryContent, into: &buf)
+        FfiConverterOptionTypeVisualInstructionContent.write(value.subContent, into: &buf)
+        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstruction_lift(_ buf: RustBuffer) throws -> VisualInstruction {
+    return try FfiConverterTypeVisualInstruction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstruction_lower(_ value: VisualInstruction) -> RustBuffer {
+    return FfiConverterTypeVisualInstruction.lower(value)
+}
+
+
+/**
+ * The content of a visual instruction.
+ */
+public struct VisualInstructionContent {
+    /**
+     * The text to display.
+     */
+    public var text: String
+    /**
+     * A standardized maneuver type (if any).
+     */
+    public var maneuverType: ManeuverType?
+    /**
+     * A standardized maneuver modifier (if any).
+     */
+    public var maneuverModifier: ManeuverModifier?
+    /**
+     * If applicable, the number of degrees you need to go around the roundabout before exiting.
+     *
+     * For example, entering and exiting the roundabout in the same direction of travel
+     * (as if you had gone straight, apart from the detour)
+     * would be an exit angle of 180 degrees.
+     */
+    public var roundaboutExitDegrees: UInt16?
+    /**
+     * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
+     */
+    public var laneInfo: [LaneInfo]?
+    /**
+     * The exit number (or similar identifier like "8B").
+     */
+    public var exitNumbers: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The text to display.
+         */text: String, 
+        /**
+         * A standardized maneuver type (if any).
+         */maneuverType: ManeuverType?, 
+        /**
+         * A standardized maneuver modifier (if any).
+         */maneuverModifier: ManeuverModifier?, 
+        /**
+         * If applicable, the number of degrees you need to go around the roundabout before exiting.
+         *
+         * For example, entering and exiting the roundabout in the same direction of travel
+         * (as if you had gone straight, apart from the detour)
+         * would be an exit angle of 180 degrees.
+         */roundaboutExitDegrees: UInt16?, 
+        /**
+         * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
+         */laneInfo: [LaneInfo]?, 
+        /**
+         * The exit number (or similar identifier like "8B").
+         */exitNumbers: [String]) {
+        self.text = text
+        self.maneuverType = maneuverType
+        self.maneuverModifier = maneuverModifier
+        self.roundaboutExitDegrees = roundaboutExitDegrees
+        self.laneInfo = laneInfo
+        self.exitNumbers = exitNumbers
+    }
+}
+
+
+
+extension VisualInstructionContent: Equatable, Hashable {
+    public static func ==(lhs: VisualInstructionContent, rhs: VisualInstructionContent) -> Bool {
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.maneuverType != rhs.maneuverType {
+            return false
+        }
+        if lhs.maneuverModifier != rhs.maneuverModifier {
+            return false
+        }
+        if lhs.roundaboutExitDegrees != rhs.roundaboutExitDegrees {
+            return false
+        }
+        if lhs.laneInfo != rhs.laneInfo {
+            return false
+        }
+        if lhs.exitNumbers != rhs.exitNumbers {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(text)
+        hasher.combine(maneuverType)
+        hasher.combine(maneuverModifier)
+        hasher.combine(roundaboutExitDegrees)
+        hasher.combine(laneInfo)
+        hasher.combine(exitNumbers)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstructionContent {
+        return
+            try VisualInstructionContent(
+                text: FfiConverterString.read(from: &buf), 
+                maneuverType: FfiConverterOptionTypeManeuverType.read(from: &buf), 
+                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf), 
+                roundaboutExitDegrees: FfiConverterOptionUInt16.read(from: &buf), 
+                laneInfo: FfiConverterOptionSequenceTypeLaneInfo.read(from: &buf), 
+                exitNumbers: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VisualInstructionContent, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionTypeManeuverType.write(value.maneuverType, into: &buf)
+        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
+        FfiConverterOptionUInt16.write(value.roundaboutExitDegrees, into: &buf)
+        FfiConverterOptionSequenceTypeLaneInfo.write(value.laneInfo, into: &buf)
+        FfiConverterSequenceString.write(value.exitNumbers, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstructionContent_lift(_ buf: RustBuffer) throws -> VisualInstructionContent {
+    return try FfiConverterTypeVisualInstructionContent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstructionContent_lower(_ value: VisualInstructionContent) -> RustBuffer {
+    return FfiConverterTypeVisualInstructionContent.lower(value)
+}
+
+
+/**
+ * A waypoint along a route.
+ *
+ * Within the context of Ferrostar, a route request consists of exactly one [`UserLocation`]
+ * and at least one [`Waypoint`]. The route starts from the user's location (which may
+ * contain other useful information like their current course for the [`crate::routing_adapters::RouteRequestGenerator`]
+ * to use) and proceeds through one or more waypoints.
+ *
+ * Waypoints are used during route calculation, are tracked throughout the lifecycle of a trip,
+ * and are used for recalculating when the user deviates from the expected route.
+ *
+ * Note that support for properties beyond basic geographic coordinates varies by routing engine.
+ */
+public struct Waypoint {
+    public var coordinate: GeographicCoordinate
+    public var kind: WaypointKind
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(coordinate: GeographicCoordinate, kind: WaypointKind) {
+        self.coordinate = coordinate
+        self.kind = kind
+    }
+}
+
+
+
+extension Waypoint: Equatable, Hashable {
+    public static func ==(lhs: Waypoint, rhs: Waypoint) -> Bool {
+        if lhs.coordinate != rhs.coordinate {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(coordinate)
+        hasher.combine(kind)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Waypoint {
+        return
+            try Waypoint(
+                coordinate: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                kind: FfiConverterTypeWaypointKind.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Waypoint, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.coordinate, into: &buf)
+        FfiConverterTypeWaypointKind.write(value.kind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypoint_lift(_ buf: RustBuffer) throws -> Waypoint {
+    return try FfiConverterTypeWaypoint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypoint_lower(_ value: Waypoint) -> RustBuffer {
+    return FfiConverterTypeWaypoint.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls whether (and when) the [`NavigationController`](super::NavigationController)
+ * detects arrival at waypoints where the user stops.
+ *
+ * These are the waypoints at the end of each route leg
+ * ([`WaypointKind::Break`](crate::models::WaypointKind::Break) waypoints, including the final destination).
+ */
+
+public enum ArrivalDetection {
+    
+    /**
+     * Arrival is not detected.
+     *
+     * Waypoints are completed as the user proceeds along the route,
+     * and the trip state changes directly to [`TripState::Complete`] at the end of the route.
+     */
+    case disabled
+    /**
+     * Enters [`TripState::Arrived`] at the destination of each route leg.
+     *
+     * The user arrives once they have remained within `radius` of the destination
+     * for at least `minimum_dwell_time`.
+     * Reaching the end of the route always counts as arriving at the final destination.
+     */
+    case enabled(
+        /**
+         * The distance from the waypoint, in meters,
+         * within which the user may be considered to have arrived.
+         */radius: UInt16, 
+        /**
+         * The minimum time, in seconds, that the user must remain within `radius`
+         * before they are considered to have arrived.
+         */minimumDwellTime: UInt16, 
+        /**
+         * If true, the user also arrives as soon as they pass the end of a leg,
+         * even if they never came within `radius` of the waypoint
+         * (ex: when the waypoint is on the other side of the street, or set back from the road).
+         *
+         * If false, passing the end of an intermediate leg continues navigation into the next leg
+         * without an arrival.
+         */arriveWhenPassed: Bool
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeArrivalDetection: FfiConverterRustBuffer {
+    typealias SwiftType = ArrivalDetection
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ArrivalDetection {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .disabled
+        
+        case 2: return .enabled(radius: try FfiConverterUInt16.read(from: &buf), minimumDwellTime: try FfiConverterUInt16.read(from: &buf), arriveWhenPassed: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ArrivalDetection, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .disabled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .enabled(radius,minimumDwellTime,arriveWhenPassed):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(radius, into: &buf)
+            FfiConverterUInt16.write(minimumDwellTime, into: &buf)
+            FfiConverterBool.write(arriveWhenPassed, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeArrivalDetection_lift(_ buf: RustBuffer) throws -> ArrivalDetection {
+    return try FfiConverterTypeArrivalDetection.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeArrivalDetection_lower(_ value: ArrivalDetection) -> RustBuffer {
+    return FfiConverterTypeArrivalDetection.lower(value)
+}
+
+
+
+extension ArrivalDetection: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The lane type blocked by the incident.
+ */
+
+public enum BlockedLane {
+    
+    case left
+    case leftCenter
+    case leftTurnLane
+    case center
+    case right
+    case rightCenter
+    case rightTurnLane
+    case hov
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
+    typealias SwiftType = BlockedLane
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BlockedLane {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .left
+        
+        case 2: return .leftCenter
+        
+        case 3: return .leftTurnLane
+        
+        case 4: return .center
+        
+        case 5: return .right
+        
+        case 6: return .rightCenter
+        
+        case 7: return .rightTurnLane
+        
+        case 8: return .hov
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BlockedLane, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .left:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .leftCenter:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .leftTurnLane:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .center:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .right:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .rightCenter:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .rightTurnLane:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .hov:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBlockedLane_lift(_ buf: RustBuffer) throws -> BlockedLane {
+    return try FfiConverterTypeBlockedLane.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBlockedLane_lower(_ value: BlockedLane) -> RustBuffer {
+    return FfiConverterTypeBlockedLane.lower(value)
+}
+
+
+
+extension BlockedLane: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The level of traffic congestion.
+ */
+
+public enum CongestionLevel {
+    
+    case unknown
+    case low
+    case moderate
+    case heavy
+    case severe
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCongestionLevel: FfiConverterRustBuffer {
+    typealias SwiftType = CongestionLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CongestionLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unknown
+        
+        case 2: return .low
+        
+        case 3: return .moderate
+        
+        case 4: return .heavy
+        
+        case 5: return .severe
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CongestionLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .low:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .moderate:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .heavy:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .severe:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCongestionLevel_lift(_ buf: RustBuffer) throws -> CongestionLevel {
+    return try FfiConverterTypeCongestionLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCongestionLevel_lower(_ value: CongestionLevel) -> RustBuffer {
+    return FfiConverterTypeCongestionLevel.lower(value)
+}
+
+
+
+extension CongestionLevel: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls filtering/post-processing of user course by the [`NavigationController`].
+ */
+
+public enum CourseFiltering {
+    
+    /**
+     * Snap the user's course to the current step's linestring using the next index in the step's geometry.
+     */
+    case snapToRoute
+    /**
+     * Snap the user's course to the current step's linestring,
+     * averaging the bearing of the route over a window around the snapped location.
+     *
+     * This keeps the course steady on densely sampled, curvy geometry,
+     * where the bearing of individual segments can vary wildly.
+     * Approaching the end of the step, the course also blends toward the start of the next step,
+     * so that it doesn't jump when the step advances.
+     */
+    case snapToRouteSmoothed(
+        /**
+         * The distance (in meters) behind and ahead of the snapped location to average over.
+         *
+         * The window doesn't extend beyond the current step.
+         */window: Double, 
+        /**
+         * The distance (in meters) before the end of the step at which the course
+         * starts blending toward the start of the next step (fully reaching it at the maneuver).
+         */maneuverBlendDistance: Double
+    )
+    /**
+     * Use the raw course as reported by the location provider with no processing.
+     */
+    case raw
+    /**
+     * Fuse the device's compass heading (see [`NavigationController::update_heading`](super::NavigationController::update_heading))
+     * with the raw course, depending on the user's speed and the accuracy of each.
+     *
+     * Course over ground comes from the user's movement, so it is unreliable at walking speeds
+     * and meaningless when stationary, whereas the compass doesn't depend on speed.
+     */
+    case fuseHeading(
+        /**
+         * The speed (in meters per second) at or below which only the heading is used.
+         */headingOnlySpeed: Double, 
+        /**
+         * The speed (in meters per second) at or above which only the course over ground is used.
+         */courseOnlySpeed: Double, 
+        /**
+         * The maximum age of a heading (in seconds) relative to a location update.
+         *
+         * Older headings are ignored, in case the compass has stopped reporting.
+         */maxHeadingAge: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
+    typealias SwiftType = CourseFiltering
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseFiltering {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .snapToRoute
+        
+        case 2: return .snapToRouteSmoothed(window: try FfiConverterDouble.read(from: &buf), maneuverBlendDistance: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 3: return .raw
+        
+        case 4: return .fuseHeading(headingOnlySpeed: try FfiConverterDouble.read(from: &buf), courseOnlySpeed: try FfiConverterDouble.read(from: &buf), maxHeadingAge: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CourseFiltering, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .snapToRoute:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .snapToRouteSmoothed(window,maneuverBlendDistance):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(window, into: &buf)
+            FfiConverterDouble.write(maneuverBlendDistance, into: &buf)
+            
+        
+        case .raw:
+            writeInt(&buf, Int32(3))
+        
+        
+        case let .fuseHeading(headingOnlySpeed,courseOnlySpeed,maxHeadingAge):
+            writeInt(&buf, Int32(4))
+            FfiConverterDouble.write(headingOnlySpeed, into: &buf)
+            FfiConverterDouble.write(courseOnlySpeed, into: &buf)
+            FfiConverterDouble.write(maxHeadingAge, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCourseFiltering_lift(_ buf: RustBuffer) throws -> CourseFiltering {
+    return try FfiConverterTypeCourseFiltering.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCourseFiltering_lower(_ value: CourseFiltering) -> RustBuffer {
+    return FfiConverterTypeCourseFiltering.lower(value)
+}
+
+
+
+extension CourseFiltering: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How a distance is worded.
+ */
+
+public enum DistanceStyle {
+    
+    /**
+     * Abbreviated units for display (ex: 150 m).
+     */
+    case abbreviated
+    /**
+     * Units spelled out for speech (ex: 150 meters).
+     */
+    case spoken
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDistanceStyle: FfiConverterRustBuffer {
+    typealias SwiftType = DistanceStyle
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DistanceStyle {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .abbreviated
+        
+        case 2: return .spoken
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DistanceStyle, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .abbreviated:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .spoken:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceStyle_lift(_ buf: RustBuffer) throws -> DistanceStyle {
+    return try FfiConverterTypeDistanceStyle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceStyle_lower(_ value: DistanceStyle) -> RustBuffer {
+    return FfiConverterTypeDistanceStyle.lower(value)
+}
+
+
+
+extension DistanceStyle: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A unit of distance.
+ */
+
+public enum DistanceUnit {
+    
+    case meters
+    case kilometers
+    case feet
+    case yards
+    case miles
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDistanceUnit: FfiConverterRustBuffer {
+    typealias SwiftType = DistanceUnit
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DistanceUnit {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .meters
+        
+        case 2: return .kilometers
+        
+        case 3: return .feet
+        
+        case 4: return .yards
+        
+        case 5: return .miles
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DistanceUnit, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .meters:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .kilometers:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .feet:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .yards:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .miles:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceUnit_lift(_ buf: RustBuffer) throws -> DistanceUnit {
+    return try FfiConverterTypeDistanceUnit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceUnit_lower(_ value: DistanceUnit) -> RustBuffer {
+    return FfiConverterTypeDistanceUnit.lower(value)
+}
+
+
+
+extension DistanceUnit: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls whether the estimated remaining duration of the trip is adjusted
+ * for the user's observed rate of progress (see [`TripProgress::adjusted_duration_remaining`]).
+ *
+ * Routing engines estimate durations with a model of typical travel,
+ * which may not match how the user is actually moving (ex: a slow vehicle, or heavy traffic).
+ */
+
+public enum EtaBlending {
+    
+    /**
+     * The adjusted duration is always the same as the route's expected duration.
+     */
+    case disabled
+    /**
+     * Blends the route's expected duration with the rate of progress observed over a sliding window.
+     *
+     * The rate of progress is how many seconds of expected travel time the user covered
+     * per second of actual time during the window.
+     * For example, a user progressing at half the expected rate will (at full weight)
+     * take twice as long as expected to finish the trip.
+     */
+    case observedProgress(
+        /**
+         * The length of the sliding window, in seconds (of elapsed trip time).
+         */window: UInt16, 
+        /**
+         * How much weight to give the observed rate of progress,
+         * from 0 (use the route's expected duration) to 1 (use the observed rate only).
+         *
+         * Values less than 1 keep the estimate finite while the user is stopped.
+         */observedWeight: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEtaBlending: FfiConverterRustBuffer {
+    typealias SwiftType = EtaBlending
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EtaBlending {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .disabled
+        
+        case 2: return .observedProgress(window: try FfiConverterUInt16.read(from: &buf), observedWeight: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: EtaBlending, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .disabled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .observedProgress(window,observedWeight):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(window, into: &buf)
+            FfiConverterDouble.write(observedWeight, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEtaBlending_lift(_ buf: RustBuffer) throws -> EtaBlending {
+    return try FfiConverterTypeEtaBlending.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEtaBlending_lower(_ value: EtaBlending) -> RustBuffer {
+    return FfiConverterTypeEtaBlending.lower(value)
+}
+
+
+
+extension EtaBlending: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls whether [`UpcomingEvents`] are included in [`TripState::Navigating`].
+ *
+ * The same information is available on demand from
+ * [`NavigationController::upcoming_events`](super::NavigationController::upcoming_events).
+ */
+
+public enum EventLookAhead {
+    
+    /**
+     * Upcoming events are not included in the trip state.
+     */
+    case disabled
+    /**
+     * The nearest upcoming events are included in the trip state.
+     */
+    case enabled(
+        /**
+         * The maximum number of incidents (and separately, congestion spans) to include.
+         */maxCount: UInt16, 
+        /**
+         * How far ahead to look for events, in meters along the route.
+         *
+         * Events which start farther ahead than this are not included.
+         */maxDistance: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEventLookAhead: FfiConverterRustBuffer {
+    typealias SwiftType = EventLookAhead
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EventLookAhead {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .disabled
+        
+        case 2: return .enabled(maxCount: try FfiConverterUInt16.read(from: &buf), maxDistance: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: EventLookAhead, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .disabled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .enabled(maxCount,maxDistance):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(maxCount, into: &buf)
+            FfiConverterDouble.write(maxDistance, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEventLookAhead_lift(_ buf: RustBuffer) throws -> EventLookAhead {
+    return try FfiConverterTypeEventLookAhead.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEventLookAhead_lower(_ value: EventLookAhead) -> RustBuffer {
+    return FfiConverterTypeEventLookAhead.lower(value)
+}
+
+
+
+extension EventLookAhead: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The impact of the incident that has occurred.
+ */
+
+public enum Impact {
+    
+    case unknown
+    case critical
+    case major
+    case minor
+    case low
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImpact: FfiConverterRustBuffer {
+    typealias SwiftType = Impact
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Impact {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unknown
+        
+        case 2: return .critical
+        
+        case 3: return .major
+        
+        case 4: return .minor
+        
+        case 5: return .low
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Impact, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .critical:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .major:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .minor:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .low:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImpact_lift(_ buf: RustBuffer) throws -> Impact {
+    return try FfiConverterTypeImpact.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImpact_lower(_ value: Impact) -> RustBuffer {
+    return FfiConverterTypeImpact.lower(value)
+}
+
+
+
+extension Impact: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The type of incident that has occurred.
+ */
+
+public enum IncidentType {
+    
+    case accident
+    case congestion
+    case construction
+    case disabledVehicle
+    case laneRestriction
+    case massTransit
+    case miscellaneous
+    case otherNews
+    case plannedEvent
+    case roadClosure
+    case roadHazard
+    case weather
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
+    typealias SwiftType = IncidentType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IncidentType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .accident
+        
+        case 2: return .congestion
+        
+        case 3: return .construction
+        
+        case 4: return .disabledVehicle
+        
+        case 5: return .laneRestriction
+        
+        case 6: return .massTransit
+        
+        case 7: return .miscellaneous
+        
+        case 8: return .otherNews
+        
+        case 9: return .plannedEvent
+        
+        case 10: return .roadClosure
+        
+        case 11: return .roadHazard
+        
+        case 12: return .weather
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: IncidentType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .accident:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .congestion:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .construction:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .disabledVehicle:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .laneRestriction:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .massTransit:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .miscellaneous:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .otherNews:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .plannedEvent:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .roadClosure:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .roadHazard:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .weather:
+            writeInt(&buf, Int32(12))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIncidentType_lift(_ buf: RustBuffer) throws -> IncidentType {
+    return try FfiConverterTypeIncidentType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIncidentType_lower(_ value: IncidentType) -> RustBuffer {
+    return FfiConverterTypeIncidentType.lower(value)
+}
+
+
+
+extension IncidentType: Equatable, Hashable {}
+
+
+
+
+public enum InstantiationError {
+
+    
+    
+    case OptionsJsonParseError
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInstantiationError: FfiConverterRustBuffer {
+    typealias SwiftType = InstantiationError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstantiationError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .OptionsJsonParseError
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: InstantiationError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .OptionsJsonParseError:
+            writeInt(&buf, Int32(1))
+        
+        }
+    }
+}
+
+
+extension InstantiationError: Equatable, Hashable {}
+
+extension InstantiationError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls when visual and spoken instructions are triggered as the user approaches a maneuver.
+ */
+
+public enum InstructionTriggerMode {
+    
+    /**
+     * Instructions are triggered once the user is within
+     * the instruction's `trigger_distance_before_maneuver` of the maneuver.
+     */
+    case distanceBeforeManeuver(
+        /**
+         * Instructions are triggered up to this many meters early,
+         * to account for imprecision in calculation methodologies from different engines and CPUs
+         * (particularly at the start of a step).
+         */tolerance: Double
+    )
+    /**
+     * Instructions are triggered based on the time remaining until the maneuver.
+     *
+     * The trigger distance of each instruction is converted into a time before the maneuver
+     * using the expected speed along the step (from the route's distance and duration).
+     * The instruction is triggered once the user, travelling at their current speed,
+     * is no more than that time away from the maneuver.
+     * This means announcements come earlier when the user is faster than expected,
+     * and later when they are slower.
+     *
+     * If the user's location does not include a speed
+     * (or the step does not have a duration),
+     * this behaves like [`InstructionTriggerMode::DistanceBeforeManeuver`].
+     */
+    case timeBeforeManeuver(
+        /**
+         * Instructions are triggered up to this many meters early,
+         * to account for imprecision in calculation methodologies from different engines and CPUs
+         * (particularly at the start of a step).
+         */tolerance: Double, 
+        /**
+         * The minimum speed, in meters per second, used to compute the time before the maneuver.
+         *
+         * This ensures that instructions are still triggered when the user is stopped or moving very slowly.
+         */minimumSpeed: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInstructionTriggerMode: FfiConverterRustBuffer {
+    typealias SwiftType = InstructionTriggerMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstructionTriggerMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .distanceBeforeManeuver(tolerance: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 2: return .timeBeforeManeuver(tolerance: try FfiConverterDouble.read(from: &buf), minimumSpeed: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: InstructionTriggerMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .distanceBeforeManeuver(tolerance):
+            writeInt(&buf, Int32(1))
+            FfiConverterDouble.write(tolerance, into: &buf)
+            
+        
+        case let .timeBeforeManeuver(tolerance,minimumSpeed):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(tolerance, into: &buf)
+            FfiConverterDouble.write(minimumSpeed, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInstructionTriggerMode_lift(_ buf: RustBuffer) throws -> InstructionTriggerMode {
+    return try FfiConverterTypeInstructionTriggerMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInstructionTriggerMode_lower(_ value: InstructionTriggerMode) -> RustBuffer {
+    return FfiConverterTypeInstructionTriggerMode.lower(value)
+}
+
+
+
+extension InstructionTriggerMode: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls how simulated locations deviate from the actual route line.
+ * This simulates real-world GPS behavior where readings often have systematic bias.
+ */
+
+public enum LocationBias {
+    
+    /**
+     * Simulates GPS bias by offsetting locations to the left of the route direction.
+     * The f64 parameter specifies the offset distance in meters.
+     */
+    case left(Double
+    )
+    /**
+     * Simulates GPS bias by offsetting locations to the right of the route direction.
+     * The f64 parameter specifies the offset distance in meters.
+     */
+    case right(Double
+    )
+    /**
+     * Simulates GPS bias by randomly choosing left or right offset on initialization
+     * and maintaining that bias throughout the route.
+     * The f64 parameter specifies the offset distance in meters.
+     *
+     * This mimics real-world GPS behavior where bias direction is random but typically
+     * remains consistent during a trip.
+     */
+    case random(Double
+    )
+    /**
+     * No position bias - locations follow the route line exactly.
+     *
+     * This provides "perfect" GPS behavior, useful for testing basic route following
+     * without position uncertainty.
+     */
+    case none
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
+    typealias SwiftType = LocationBias
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationBias {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .left(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 2: return .right(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 3: return .random(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 4: return .none
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LocationBias, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .left(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case let .right(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case let .random(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case .none:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationBias_lift(_ buf: RustBuffer) throws -> LocationBias {
+    return try FfiConverterTypeLocationBias.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationBias_lower(_ value: LocationBias) -> RustBuffer {
+    return FfiConverterTypeLocationBias.lower(value)
+}
+
+
+
+extension LocationBias: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls how raw location updates are cleaned up before the [`NavigationController`] uses them.
+ *
+ * A single wild location (ex: from a multipath reflection) can otherwise trigger
+ * a route deviation or a premature step advance.
+ */
+
+public enum LocationFiltering {
+    
+    /**
+     * Locations are used as reported by the location provider.
+     */
+    case disabled
+    /**
+     * Smooths locations with an alpha-beta filter, which tracks the user's position and velocity,
+     * and rejects locations which are out of order or imply an impossible speed.
+     *
+     * Rejected locations leave the trip state unchanged.
+     */
+    case alphaBeta(
+        /**
+         * How far to correct the predicted position toward each new location,
+         * from 0 (ignore the location) to 1 (use the location as reported).
+         */alpha: Double, 
+        /**
+         * How far to correct the estimated velocity for the difference
+         * between the predicted position and each new location (typically less than `alpha`).
+         */beta: Double, 
+        /**
+         * The fastest plausible speed of the user, in meters per second.
+         *
+         * Locations which are farther from the previous estimate than the user could have traveled
+         * (allowing for the location's accuracy) are rejected.
+         */maxSpeed: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocationFiltering: FfiConverterRustBuffer {
+    typealias SwiftType = LocationFiltering
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationFiltering {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .disabled
+        
+        case 2: return .alphaBeta(alpha: try FfiConverterDouble.read(from: &buf), beta: try FfiConverterDouble.read(from: &buf), maxSpeed: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LocationFiltering, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .disabled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .alphaBeta(alpha,beta,maxSpeed):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(alpha, into: &buf)
+            FfiConverterDouble.write(beta, into: &buf)
+            FfiConverterDouble.write(maxSpeed, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationFiltering_lift(_ buf: RustBuffer) throws -> LocationFiltering {
+    return try FfiConverterTypeLocationFiltering.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationFiltering_lower(_ value: LocationFiltering) -> RustBuffer {
+    return FfiConverterTypeLocationFiltering.lower(value)
+}
+
+
+
+extension LocationFiltering: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls how the user's location is snapped to the route by the [`NavigationController`].
+ */
+
+public enum LocationSnapping {
+    
+    /**
+     * Snap each location to the nearest point on the current step's linestring.
+     */
+    case nearest
+    /**
+     * Match recent locations to the current step with a hidden Markov model.
+     *
+     * Each location has several candidate positions along the step.
+     * The most likely sequence of positions is chosen based on
+     * the distance of each candidate from the location,
+     * how well the user's course agrees with the route at the candidate,
+     * and how well the distance traveled along the route agrees with the distance between locations.
+     *
+     * This keeps the snapped location from jumping to a crossing segment
+     * (ex: where a route intersects itself, or passes over or next to another part of the route).
+     * The snapped location never moves backward along the route.
+     */
+    case mapMatching(
+        /**
+         * The number of previous location updates which are considered along with the current one
+         * (at least one).
+         */window: UInt16
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocationSnapping: FfiConverterRustBuffer {
+    typealias SwiftType = LocationSnapping
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSnapping {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .nearest
+        
+        case 2: return .mapMatching(window: try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LocationSnapping, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .nearest:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .mapMatching(window):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(window, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationSnapping_lift(_ buf: RustBuffer) throws -> LocationSnapping {
+    return try FfiConverterTypeLocationSnapping.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationSnapping_lower(_ value: LocationSnapping) -> RustBuffer {
+    return FfiConverterTypeLocationSnapping.lower(value)
+}
+
+
+
+extension LocationSnapping: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Additional information to further specify a [`ManeuverType`].
+ */
+
+public enum ManeuverModifier {
+    
+    case uTurn
+    case sharpRight
+    case right
+    case slightRight
+    case straight
+    case slightLeft
+    case left
+    case sharpLeft
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverModifier
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverModifier {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .uTurn
+        
+        case 2: return .sharpRight
+        
+        case 3: return .right
+        
+        case 4: return .slightRight
+        
+        case 5: return .straight
+        
+        case 6: return .slightLeft
+        
+        case 7: return .left
+        
+        case 8: return .sharpLeft
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ManeuverModifier, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .uTurn:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .sharpRight:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .right:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .slightRight:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .straight:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .slightLeft:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .left:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .sharpLeft:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverModifier_lift(_ buf: RustBuffer) throws -> ManeuverModifier {
+    return try FfiConverterTypeManeuverModifier.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverModifier_lower(_ value: ManeuverModifier) -> RustBuffer {
+    return FfiConverterTypeManeuverModifier.lower(value)
+}
+
+
+
+extension ManeuverModifier: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The broad class of maneuver to perform.
+ *
+ * This is usually combined with [`ManeuverModifier`] in [`VisualInstructionContent`].
+ */
+
+public enum ManeuverType {
+    
+    case turn
+    case newName
+    case depart
+    case arrive
+    case merge
+    case onRamp
+    case offRamp
+    case fork
+    case endOfRoad
+    case `continue`
+    case roundabout
+    case rotary
+    case roundaboutTurn
+    case notification
+    case exitRoundabout
+    case exitRotary
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManeuverType: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .turn
+        
+        case 2: return .newName
+        
+        case 3: return .depart
+        
+        case 4: return .arrive
+        
+        case 5: return .merge
+        
+        case 6: return .onRamp
+        
+        case 7: return .offRamp
+        
+        case 8: return .fork
+        
+        case 9: return .endOfRoad
+        
+        case 10: return .`continue`
+        
+        case 11: return .roundabout
+        
+        case 12: return .rotary
+        
+        case 13: return .roundaboutTurn
+        
+        case 14: return .notification
+        
+        case 15: return .exitRoundabout
+        
+        case 16: return .exitRotary
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ManeuverType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .turn:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .newName:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .depart:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .arrive:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .merge:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .onRamp:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .offRamp:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .fork:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .endOfRoad:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .`continue`:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .roundabout:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .rotary:
+            writeInt(&buf, Int32(12))
+        
+        
+        case .roundaboutTurn:
+            writeInt(&buf, Int32(13))
+        
+        
+        case .notification:
+            writeInt(&buf, Int32(14))
+        
+        
+        case .exitRoundabout:
+            writeInt(&buf, Int32(15))
+        
+        
+        case .exitRotary:
+            writeInt(&buf, Int32(16))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverType_lift(_ buf: RustBuffer) throws -> ManeuverType {
+    return try FfiConverterTypeManeuverType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverType_lower(_ value: ManeuverType) -> RustBuffer {
+    return FfiConverterTypeManeuverType.lower(value)
+}
+
+
+
+extension ManeuverType: Equatable, Hashable {}
+
+
+
+
+public enum ModelError {
+
+    
+    
+    case PolylineGenerationError(error: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeModelError: FfiConverterRustBuffer {
+    typealias SwiftType = ModelError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .PolylineGenerationError(
+            error: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ModelError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .PolylineGenerationError(error):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(error, into: &buf)
+            
+        }
+    }
+}
+
+
+extension ModelError: Equatable, Hashable {}
+
+extension ModelError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
     }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A discrete change which occurred during a navigation state update.
+ *
+ * These are reported by
+ * [`update_user_location_with_events`](super::NavigationController::update_user_location_with_events)
+ * so that UIs, analytics, etc. do not need to compare successive [`TripState`]s.
+ */
+
+public enum NavigationEvent {
+    
+    /**
+     * Navigation advanced to a new step, which is now the current step.
+     */
+    case stepAdvanced(step: RouteStep
+    )
+    /**
+     * The user reached a waypoint, which was moved to the list of completed waypoints.
+     */
+    case waypointReached(waypoint: Waypoint
+    )
+    /**
+     * A spoken instruction was triggered for the first time during the trip.
+     */
+    case spokenInstructionTriggered(instruction: SpokenInstruction
+    )
+    /**
+     * The user went off the route.
+     */
+    case deviationStarted(
+        /**
+         * The deviation from the route line, in meters.
+         */deviationFromRouteLine: Double
+    )
+    /**
+     * The user is back on the route after a deviation.
+     */
+    case deviationEnded
+    /**
+     * The new current step has an incident along it.
+     */
+    case incidentAhead(incident: Incident
+    )
+    /**
+     * The user started the last maneuver before the end of a route leg
+     * (the next maneuver is the arrival at `waypoint`).
+     */
+    case arrivalImminent(waypoint: Waypoint
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavigationEvent: FfiConverterRustBuffer {
+    typealias SwiftType = NavigationEvent
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationEvent {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .stepAdvanced(step: try FfiConverterTypeRouteStep.read(from: &buf)
+        )
+        
+        case 2: return .waypointReached(waypoint: try FfiConverterTypeWaypoint.read(from: &buf)
+        )
+        
+        case 3: return .spokenInstructionTriggered(instruction: try FfiConverterTypeSpokenInstruction.read(from: &buf)
+        )
+        
+        case 4: return .deviationStarted(deviationFromRouteLine: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 5: return .deviationEnded
+        
+        case 6: return .incidentAhead(incident: try FfiConverterTypeIncident.read(from: &buf)
+        )
+        
+        case 7: return .arrivalImminent(waypoint: try FfiConverterTypeWaypoint.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: NavigationEvent, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .stepAdvanced(step):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeRouteStep.write(step, into: &buf)
+            
+        
+        case let .waypointReached(waypoint):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeWaypoint.write(waypoint, into: &buf)
+            
+        
+        case let .spokenInstructionTriggered(instruction):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypeSpokenInstruction.write(instruction, into: &buf)
+            
+        
+        case let .deviationStarted(deviationFromRouteLine):
+            writeInt(&buf, Int32(4))
+            FfiConverterDouble.write(deviationFromRouteLine, into: &buf)
+            
+        
+        case .deviationEnded:
+            writeInt(&buf, Int32(5))
+        
+        
+        case let .incidentAhead(incident):
+            writeInt(&buf, Int32(6))
+            FfiConverterTypeIncident.write(incident, into: &buf)
+            
+        
+        case let .arrivalImminent(waypoint):
+            writeInt(&buf, Int32(7))
+            FfiConverterTypeWaypoint.write(waypoint, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationEvent_lift(_ buf: RustBuffer) throws -> NavigationEvent {
+    return try FfiConverterTypeNavigationEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationEvent_lower(_ value: NavigationEvent) -> RustBuffer {
+    return FfiConverterTypeNavigationEvent.lower(value)
+}
+
+
+
+extension NavigationEvent: Equatable, Hashable {}
+
+
+
+
+public enum ParsingError {
+
+    
+    
+    case InvalidRouteObject(error: String
+    )
+    case InvalidGeometry(error: String
+    )
+    case MalformedAnnotations(error: String
+    )
+    case InvalidStatusCode(code: String
+    )
+    case UnknownParsingError
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeParsingError: FfiConverterRustBuffer {
+    typealias SwiftType = ParsingError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParsingError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidRouteObject(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .InvalidGeometry(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .MalformedAnnotations(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .InvalidStatusCode(
+            code: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .UnknownParsingError
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ParsingError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .InvalidRouteObject(error):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case let .InvalidGeometry(error):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case let .MalformedAnnotations(error):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case let .InvalidStatusCode(code):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(code, into: &buf)
+            
+        
+        case .UnknownParsingError:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+extension ParsingError: Equatable, Hashable {}
+
+extension ParsingError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The decision of a [`ReroutePolicy`] for a single trip state.
+ */
+
+public enum RerouteDecision {
+    
+    /**
+     * The user is on the route (or is not navigating), so no action is needed.
+     */
+    case onRoute
+    /**
+     * The user is off the route, but the conditions for requesting a new route have not been met yet.
+     */
+    case wait
+    /**
+     * A new route should be requested now.
+     */
+    case requestReroute
+    /**
+     * The user is off the route, but the maximum number of reroutes for the trip has been reached.
+     */
+    case limitReached
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRerouteDecision: FfiConverterRustBuffer {
+    typealias SwiftType = RerouteDecision
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RerouteDecision {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .onRoute
+        
+        case 2: return .wait
+        
+        case 3: return .requestReroute
+        
+        case 4: return .limitReached
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RerouteDecision, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .onRoute:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .wait:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .requestReroute:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .limitReached:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRerouteDecision_lift(_ buf: RustBuffer) throws -> RerouteDecision {
+    return try FfiConverterTypeRerouteDecision.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRerouteDecision_lower(_ value: RerouteDecision) -> RustBuffer {
+    return FfiConverterTypeRerouteDecision.lower(value)
+}
+
+
 
-    public static func write(_ value: InstantiationError, into buf: inout [UInt8]) {
-        switch value {
+extension RerouteDecision: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Status information that describes whether the user is proceeding according to the route or not.
+ *
+ * Note that the name is intentionally a bit generic to allow for expansion of other states.
+ * For example, we could conceivably add a "wrong way" status in the future.
+ */
+
+public enum RouteDeviation {
+    
+    /**
+     * The user is proceeding on course within the expected tolerances; everything is normal.
+     */
+    case noDeviation
+    /**
+     * The user is off the expected route.
+     */
+    case offRoute(
+        /**
+         * The deviation from the route line, in meters.
+         */deviationFromRouteLine: Double
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRouteDeviation: FfiConverterRustBuffer {
+    typealias SwiftType = RouteDeviation
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviation {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .noDeviation
+        
+        case 2: return .offRoute(deviationFromRouteLine: try FfiConverterDouble.read(from: &buf)
+        )
         
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
 
+    public static func write(_ value: RouteDeviation, into buf: inout [UInt8]) {
+        switch value {
         
         
-        case .OptionsJsonParseError:
+        case .noDeviation:
             writeInt(&buf, Int32(1))
         
+        
+        case let .offRoute(deviationFromRouteLine):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(deviationFromRouteLine, into: &buf)
+            
         }
     }
 }
 
 
-extension InstantiationError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteDeviation_lift(_ buf: RustBuffer) throws -> RouteDeviation {
+    return try FfiConverterTypeRouteDeviation.lift(buf)
+}
 
-extension InstantiationError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteDeviation_lower(_ value: RouteDeviation) -> RustBuffer {
+    return FfiConverterTypeRouteDeviation.lower(value)
 }
 
+
+
+extension RouteDeviation: Equatable, Hashable {}
+
+
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Controls how simulated locations deviate from the actual route line.
- * This simulates real-world GPS behavior where readings often have systematic bias.
+ * Determines if the user has deviated from the expected route.
  */
 
-public enum LocationBias {
+public enum RouteDeviationTracking {
     
     /**
-     * Simulates GPS bias by offsetting locations to the left of the route direction.
-     * The f64 parameter specifies the offset distance in meters.
+     * No checks will be done, and we assume the user is always following the route.
      */
-    case left(Double
-    )
+    case none
     /**
-     * Simulates GPS bias by offsetting locations to the right of the route direction.
-     * The f64 parameter specifies the offset distance in meters.
+     * Detects deviation from the route using a configurable static distance threshold from the route line.
      */
-    case right(Double
+    case staticThreshold(
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Values larger than this will not trigger route deviation warnings.
+         */minimumHorizontalAccuracy: UInt16, 
+        /**
+         * The maximum acceptable deviation from the route line, in meters.
+         *
+         * If the distance between the reported location and the expected route line
+         * is greater than this threshold, it will be flagged as an off route condition.
+         */maxAcceptableDeviation: Double
     )
     /**
-     * Simulates GPS bias by randomly choosing left or right offset on initialization
-     * and maintaining that bias throughout the route.
-     * The f64 parameter specifies the offset distance in meters.
-     *
-     * This mimics real-world GPS behavior where bias direction is random but typically
-     * remains consistent during a trip.
+     * An arbitrary user-defined implementation.
+     * You decide with your own [`RouteDeviationDetector`] implementation!
      */
-    case random(Double
+    case custom(detector: RouteDeviationDetector
     )
-    /**
-     * No position bias - locations follow the route line exactly.
-     *
-     * This provides "perfect" GPS behavior, useful for testing basic route following
-     * without position uncertainty.
-     */
-    case none
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
-    typealias SwiftType = LocationBias
+public struct FfiConverterTypeRouteDeviationTracking: FfiConverterRustBuffer {
+    typealias SwiftType = RouteDeviationTracking
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationBias {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviationTracking {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .left(try FfiConverterDouble.read(from: &buf)
-        )
+        case 1: return .none
         
-        case 2: return .right(try FfiConverterDouble.read(from: &buf)
+        case 2: return .staticThreshold(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), maxAcceptableDeviation: try FfiConverterDouble.read(from: &buf)
         )
         
-        case 3: return .random(try FfiConverterDouble.read(from: &buf)
+        case 3: return .custom(detector: try FfiConverterTypeRouteDeviationDetector.read(from: &buf)
         )
         
-        case 4: return .none
-        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: LocationBias, into buf: inout [UInt8]) {
+    public static func write(_ value: RouteDeviationTracking, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .left(v1):
+        case .none:
             writeInt(&buf, Int32(1))
-            FfiConverterDouble.write(v1, into: &buf)
-            
         
-        case let .right(v1):
+        
+        case let .staticThreshold(minimumHorizontalAccuracy,maxAcceptableDeviation):
             writeInt(&buf, Int32(2))
-            FfiConverterDouble.write(v1, into: &buf)
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            FfiConverterDouble.write(maxAcceptableDeviation, into: &buf)
             
         
-        case let .random(v1):
+        case let .custom(detector):
             writeInt(&buf, Int32(3))
-            FfiConverterDouble.write(v1, into: &buf)
+            FfiConverterTypeRouteDeviationDetector.write(detector, into: &buf)
             
-        
-        case .none:
-            writeInt(&buf, Int32(4))
-        
         }
     }
 }
@@ -4215,107 +8881,71 @@ public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationBias_lift(_ buf: RustBuffer) throws -> LocationBias {
-    return try FfiConverterTypeLocationBias.lift(buf)
+public func FfiConverterTypeRouteDeviationTracking_lift(_ buf: RustBuffer) throws -> RouteDeviationTracking {
+    return try FfiConverterTypeRouteDeviationTracking.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationBias_lower(_ value: LocationBias) -> RustBuffer {
-    return FfiConverterTypeLocationBias.lower(value)
+public func FfiConverterTypeRouteDeviationTracking_lower(_ value: RouteDeviationTracking) -> RustBuffer {
+    return FfiConverterTypeRouteDeviationTracking.lower(value)
 }
 
 
 
-extension LocationBias: Equatable, Hashable {}
-
-
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Additional information to further specify a [`ManeuverType`].
+ * A route request generated by a [`RouteRequestGenerator`].
  */
 
-public enum ManeuverModifier {
+public enum RouteRequest {
     
-    case uTurn
-    case sharpRight
-    case right
-    case slightRight
-    case straight
-    case slightLeft
-    case left
-    case sharpLeft
+    case httpPost(url: String, headers: [String: String], body: Data
+    )
+    case httpGet(url: String, headers: [String: String]
+    )
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverModifier
+public struct FfiConverterTypeRouteRequest: FfiConverterRustBuffer {
+    typealias SwiftType = RouteRequest
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverModifier {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteRequest {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .uTurn
-        
-        case 2: return .sharpRight
-        
-        case 3: return .right
-        
-        case 4: return .slightRight
-        
-        case 5: return .straight
-        
-        case 6: return .slightLeft
-        
-        case 7: return .left
+        case 1: return .httpPost(url: try FfiConverterString.read(from: &buf), headers: try FfiConverterDictionaryStringString.read(from: &buf), body: try FfiConverterData.read(from: &buf)
+        )
         
-        case 8: return .sharpLeft
+        case 2: return .httpGet(url: try FfiConverterString.read(from: &buf), headers: try FfiConverterDictionaryStringString.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ManeuverModifier, into buf: inout [UInt8]) {
+    public static func write(_ value: RouteRequest, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .uTurn:
+        case let .httpPost(url,headers,body):
             writeInt(&buf, Int32(1))
+            FfiConverterString.write(url, into: &buf)
+            FfiConverterDictionaryStringString.write(headers, into: &buf)
+            FfiConverterData.write(body, into: &buf)
+            
         
-        
-        case .sharpRight:
+        case let .httpGet(url,headers):
             writeInt(&buf, Int32(2))
-        
-        
-        case .right:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .slightRight:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .straight:
-            writeInt(&buf, Int32(5))
-        
-        
-        case .slightLeft:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .left:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .sharpLeft:
-            writeInt(&buf, Int32(8))
-        
+            FfiConverterString.write(url, into: &buf)
+            FfiConverterDictionaryStringString.write(headers, into: &buf)
+            
         }
     }
 }
@@ -4324,393 +8954,390 @@ public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverModifier_lift(_ buf: RustBuffer) throws -> ManeuverModifier {
-    return try FfiConverterTypeManeuverModifier.lift(buf)
+public func FfiConverterTypeRouteRequest_lift(_ buf: RustBuffer) throws -> RouteRequest {
+    return try FfiConverterTypeRouteRequest.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverModifier_lower(_ value: ManeuverModifier) -> RustBuffer {
-    return FfiConverterTypeManeuverModifier.lower(value)
+public func FfiConverterTypeRouteRequest_lower(_ value: RouteRequest) -> RustBuffer {
+    return FfiConverterTypeRouteRequest.lower(value)
 }
 
 
 
-extension ManeuverModifier: Equatable, Hashable {}
+extension RouteRequest: Equatable, Hashable {}
+
 
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The broad class of maneuver to perform.
- *
- * This is usually combined with [`ManeuverModifier`] in [`VisualInstructionContent`].
- */
+public enum RoutingRequestGenerationError {
 
-public enum ManeuverType {
     
-    case turn
-    case newName
-    case depart
-    case arrive
-    case merge
-    case onRamp
-    case offRamp
-    case fork
-    case endOfRoad
-    case `continue`
-    case roundabout
-    case rotary
-    case roundaboutTurn
-    case notification
-    case exitRoundabout
-    case exitRotary
+    
+    case NotEnoughWaypoints
+    case JsonError
+    case UnknownRequestGenerationError
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeManeuverType: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverType
+public struct FfiConverterTypeRoutingRequestGenerationError: FfiConverterRustBuffer {
+    typealias SwiftType = RoutingRequestGenerationError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverType {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoutingRequestGenerationError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
+
         
-        case 1: return .turn
-        
-        case 2: return .newName
-        
-        case 3: return .depart
-        
-        case 4: return .arrive
-        
-        case 5: return .merge
-        
-        case 6: return .onRamp
-        
-        case 7: return .offRamp
-        
-        case 8: return .fork
-        
-        case 9: return .endOfRoad
-        
-        case 10: return .`continue`
-        
-        case 11: return .roundabout
-        
-        case 12: return .rotary
-        
-        case 13: return .roundaboutTurn
-        
-        case 14: return .notification
-        
-        case 15: return .exitRoundabout
-        
-        case 16: return .exitRotary
+
         
-        default: throw UniffiInternalError.unexpectedEnumCase
+        case 1: return .NotEnoughWaypoints
+        case 2: return .JsonError
+        case 3: return .UnknownRequestGenerationError
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ManeuverType, into buf: inout [UInt8]) {
+    public static func write(_ value: RoutingRequestGenerationError, into buf: inout [UInt8]) {
         switch value {
+
         
+
         
-        case .turn:
+        
+        case .NotEnoughWaypoints:
             writeInt(&buf, Int32(1))
         
         
-        case .newName:
+        case .JsonError:
             writeInt(&buf, Int32(2))
         
         
-        case .depart:
+        case .UnknownRequestGenerationError:
             writeInt(&buf, Int32(3))
         
-        
-        case .arrive:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .merge:
-            writeInt(&buf, Int32(5))
-        
-        
-        case .onRamp:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .offRamp:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .fork:
-            writeInt(&buf, Int32(8))
-        
-        
-        case .endOfRoad:
-            writeInt(&buf, Int32(9))
-        
-        
-        case .`continue`:
-            writeInt(&buf, Int32(10))
-        
-        
-        case .roundabout:
-            writeInt(&buf, Int32(11))
-        
-        
-        case .rotary:
-            writeInt(&buf, Int32(12))
-        
-        
-        case .roundaboutTurn:
-            writeInt(&buf, Int32(13))
-        
-        
-        case .notification:
-            writeInt(&buf, Int32(14))
-        
-        
-        case .exitRoundabout:
-            writeInt(&buf, Int32(15))
-        
-        
-        case .exitRotary:
-            writeInt(&buf, Int32(16))
-        
         }
     }
 }
 
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeManeuverType_lift(_ buf: RustBuffer) throws -> ManeuverType {
-    return try FfiConverterTypeManeuverType.lift(buf)
-}
+extension RoutingRequestGenerationError: Equatable, Hashable {}
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeManeuverType_lower(_ value: ManeuverType) -> RustBuffer {
-    return FfiConverterTypeManeuverType.lower(value)
+extension RoutingRequestGenerationError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
 }
 
 
-
-extension ManeuverType: Equatable, Hashable {}
-
-
-
-
-public enum ModelError {
+public enum SimulationError {
 
     
     
-    case PolylineGenerationError(error: String
+    /**
+     * Errors decoding the polyline string.
+     */
+    case PolylineError(error: String
     )
+    /**
+     * Not enough points in the input.
+     */
+    case NotEnoughPoints
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeModelError: FfiConverterRustBuffer {
-    typealias SwiftType = ModelError
+public struct FfiConverterTypeSimulationError: FfiConverterRustBuffer {
+    typealias SwiftType = SimulationError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SimulationError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
         
 
         
-        case 1: return .PolylineGenerationError(
+        case 1: return .PolylineError(
             error: try FfiConverterString.read(from: &buf)
             )
+        case 2: return .NotEnoughPoints
 
          default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ModelError, into buf: inout [UInt8]) {
+    public static func write(_ value: SimulationError, into buf: inout [UInt8]) {
         switch value {
 
         
 
         
         
-        case let .PolylineGenerationError(error):
+        case let .PolylineError(error):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case .NotEnoughPoints:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+extension SimulationError: Equatable, Hashable {}
+
+extension SimulationError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Special conditions which alter the normal step advance logic,
+ */
+
+public enum SpecialAdvanceConditions {
+    
+    /**
+     * Allows navigation to advance to the next step as soon as the user
+     * comes within this distance (in meters) of the end of the current step.
+     *
+     * This results in *early* advance when the user is near the goal.
+     */
+    case advanceAtDistanceFromEnd(UInt16
+    )
+    /**
+     * Requires that the user be at least this far (distance in meters)
+     * from the current route step.
+     *
+     * This results in *delayed* advance,
+     * but is more robust to spurious / unwanted step changes in scenarios including
+     * self-intersecting routes (sudden jump to the next step)
+     * and pauses at intersections (advancing too soon before the maneuver is complete).
+     *
+     * Note that this could be theoretically less robust to things like U-turns,
+     * but we need a bit more real-world testing to confirm if it's an issue.
+     */
+    case minimumDistanceFromCurrentStepLine(UInt16
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpecialAdvanceConditions: FfiConverterRustBuffer {
+    typealias SwiftType = SpecialAdvanceConditions
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpecialAdvanceConditions {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .advanceAtDistanceFromEnd(try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        case 2: return .minimumDistanceFromCurrentStepLine(try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SpecialAdvanceConditions, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .advanceAtDistanceFromEnd(v1):
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(error, into: &buf)
+            FfiConverterUInt16.write(v1, into: &buf)
+            
+        
+        case let .minimumDistanceFromCurrentStepLine(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterUInt16.write(v1, into: &buf)
             
         }
     }
 }
 
 
-extension ModelError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpecialAdvanceConditions_lift(_ buf: RustBuffer) throws -> SpecialAdvanceConditions {
+    return try FfiConverterTypeSpecialAdvanceConditions.lift(buf)
+}
 
-extension ModelError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpecialAdvanceConditions_lower(_ value: SpecialAdvanceConditions) -> RustBuffer {
+    return FfiConverterTypeSpecialAdvanceConditions.lower(value)
 }
 
 
-public enum ParsingError {
 
+extension SpecialAdvanceConditions: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * A speed limit.
+ *
+ * For more information, see <https://wiki.openstreetmap.org/wiki/Key:maxspeed>.
+ */
+
+public enum SpeedLimit {
     
-    
-    case InvalidRouteObject(error: String
-    )
-    case InvalidGeometry(error: String
-    )
-    case MalformedAnnotations(error: String
-    )
-    case InvalidStatusCode(code: String
+    /**
+     * There is no speed limit (ex: parts of the German Autobahn).
+     */
+    case noLimit
+    /**
+     * The speed limit is not known.
+     */
+    case unknown
+    /**
+     * The speed limit is a known value.
+     *
+     * The unit depends on the routing engine (and possibly on local conventions).
+     */
+    case limited(
+        /**
+         * The speed limit, in `unit`s.
+         */value: Double, 
+        /**
+         * The unit of the speed limit.
+         */unit: SpeedUnit
     )
-    case UnknownParsingError
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeParsingError: FfiConverterRustBuffer {
-    typealias SwiftType = ParsingError
+public struct FfiConverterTypeSpeedLimit: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedLimit
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParsingError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedLimit {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .noLimit
         
-        case 1: return .InvalidRouteObject(
-            error: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .InvalidGeometry(
-            error: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .MalformedAnnotations(
-            error: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InvalidStatusCode(
-            code: try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .UnknownParsingError
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .unknown
+        
+        case 3: return .limited(value: try FfiConverterDouble.read(from: &buf), unit: try FfiConverterTypeSpeedUnit.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ParsingError, into buf: inout [UInt8]) {
+    public static func write(_ value: SpeedLimit, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case let .InvalidRouteObject(error):
+        case .noLimit:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(error, into: &buf)
-            
         
-        case let .InvalidGeometry(error):
+        
+        case .unknown:
             writeInt(&buf, Int32(2))
-            FfiConverterString.write(error, into: &buf)
-            
         
-        case let .MalformedAnnotations(error):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(error, into: &buf)
-            
         
-        case let .InvalidStatusCode(code):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(code, into: &buf)
+        case let .limited(value,unit):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(value, into: &buf)
+            FfiConverterTypeSpeedUnit.write(unit, into: &buf)
             
-        
-        case .UnknownParsingError:
-            writeInt(&buf, Int32(5))
-        
         }
     }
 }
 
 
-extension ParsingError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedLimit_lift(_ buf: RustBuffer) throws -> SpeedLimit {
+    return try FfiConverterTypeSpeedLimit.lift(buf)
+}
 
-extension ParsingError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedLimit_lower(_ value: SpeedLimit) -> RustBuffer {
+    return FfiConverterTypeSpeedLimit.lower(value)
 }
 
+
+
+extension SpeedLimit: Equatable, Hashable {}
+
+
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Status information that describes whether the user is proceeding according to the route or not.
- *
- * Note that the name is intentionally a bit generic to allow for expansion of other states.
- * For example, we could conceivably add a "wrong way" status in the future.
+ * A unit of speed.
  */
 
-public enum RouteDeviation {
+public enum SpeedUnit {
     
-    /**
-     * The user is proceeding on course within the expected tolerances; everything is normal.
-     */
-    case noDeviation
-    /**
-     * The user is off the expected route.
-     */
-    case offRoute(
-        /**
-         * The deviation from the route line, in meters.
-         */deviationFromRouteLine: Double
-    )
+    case kilometersPerHour
+    case milesPerHour
+    case knots
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteDeviation: FfiConverterRustBuffer {
-    typealias SwiftType = RouteDeviation
+public struct FfiConverterTypeSpeedUnit: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedUnit
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviation {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedUnit {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .noDeviation
+        case 1: return .kilometersPerHour
         
-        case 2: return .offRoute(deviationFromRouteLine: try FfiConverterDouble.read(from: &buf)
-        )
+        case 2: return .milesPerHour
+        
+        case 3: return .knots
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: RouteDeviation, into buf: inout [UInt8]) {
+    public static func write(_ value: SpeedUnit, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .noDeviation:
+        case .kilometersPerHour:
             writeInt(&buf, Int32(1))
         
         
-        case let .offRoute(deviationFromRouteLine):
+        case .milesPerHour:
             writeInt(&buf, Int32(2))
-            FfiConverterDouble.write(deviationFromRouteLine, into: &buf)
-            
+        
+        
+        case .knots:
+            writeInt(&buf, Int32(3))
+        
         }
     }
 }
@@ -4719,55 +9346,46 @@ public struct FfiConverterTypeRouteDeviation: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviation_lift(_ buf: RustBuffer) throws -> RouteDeviation {
-    return try FfiConverterTypeRouteDeviation.lift(buf)
+public func FfiConverterTypeSpeedUnit_lift(_ buf: RustBuffer) throws -> SpeedUnit {
+    return try FfiConverterTypeSpeedUnit.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviation_lower(_ value: RouteDeviation) -> RustBuffer {
-    return FfiConverterTypeRouteDeviation.lower(value)
+public func FfiConverterTypeSpeedUnit_lower(_ value: SpeedUnit) -> RustBuffer {
+    return FfiConverterTypeSpeedUnit.lower(value)
 }
 
 
 
-extension RouteDeviation: Equatable, Hashable {}
+extension SpeedUnit: Equatable, Hashable {}
 
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Determines if the user has deviated from the expected route.
+ * Controls whether the user is flagged as speeding (see `TripState::Navigating::is_speeding`).
  */
 
-public enum RouteDeviationTracking {
+public enum SpeedingDetection {
     
     /**
-     * No checks will be done, and we assume the user is always following the route.
+     * The user is never flagged as speeding.
      */
-    case none
+    case disabled
     /**
-     * Detects deviation from the route using a configurable static distance threshold from the route line.
+     * The user is flagged as speeding when their speed exceeds the posted speed limit
+     * by more than a tolerance.
      */
-    case staticThreshold(
-        /**
-         * The minimum required horizontal accuracy of the user location, in meters.
-         * Values larger than this will not trigger route deviation warnings.
-         */minimumHorizontalAccuracy: UInt16, 
+    case enabled(
         /**
-         * The maximum acceptable deviation from the route line, in meters.
+         * How far the user's speed may exceed the speed limit before they are speeding,
+         * as a percentage of the speed limit.
          *
-         * If the distance between the reported location and the expected route line
-         * is greater than this threshold, it will be flagged as an off route condition.
-         */maxAcceptableDeviation: Double
-    )
-    /**
-     * An arbitrary user-defined implementation.
-     * You decide with your own [`RouteDeviationDetector`] implementation!
-     */
-    case custom(detector: RouteDeviationDetector
+         * For example, a tolerance of 10 allows up to 55 km/h in a 50 km/h zone.
+         */tolerancePercent: UInt16
     )
 }
 
@@ -4775,42 +9393,33 @@ public enum RouteDeviationTracking {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteDeviationTracking: FfiConverterRustBuffer {
-    typealias SwiftType = RouteDeviationTracking
+public struct FfiConverterTypeSpeedingDetection: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedingDetection
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviationTracking {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedingDetection {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .none
-        
-        case 2: return .staticThreshold(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), maxAcceptableDeviation: try FfiConverterDouble.read(from: &buf)
-        )
+        case 1: return .disabled
         
-        case 3: return .custom(detector: try FfiConverterTypeRouteDeviationDetector.read(from: &buf)
+        case 2: return .enabled(tolerancePercent: try FfiConverterUInt16.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: RouteDeviationTracking, into buf: inout [UInt8]) {
+    public static func write(_ value: SpeedingDetection, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .none:
+        case .disabled:
             writeInt(&buf, Int32(1))
         
         
-        case let .staticThreshold(minimumHorizontalAccuracy,maxAcceptableDeviation):
+        case let .enabled(tolerancePercent):
             writeInt(&buf, Int32(2))
-            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
-            FfiConverterDouble.write(maxAcceptableDeviation, into: &buf)
-            
-        
-        case let .custom(detector):
-            writeInt(&buf, Int32(3))
-            FfiConverterTypeRouteDeviationDetector.write(detector, into: &buf)
+            FfiConverterUInt16.write(tolerancePercent, into: &buf)
             
         }
     }
@@ -4820,31 +9429,65 @@ public struct FfiConverterTypeRouteDeviationTracking: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviationTracking_lift(_ buf: RustBuffer) throws -> RouteDeviationTracking {
-    return try FfiConverterTypeRouteDeviationTracking.lift(buf)
+public func FfiConverterTypeSpeedingDetection_lift(_ buf: RustBuffer) throws -> SpeedingDetection {
+    return try FfiConverterTypeSpeedingDetection.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviationTracking_lower(_ value: RouteDeviationTracking) -> RustBuffer {
-    return FfiConverterTypeRouteDeviationTracking.lower(value)
+public func FfiConverterTypeSpeedingDetection_lower(_ value: SpeedingDetection) -> RustBuffer {
+    return FfiConverterTypeSpeedingDetection.lower(value)
 }
 
 
 
+extension SpeedingDetection: Equatable, Hashable {}
+
+
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * A route request generated by a [`RouteRequestGenerator`].
+ * The step advance mode describes when the current maneuver has been successfully completed,
+ * and we should advance to the next step.
  */
 
-public enum RouteRequest {
+public enum StepAdvanceMode {
     
-    case httpPost(url: String, headers: [String: String], body: Data
+    /**
+     * Never advances to the next step automatically;
+     * requires calling [`NavigationController::advance_to_next_step`](super::NavigationController::advance_to_next_step).
+     *
+     * You can use this to implement custom behaviors in external code.
+     */
+    case manual
+    /**
+     * Automatically advances when the user's location is close enough to the end of the step
+     */
+    case distanceToEndOfStep(
+        /**
+         * Distance to the last waypoint in the step, measured in meters, at which to advance.
+         */distance: UInt16, 
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Values larger than this cannot trigger a step advance.
+         */minimumHorizontalAccuracy: UInt16
     )
-    case httpGet(url: String, headers: [String: String]
+    /**
+     * Automatically advances when the user's distance to the *next* step's linestring  is less
+     * than the distance to the current step's linestring, subject to certain conditions.
+     */
+    case relativeLineStringDistance(
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Values larger than this cannot ever trigger a step advance.
+         */minimumHorizontalAccuracy: UInt16, 
+        /**
+         * Optional extra conditions which refine the step advance logic.
+         *
+         * See the enum variant documentation for details.
+         */specialAdvanceConditions: SpecialAdvanceConditions?
     )
 }
 
@@ -4852,38 +9495,43 @@ public enum RouteRequest {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteRequest: FfiConverterRustBuffer {
-    typealias SwiftType = RouteRequest
+public struct FfiConverterTypeStepAdvanceMode: FfiConverterRustBuffer {
+    typealias SwiftType = StepAdvanceMode
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteRequest {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceMode {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .httpPost(url: try FfiConverterString.read(from: &buf), headers: try FfiConverterDictionaryStringString.read(from: &buf), body: try FfiConverterData.read(from: &buf)
+        case 1: return .manual
+        
+        case 2: return .distanceToEndOfStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
         )
         
-        case 2: return .httpGet(url: try FfiConverterString.read(from: &buf), headers: try FfiConverterDictionaryStringString.read(from: &buf)
+        case 3: return .relativeLineStringDistance(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), specialAdvanceConditions: try FfiConverterOptionTypeSpecialAdvanceConditions.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: RouteRequest, into buf: inout [UInt8]) {
+    public static func write(_ value: StepAdvanceMode, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .httpPost(url,headers,body):
+        case .manual:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(url, into: &buf)
-            FfiConverterDictionaryStringString.write(headers, into: &buf)
-            FfiConverterData.write(body, into: &buf)
-            
         
-        case let .httpGet(url,headers):
+        
+        case let .distanceToEndOfStep(distance,minimumHorizontalAccuracy):
             writeInt(&buf, Int32(2))
-            FfiConverterString.write(url, into: &buf)
-            FfiConverterDictionaryStringString.write(headers, into: &buf)
+            FfiConverterUInt16.write(distance, into: &buf)
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            
+        
+        case let .relativeLineStringDistance(minimumHorizontalAccuracy,specialAdvanceConditions):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            FfiConverterOptionTypeSpecialAdvanceConditions.write(specialAdvanceConditions, into: &buf)
             
         }
     }
@@ -4893,219 +9541,432 @@ public struct FfiConverterTypeRouteRequest: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteRequest_lift(_ buf: RustBuffer) throws -> RouteRequest {
-    return try FfiConverterTypeRouteRequest.lift(buf)
+public func FfiConverterTypeStepAdvanceMode_lift(_ buf: RustBuffer) throws -> StepAdvanceMode {
+    return try FfiConverterTypeStepAdvanceMode.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteRequest_lower(_ value: RouteRequest) -> RustBuffer {
-    return FfiConverterTypeRouteRequest.lower(value)
+public func FfiConverterTypeStepAdvanceMode_lower(_ value: StepAdvanceMode) -> RustBuffer {
+    return FfiConverterTypeStepAdvanceMode.lower(value)
 }
 
 
 
-extension RouteRequest: Equatable, Hashable {}
-
+extension StepAdvanceMode: Equatable, Hashable {}
 
 
 
-public enum RoutingRequestGenerationError {
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls whether the [`NavigationController`](super::NavigationController)
+ * considers upcoming steps (and not just the current one) when matching the user's location to the route.
+ *
+ * Looking ahead lets navigation recover when the user skips past one or more steps
+ * (ex: a short step which was missed entirely, or a jump in the GPS signal)
+ * by jumping directly to the best matching step.
+ */
 
+public enum StepLookAhead {
     
-    
-    case NotEnoughWaypoints
-    case JsonError
-    case UnknownRequestGenerationError
+    /**
+     * Only the current step is considered;
+     * navigation proceeds one step at a time according to the [`StepAdvanceMode`].
+     */
+    case disabled
+    /**
+     * Searches a window of upcoming steps for a better match than the current step.
+     *
+     * The controller only jumps ahead when the user is *farther* than `max_distance_from_step`
+     * from the current step, and *within* `max_distance_from_step` of an upcoming step.
+     * The first condition keeps navigation on the current step in places where a route crosses itself.
+     * When several upcoming steps qualify, the closest one wins.
+     *
+     * The window never extends past the end of the current route leg,
+     * so that waypoints are still completed (and arrival detected) one leg at a time.
+     */
+    case window(
+        /**
+         * The maximum number of steps after the current one to consider.
+         */maxSteps: UInt16, 
+        /**
+         * The distance from a step's geometry, in meters,
+         * within which the user is considered to be on that step.
+         */maxDistanceFromStep: UInt16, 
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Values larger than this will never cause navigation to jump ahead.
+         */minimumHorizontalAccuracy: UInt16
+    )
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRoutingRequestGenerationError: FfiConverterRustBuffer {
-    typealias SwiftType = RoutingRequestGenerationError
+public struct FfiConverterTypeStepLookAhead: FfiConverterRustBuffer {
+    typealias SwiftType = StepLookAhead
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoutingRequestGenerationError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepLookAhead {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .disabled
         
-        case 1: return .NotEnoughWaypoints
-        case 2: return .JsonError
-        case 3: return .UnknownRequestGenerationError
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .window(maxSteps: try FfiConverterUInt16.read(from: &buf), maxDistanceFromStep: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: RoutingRequestGenerationError, into buf: inout [UInt8]) {
+    public static func write(_ value: StepLookAhead, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case .NotEnoughWaypoints:
+        case .disabled:
             writeInt(&buf, Int32(1))
         
         
-        case .JsonError:
+        case let .window(maxSteps,maxDistanceFromStep,minimumHorizontalAccuracy):
             writeInt(&buf, Int32(2))
-        
-        
-        case .UnknownRequestGenerationError:
-            writeInt(&buf, Int32(3))
-        
+            FfiConverterUInt16.write(maxSteps, into: &buf)
+            FfiConverterUInt16.write(maxDistanceFromStep, into: &buf)
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            
         }
     }
 }
 
 
-extension RoutingRequestGenerationError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepLookAhead_lift(_ buf: RustBuffer) throws -> StepLookAhead {
+    return try FfiConverterTypeStepLookAhead.lift(buf)
+}
 
-extension RoutingRequestGenerationError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepLookAhead_lower(_ value: StepLookAhead) -> RustBuffer {
+    return FfiConverterTypeStepLookAhead.lower(value)
 }
 
 
-public enum SimulationError {
 
-    
+extension StepLookAhead: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The state of a navigation session.
+ *
+ * This is produced by [`NavigationController`](super::NavigationController) methods
+ * including [`get_initial_state`](super::NavigationController::get_initial_state)
+ * and [`update_user_location`](super::NavigationController::update_user_location).
+ */
+
+public enum TripState {
     
     /**
-     * Errors decoding the polyline string.
+     * The navigation controller is idle and there is no active trip.
      */
-    case PolylineError(error: String
+    case idle
+    /**
+     * The navigation controller is actively navigating a trip.
+     */
+    case navigating(
+        /**
+         * The index of the closest coordinate to the user's snapped location.
+         *
+         * This index is relative to the *current* [`RouteStep`]'s geometry.
+         */currentStepGeometryIndex: UInt64?, 
+        /**
+         * A location on the line string that
+         */snappedUserLocation: UserLocation, 
+        /**
+         * The ordered list of steps that remain in the trip.
+         *
+         * The step at the front of the list is always the current step.
+         * We currently assume that you cannot move backward to a previous step.
+         */remainingSteps: [RouteStep], 
+        /**
+         * Remaining waypoints to visit on the route.
+         *
+         * The waypoint at the front of the list is always the *next* waypoint "goal."
+         * Unlike the current step, there is no value in tracking the "current" waypoint,
+         * as the main use of waypoints is recalculation when the user deviates from the route.
+         * (In most use cases, a route will have only two waypoints, but more complex use cases
+         * may have multiple intervening points that are visited along the route.)
+         * This list is updated as the user advances through the route.
+         */remainingWaypoints: [Waypoint], 
+        /**
+         * The trip progress includes information that is useful for showing the
+         * user's progress along the full navigation trip, the route and its components.
+         */progress: TripProgress, 
+        /**
+         * The route deviation status: is the user following the route or not?
+         */deviation: RouteDeviation, 
+        /**
+         * The visual instruction that should be displayed in the user interface.
+         */visualInstruction: VisualInstruction?, 
+        /**
+         * A spoken instruction which was triggered by this update, and should be synthesized using TTS.
+         *
+         * This is only set by the update which first triggers the instruction.
+         * Instructions which were already announced during the trip
+         * are never surfaced again, including after manual step advances and route replacement.
+         */spokenInstruction: SpokenInstruction?, 
+        /**
+         * Annotation data for the route segment at the current location.
+         */annotation: SegmentAnnotation?, 
+        /**
+         * The posted speed limit at the current location (if known).
+         *
+         * This comes from the `max_speed` in the current segment's `annotation`.
+         */speedLimit: SpeedLimit?, 
+        /**
+         * True if the user's speed exceeds the `speed_limit`
+         * by more than the tolerance configured in [`SpeedingDetection`].
+         *
+         * This is always false if the user's speed or the speed limit is unknown.
+         */isSpeeding: Bool, 
+        /**
+         * True if the snapped location was estimated by dead reckoning
+         * (see [`predict_state_at`](super::NavigationController::predict_state_at))
+         * rather than matched from a location update.
+         */isLocationEstimated: Bool, 
+        /**
+         * Incidents and congestion on the route ahead.
+         *
+         * This is only computed when [`EventLookAhead`] is enabled,
+         * and is [`None`] otherwise.
+         */upcomingEvents: UpcomingEvents?, 
+        /**
+         * Waypoints which have already been visited during the trip, in the order they were reached.
+         *
+         * Unlike `remaining_waypoints`, this list survives route replacement
+         * (see [`get_rerouted_state`](super::NavigationController::get_rerouted_state)),
+         * so it always describes the whole trip rather than the current route.
+         */completedWaypoints: [Waypoint], 
+        /**
+         * Statistics about the trip so far.
+         *
+         * Like `completed_waypoints`, these survive route replacement.
+         */statistics: TripStatistics, 
+        /**
+         * Working state which the navigation controller carries from one update to the next.
+         *
+         * This is only meaningful to the navigation controller;
+         * pass it back unchanged with the rest of the trip state.
+         */bookkeeping: TripBookkeeping
     )
     /**
-     * Not enough points in the input.
+     * The user has arrived at a waypoint where they stop
+     * (an intermediate stop or the final destination).
+     *
+     * This state is only produced when [`ArrivalDetection`] is enabled.
+     * Navigation does not continue on its own;
+     * use [`resume_navigation`](super::NavigationController::resume_navigation)
+     * to continue to the next leg of the route (or complete the trip at the final destination).
      */
-    case NotEnoughPoints
+    case arrived(
+        /**
+         * The waypoint which the user arrived at.
+         */waypoint: Waypoint, 
+        /**
+         * Whether the waypoint is the final destination of the route.
+         */isFinalDestination: Bool, 
+        /**
+         * The user's snapped location at the time of arrival.
+         */snappedUserLocation: UserLocation, 
+        /**
+         * The steps which remained at the time of arrival, starting with the final step of the leg.
+         */remainingSteps: [RouteStep], 
+        /**
+         * Remaining waypoints to visit on the route.
+         *
+         * The waypoint that the user arrived at has already been removed from this list.
+         */remainingWaypoints: [Waypoint], 
+        /**
+         * Waypoints which have already been visited during the trip (including this one),
+         * in the order they were reached.
+         */completedWaypoints: [Waypoint], 
+        /**
+         * Statistics about the trip up to the time of arrival.
+         */statistics: TripStatistics, 
+        /**
+         * Working state which the navigation controller carries from one update to the next.
+         */bookkeeping: TripBookkeeping
+    )
+    /**
+     * The navigation controller has reached the end of the trip.
+     */
+    case complete(
+        /**
+         * Statistics about the whole trip.
+         */summary: TripStatistics
+    )
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSimulationError: FfiConverterRustBuffer {
-    typealias SwiftType = SimulationError
+public struct FfiConverterTypeTripState: FfiConverterRustBuffer {
+    typealias SwiftType = TripState
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SimulationError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripState {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .idle
         
-        case 1: return .PolylineError(
-            error: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .NotEnoughPoints
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .navigating(currentStepGeometryIndex: try FfiConverterOptionUInt64.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), progress: try FfiConverterTypeTripProgress.read(from: &buf), deviation: try FfiConverterTypeRouteDeviation.read(from: &buf), visualInstruction: try FfiConverterOptionTypeVisualInstruction.read(from: &buf), spokenInstruction: try FfiConverterOptionTypeSpokenInstruction.read(from: &buf), annotation: try FfiConverterOptionTypeSegmentAnnotation.read(from: &buf), speedLimit: try FfiConverterOptionTypeSpeedLimit.read(from: &buf), isSpeeding: try FfiConverterBool.read(from: &buf), isLocationEstimated: try FfiConverterBool.read(from: &buf), upcomingEvents: try FfiConverterOptionTypeUpcomingEvents.read(from: &buf), completedWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), statistics: try FfiConverterTypeTripStatistics.read(from: &buf), bookkeeping: try FfiConverterTypeTripBookkeeping.read(from: &buf)
+        )
+        
+        case 3: return .arrived(waypoint: try FfiConverterTypeWaypoint.read(from: &buf), isFinalDestination: try FfiConverterBool.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), completedWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), statistics: try FfiConverterTypeTripStatistics.read(from: &buf), bookkeeping: try FfiConverterTypeTripBookkeeping.read(from: &buf)
+        )
+        
+        case 4: return .complete(summary: try FfiConverterTypeTripStatistics.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: SimulationError, into buf: inout [UInt8]) {
+    public static func write(_ value: TripState, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case let .PolylineError(error):
+        case .idle:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(error, into: &buf)
-            
         
-        case .NotEnoughPoints:
+        
+        case let .navigating(currentStepGeometryIndex,snappedUserLocation,remainingSteps,remainingWaypoints,progress,deviation,visualInstruction,spokenInstruction,annotation,speedLimit,isSpeeding,isLocationEstimated,upcomingEvents,completedWaypoints,statistics,bookkeeping):
             writeInt(&buf, Int32(2))
+            FfiConverterOptionUInt64.write(currentStepGeometryIndex, into: &buf)
+            FfiConverterTypeUserLocation.write(snappedUserLocation, into: &buf)
+            FfiConverterSequenceTypeRouteStep.write(remainingSteps, into: &buf)
+            FfiConverterSequenceTypeWaypoint.write(remainingWaypoints, into: &buf)
+            FfiConverterTypeTripProgress.write(progress, into: &buf)
+            FfiConverterTypeRouteDeviation.write(deviation, into: &buf)
+            FfiConverterOptionTypeVisualInstruction.write(visualInstruction, into: &buf)
+            FfiConverterOptionTypeSpokenInstruction.write(spokenInstruction, into: &buf)
+            FfiConverterOptionTypeSegmentAnnotation.write(annotation, into: &buf)
+            FfiConverterOptionTypeSpeedLimit.write(speedLimit, into: &buf)
+            FfiConverterBool.write(isSpeeding, into: &buf)
+            FfiConverterBool.write(isLocationEstimated, into: &buf)
+            FfiConverterOptionTypeUpcomingEvents.write(upcomingEvents, into: &buf)
+            FfiConverterSequenceTypeWaypoint.write(completedWaypoints, into: &buf)
+            FfiConverterTypeTripStatistics.write(statistics, into: &buf)
+            FfiConverterTypeTripBookkeeping.write(bookkeeping, into: &buf)
+            
+        
+        case let .arrived(waypoint,isFinalDestination,snappedUserLocation,remainingSteps,remainingWaypoints,completedWaypoints,statistics,bookkeeping):
+            writeInt(&buf, Int32(3))
+            FfiConverterTypeWaypoint.write(waypoint, into: &buf)
+            FfiConverterBool.write(isFinalDestination, into: &buf)
+            FfiConverterTypeUserLocation.write(snappedUserLocation, into: &buf)
+            FfiConverterSequenceTypeRouteStep.write(remainingSteps, into: &buf)
+            FfiConverterSequenceTypeWaypoint.write(remainingWaypoints, into: &buf)
+            FfiConverterSequenceTypeWaypoint.write(completedWaypoints, into: &buf)
+            FfiConverterTypeTripStatistics.write(statistics, into: &buf)
+            FfiConverterTypeTripBookkeeping.write(bookkeeping, into: &buf)
+            
         
+        case let .complete(summary):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeTripStatistics.write(summary, into: &buf)
+            
         }
     }
 }
 
 
-extension SimulationError: Equatable, Hashable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripState_lift(_ buf: RustBuffer) throws -> TripState {
+    return try FfiConverterTypeTripState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripState_lower(_ value: TripState) -> RustBuffer {
+    return FfiConverterTypeTripState.lower(value)
+}
+
+
+
+extension TripState: Equatable, Hashable {}
+
 
-extension SimulationError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-}
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Special conditions which alter the normal step advance logic,
+ * A system of units for displaying and announcing distances.
  */
 
-public enum SpecialAdvanceConditions {
+public enum UnitSystem {
     
     /**
-     * Allows navigation to advance to the next step as soon as the user
-     * comes within this distance (in meters) of the end of the current step.
-     *
-     * This results in *early* advance when the user is near the goal.
+     * Meters and kilometers.
      */
-    case advanceAtDistanceFromEnd(UInt16
-    )
+    case metric
     /**
-     * Requires that the user be at least this far (distance in meters)
-     * from the current route step.
-     *
-     * This results in *delayed* advance,
-     * but is more robust to spurious / unwanted step changes in scenarios including
-     * self-intersecting routes (sudden jump to the next step)
-     * and pauses at intersections (advancing too soon before the maneuver is complete).
-     *
-     * Note that this could be theoretically less robust to things like U-turns,
-     * but we need a bit more real-world testing to confirm if it's an issue.
+     * Feet and miles (as in the United States).
      */
-    case minimumDistanceFromCurrentStepLine(UInt16
-    )
+    case imperial
+    /**
+     * Yards and miles (as in the United Kingdom).
+     */
+    case imperialWithYards
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpecialAdvanceConditions: FfiConverterRustBuffer {
-    typealias SwiftType = SpecialAdvanceConditions
+public struct FfiConverterTypeUnitSystem: FfiConverterRustBuffer {
+    typealias SwiftType = UnitSystem
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpecialAdvanceConditions {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UnitSystem {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .advanceAtDistanceFromEnd(try FfiConverterUInt16.read(from: &buf)
-        )
+        case 1: return .metric
         
-        case 2: return .minimumDistanceFromCurrentStepLine(try FfiConverterUInt16.read(from: &buf)
-        )
+        case 2: return .imperial
+        
+        case 3: return .imperialWithYards
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: SpecialAdvanceConditions, into buf: inout [UInt8]) {
+    public static func write(_ value: UnitSystem, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .advanceAtDistanceFromEnd(v1):
+        case .metric:
             writeInt(&buf, Int32(1))
-            FfiConverterUInt16.write(v1, into: &buf)
-            
         
-        case let .minimumDistanceFromCurrentStepLine(v1):
+        
+        case .imperial:
             writeInt(&buf, Int32(2))
-            FfiConverterUInt16.write(v1, into: &buf)
-            
+        
+        
+        case .imperialWithYards:
+            writeInt(&buf, Int32(3))
+        
         }
     }
 }
@@ -5114,356 +9975,391 @@ public struct FfiConverterTypeSpecialAdvanceConditions: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpecialAdvanceConditions_lift(_ buf: RustBuffer) throws -> SpecialAdvanceConditions {
-    return try FfiConverterTypeSpecialAdvanceConditions.lift(buf)
+public func FfiConverterTypeUnitSystem_lift(_ buf: RustBuffer) throws -> UnitSystem {
+    return try FfiConverterTypeUnitSystem.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpecialAdvanceConditions_lower(_ value: SpecialAdvanceConditions) -> RustBuffer {
-    return FfiConverterTypeSpecialAdvanceConditions.lower(value)
+public func FfiConverterTypeUnitSystem_lower(_ value: UnitSystem) -> RustBuffer {
+    return FfiConverterTypeUnitSystem.lower(value)
 }
 
 
 
-extension SpecialAdvanceConditions: Equatable, Hashable {}
+extension UnitSystem: Equatable, Hashable {}
 
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The step advance mode describes when the current maneuver has been successfully completed,
- * and we should advance to the next step.
+ * Describes characteristics of the waypoint for the routing backend.
  */
 
-public enum StepAdvanceMode {
+public enum WaypointKind {
     
     /**
-     * Never advances to the next step automatically;
-     * requires calling [`NavigationController::advance_to_next_step`](super::NavigationController::advance_to_next_step).
+     * Starts or ends a leg of the trip.
      *
-     * You can use this to implement custom behaviors in external code.
-     */
-    case manual
-    /**
-     * Automatically advances when the user's location is close enough to the end of the step
+     * Most routing engines will generate arrival and departure instructions.
      */
-    case distanceToEndOfStep(
-        /**
-         * Distance to the last waypoint in the step, measured in meters, at which to advance.
-         */distance: UInt16, 
-        /**
-         * The minimum required horizontal accuracy of the user location, in meters.
-         * Values larger than this cannot trigger a step advance.
-         */minimumHorizontalAccuracy: UInt16
-    )
+    case `break`
     /**
-     * Automatically advances when the user's distance to the *next* step's linestring  is less
-     * than the distance to the current step's linestring, subject to certain conditions.
+     * A waypoint that is simply passed through, but will not have any arrival or departure instructions.
      */
-    case relativeLineStringDistance(
-        /**
-         * The minimum required horizontal accuracy of the user location, in meters.
-         * Values larger than this cannot ever trigger a step advance.
-         */minimumHorizontalAccuracy: UInt16, 
-        /**
-         * Optional extra conditions which refine the step advance logic.
-         *
-         * See the enum variant documentation for details.
-         */specialAdvanceConditions: SpecialAdvanceConditions?
-    )
+    case via
 }
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeStepAdvanceMode: FfiConverterRustBuffer {
-    typealias SwiftType = StepAdvanceMode
+public struct FfiConverterTypeWaypointKind: FfiConverterRustBuffer {
+    typealias SwiftType = WaypointKind
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceMode {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointKind {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .manual
-        
-        case 2: return .distanceToEndOfStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
-        )
+        case 1: return .`break`
         
-        case 3: return .relativeLineStringDistance(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), specialAdvanceConditions: try FfiConverterOptionTypeSpecialAdvanceConditions.read(from: &buf)
-        )
+        case 2: return .via
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: StepAdvanceMode, into buf: inout [UInt8]) {
+    public static func write(_ value: WaypointKind, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .manual:
+        case .`break`:
             writeInt(&buf, Int32(1))
         
         
-        case let .distanceToEndOfStep(distance,minimumHorizontalAccuracy):
+        case .via:
             writeInt(&buf, Int32(2))
-            FfiConverterUInt16.write(distance, into: &buf)
-            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
-            
         
-        case let .relativeLineStringDistance(minimumHorizontalAccuracy,specialAdvanceConditions):
-            writeInt(&buf, Int32(3))
-            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
-            FfiConverterOptionTypeSpecialAdvanceConditions.write(specialAdvanceConditions, into: &buf)
-            
         }
     }
 }
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeStepAdvanceMode_lift(_ buf: RustBuffer) throws -> StepAdvanceMode {
-    return try FfiConverterTypeStepAdvanceMode.lift(buf)
-}
-
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointKind_lift(_ buf: RustBuffer) throws -> WaypointKind {
+    return try FfiConverterTypeWaypointKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointKind_lower(_ value: WaypointKind) -> RustBuffer {
+    return FfiConverterTypeWaypointKind.lower(value)
+}
+
+
+
+extension WaypointKind: Equatable, Hashable {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = UInt16?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt16.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt16.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStepAdvanceMode_lower(_ value: StepAdvanceMode) -> RustBuffer {
-    return FfiConverterTypeStepAdvanceMode.lower(value)
-}
-
-
-
-extension StepAdvanceMode: Equatable, Hashable {}
-
-
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * The state of a navigation session.
- *
- * This is produced by [`NavigationController`](super::NavigationController) methods
- * including [`get_initial_state`](super::NavigationController::get_initial_state)
- * and [`update_user_location`](super::NavigationController::update_user_location).
- */
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
 
-public enum TripState {
-    
-    /**
-     * The navigation controller is idle and there is no active trip.
-     */
-    case idle
-    /**
-     * The navigation controller is actively navigating a trip.
-     */
-    case navigating(
-        /**
-         * The index of the closest coordinate to the user's snapped location.
-         *
-         * This index is relative to the *current* [`RouteStep`]'s geometry.
-         */currentStepGeometryIndex: UInt64?, 
-        /**
-         * A location on the line string that
-         */snappedUserLocation: UserLocation, 
-        /**
-         * The ordered list of steps that remain in the trip.
-         *
-         * The step at the front of the list is always the current step.
-         * We currently assume that you cannot move backward to a previous step.
-         */remainingSteps: [RouteStep], 
-        /**
-         * Remaining waypoints to visit on the route.
-         *
-         * The waypoint at the front of the list is always the *next* waypoint "goal."
-         * Unlike the current step, there is no value in tracking the "current" waypoint,
-         * as the main use of waypoints is recalculation when the user deviates from the route.
-         * (In most use cases, a route will have only two waypoints, but more complex use cases
-         * may have multiple intervening points that are visited along the route.)
-         * This list is updated as the user advances through the route.
-         */remainingWaypoints: [Waypoint], 
-        /**
-         * The trip progress includes information that is useful for showing the
-         * user's progress along the full navigation trip, the route and its components.
-         */progress: TripProgress, 
-        /**
-         * The route deviation status: is the user following the route or not?
-         */deviation: RouteDeviation, 
-        /**
-         * The visual instruction that should be displayed in the user interface.
-         */visualInstruction: VisualInstruction?, 
-        /**
-         * The most recent spoken instruction that should be synthesized using TTS.
-         *
-         * Note it is the responsibility of the platform layer to ensure that utterances are not synthesized multiple times. This property simply reports the current spoken instruction.
-         */spokenInstruction: SpokenInstruction?, 
-        /**
-         * Annotation data at the current location.
-         * This is represented as a json formatted byte array to allow for flexible encoding of custom annotations.
-         */annotationJson: String?
-    )
-    /**
-     * The navigation controller has reached the end of the trip.
-     */
-    case complete
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTripState: FfiConverterRustBuffer {
-    typealias SwiftType = TripState
+fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
+    typealias SwiftType = BoundingBox?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripState {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .idle
-        
-        case 2: return .navigating(currentStepGeometryIndex: try FfiConverterOptionUInt64.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), progress: try FfiConverterTypeTripProgress.read(from: &buf), deviation: try FfiConverterTypeRouteDeviation.read(from: &buf), visualInstruction: try FfiConverterOptionTypeVisualInstruction.read(from: &buf), spokenInstruction: try FfiConverterOptionTypeSpokenInstruction.read(from: &buf), annotationJson: try FfiConverterOptionString.read(from: &buf)
-        )
-        
-        case 3: return .complete
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeBoundingBox.write(value, into: &buf)
     }
 
-    public static func write(_ value: TripState, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .idle:
-            writeInt(&buf, Int32(1))
-        
-        
-        case let .navigating(currentStepGeometryIndex,snappedUserLocation,remainingSteps,remainingWaypoints,progress,deviation,visualInstruction,spokenInstruction,annotationJson):
-            writeInt(&buf, Int32(2))
-            FfiConverterOptionUInt64.write(currentStepGeometryIndex, into: &buf)
-            FfiConverterTypeUserLocation.write(snappedUserLocation, into: &buf)
-            FfiConverterSequenceTypeRouteStep.write(remainingSteps, into: &buf)
-            FfiConverterSequenceTypeWaypoint.write(remainingWaypoints, into: &buf)
-            FfiConverterTypeTripProgress.write(progress, into: &buf)
-            FfiConverterTypeRouteDeviation.write(deviation, into: &buf)
-            FfiConverterOptionTypeVisualInstruction.write(visualInstruction, into: &buf)
-            FfiConverterOptionTypeSpokenInstruction.write(spokenInstruction, into: &buf)
-            FfiConverterOptionString.write(annotationJson, into: &buf)
-            
-        
-        case .complete:
-            writeInt(&buf, Int32(3))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeBoundingBox.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripState_lift(_ buf: RustBuffer) throws -> TripState {
-    return try FfiConverterTypeTripState.lift(buf)
+fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
+    typealias SwiftType = Congestion?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCongestion.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCongestion.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripState_lower(_ value: TripState) -> RustBuffer {
-    return FfiConverterTypeTripState.lower(value)
-}
-
-
-
-extension TripState: Equatable, Hashable {}
-
-
+fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffer {
+    typealias SwiftType = CourseOverGround?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Describes characteristics of the waypoint for the routing backend.
- */
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCourseOverGround.write(value, into: &buf)
+    }
 
-public enum WaypointKind {
-    
-    /**
-     * Starts or ends a leg of the trip.
-     *
-     * Most routing engines will generate arrival and departure instructions.
-     */
-    case `break`
-    /**
-     * A waypoint that is simply passed through, but will not have any arrival or departure instructions.
-     */
-    case via
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCourseOverGround.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWaypointKind: FfiConverterRustBuffer {
-    typealias SwiftType = WaypointKind
+fileprivate struct FfiConverterOptionTypeHeading: FfiConverterRustBuffer {
+    typealias SwiftType = Heading?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointKind {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .`break`
-        
-        case 2: return .via
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeHeading.write(value, into: &buf)
     }
 
-    public static func write(_ value: WaypointKind, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .`break`:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .via:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeHeading.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointKind_lift(_ buf: RustBuffer) throws -> WaypointKind {
-    return try FfiConverterTypeWaypointKind.lift(buf)
+fileprivate struct FfiConverterOptionTypeInstructionLanguage: FfiConverterRustBuffer {
+    typealias SwiftType = InstructionLanguage?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeInstructionLanguage.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeInstructionLanguage.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointKind_lower(_ value: WaypointKind) -> RustBuffer {
-    return FfiConverterTypeWaypointKind.lower(value)
-}
-
-
-
-extension WaypointKind: Equatable, Hashable {}
+fileprivate struct FfiConverterOptionTypeLocationEstimate: FfiConverterRustBuffer {
+    typealias SwiftType = LocationEstimate?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLocationEstimate.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLocationEstimate.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
-    typealias SwiftType = UInt16?
+fileprivate struct FfiConverterOptionTypeManeuver: FfiConverterRustBuffer {
+    typealias SwiftType = Maneuver?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5471,13 +10367,13 @@ fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt16.write(value, into: &buf)
+        FfiConverterTypeManeuver.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt16.read(from: &buf)
+        case 1: return try FfiConverterTypeManeuver.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5486,8 +10382,8 @@ fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
-    typealias SwiftType = UInt64?
+fileprivate struct FfiConverterOptionTypeSegmentAnnotation: FfiConverterRustBuffer {
+    typealias SwiftType = SegmentAnnotation?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5495,13 +10391,13 @@ fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt64.write(value, into: &buf)
+        FfiConverterTypeSegmentAnnotation.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt64.read(from: &buf)
+        case 1: return try FfiConverterTypeSegmentAnnotation.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5510,8 +10406,8 @@ fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
-    typealias SwiftType = Double?
+fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
+    typealias SwiftType = Speed?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5519,13 +10415,13 @@ fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterDouble.write(value, into: &buf)
+        FfiConverterTypeSpeed.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterDouble.read(from: &buf)
+        case 1: return try FfiConverterTypeSpeed.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5534,8 +10430,8 @@ fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
-    typealias SwiftType = Bool?
+fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuffer {
+    typealias SwiftType = SpokenInstruction?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5543,13 +10439,13 @@ fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterBool.write(value, into: &buf)
+        FfiConverterTypeSpokenInstruction.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterBool.read(from: &buf)
+        case 1: return try FfiConverterTypeSpokenInstruction.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5558,8 +10454,8 @@ fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterOptionTypeUpcomingEvents: FfiConverterRustBuffer {
+    typealias SwiftType = UpcomingEvents?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5567,13 +10463,13 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+        FfiConverterTypeUpcomingEvents.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
+        case 1: return try FfiConverterTypeUpcomingEvents.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5582,8 +10478,8 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
-    typealias SwiftType = BoundingBox?
+fileprivate struct FfiConverterOptionTypeVisualInstruction: FfiConverterRustBuffer {
+    typealias SwiftType = VisualInstruction?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5591,13 +10487,13 @@ fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeBoundingBox.write(value, into: &buf)
+        FfiConverterTypeVisualInstruction.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeBoundingBox.read(from: &buf)
+        case 1: return try FfiConverterTypeVisualInstruction.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5606,8 +10502,8 @@ fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
-    typealias SwiftType = Congestion?
+fileprivate struct FfiConverterOptionTypeVisualInstructionContent: FfiConverterRustBuffer {
+    typealias SwiftType = VisualInstructionContent?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5615,13 +10511,13 @@ fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeCongestion.write(value, into: &buf)
+        FfiConverterTypeVisualInstructionContent.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeCongestion.read(from: &buf)
+        case 1: return try FfiConverterTypeVisualInstructionContent.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5630,8 +10526,8 @@ fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffer {
-    typealias SwiftType = CourseOverGround?
+fileprivate struct FfiConverterOptionTypeCongestionLevel: FfiConverterRustBuffer {
+    typealias SwiftType = CongestionLevel?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5639,13 +10535,13 @@ fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffe
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeCourseOverGround.write(value, into: &buf)
+        FfiConverterTypeCongestionLevel.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeCourseOverGround.read(from: &buf)
+        case 1: return try FfiConverterTypeCongestionLevel.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5654,8 +10550,8 @@ fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffe
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
-    typealias SwiftType = Speed?
+fileprivate struct FfiConverterOptionTypeImpact: FfiConverterRustBuffer {
+    typealias SwiftType = Impact?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5663,13 +10559,13 @@ fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeSpeed.write(value, into: &buf)
+        FfiConverterTypeImpact.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeSpeed.read(from: &buf)
+        case 1: return try FfiConverterTypeImpact.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5678,8 +10574,8 @@ fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuffer {
-    typealias SwiftType = SpokenInstruction?
+fileprivate struct FfiConverterOptionTypeManeuverModifier: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverModifier?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5687,13 +10583,13 @@ fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuff
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeSpokenInstruction.write(value, into: &buf)
+        FfiConverterTypeManeuverModifier.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeSpokenInstruction.read(from: &buf)
+        case 1: return try FfiConverterTypeManeuverModifier.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5702,8 +10598,8 @@ fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuff
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeVisualInstruction: FfiConverterRustBuffer {
-    typealias SwiftType = VisualInstruction?
+fileprivate struct FfiConverterOptionTypeManeuverType: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverType?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5711,13 +10607,13 @@ fileprivate struct FfiConverterOptionTypeVisualInstruction: FfiConverterRustBuff
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeVisualInstruction.write(value, into: &buf)
+        FfiConverterTypeManeuverType.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeVisualInstruction.read(from: &buf)
+        case 1: return try FfiConverterTypeManeuverType.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5726,8 +10622,8 @@ fileprivate struct FfiConverterOptionTypeVisualInstruction: FfiConverterRustBuff
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeVisualInstructionContent: FfiConverterRustBuffer {
-    typealias SwiftType = VisualInstructionContent?
+fileprivate struct FfiConverterOptionTypeSpecialAdvanceConditions: FfiConverterRustBuffer {
+    typealias SwiftType = SpecialAdvanceConditions?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5735,13 +10631,13 @@ fileprivate struct FfiConverterOptionTypeVisualInstructionContent: FfiConverterR
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeVisualInstructionContent.write(value, into: &buf)
+        FfiConverterTypeSpecialAdvanceConditions.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeVisualInstructionContent.read(from: &buf)
+        case 1: return try FfiConverterTypeSpecialAdvanceConditions.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5750,8 +10646,8 @@ fileprivate struct FfiConverterOptionTypeVisualInstructionContent: FfiConverterR
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeImpact: FfiConverterRustBuffer {
-    typealias SwiftType = Impact?
+fileprivate struct FfiConverterOptionTypeSpeedLimit: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedLimit?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5759,13 +10655,13 @@ fileprivate struct FfiConverterOptionTypeImpact: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeImpact.write(value, into: &buf)
+        FfiConverterTypeSpeedLimit.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeImpact.read(from: &buf)
+        case 1: return try FfiConverterTypeSpeedLimit.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5774,8 +10670,8 @@ fileprivate struct FfiConverterOptionTypeImpact: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeManeuverModifier: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverModifier?
+fileprivate struct FfiConverterOptionSequenceTypeLaneInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [LaneInfo]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5783,13 +10679,13 @@ fileprivate struct FfiConverterOptionTypeManeuverModifier: FfiConverterRustBuffe
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeManeuverModifier.write(value, into: &buf)
+        FfiConverterSequenceTypeLaneInfo.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeManeuverModifier.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeLaneInfo.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5798,8 +10694,8 @@ fileprivate struct FfiConverterOptionTypeManeuverModifier: FfiConverterRustBuffe
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeManeuverType: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverType?
+fileprivate struct FfiConverterOptionSequenceTypeSegmentAnnotation: FfiConverterRustBuffer {
+    typealias SwiftType = [SegmentAnnotation]?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5807,13 +10703,13 @@ fileprivate struct FfiConverterOptionTypeManeuverType: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeManeuverType.write(value, into: &buf)
+        FfiConverterSequenceTypeSegmentAnnotation.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeManeuverType.read(from: &buf)
+        case 1: return try FfiConverterSequenceTypeSegmentAnnotation.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5822,8 +10718,8 @@ fileprivate struct FfiConverterOptionTypeManeuverType: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeSpecialAdvanceConditions: FfiConverterRustBuffer {
-    typealias SwiftType = SpecialAdvanceConditions?
+fileprivate struct FfiConverterOptionTypeUtcDateTime: FfiConverterRustBuffer {
+    typealias SwiftType = UtcDateTime?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -5831,13 +10727,13 @@ fileprivate struct FfiConverterOptionTypeSpecialAdvanceConditions: FfiConverterR
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeSpecialAdvanceConditions.write(value, into: &buf)
+        FfiConverterTypeUtcDateTime.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeSpecialAdvanceConditions.read(from: &buf)
+        case 1: return try FfiConverterTypeUtcDateTime.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -5846,95 +10742,248 @@ fileprivate struct FfiConverterOptionTypeSpecialAdvanceConditions: FfiConverterR
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]?
+fileprivate struct FfiConverterSequenceUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt16]
+
+    public static func write(_ value: [UInt16], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt16.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt16] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt16]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt16.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceBool: FfiConverterRustBuffer {
+    typealias SwiftType = [Bool]
+
+    public static func write(_ value: [Bool], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterBool.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Bool] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Bool]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterBool.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCongestionSpan: FfiConverterRustBuffer {
+    typealias SwiftType = [CongestionSpan]
+
+    public static func write(_ value: [CongestionSpan], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCongestionSpan.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CongestionSpan] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CongestionSpan]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCongestionSpan.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeGeographicCoordinate: FfiConverterRustBuffer {
+    typealias SwiftType = [GeographicCoordinate]
+
+    public static func write(_ value: [GeographicCoordinate], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeGeographicCoordinate.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [GeographicCoordinate] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [GeographicCoordinate]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeGeographicCoordinate.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeIncident: FfiConverterRustBuffer {
+    typealias SwiftType = [Incident]
+
+    public static func write(_ value: [Incident], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIncident.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Incident] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Incident]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIncident.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeIntersection: FfiConverterRustBuffer {
+    typealias SwiftType = [Intersection]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [Intersection], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIntersection.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterSequenceString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterSequenceString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Intersection] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Intersection]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIntersection.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionSequenceTypeLaneInfo: FfiConverterRustBuffer {
-    typealias SwiftType = [LaneInfo]?
+fileprivate struct FfiConverterSequenceTypeIntersectionLane: FfiConverterRustBuffer {
+    typealias SwiftType = [IntersectionLane]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [IntersectionLane], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIntersectionLane.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterSequenceTypeLaneInfo.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterSequenceTypeLaneInfo.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [IntersectionLane] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [IntersectionLane]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIntersectionLane.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeUtcDateTime: FfiConverterRustBuffer {
-    typealias SwiftType = UtcDateTime?
+fileprivate struct FfiConverterSequenceTypeLaneInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [LaneInfo]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [LaneInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLaneInfo.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterTypeUtcDateTime.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterTypeUtcDateTime.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LaneInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LaneInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLaneInfo.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
+fileprivate struct FfiConverterSequenceTypeLocationMatch: FfiConverterRustBuffer {
+    typealias SwiftType = [LocationMatch]
 
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
+    public static func write(_ value: [LocationMatch], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterString.write(item, into: &buf)
+            FfiConverterTypeLocationMatch.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LocationMatch] {
         let len: Int32 = try readInt(&buf)
-        var seq = [String]()
+        var seq = [LocationMatch]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
+            seq.append(try FfiConverterTypeLocationMatch.read(from: &buf))
         }
         return seq
     }
@@ -5943,23 +10992,23 @@ fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeGeographicCoordinate: FfiConverterRustBuffer {
-    typealias SwiftType = [GeographicCoordinate]
+fileprivate struct FfiConverterSequenceTypeMatchCandidate: FfiConverterRustBuffer {
+    typealias SwiftType = [MatchCandidate]
 
-    public static func write(_ value: [GeographicCoordinate], into buf: inout [UInt8]) {
+    public static func write(_ value: [MatchCandidate], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeGeographicCoordinate.write(item, into: &buf)
+            FfiConverterTypeMatchCandidate.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [GeographicCoordinate] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MatchCandidate] {
         let len: Int32 = try readInt(&buf)
-        var seq = [GeographicCoordinate]()
+        var seq = [MatchCandidate]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeGeographicCoordinate.read(from: &buf))
+            seq.append(try FfiConverterTypeMatchCandidate.read(from: &buf))
         }
         return seq
     }
@@ -5968,23 +11017,23 @@ fileprivate struct FfiConverterSequenceTypeGeographicCoordinate: FfiConverterRus
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeIncident: FfiConverterRustBuffer {
-    typealias SwiftType = [Incident]
+fileprivate struct FfiConverterSequenceTypeProgressSample: FfiConverterRustBuffer {
+    typealias SwiftType = [ProgressSample]
 
-    public static func write(_ value: [Incident], into buf: inout [UInt8]) {
+    public static func write(_ value: [ProgressSample], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeIncident.write(item, into: &buf)
+            FfiConverterTypeProgressSample.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Incident] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ProgressSample] {
         let len: Int32 = try readInt(&buf)
-        var seq = [Incident]()
+        var seq = [ProgressSample]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeIncident.read(from: &buf))
+            seq.append(try FfiConverterTypeProgressSample.read(from: &buf))
         }
         return seq
     }
@@ -5993,23 +11042,23 @@ fileprivate struct FfiConverterSequenceTypeIncident: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeLaneInfo: FfiConverterRustBuffer {
-    typealias SwiftType = [LaneInfo]
+fileprivate struct FfiConverterSequenceTypePronunciation: FfiConverterRustBuffer {
+    typealias SwiftType = [Pronunciation]
 
-    public static func write(_ value: [LaneInfo], into buf: inout [UInt8]) {
+    public static func write(_ value: [Pronunciation], into buf: inout [UInt8]) {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            FfiConverterTypeLaneInfo.write(item, into: &buf)
+            FfiConverterTypePronunciation.write(item, into: &buf)
         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LaneInfo] {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Pronunciation] {
         let len: Int32 = try readInt(&buf)
-        var seq = [LaneInfo]()
+        var seq = [Pronunciation]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeLaneInfo.read(from: &buf))
+            seq.append(try FfiConverterTypePronunciation.read(from: &buf))
         }
         return seq
     }
@@ -6040,6 +11089,31 @@ fileprivate struct FfiConverterSequenceTypeRoute: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRouteLeg: FfiConverterRustBuffer {
+    typealias SwiftType = [RouteLeg]
+
+    public static func write(_ value: [RouteLeg], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRouteLeg.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [RouteLeg] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [RouteLeg]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRouteLeg.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -6065,6 +11139,31 @@ fileprivate struct FfiConverterSequenceTypeRouteStep: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSegmentAnnotation: FfiConverterRustBuffer {
+    typealias SwiftType = [SegmentAnnotation]
+
+    public static func write(_ value: [SegmentAnnotation], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSegmentAnnotation.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SegmentAnnotation] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SegmentAnnotation]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSegmentAnnotation.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -6090,6 +11189,31 @@ fileprivate struct FfiConverterSequenceTypeSpokenInstruction: FfiConverterRustBu
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUpcomingIncident: FfiConverterRustBuffer {
+    typealias SwiftType = [UpcomingIncident]
+
+    public static func write(_ value: [UpcomingIncident], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUpcomingIncident.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UpcomingIncident] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UpcomingIncident]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUpcomingIncident.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -6165,6 +11289,56 @@ fileprivate struct FfiConverterSequenceTypeBlockedLane: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeNavigationEvent: FfiConverterRustBuffer {
+    typealias SwiftType = [NavigationEvent]
+
+    public static func write(_ value: [NavigationEvent], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNavigationEvent.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NavigationEvent] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NavigationEvent]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNavigationEvent.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUuid: FfiConverterRustBuffer {
+    typealias SwiftType = [Uuid]
+
+    public static func write(_ value: [Uuid], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUuid.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Uuid] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Uuid]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUuid.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -6314,6 +11488,39 @@ public func createFerrostarLogger() {try! rustCall() {
     )
 }
 }
+/**
+ * Creates a [`RouteRequestGenerator`]
+ * which generates requests to an arbitrary Valhalla server (using the OSRM response format),
+ * with instructions in the given language and units.
+ */
+public func createLocalizedValhallaRequestGenerator(endpointUrl: String, profile: String, optionsJson: String?, language: String, units: UnitSystem)throws  -> RouteRequestGenerator {
+    return try  FfiConverterTypeRouteRequestGenerator.lift(try rustCallWithError(FfiConverterTypeInstantiationError.lift) {
+    uniffi_ferrostar_fn_func_create_localized_valhalla_request_generator(
+        FfiConverterString.lower(endpointUrl),
+        FfiConverterString.lower(profile),
+        FfiConverterOptionString.lower(optionsJson),
+        FfiConverterString.lower(language),
+        FfiConverterTypeUnitSystem.lower(units),$0
+    )
+})
+}
+/**
+ * Creates a [`NavigationControllerConfig`] with the given step advance, route deviation tracking,
+ * and course filtering behavior, leaving all other behaviors at their defaults.
+ *
+ * Foreign code can use this rather than the record's constructor,
+ * so that it does not need to change whenever a new behavior is added.
+ * Set the remaining fields on the result to opt in to other behaviors.
+ */
+public func createNavigationControllerConfig(stepAdvance: StepAdvanceMode, routeDeviationTracking: RouteDeviationTracking, snappedLocationCourseFiltering: CourseFiltering) -> NavigationControllerConfig {
+    return try!  FfiConverterTypeNavigationControllerConfig.lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_create_navigation_controller_config(
+        FfiConverterTypeStepAdvanceMode.lower(stepAdvance),
+        FfiConverterTypeRouteDeviationTracking.lower(routeDeviationTracking),
+        FfiConverterTypeCourseFiltering.lower(snappedLocationCourseFiltering),$0
+    )
+})
+}
 /**
  * Creates a [`RouteResponseParser`] capable of parsing OSRM responses.
  *
@@ -6328,6 +11535,24 @@ public func createOsrmResponseParser(polylinePrecision: UInt32) -> RouteResponse
     )
 })
 }
+/**
+ * Creates a [`RouteResponseParser`] capable of parsing OSRM responses,
+ * which synthesizes any missing instructions in the given language and unit system.
+ *
+ * Phrases which are missing from the language fall back to English.
+ * If `generate_ssml` is set, SSML is generated for spoken instructions which don't include any
+ * (see [`OsrmResponseParser::with_ssml_generation`]).
+ */
+public func createOsrmResponseParserWithInstructionLanguage(polylinePrecision: UInt32, instructionLanguage: InstructionLanguage, units: UnitSystem, generateSsml: Bool = false) -> RouteResponseParser {
+    return try!  FfiConverterTypeRouteResponseParser.lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_create_osrm_response_parser_with_instruction_language(
+        FfiConverterUInt32.lower(polylinePrecision),
+        FfiConverterTypeInstructionLanguage.lower(instructionLanguage),
+        FfiConverterTypeUnitSystem.lower(units),
+        FfiConverterBool.lower(generateSsml),$0
+    )
+})
+}
 /**
  * Creates a [`Route`] from OSRM data.
  *
@@ -6359,6 +11584,39 @@ public func createValhallaRequestGenerator(endpointUrl: String, profile: String,
     )
 })
 }
+/**
+ * Formats a distance in the given unit system (ex: `150 m` or `500 feet`).
+ *
+ * The wording comes from the instruction language (English if `None`),
+ * so that units and decimal separators can be localized along with instructions.
+ */
+public func formatDistance(meters: Double, units: UnitSystem, style: DistanceStyle, instructionLanguage: InstructionLanguage?) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_format_distance(
+        FfiConverterDouble.lower(meters),
+        FfiConverterTypeUnitSystem.lower(units),
+        FfiConverterTypeDistanceStyle.lower(style),
+        FfiConverterOptionTypeInstructionLanguage.lower(instructionLanguage),$0
+    )
+})
+}
+/**
+ * Generates SSML for the plain text of an instruction.
+ *
+ * Hinted names get phonemes, route numbers are read as addresses,
+ * and exit numbers and other numbers are read as numbers.
+ * Hints are only applied to whole words,
+ * and the first matching hint wins where they overlap
+ * (pronunciations come first, then route numbers, then exit numbers).
+ */
+public func generateSsml(text: String, hints: SsmlHints) -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_generate_ssml(
+        FfiConverterString.lower(text),
+        FfiConverterTypeSsmlHints.lower(hints),$0
+    )
+})
+}
 /**
  * Helper function for getting the route as an encoded polyline.
  *
@@ -6415,6 +11673,22 @@ public func locationSimulationFromRoute(route: Route, resampleDistance: Double?,
     )
 })
 }
+/**
+ * Converts a distance to the most natural unit in a unit system,
+ * rounded to a precision which is meaningful to the user.
+ *
+ * Short distances use the smaller unit (meters, feet, or yards),
+ * rounded to 10 below 100 and to 50 above that.
+ * Longer distances use kilometers or miles, with one decimal place below 10.
+ */
+public func roundDistance(meters: Double, units: UnitSystem) -> RoundedDistance {
+    return try!  FfiConverterTypeRoundedDistance.lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_round_distance(
+        FfiConverterDouble.lower(meters),
+        FfiConverterTypeUnitSystem.lower(units),$0
+    )
+})
+}
 
 private enum InitializationResult {
     case ok
@@ -6437,15 +11711,30 @@ private var initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_func_create_ferrostar_logger() != 18551) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_create_localized_valhalla_request_generator() != 1027) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_func_create_navigation_controller_config() != 13493) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_func_create_osrm_response_parser() != 16550) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_create_osrm_response_parser_with_instruction_language() != 19549) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_func_create_route_from_osrm() != 42270) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ferrostar_checksum_func_create_valhalla_request_generator() != 16275) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_format_distance() != 51427) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_func_generate_ssml() != 21716) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_func_get_route_polyline() != 31480) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -6458,15 +11747,42 @@ private var initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_func_location_simulation_from_route() != 39027) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_round_distance() != 44215) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationcontroller_advance_to_next_step() != 3820) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ferrostar_checksum_method_navigationcontroller_get_initial_state() != 63862) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_get_rerouted_state() != 15304) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_predict_state_at() != 8250) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_resume_navigation() != 28505) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_upcoming_events() != 43970) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_update_heading() != 43748) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationcontroller_update_user_location() != 3165) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationcontroller_update_user_location_with_events() != 43027) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_reroutepolicy_get_initial_state() != 62072) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_reroutepolicy_update() != 26487) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_routeadapter_generate_request() != 59034) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -6485,9 +11801,15 @@ private var initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_constructor_navigationcontroller_new() != 60881) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_constructor_reroutepolicy_new() != 54406) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_constructor_routeadapter_new() != 32290) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_constructor_routeadapter_new_localized_valhalla_http() != 61837) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_constructor_routeadapter_new_valhalla_http() != 3524) {
         return InitializationResult.apiChecksumMismatch
     }
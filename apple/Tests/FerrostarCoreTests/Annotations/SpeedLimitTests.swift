@@ -0,0 +1,31 @@
+import FerrostarCoreFFI
+import XCTest
+@testable import FerrostarCore
+
+final class SpeedLimitTests: XCTestCase {
+    func testMilesPerHour() {
+        let speedLimit = SpeedLimit.limited(value: 15, unit: .milesPerHour).measurementValue
+        XCTAssertEqual(speedLimit?.value, 15.0)
+        XCTAssertEqual(speedLimit?.unit, .milesPerHour)
+    }
+
+    func testKilometersPerHour() {
+        let speedLimit = SpeedLimit.limited(value: 15, unit: .kilometersPerHour).measurementValue
+        XCTAssertEqual(speedLimit?.value, 15.0)
+        XCTAssertEqual(speedLimit?.unit, .kilometersPerHour)
+    }
+
+    func testKnots() {
+        let speedLimit = SpeedLimit.limited(value: 15, unit: .knots).measurementValue
+        XCTAssertEqual(speedLimit?.value, 15.0)
+        XCTAssertEqual(speedLimit?.unit, .knots)
+    }
+
+    func testNoLimit() {
+        XCTAssertEqual(SpeedLimit.noLimit.measurementValue?.value, .infinity)
+    }
+
+    func testUnknown() {
+        XCTAssertNil(SpeedLimit.unknown.measurementValue)
+    }
+}
@@ -1,19 +1,25 @@
 //! Common spatial algorithms which are useful for navigation.
 
 use crate::{
-    models::CourseOverGround,
+    models::{CongestionLevel, GeographicCoordinate, RouteStep, UserLocation},
     navigation_controller::models::{
-        StepAdvanceMode,
-        StepAdvanceStatus::{self, Advanced, EndOfRoute},
+        CongestionSpan, ProgressSample, SpecialAdvanceConditions, TripProgress, UpcomingEvents,
+        UpcomingIncident,
     },
 };
 use crate::{
-    models::{GeographicCoordinate, RouteStep, UserLocation},
-    navigation_controller::models::{SpecialAdvanceConditions, TripProgress},
+    models::{CourseOverGround, Heading},
+    navigation_controller::geometry_index::StepGeometry,
+    navigation_controller::models::{
+        InstructionTriggerMode, StepAdvanceMode,
+        StepAdvanceStatus::{self, Advanced, EndOfRoute},
+    },
 };
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
 use geo::{
     Bearing, Closest, Coord, Distance, Euclidean, Geodesic, Haversine, HaversineClosestPoint,
-    Length, LineLocatePoint, LineString, Point,
+    Length, Line, LineLocatePoint, LineString, Point,
 };
 
 #[cfg(test)]
@@ -99,6 +105,87 @@ pub fn apply_snapped_course(
     }
 }
 
+/// Fuses the device's compass heading with the course over ground from the location provider.
+///
+/// Course over ground is derived from the user's movement,
+/// so it is unreliable at low speeds (and meaningless when stationary),
+/// whereas the compass doesn't depend on speed.
+/// The weight of the course rises linearly from zero at `heading_only_speed`
+/// to one at `course_only_speed` (an unknown speed counts as stationary).
+/// When the course accuracy is known, each weight is further divided by the variance of its source.
+///
+/// The result has the accuracy of whichever source has the greater weight.
+pub(crate) fn fuse_heading(
+    course_over_ground: Option<CourseOverGround>,
+    speed: Option<f64>,
+    heading: Option<&Heading>,
+    heading_only_speed: f64,
+    course_only_speed: f64,
+) -> Option<CourseOverGround> {
+    let Some(heading) = heading else {
+        return course_over_ground;
+    };
+    let heading_course = CourseOverGround {
+        degrees: heading.true_heading % 360,
+        accuracy: Some(heading.accuracy),
+    };
+    let Some(course) = course_over_ground else {
+        return Some(heading_course);
+    };
+
+    let speed = speed.unwrap_or(0.0);
+    let course_weight = if speed >= course_only_speed {
+        1.0
+    } else if speed <= heading_only_speed {
+        0.0
+    } else {
+        (speed - heading_only_speed) / (course_only_speed - heading_only_speed)
+    };
+    let (course_weight, heading_weight) = match course.accuracy {
+        Some(course_accuracy) => {
+            let variance = |accuracy: u16| f64::from(accuracy.max(1)).powi(2);
+            (
+                course_weight / variance(course_accuracy),
+                (1.0 - course_weight) / variance(heading.accuracy),
+            )
+        }
+        None => (course_weight, 1.0 - course_weight),
+    };
+
+    let degrees = mean_bearing([
+        (f64::from(course.degrees), course_weight),
+        (f64::from(heading_course.degrees), heading_weight),
+    ])
+    .unwrap_or(f64::from(course.degrees));
+    let accuracy = if course_weight >= heading_weight {
+        course.accuracy
+    } else {
+        heading_course.accuracy
+    };
+    Some(CourseOverGround::new(degrees.round() % 360.0, accuracy))
+}
+
+/// Averages bearings (in degrees) with the given weights.
+///
+/// Bearings are averaged as vectors, so that (ex) 350 and 10 average to 0 rather than 180.
+/// The result is in the range [0, 360).
+/// Returns [`None`] if there is no weight, or the bearings cancel each other out.
+pub(crate) fn mean_bearing(weighted_bearings: impl IntoIterator<Item = (f64, f64)>) -> Option<f64> {
+    let (east, north, total_weight) = weighted_bearings.into_iter().fold(
+        (0.0, 0.0, 0.0),
+        |(east, north, total_weight), (bearing, weight): (f64, f64)| {
+            let radians = bearing.to_radians();
+            (
+                east + weight * radians.sin(),
+                north + weight * radians.cos(),
+                total_weight + weight,
+            )
+        },
+    );
+    (east.hypot(north) > 1e-9 * total_weight)
+        .then(|| east.atan2(north).to_degrees().rem_euclid(360.0))
+}
+
 /// Snaps a user location to the closest point on a route line.
 ///
 /// If the location cannot be snapped (should only be possible with an invalid coordinate or geometry),
@@ -225,7 +312,7 @@ fn is_within_threshold_to_end_of_linestring(
     current_step_linestring
         .coords()
         .last()
-        .map_or(false, |end_coord| {
+        .is_some_and(|end_coord| {
             let end_point = Point::from(*end_coord);
             let distance_to_end = Haversine::distance(end_point, *current_position);
 
@@ -242,6 +329,24 @@ pub fn should_advance_to_next_step(
     next_route_step: Option<&RouteStep>,
     user_location: &UserLocation,
     step_advance_mode: StepAdvanceMode,
+) -> bool {
+    let next_step_linestring = next_route_step.map(RouteStep::get_linestring);
+    should_advance_to_next_step_with_geometry(
+        current_step_linestring,
+        next_route_step.zip(next_step_linestring.as_ref()),
+        user_location,
+        step_advance_mode,
+    )
+}
+
+/// Same as [`should_advance_to_next_step`],
+/// but takes the linestring of the next step along with the step itself
+/// so that callers with precomputed geometry don't need to rebuild it.
+pub(crate) fn should_advance_to_next_step_with_geometry(
+    current_step_linestring: &LineString,
+    next_route_step: Option<(&RouteStep, &LineString)>,
+    user_location: &UserLocation,
+    step_advance_mode: StepAdvanceMode,
 ) -> bool {
     let current_position = Point::from(user_location.coordinates);
 
@@ -291,12 +396,12 @@ pub fn should_advance_to_next_step(
                             // necessarily near the intersection.
                             //
                             // The last step is special and this logic does not apply.
-                            if let Some(next_step) = next_route_step {
+                            if let Some((next_step, _)) = next_route_step {
                                 // Note this special next_step distance check; otherwise we get stuck at the end!
                                 if next_step.distance > f64::from(distance)
                                     && deviation_from_line(
                                         &current_position,
-                                        &current_step_linestring,
+                                        current_step_linestring,
                                     )
                                     .map_or(true, |deviation| deviation <= f64::from(distance))
                                 {
@@ -307,15 +412,12 @@ pub fn should_advance_to_next_step(
                     }
                 }
 
-                if let Some(next_step) = next_route_step {
-                    // FIXME: This isn't very efficient to keep doing at the moment
-                    let next_step_linestring = next_step.get_linestring();
-
+                if let Some((_, next_step_linestring)) = next_route_step {
                     // Try to snap the user's current location to the current step
                     // and next step geometries
                     if let (Some(current_step_closest_point), Some(next_step_closest_point)) = (
                         snap_point_to_line(&current_position, current_step_linestring),
-                        snap_point_to_line(&current_position, &next_step_linestring),
+                        snap_point_to_line(&current_position, next_step_linestring),
                     ) {
                         // If the user's distance to the snapped location on the *next* step is <=
                         // the user's distance to the snapped location on the *current* step,
@@ -326,7 +428,7 @@ pub fn should_advance_to_next_step(
                         // The user's location couldn't be mapped to a single point on both the current and next step.
                         // Fall back to the distance to end of step mode, which has some graceful fallbacks.
                         // In real-world use, this should only happen for values which are EXTREMELY close together.
-                        should_advance_to_next_step(
+                        should_advance_to_next_step_with_geometry(
                             current_step_linestring,
                             None,
                             user_location,
@@ -338,7 +440,7 @@ pub fn should_advance_to_next_step(
                     }
                 } else {
                     // Trigger arrival when the user gets within a circle of the minimum horizontal accuracy
-                    should_advance_to_next_step(
+                    should_advance_to_next_step_with_geometry(
                         current_step_linestring,
                         None,
                         user_location,
@@ -353,6 +455,39 @@ pub fn should_advance_to_next_step(
     }
 }
 
+/// Determines whether an instruction with the given trigger distance should be triggered,
+/// according to the [`InstructionTriggerMode`].
+///
+/// `speed` is the user's current speed, and `expected_speed` is the average speed along the current step
+/// (according to the route), both in meters per second.
+pub(crate) fn is_instruction_triggered(
+    trigger_distance_before_maneuver: f64,
+    distance_to_end_of_step: f64,
+    speed: Option<f64>,
+    expected_speed: Option<f64>,
+    mode: InstructionTriggerMode,
+) -> bool {
+    match mode {
+        InstructionTriggerMode::DistanceBeforeManeuver { tolerance } => {
+            distance_to_end_of_step - trigger_distance_before_maneuver <= tolerance
+        }
+        InstructionTriggerMode::TimeBeforeManeuver {
+            tolerance,
+            minimum_speed,
+        } => {
+            let expected_speed = expected_speed.filter(|expected_speed| *expected_speed > 0.0);
+            let trigger_distance = match (speed, expected_speed) {
+                // Scale the trigger distance so that it corresponds to the same time before the maneuver
+                (Some(speed), Some(expected_speed)) => {
+                    trigger_distance_before_maneuver * speed.max(minimum_speed) / expected_speed
+                }
+                _ => trigger_distance_before_maneuver,
+            };
+            distance_to_end_of_step - trigger_distance <= tolerance
+        }
+    }
+}
+
 /// Runs a state machine transformation to advance one step.
 ///
 /// Note that this function is pure and the caller must persist any mutations
@@ -364,66 +499,125 @@ pub(crate) fn advance_step(remaining_steps: &[RouteStep]) -> StepAdvanceStatus {
     match remaining_steps.get(1) {
         Some(new_step) => Advanced {
             step: new_step.clone(),
-            linestring: new_step.get_linestring(),
         },
         None => EndOfRoute,
     }
 }
 
-/// Computes the distance that a point lies along a linestring,
+/// The position of a point along a linestring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LinePosition {
+    /// The index of the segment which the point lies on.
+    pub(crate) segment_index: usize,
+    /// How far along the segment the point lies, from 0 (the start) to 1 (the end).
+    pub(crate) segment_fraction: f64,
+    /// The distance along the linestring, in meters.
+    pub(crate) distance_along: f64,
+}
+
+/// Locates a point along a linestring,
 /// assuming that units are latitude and longitude for the geometries.
 ///
-/// The result is given in meters.
 /// The result may be [`None`] in case of invalid input such as infinite floats.
-fn distance_along(point: &Point, linestring: &LineString) -> Option<f64> {
-    let total_length = linestring.length::<Haversine>();
-    if total_length == 0.0 {
-        return Some(0.0);
+fn locate_along(point: &Point, linestring: &LineString) -> Option<LinePosition> {
+    locate_along_with_coordinate_distances(
+        point,
+        linestring,
+        // Convert to a LineString so we get haversine ops
+        &coordinate_distances(
+            linestring
+                .lines()
+                .map(|segment| LineString::from(segment).length::<Haversine>()),
+        ),
+        None,
+    )
+}
+
+/// The distance (in meters) along a line to each of its coordinates,
+/// given the length of each segment.
+pub(crate) fn coordinate_distances(segment_lengths: impl IntoIterator<Item = f64>) -> Vec<f64> {
+    core::iter::once(0.0)
+        .chain(segment_lengths.into_iter().scan(0.0, |distance, length| {
+            *distance += length;
+            Some(*distance)
+        }))
+        .collect()
+}
+
+/// Locates a point along a linestring,
+/// given the distance along the linestring (in meters) to each of its coordinates
+/// (see [`coordinate_distances`]).
+///
+/// When the index of the segment which the point lies on is already known
+/// (ex: the current step geometry index), only that segment is considered.
+/// Otherwise, every segment is searched for the one closest to the point.
+pub(crate) fn locate_along_with_coordinate_distances(
+    point: &Point,
+    linestring: &LineString,
+    coordinate_distances: &[f64],
+    segment_index: Option<usize>,
+) -> Option<LinePosition> {
+    let start = LinePosition {
+        segment_index: 0,
+        segment_fraction: 0.0,
+        distance_along: 0.0,
+    };
+    if coordinate_distances
+        .last()
+        .map_or(true, |length| *length == 0.0)
+    {
+        return Some(start);
     }
 
-    let (_, _, traversed) = linestring.lines().try_fold(
-        (0f64, f64::INFINITY, 0f64),
-        |(cum_length, closest_dist_to_point, traversed), segment| {
-            // Convert to a LineString so we get haversine ops
-            let segment_linestring = LineString::from(segment);
+    let position_on_segment = |index: usize, segment: Line| {
+        let segment_fraction = segment.line_locate_point(point)?;
+        let segment_start = coordinate_distances.get(index)?;
+        let segment_length = coordinate_distances.get(index + 1)? - segment_start;
+        Some(LinePosition {
+            segment_index: index,
+            segment_fraction,
+            distance_along: segment_start + segment_fraction * segment_length,
+        })
+    };
 
+    if let Some(index) = segment_index {
+        if let Some(segment) = linestring.lines().nth(index) {
+            return position_on_segment(index, segment);
+        }
+    }
+
+    let (_, position) = linestring.lines().enumerate().try_fold(
+        (f64::INFINITY, start),
+        |(closest_dist_to_point, position), (index, segment)| {
             // Compute distance to the line (sadly Euclidean only; no haversine_distance in GeoRust
             // but this is probably OK for now)
             let segment_distance_to_point = Euclidean::distance(&segment, point);
-            // Compute total segment length in meters
-            let segment_length = segment_linestring.length::<Haversine>();
 
             if segment_distance_to_point < closest_dist_to_point {
-                let segment_fraction = segment.line_locate_point(point)?;
                 Some((
-                    cum_length + segment_length,
                     segment_distance_to_point,
-                    cum_length + segment_fraction * segment_length,
+                    position_on_segment(index, segment)?,
                 ))
             } else {
-                Some((
-                    cum_length + segment_length,
-                    closest_dist_to_point,
-                    traversed,
-                ))
+                Some((closest_dist_to_point, position))
             }
         },
     )?;
-    Some(traversed)
+    Some(position)
 }
 
-/// Computes the distance between a location and the end of the current route step.
-/// We assume that input location is pre-snapped to route step's linestring,
-/// and that travel is proceeding along the route (not straight line distance).
+/// Computes the travel time (in seconds) from a position along a route step to the end of the step,
+/// given the travel time of each segment of the step
+/// (ex: from [`RouteStep::get_annotated_segment_durations`]).
 ///
-/// The result may be [`None`] in case of invalid input such as infinite floats.
-fn travel_distance_to_end_of_step(
-    snapped_location: &Point,
-    current_step_linestring: &LineString,
+/// Returns [`None`] if the position is not on one of the segments.
+pub(crate) fn duration_to_end_of_step(
+    position: &LinePosition,
+    segment_durations: &[f64],
 ) -> Option<f64> {
-    let step_length = current_step_linestring.length::<Haversine>();
-    distance_along(snapped_location, current_step_linestring)
-        .map(|traversed| step_length - traversed)
+    let current_segment_duration = segment_durations.get(position.segment_index)?;
+    let later_segments_duration: f64 = segment_durations[position.segment_index + 1..].iter().sum();
+    Some((1.0 - position.segment_fraction) * current_segment_duration + later_segments_duration)
 }
 
 /// Computes the user's progress along the current trip (distance to destination, ETA, etc.).
@@ -434,57 +628,283 @@ pub fn calculate_trip_progress(
     current_step_linestring: &LineString,
     remaining_steps: &[RouteStep],
 ) -> TripProgress {
-    let Some(current_step) = remaining_steps.first() else {
+    let current_step = remaining_steps.first();
+    let steps_after_current = remaining_steps.get(1..).unwrap_or_default();
+
+    // We assume that the location is pre-snapped to the current step's linestring,
+    // and that travel is proceeding along the route (not straight line distance).
+    let position = locate_along(snapped_location, current_step_linestring);
+    let step_length = current_step_linestring.length::<Haversine>();
+    let duration_to_end = position.zip(current_step).and_then(|(position, step)| {
+        duration_to_end_of_step(&position, &step.get_annotated_segment_durations()?)
+    });
+
+    trip_progress_from_step_distance(
+        current_step,
+        position.map(|position| step_length - position.distance_along),
+        duration_to_end,
+        StepTotals::sum(steps_after_current),
+        StepTotals::sum(steps_after_current.iter().take_while(|step| {
+            current_step.is_some_and(|current| current.leg_index == step.leg_index)
+        })),
+    )
+}
+
+/// The total distance and duration of a sequence of route steps.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct StepTotals {
+    /// The total distance, in meters.
+    pub(crate) distance: f64,
+    /// The total duration, in seconds.
+    pub(crate) duration: f64,
+}
+
+impl StepTotals {
+    pub(crate) fn sum<'a>(steps: impl IntoIterator<Item = &'a RouteStep>) -> Self {
+        steps
+            .into_iter()
+            .fold(Self::default(), |totals, step| Self {
+                distance: totals.distance + step.distance,
+                duration: totals.duration + step.duration,
+            })
+    }
+}
+
+/// Computes the user's progress along the current trip
+/// given the travel distance and time to the end of the current step
+/// and the totals for the steps *after* the current one
+/// (both for the rest of the trip, and for the rest of the current leg).
+///
+/// If the travel distance to the end of the current step is not known,
+/// it is assumed that the user is at the start of the step.
+/// If the travel time is not known (ex: the route has no duration annotations),
+/// it is estimated from the fraction of the step's distance remaining.
+pub(crate) fn trip_progress_from_step_distance(
+    current_step: Option<&RouteStep>,
+    distance_to_end_of_step: Option<f64>,
+    duration_to_end_of_step: Option<f64>,
+    after_current_step: StepTotals,
+    after_current_step_in_leg: StepTotals,
+) -> TripProgress {
+    let Some(current_step) = current_step else {
         return TripProgress {
             distance_to_next_maneuver: 0.0,
             distance_remaining: 0.0,
             duration_remaining: 0.0,
+            adjusted_duration_remaining: 0.0,
+            distance_to_next_waypoint: 0.0,
+            duration_to_next_waypoint: 0.0,
         };
     };
 
     // Calculate the distance and duration till the end of the current route step.
-    let distance_to_next_maneuver =
-        travel_distance_to_end_of_step(snapped_location, current_step_linestring)
-            .unwrap_or(current_step.distance);
-
-    // This could be improved with live traffic data along the route.
-    // TODO: Figure out the best way to enable this use case
-    let pct_remaining_current_step = if current_step.distance > 0f64 {
-        distance_to_next_maneuver / current_step.distance
-    } else {
-        0f64
+    let distance_to_next_maneuver = distance_to_end_of_step.unwrap_or(current_step.distance);
+
+    let duration_to_next_maneuver = duration_to_end_of_step.unwrap_or_else(|| {
+        let pct_remaining_current_step = if current_step.distance > 0f64 {
+            distance_to_next_maneuver / current_step.distance
+        } else {
+            0f64
+        };
+
+        // Get the percentage of duration remaining in the current step.
+        pct_remaining_current_step * current_step.duration
+    });
+
+    let duration_remaining = duration_to_next_maneuver + after_current_step.duration;
+
+    TripProgress {
+        distance_to_next_maneuver,
+        distance_remaining: distance_to_next_maneuver + after_current_step.distance,
+        duration_remaining,
+        adjusted_duration_remaining: duration_remaining,
+        distance_to_next_waypoint: distance_to_next_maneuver + after_current_step_in_leg.distance,
+        duration_to_next_waypoint: duration_to_next_maneuver + after_current_step_in_leg.duration,
+    }
+}
+
+/// Blends the expected remaining duration of a trip with the user's observed rate of progress.
+///
+/// The rate of progress is the expected travel time covered per second of elapsed time
+/// between the oldest and newest `samples`.
+/// The blended rate is a weighted average of the observed rate and the expected rate (one),
+/// and the result is the remaining duration at the blended rate.
+///
+/// If the samples do not span any time, the expected duration is returned unchanged.
+pub(crate) fn blend_duration_remaining(
+    duration_remaining: f64,
+    samples: &[ProgressSample],
+    observed_weight: f64,
+) -> f64 {
+    let (Some(oldest), Some(newest)) = (samples.first(), samples.last()) else {
+        return duration_remaining;
     };
+    let elapsed_time = newest.elapsed_time - oldest.elapsed_time;
+    if elapsed_time <= 0.0 {
+        return duration_remaining;
+    }
 
-    // Get the percentage of duration remaining in the current step.
-    let duration_to_next_maneuver = pct_remaining_current_step * current_step.duration;
+    // Moving backwards along the route (ex: a U-turn) counts as no progress
+    let observed_rate =
+        ((oldest.duration_remaining - newest.duration_remaining) / elapsed_time).max(0.0);
+    let observed_weight = observed_weight.clamp(0.0, 1.0);
+    let blended_rate = (1.0 - observed_weight) + observed_weight * observed_rate;
 
-    // Exit early if there is only the current step:
-    if remaining_steps.len() == 1 {
-        return TripProgress {
-            distance_to_next_maneuver,
-            distance_remaining: distance_to_next_maneuver,
-            duration_remaining: duration_to_next_maneuver,
-        };
+    if blended_rate > 0.0 {
+        duration_remaining / blended_rate
+    } else {
+        duration_remaining
     }
+}
 
-    let steps_after_current = &remaining_steps[1..];
-    let distance_remaining = distance_to_next_maneuver
-        + steps_after_current
+/// Finds the nearest incidents and spans of traffic congestion on the route ahead of the user.
+///
+/// Distances are measured along the route from the snapped location,
+/// which should already be snapped to the current step (the first of the `remaining_steps`).
+/// Incidents come from [`RouteStep::incidents`],
+/// and congestion comes from the [`CongestionLevel`] of each segment's annotation.
+/// Adjacent segments with the same level of congestion (including across steps) form a single span.
+/// Only [`CongestionLevel::Moderate`] or worse is reported.
+///
+/// At most `max_count` incidents and `max_count` congestion spans are returned,
+/// and events which start more than `max_distance` meters ahead are ignored.
+/// The search stops as soon as either limit is reached,
+/// so only the part of the route ahead which is needed is visited.
+pub fn find_upcoming_events(
+    snapped_location: &Point,
+    remaining_steps: &[RouteStep],
+    max_count: usize,
+    max_distance: f64,
+) -> UpcomingEvents {
+    find_upcoming_events_with_geometry(
+        snapped_location,
+        None,
+        remaining_steps
             .iter()
-            .map(|step| step.distance)
-            .sum::<f64>();
+            .map(|step| (step, Cow::Owned(StepGeometry::new(step)))),
+        max_count,
+        max_distance,
+    )
+}
 
-    let duration_remaining = duration_to_next_maneuver
-        + steps_after_current
+/// Like [`find_upcoming_events`], but with the (lazily computed or precomputed) geometry of each step,
+/// and the index of the segment of the current step which the location is snapped to (if known).
+pub(crate) fn find_upcoming_events_with_geometry<'a>(
+    snapped_location: &Point,
+    current_step_geometry_index: Option<u64>,
+    remaining_steps: impl IntoIterator<Item = (&'a RouteStep, Cow<'a, StepGeometry>)>,
+    max_count: usize,
+    max_distance: f64,
+) -> UpcomingEvents {
+    let mut events = UpcomingEvents::default();
+    let mut remaining_steps = remaining_steps.into_iter().peekable();
+    if max_count == 0 {
+        return events;
+    }
+    let Some((_, current_step_geometry)) = remaining_steps.peek() else {
+        return events;
+    };
+
+    // The distance from the user to the start of each step (negative for the current step)
+    let mut step_offset = current_step_geometry
+        .travel_to_end(snapped_location, current_step_geometry_index)
+        .0
+        .map_or(0.0, |distance_to_end| {
+            distance_to_end - current_step_geometry.length
+        });
+    let mut open_span: Option<CongestionSpan> = None;
+
+    for (step, geometry) in remaining_steps {
+        // The distance from the user to each coordinate of the step
+        let coordinate_offsets: Vec<f64> = core::iter::once(step_offset)
+            .chain(
+                geometry
+                    .segment_lengths
+                    .iter()
+                    .scan(step_offset, |offset, length| {
+                        *offset += length;
+                        Some(*offset)
+                    }),
+            )
+            .collect();
+        let offset_at = |index: u64| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| coordinate_offsets.get(index))
+                .or(coordinate_offsets.last())
+                .copied()
+                .unwrap_or(step_offset)
+        };
+
+        let mut step_incidents: Vec<UpcomingIncident> = step
+            .incidents
             .iter()
-            .map(|step| step.duration)
-            .sum::<f64>();
+            .filter(|incident| {
+                !events
+                    .incidents
+                    .iter()
+                    .any(|upcoming| upcoming.incident.id == incident.id)
+            })
+            .filter_map(|incident| {
+                let start = offset_at(incident.geometry_index_start);
+                let end = incident.geometry_index_end.map_or(start, offset_at);
+                (end >= 0.0 && start <= max_distance).then(|| UpcomingIncident {
+                    incident: incident.clone(),
+                    distance_to_start: start.max(0.0),
+                    distance_to_end: end,
+                })
+            })
+            .collect();
+        step_incidents.sort_by(|a, b| a.distance_to_start.total_cmp(&b.distance_to_start));
+        events.incidents.extend(step_incidents);
+
+        let annotations = step.annotations.as_deref().unwrap_or_default();
+        for (index, segment) in coordinate_offsets.windows(2).enumerate() {
+            let (start, end) = (segment[0], segment[1]);
+            if start > max_distance {
+                break;
+            }
+            let level = annotations
+                .get(index)
+                .and_then(|annotation| annotation.congestion)
+                .filter(|level| {
+                    matches!(
+                        level,
+                        CongestionLevel::Moderate
+                            | CongestionLevel::Heavy
+                            | CongestionLevel::Severe
+                    )
+                });
 
-    TripProgress {
-        distance_to_next_maneuver,
-        distance_remaining,
-        duration_remaining,
+            match (&mut open_span, level) {
+                // Segments behind the user are never part of a span
+                _ if end <= 0.0 => {}
+                (Some(span), Some(level)) if span.level == level => {
+                    span.length = end - span.distance_to_start;
+                }
+                (_, level) => {
+                    events.congestion.extend(open_span.take());
+                    open_span = level.map(|level| CongestionSpan {
+                        level,
+                        distance_to_start: start.max(0.0),
+                        length: end - start.max(0.0),
+                    });
+                }
+            }
+        }
+
+        step_offset = coordinate_offsets.last().copied().unwrap_or(step_offset);
+        if step_offset > max_distance
+            || (events.incidents.len() >= max_count && events.congestion.len() >= max_count)
+        {
+            break;
+        }
     }
+
+    events.congestion.extend(open_span);
+    events.incidents.truncate(max_count);
+    events.congestion.truncate(max_count);
+    events
 }
 
 /// Convert a vector of geographic coordinates to a [`LineString`].
@@ -503,7 +923,7 @@ pub(crate) fn get_linestring(geometry: &[GeographicCoordinate]) -> LineString {
 /// with all other values set to defaults or (in the case of the timestamp), the current time.
 fn make_user_location(lng: f64, lat: f64) -> UserLocation {
     UserLocation {
-        coordinates: GeographicCoordinate { lng, lat },
+        coordinates: GeographicCoordinate { lat, lng },
         horizontal_accuracy: 0.0,
         course_over_ground: None,
         timestamp: SystemTime::now(),
@@ -521,6 +941,7 @@ prop_compose! {
 #[cfg(test)]
 proptest! {
     #[test]
+    #[allow(clippy::float_cmp)]
     fn snap_point_to_line_intersection(
         x1: f64, y1: f64,
         x2: f64, y2: f64,
@@ -547,10 +968,10 @@ proptest! {
             prop_assert!(is_valid_float(x) || (!is_valid_float(x1) && x == x1));
             prop_assert!(is_valid_float(y) || (!is_valid_float(y1) && y == y1));
 
-            prop_assert!(Euclidean::distance(&line, &snapped) < 0.000001);
+            prop_assert!(Euclidean::distance(&line, &snapped) < 0.000_001);
         } else {
             // Edge case 1: extremely small differences in values
-            let is_miniscule_difference = (x1 - x2).abs() < 0.00000001 || (y1 - y2).abs() < 0.00000001;
+            let is_miniscule_difference = (x1 - x2).abs() < 0.000_000_01 || (y1 - y2).abs() < 0.000_000_01;
             // Edge case 2: Values which are clearly not WGS84 ;)
             let is_non_wgs84 = (x1 - x2).abs() > 180.0 || (y1 - y2).abs() > 90.0;
             prop_assert!(is_miniscule_difference || is_non_wgs84);
@@ -558,6 +979,7 @@ proptest! {
     }
 
     #[test]
+    #[allow(clippy::float_cmp, clippy::nonminimal_bool)]
     fn should_advance_exact_position(
         x1: f64, y1: f64,
         x2: f64, y2: f64,
@@ -585,7 +1007,7 @@ proptest! {
             };
 
             let inaccurate_user_location = UserLocation {
-                horizontal_accuracy: (minimum_horizontal_accuracy as f64) + excess_inaccuracy,
+                horizontal_accuracy: f64::from(minimum_horizontal_accuracy) + excess_inaccuracy,
                 ..exact_user_location
             };
 
@@ -602,7 +1024,7 @@ proptest! {
             // Same when looking at the relative distances between the two step geometries
             let cond = should_advance_to_next_step(&current_route_step.get_linestring(), next_route_step.as_ref(), &exact_user_location, StepAdvanceMode::RelativeLineStringDistance {
                 minimum_horizontal_accuracy,
-                special_advance_conditions: threshold.map(|distance| SpecialAdvanceConditions::AdvanceAtDistanceFromEnd(distance))
+                special_advance_conditions: threshold.map(SpecialAdvanceConditions::AdvanceAtDistanceFromEnd)
             });
             prop_assert!(cond);
 
@@ -612,7 +1034,7 @@ proptest! {
             }), excess_inaccuracy == 0.0, "Expected that the navigation would not advance to the next step except when excess_inaccuracy is 0");
             prop_assert_eq!(should_advance_to_next_step(&current_route_step.get_linestring(), next_route_step.as_ref(), &inaccurate_user_location, StepAdvanceMode::RelativeLineStringDistance {
                 minimum_horizontal_accuracy,
-                special_advance_conditions: threshold.map(|distance| SpecialAdvanceConditions::AdvanceAtDistanceFromEnd(distance))
+                special_advance_conditions: threshold.map(SpecialAdvanceConditions::AdvanceAtDistanceFromEnd)
             }), excess_inaccuracy == 0.0, "Expected that the navigation would not advance to the next step except when excess_inaccuracy is 0");
         }
     }
@@ -657,24 +1079,25 @@ proptest! {
         }), distance_from_end_of_current_step <= distance.into(), "Expected that the step should advance in this case as we are closer to the end of the step than the threshold.");
 
         // Similar test for automatic advance on the relative line string distance mode
-        if automatic_advance_distance.map_or(false, |advance_distance| {
+        if automatic_advance_distance.is_some_and(|advance_distance| {
             distance_from_end_of_current_step <= advance_distance.into()
         }) {
             prop_assert!(should_advance_to_next_step(&current_route_step.get_linestring(), next_route_step.as_ref(), &user_location, StepAdvanceMode::RelativeLineStringDistance {
                 minimum_horizontal_accuracy,
-                special_advance_conditions: automatic_advance_distance.map(|distance| SpecialAdvanceConditions::AdvanceAtDistanceFromEnd(distance)),
+                special_advance_conditions: automatic_advance_distance.map(SpecialAdvanceConditions::AdvanceAtDistanceFromEnd),
             }), "Expected that the step should advance any time that the haversine distance to the end of the step is within the automatic advance threshold.");
         }
     }
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_end_of_step_progress(
         x1 in -180f64..180f64, y1 in -90f64..90f64,
         x2 in -180f64..180f64, y2 in -90f64..90f64,
     ) {
         let current_route_step = gen_dummy_route_step(x1, y1, x2, y2);
         let linestring = current_route_step.get_linestring();
-        let end = linestring.points().last().expect("Expected at least one point");
+        let end = linestring.points().next_back().expect("Expected at least one point");
         let progress = calculate_trip_progress(&end, &linestring, &[current_route_step]);
 
         prop_assert_eq!(progress.distance_to_next_maneuver, 0f64);
@@ -683,6 +1106,7 @@ proptest! {
     }
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_end_of_trip_progress_valhalla_arrival(
         x1: f64, y1: f64,
     ) {
@@ -690,7 +1114,7 @@ proptest! {
         // represent a point geometry for the arrival step.
         let current_route_step = gen_dummy_route_step(x1, y1, x1, y1);
         let linestring = current_route_step.get_linestring();
-        let end = linestring.points().last().expect("Expected at least one point");
+        let end = linestring.points().next_back().expect("Expected at least one point");
         let progress = calculate_trip_progress(&end, &linestring, &[current_route_step]);
 
         prop_assert_eq!(progress.distance_to_next_maneuver, 0f64);
@@ -901,6 +1325,461 @@ mod bearing_snapping_tests {
     }
 }
 
+#[cfg(test)]
+mod heading_fusion_tests {
+    use super::*;
+
+    fn heading(true_heading: u16, accuracy: u16) -> Heading {
+        Heading {
+            true_heading,
+            accuracy,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    fn fused_degrees(course: Option<CourseOverGround>, speed: f64, heading: &Heading) -> u16 {
+        fuse_heading(course, Some(speed), Some(heading), 1.0, 5.0)
+            .expect("Expected a course")
+            .degrees
+    }
+
+    #[test]
+    fn weight_by_speed() {
+        let course = Some(CourseOverGround::new(90.0, None));
+        let heading = heading(0, 10);
+
+        assert_eq!(fused_degrees(course, 0.5, &heading), 0);
+        assert_eq!(fused_degrees(course, 3.0, &heading), 45);
+        assert_eq!(fused_degrees(course, 10.0, &heading), 90);
+        // Unknown speeds count as stationary
+        assert_eq!(
+            fuse_heading(course, None, Some(&heading), 1.0, 5.0),
+            Some(CourseOverGround::new(0.0, Some(10)))
+        );
+    }
+
+    #[test]
+    fn weight_by_accuracy() {
+        // The course is (much) more accurate than the heading
+        let fused = fuse_heading(
+            Some(CourseOverGround::new(90.0, Some(5))),
+            Some(3.0),
+            Some(&heading(0, 20)),
+            1.0,
+            5.0,
+        );
+        assert_eq!(fused, Some(CourseOverGround::new(86.0, Some(5))));
+    }
+
+    #[test]
+    fn wrap_around_north() {
+        let course = Some(CourseOverGround::new(350.0, None));
+        assert_eq!(fused_degrees(course, 3.0, &heading(10, 10)), 0);
+    }
+
+    #[test]
+    fn missing_inputs() {
+        let course = Some(CourseOverGround::new(90.0, Some(10)));
+        assert_eq!(fuse_heading(course, Some(0.0), None, 1.0, 5.0), course);
+        assert_eq!(
+            fuse_heading(None, Some(10.0), Some(&heading(45, 15)), 1.0, 5.0),
+            Some(CourseOverGround::new(45.0, Some(15)))
+        );
+    }
+}
+
+#[cfg(test)]
+mod instruction_trigger_tests {
+    use super::*;
+
+    const DISTANCE_MODE: InstructionTriggerMode =
+        InstructionTriggerMode::DistanceBeforeManeuver { tolerance: 5.0 };
+    const TIME_MODE: InstructionTriggerMode = InstructionTriggerMode::TimeBeforeManeuver {
+        tolerance: 5.0,
+        minimum_speed: 2.0,
+    };
+
+    #[test]
+    fn test_distance_trigger_tolerance() {
+        assert!(is_instruction_triggered(
+            100.0,
+            105.0,
+            None,
+            None,
+            DISTANCE_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            106.0,
+            None,
+            None,
+            DISTANCE_MODE
+        ));
+        assert!(is_instruction_triggered(
+            100.0,
+            120.0,
+            None,
+            None,
+            InstructionTriggerMode::DistanceBeforeManeuver { tolerance: 20.0 }
+        ));
+    }
+
+    #[test]
+    fn test_time_trigger_scales_with_speed() {
+        // At the expected speed, the trigger point is unchanged
+        assert!(is_instruction_triggered(
+            100.0,
+            105.0,
+            Some(10.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            106.0,
+            Some(10.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+
+        // Twice as fast as expected triggers twice as far from the maneuver
+        assert!(is_instruction_triggered(
+            100.0,
+            200.0,
+            Some(20.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            210.0,
+            Some(20.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+
+        // Slower than expected triggers closer to the maneuver
+        assert!(!is_instruction_triggered(
+            100.0,
+            100.0,
+            Some(5.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(is_instruction_triggered(
+            100.0,
+            50.0,
+            Some(5.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+
+        // Very slow speeds are clamped to the minimum speed
+        assert!(is_instruction_triggered(
+            100.0,
+            20.0,
+            Some(0.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            30.0,
+            Some(0.0),
+            Some(10.0),
+            TIME_MODE
+        ));
+    }
+
+    #[test]
+    fn test_time_trigger_falls_back_to_distance() {
+        assert!(is_instruction_triggered(
+            100.0,
+            105.0,
+            None,
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            106.0,
+            None,
+            Some(10.0),
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            106.0,
+            Some(20.0),
+            None,
+            TIME_MODE
+        ));
+        assert!(!is_instruction_triggered(
+            100.0,
+            106.0,
+            Some(20.0),
+            Some(0.0),
+            TIME_MODE
+        ));
+    }
+}
+
+#[cfg(test)]
+mod trip_progress_tests {
+    use super::*;
+    use crate::models::SegmentAnnotation;
+
+    fn gen_annotation(distance: Option<f64>, duration: Option<f64>) -> SegmentAnnotation {
+        SegmentAnnotation {
+            distance,
+            duration,
+            ..SegmentAnnotation::default()
+        }
+    }
+
+    /// A step with two segments of equal length,
+    /// where the first is much slower than the second (ex: due to congestion).
+    fn gen_congested_step(annotations: Option<Vec<SegmentAnnotation>>) -> RouteStep {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.geometry.insert(
+            1,
+            GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.0,
+            },
+        );
+        step.duration = 30.0;
+        step.annotations = annotations;
+        step
+    }
+
+    #[test]
+    fn test_duration_from_annotations() {
+        let step = gen_congested_step(Some(vec![
+            gen_annotation(Some(111.2), Some(25.0)),
+            gen_annotation(Some(111.2), Some(5.0)),
+        ]));
+        let linestring = step.get_linestring();
+        // Halfway along the first segment
+        let point = point!(x: 0.0005, y: 0.0);
+
+        let progress = calculate_trip_progress(&point, &linestring, &[step]);
+        assert!((progress.duration_remaining - 17.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_duration_falls_back_to_distance_fraction() {
+        let point = point!(x: 0.0005, y: 0.0);
+
+        // Without annotations
+        let step = gen_congested_step(None);
+        let progress = calculate_trip_progress(&point, &step.get_linestring(), &[step]);
+        assert!((progress.duration_remaining - 22.5).abs() < 1e-6);
+
+        // Without a duration for every segment
+        let step = gen_congested_step(Some(vec![
+            gen_annotation(None, Some(25.0)),
+            gen_annotation(Some(111.2), None),
+        ]));
+        let progress = calculate_trip_progress(&point, &step.get_linestring(), &[step]);
+        assert!((progress.duration_remaining - 22.5).abs() < 1e-6);
+    }
+}
+
+#[cfg(test)]
+mod eta_blending_tests {
+    use super::*;
+
+    fn sample(elapsed_time: f64, duration_remaining: f64) -> ProgressSample {
+        ProgressSample {
+            elapsed_time,
+            duration_remaining,
+        }
+    }
+
+    #[test]
+    fn test_expected_progress_is_unchanged() {
+        let samples = [sample(0.0, 100.0), sample(10.0, 90.0)];
+        assert!((blend_duration_remaining(90.0, &samples, 0.5) - 90.0).abs() < 1e-6);
+        assert!((blend_duration_remaining(90.0, &samples, 1.0) - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_slow_progress_extends_duration() {
+        // Progressing at half the expected rate
+        let samples = [sample(0.0, 100.0), sample(5.0, 97.5), sample(10.0, 95.0)];
+        assert!((blend_duration_remaining(95.0, &samples, 1.0) - 190.0).abs() < 1e-6);
+        // Half weight gives a blended rate of 0.75
+        assert!((blend_duration_remaining(95.0, &samples, 0.5) - 95.0 / 0.75).abs() < 1e-6);
+        assert!((blend_duration_remaining(95.0, &samples, 0.0) - 95.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_stopped_user_stays_finite() {
+        let samples = [sample(0.0, 100.0), sample(10.0, 100.0)];
+        assert!((blend_duration_remaining(100.0, &samples, 0.5) - 200.0).abs() < 1e-6);
+        assert!((blend_duration_remaining(100.0, &samples, 1.0) - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_insufficient_samples() {
+        assert!((blend_duration_remaining(100.0, &[], 1.0) - 100.0).abs() < 1e-6);
+        assert!((blend_duration_remaining(100.0, &[sample(10.0, 50.0)], 1.0) - 100.0).abs() < 1e-6);
+    }
+}
+
+#[cfg(test)]
+mod upcoming_events_tests {
+    use super::*;
+    use crate::models::{Incident, IncidentType, SegmentAnnotation};
+
+    /// The length of 0.001 degrees of longitude along the equator, in meters.
+    const SEGMENT_LENGTH: f64 = 111.195;
+
+    fn gen_incident(id: &str, geometry_index_start: u64, geometry_index_end: u64) -> Incident {
+        Incident {
+            id: id.to_string(),
+            incident_type: IncidentType::Accident,
+            description: None,
+            long_description: None,
+            creation_time: None,
+            start_time: None,
+            end_time: None,
+            impact: None,
+            lanes_blocked: vec![],
+            congestion: None,
+            closed: None,
+            geometry_index_start,
+            geometry_index_end: Some(geometry_index_end),
+            sub_type: None,
+            sub_type_description: None,
+            iso_3166_1_alpha2: None,
+            iso_3166_1_alpha3: None,
+            affected_road_names: vec![],
+            bbox: None,
+        }
+    }
+
+    /// A step along the equator with two segments of 0.001 degrees,
+    /// with the given congestion along each segment.
+    fn gen_step(
+        start_lng: f64,
+        congestion: [CongestionLevel; 2],
+        incidents: Vec<Incident>,
+    ) -> RouteStep {
+        let mut step = gen_dummy_route_step(start_lng, 0.0, start_lng + 0.002, 0.0);
+        step.geometry.insert(
+            1,
+            GeographicCoordinate {
+                lng: start_lng + 0.001,
+                lat: 0.0,
+            },
+        );
+        step.annotations = Some(
+            congestion
+                .into_iter()
+                .map(|level| SegmentAnnotation {
+                    congestion: Some(level),
+                    ..SegmentAnnotation::default()
+                })
+                .collect(),
+        );
+        step.incidents = incidents;
+        step
+    }
+
+    fn gen_steps() -> Vec<RouteStep> {
+        vec![
+            gen_step(
+                0.0,
+                [CongestionLevel::Low, CongestionLevel::Heavy],
+                vec![gen_incident("behind", 0, 1)],
+            ),
+            gen_step(
+                0.002,
+                [CongestionLevel::Heavy, CongestionLevel::Moderate],
+                vec![gen_incident("ahead", 1, 2), gen_incident("current", 0, 1)],
+            ),
+            gen_step(
+                0.004,
+                [CongestionLevel::Low, CongestionLevel::Severe],
+                vec![gen_incident("far", 0, 2)],
+            ),
+        ]
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn finds_events_ahead_of_user() {
+        // Halfway along the second segment of the first step
+        let events =
+            find_upcoming_events(&point!(x: 0.0015, y: 0.0), &gen_steps(), 10, f64::INFINITY);
+
+        let incidents: Vec<_> = events
+            .incidents
+            .iter()
+            .map(|upcoming| upcoming.incident.id.as_str())
+            .collect();
+        assert_eq!(incidents, vec!["current", "ahead", "far"]);
+        assert_close(events.incidents[0].distance_to_start, 0.5 * SEGMENT_LENGTH);
+        assert_close(events.incidents[1].distance_to_start, 1.5 * SEGMENT_LENGTH);
+        assert_close(events.incidents[1].distance_to_end, 2.5 * SEGMENT_LENGTH);
+
+        // Spans of the same level are merged across steps, and low congestion is ignored
+        let congestion: Vec<_> = events.congestion.iter().map(|span| span.level).collect();
+        assert_eq!(
+            congestion,
+            vec![
+                CongestionLevel::Heavy,
+                CongestionLevel::Moderate,
+                CongestionLevel::Severe
+            ]
+        );
+        assert_close(events.congestion[0].distance_to_start, 0.0);
+        assert_close(events.congestion[0].length, 1.5 * SEGMENT_LENGTH);
+        assert_close(events.congestion[1].distance_to_start, 1.5 * SEGMENT_LENGTH);
+        assert_close(events.congestion[1].length, SEGMENT_LENGTH);
+        assert_close(events.congestion[2].distance_to_start, 3.5 * SEGMENT_LENGTH);
+    }
+
+    #[test]
+    fn limits_number_of_events() {
+        let events =
+            find_upcoming_events(&point!(x: 0.0015, y: 0.0), &gen_steps(), 1, f64::INFINITY);
+        assert_eq!(events.incidents.len(), 1);
+        assert_eq!(events.incidents[0].incident.id, "current");
+        assert_eq!(events.congestion.len(), 1);
+        assert_eq!(events.congestion[0].level, CongestionLevel::Heavy);
+
+        let events =
+            find_upcoming_events(&point!(x: 0.0015, y: 0.0), &gen_steps(), 0, f64::INFINITY);
+        assert_eq!(events, UpcomingEvents::default());
+    }
+
+    #[test]
+    fn limits_distance_of_events() {
+        let events =
+            find_upcoming_events(&point!(x: 0.0015, y: 0.0), &gen_steps(), 10, SEGMENT_LENGTH);
+        let incidents: Vec<_> = events
+            .incidents
+            .iter()
+            .map(|upcoming| upcoming.incident.id.as_str())
+            .collect();
+        assert_eq!(incidents, vec!["current"]);
+
+        // The span which starts within the distance is reported in full
+        assert_eq!(events.congestion.len(), 1);
+        assert_eq!(events.congestion[0].level, CongestionLevel::Heavy);
+        assert_close(events.congestion[0].length, 1.5 * SEGMENT_LENGTH);
+    }
+}
+
 // TODO: Other unit tests
 // - Under and over distance accuracy thresholds
 // - Equator and extreme latitude
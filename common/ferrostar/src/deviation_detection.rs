@@ -8,6 +8,9 @@
 //! For example, on iOS and Android, the `FerrostarCore` class is in charge of deciding
 //! when to kick off a new route request.
 //! Similarly, you may observe this in your own UI layer and display an overlay under certain conditions.
+//! If you need the same rerouting behavior elsewhere (ex: on the web),
+//! the [`ReroutePolicy`](crate::reroute_policy::ReroutePolicy) turns a sequence of deviations
+//! into an explicit decision to request a new route.
 //!
 //! When architecting a Ferrostar core integration for a new platform,
 //! we suggest enforcing a similar separation of concerns.
@@ -16,7 +19,7 @@ use crate::algorithms::deviation_from_line;
 use crate::models::{Route, RouteStep, UserLocation};
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
-use geo::Point;
+use geo::{LineString, Point};
 
 #[cfg(any(feature = "wasm-bindgen", test))]
 use serde::{Deserialize, Serialize};
@@ -74,6 +77,7 @@ impl RouteDeviationTracking {
         location: UserLocation,
         route: &Route,
         current_route_step: &RouteStep,
+        current_step_linestring: &LineString,
     ) -> RouteDeviation {
         match self {
             RouteDeviationTracking::None => RouteDeviation::NoDeviation,
@@ -84,19 +88,18 @@ impl RouteDeviationTracking {
                 if location.horizontal_accuracy < f64::from(*minimum_horizontal_accuracy) {
                     // Check if the deviation from the route line is within tolerance,
                     // after sanity checking that the positioning signal is within accuracy tolerance.
-                    deviation_from_line(
-                        &Point::from(location),
-                        &current_route_step.get_linestring(),
-                    )
-                    .map_or(RouteDeviation::NoDeviation, |deviation| {
-                        if deviation > 0.0 && deviation > *max_acceptable_deviation {
-                            RouteDeviation::OffRoute {
-                                deviation_from_route_line: deviation,
+                    deviation_from_line(&Point::from(location), current_step_linestring).map_or(
+                        RouteDeviation::NoDeviation,
+                        |deviation| {
+                            if deviation > 0.0 && deviation > *max_acceptable_deviation {
+                                RouteDeviation::OffRoute {
+                                    deviation_from_route_line: deviation,
+                                }
+                            } else {
+                                RouteDeviation::NoDeviation
                             }
-                        } else {
-                            RouteDeviation::NoDeviation
-                        }
-                    })
+                        },
+                    )
                 } else {
                     RouteDeviation::NoDeviation
                 }
@@ -176,7 +179,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
 
@@ -193,7 +196,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_random, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_random, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
     }
@@ -215,7 +218,7 @@ proptest! {
                 _route: Route,
                 _current_route_step: RouteStep,
             ) -> RouteDeviation {
-                return RouteDeviation::NoDeviation
+                RouteDeviation::NoDeviation
             }
         }
 
@@ -238,7 +241,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
 
@@ -255,7 +258,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_random, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_random, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
     }
@@ -276,7 +279,7 @@ proptest! {
                 _route: Route,
                 _current_route_step: RouteStep,
             ) -> RouteDeviation {
-                return RouteDeviation::OffRoute {
+                RouteDeviation::OffRoute {
                     deviation_from_route_line: 7.0
                 }
             }
@@ -301,7 +304,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::OffRoute {
                 deviation_from_route_line: 7.0
             }
@@ -320,7 +323,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_random, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_random, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::OffRoute {
                 deviation_from_route_line: 7.0
             }
@@ -330,6 +333,7 @@ proptest! {
     /// Tests [`RouteDeviationTracking::StaticThreshold`] behavior,
     /// using [`algorithms::deviation_from_line`](crate::algorithms::deviation_from_line)
     #[test]
+    #[allow(clippy::float_cmp)]
     fn static_threshold_oracle_test(
         x1: f64, y1: f64,
         x2: f64, y2: f64,
@@ -358,7 +362,7 @@ proptest! {
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_on_route, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
 
@@ -377,7 +381,7 @@ proptest! {
             speed: None
         };
         let deviation = deviation_from_line(&Point::from(coordinates), &current_route_step.get_linestring());
-        match tracking.check_route_deviation(user_location_random, &route, &current_route_step) {
+        match tracking.check_route_deviation(user_location_random, &route, &current_route_step, &current_route_step.get_linestring()) {
             RouteDeviation::NoDeviation => {
                 if let Some(calculated) = deviation {
                     prop_assert!(calculated <= max_acceptable_deviation);
@@ -415,13 +419,13 @@ proptest! {
         };
         let user_location_random = UserLocation {
             coordinates,
-            horizontal_accuracy: horizontal_accuracy as f64,
+            horizontal_accuracy: f64::from(horizontal_accuracy),
             course_over_ground: None,
             timestamp: SystemTime::now(),
             speed: None
         };
         prop_assert_eq!(
-            tracking.check_route_deviation(user_location_random, &route, &current_route_step),
+            tracking.check_route_deviation(user_location_random, &route, &current_route_step, &current_route_step.get_linestring()),
             RouteDeviation::NoDeviation
         );
     }
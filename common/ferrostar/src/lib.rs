@@ -28,10 +28,13 @@ use android_logger::{Config, FilterBuilder};
 
 pub mod algorithms;
 pub mod deviation_detection;
+pub mod localization;
 pub mod models;
 pub mod navigation_controller;
+pub mod reroute_policy;
 pub mod routing_adapters;
 pub mod simulation;
+pub mod ssml;
 
 #[cfg(target_os = "android")]
 fn init_logger() {
@@ -56,10 +59,16 @@ pub fn create_ferrostar_logger() {
 
 #[cfg(feature = "uniffi")]
 mod uniffi_deps {
+    pub use crate::deviation_detection::RouteDeviationTracking;
+    pub use crate::localization::UnitSystem;
     pub use crate::models::Route;
+    pub use crate::navigation_controller::models::{
+        CourseFiltering, NavigationControllerConfig, StepAdvanceMode,
+    };
     pub use crate::routing_adapters::{
         error::{InstantiationError, ParsingError},
         osrm::{
+            instructions::InstructionLanguage,
             models::{Route as OsrmRoute, Waypoint as OsrmWaypoint},
             OsrmResponseParser,
         },
@@ -128,6 +137,7 @@ impl UniffiCustomTypeConverter for DateTime<Utc> {
 /// This is provided as a convenience for use from foreign code when creating your own [`routing_adapters::RouteAdapter`].
 #[cfg(feature = "uniffi")]
 #[uniffi::export]
+#[allow(clippy::needless_pass_by_value)]
 fn create_valhalla_request_generator(
     endpoint_url: String,
     profile: String,
@@ -140,6 +150,28 @@ fn create_valhalla_request_generator(
     )?))
 }
 
+/// Creates a [`RouteRequestGenerator`]
+/// which generates requests to an arbitrary Valhalla server (using the OSRM response format),
+/// with instructions in the given language and units.
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+#[allow(clippy::needless_pass_by_value)]
+fn create_localized_valhalla_request_generator(
+    endpoint_url: String,
+    profile: String,
+    options_json: Option<String>,
+    language: String,
+    units: UnitSystem,
+) -> Result<Arc<dyn RouteRequestGenerator>, InstantiationError> {
+    Ok(Arc::new(ValhallaHttpRequestGenerator::with_localization(
+        endpoint_url,
+        profile,
+        options_json.as_deref(),
+        language,
+        units,
+    )?))
+}
+
 /// Creates a [`RouteResponseParser`] capable of parsing OSRM responses.
 ///
 /// This response parser is designed to be fairly flexible,
@@ -151,8 +183,52 @@ fn create_osrm_response_parser(polyline_precision: u32) -> Arc<dyn RouteResponse
     Arc::new(OsrmResponseParser::new(polyline_precision))
 }
 
+/// Creates a [`RouteResponseParser`] capable of parsing OSRM responses,
+/// which synthesizes any missing instructions in the given language and unit system.
+///
+/// Phrases which are missing from the language fall back to English.
+/// If `generate_ssml` is set, SSML is generated for spoken instructions which don't include any
+/// (see [`OsrmResponseParser::with_ssml_generation`]).
+#[cfg(feature = "uniffi")]
+#[uniffi::export(default(generate_ssml = false))]
+fn create_osrm_response_parser_with_instruction_language(
+    polyline_precision: u32,
+    instruction_language: InstructionLanguage,
+    units: UnitSystem,
+    generate_ssml: bool,
+) -> Arc<dyn RouteResponseParser> {
+    Arc::new(
+        OsrmResponseParser::with_instruction_language(
+            polyline_precision,
+            instruction_language,
+            units,
+        )
+        .with_ssml_generation(generate_ssml),
+    )
+}
+
 // MARK: OSRM Route Conversion
 
+/// Creates a [`NavigationControllerConfig`] with the given step advance, route deviation tracking,
+/// and course filtering behavior, leaving all other behaviors at their defaults.
+///
+/// Foreign code can use this rather than the record's constructor,
+/// so that it does not need to change whenever a new behavior is added.
+/// Set the remaining fields on the result to opt in to other behaviors.
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+fn create_navigation_controller_config(
+    step_advance: StepAdvanceMode,
+    route_deviation_tracking: RouteDeviationTracking,
+    snapped_location_course_filtering: CourseFiltering,
+) -> NavigationControllerConfig {
+    NavigationControllerConfig::new(
+        step_advance,
+        route_deviation_tracking,
+        snapped_location_course_filtering,
+    )
+}
+
 /// Creates a [`Route`] from OSRM data.
 ///
 /// This uses the same logic as the [`OsrmResponseParser`] and is designed to be fairly flexible,
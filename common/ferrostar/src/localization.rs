@@ -0,0 +1,301 @@
+//! Helpers for presenting navigation information in the user's language and units.
+//!
+//! Routing engines like Valhalla localize their own instructions,
+//! but distances in the UI (ex: the distance to the next maneuver)
+//! and locally synthesized instructions are formatted by Ferrostar.
+//! The helpers here round distances to a precision that is natural for each unit system,
+//! so that every platform displays and announces the same thing.
+//!
+//! ```
+//! use ferrostar::localization::{format_distance, DistanceStyle, UnitSystem};
+//!
+//! assert_eq!(format_distance(152.0, UnitSystem::Metric, DistanceStyle::Abbreviated, None), "150 m");
+//! assert_eq!(format_distance(152.0, UnitSystem::Imperial, DistanceStyle::Spoken, None), "500 feet");
+//! ```
+
+use crate::routing_adapters::osrm::instructions::InstructionLanguage;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::String;
+#[cfg(any(feature = "wasm-bindgen", test))]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-bindgen")]
+use tsify::Tsify;
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::prelude::*;
+
+const METERS_PER_FOOT: f64 = 0.3048;
+const METERS_PER_YARD: f64 = 0.9144;
+const METERS_PER_MILE: f64 = 1609.344;
+
+/// A system of units for displaying and announcing distances.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum UnitSystem {
+    /// Meters and kilometers.
+    Metric,
+    /// Feet and miles (as in the United States).
+    Imperial,
+    /// Yards and miles (as in the United Kingdom).
+    ImperialWithYards,
+}
+
+/// A unit of distance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum DistanceUnit {
+    Meters,
+    Kilometers,
+    Feet,
+    Yards,
+    Miles,
+}
+
+/// A distance which has been converted and rounded for presentation to the user.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct RoundedDistance {
+    pub value: f64,
+    pub unit: DistanceUnit,
+}
+
+/// How a distance is worded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum DistanceStyle {
+    /// Abbreviated units for display (ex: 150 m).
+    Abbreviated,
+    /// Units spelled out for speech (ex: 150 meters).
+    Spoken,
+}
+
+/// Converts a distance to the most natural unit in a unit system,
+/// rounded to a precision which is meaningful to the user.
+///
+/// Short distances use the smaller unit (meters, feet, or yards),
+/// rounded to 10 below 100 and to 50 above that.
+/// Longer distances use kilometers or miles, with one decimal place below 10.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn round_distance(meters: f64, units: UnitSystem) -> RoundedDistance {
+    let (small_unit, meters_per_small_unit, large_unit, meters_per_large_unit, threshold) =
+        match units {
+            // Past 950 m, 1 km reads better than 1000 m
+            UnitSystem::Metric => (
+                DistanceUnit::Meters,
+                1.0,
+                DistanceUnit::Kilometers,
+                1000.0,
+                950.0,
+            ),
+            UnitSystem::Imperial => (
+                DistanceUnit::Feet,
+                METERS_PER_FOOT,
+                DistanceUnit::Miles,
+                METERS_PER_MILE,
+                0.1 * METERS_PER_MILE,
+            ),
+            UnitSystem::ImperialWithYards => (
+                DistanceUnit::Yards,
+                METERS_PER_YARD,
+                DistanceUnit::Miles,
+                METERS_PER_MILE,
+                0.25 * METERS_PER_MILE,
+            ),
+        };
+
+    if meters < threshold {
+        let value = meters / meters_per_small_unit;
+        let increment = if value < 100.0 { 10.0 } else { 50.0 };
+        RoundedDistance {
+            value: (value / increment).round() * increment,
+            unit: small_unit,
+        }
+    } else {
+        let value = meters / meters_per_large_unit;
+        RoundedDistance {
+            value: if value < 10.0 {
+                (value * 10.0).round() / 10.0
+            } else {
+                value.round()
+            },
+            unit: large_unit,
+        }
+    }
+}
+
+/// Formats a distance in the given unit system (ex: `150 m` or `500 feet`).
+///
+/// The wording comes from the instruction language (English if `None`),
+/// so that units and decimal separators can be localized along with instructions.
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn format_distance(
+    meters: f64,
+    units: UnitSystem,
+    style: DistanceStyle,
+    instruction_language: Option<InstructionLanguage>,
+) -> String {
+    instruction_language
+        .unwrap_or_default()
+        .format_distance(meters, units, style)
+}
+
+/// JavaScript wrapper for `round_distance`.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = roundDistance)]
+pub fn js_round_distance(meters: f64, units: UnitSystem) -> RoundedDistance {
+    round_distance(meters, units)
+}
+
+/// JavaScript wrapper for `format_distance`.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = formatDistance)]
+pub fn js_format_distance(
+    meters: f64,
+    units: UnitSystem,
+    style: DistanceStyle,
+    instruction_language: Option<InstructionLanguage>,
+) -> String {
+    format_distance(meters, units, style, instruction_language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn rounded(meters: f64, units: UnitSystem) -> (f64, DistanceUnit) {
+        let distance = round_distance(meters, units);
+        (distance.value, distance.unit)
+    }
+
+    #[test]
+    fn round_metric_distances() {
+        assert_eq!(
+            rounded(34.0, UnitSystem::Metric),
+            (30.0, DistanceUnit::Meters)
+        );
+        assert_eq!(
+            rounded(236.0, UnitSystem::Metric),
+            (250.0, DistanceUnit::Meters)
+        );
+        assert_eq!(
+            rounded(960.0, UnitSystem::Metric),
+            (1.0, DistanceUnit::Kilometers)
+        );
+        assert_eq!(
+            rounded(1_540.0, UnitSystem::Metric),
+            (1.5, DistanceUnit::Kilometers)
+        );
+        assert_eq!(
+            rounded(12_600.0, UnitSystem::Metric),
+            (13.0, DistanceUnit::Kilometers)
+        );
+    }
+
+    #[test]
+    fn round_imperial_distances() {
+        assert_eq!(
+            rounded(10.0, UnitSystem::Imperial),
+            (30.0, DistanceUnit::Feet)
+        );
+        assert_eq!(
+            rounded(152.0, UnitSystem::Imperial),
+            (500.0, DistanceUnit::Feet)
+        );
+        assert_eq!(
+            rounded(500.0, UnitSystem::Imperial),
+            (0.3, DistanceUnit::Miles)
+        );
+        assert_eq!(
+            rounded(20_000.0, UnitSystem::Imperial),
+            (12.0, DistanceUnit::Miles)
+        );
+
+        assert_eq!(
+            rounded(275.0, UnitSystem::ImperialWithYards),
+            (300.0, DistanceUnit::Yards)
+        );
+        assert_eq!(
+            rounded(1_609.0, UnitSystem::ImperialWithYards),
+            (1.0, DistanceUnit::Miles)
+        );
+    }
+
+    #[test]
+    fn format_distances() {
+        let format = |meters, units, style| format_distance(meters, units, style, None);
+        assert_eq!(
+            format(152.0, UnitSystem::Metric, DistanceStyle::Abbreviated),
+            "150 m"
+        );
+        assert_eq!(
+            format(1_540.0, UnitSystem::Metric, DistanceStyle::Spoken),
+            "1.5 kilometers"
+        );
+        assert_eq!(
+            format(1_000.0, UnitSystem::Metric, DistanceStyle::Spoken),
+            "1 kilometer"
+        );
+        assert_eq!(
+            format(152.0, UnitSystem::Imperial, DistanceStyle::Spoken),
+            "500 feet"
+        );
+        assert_eq!(
+            format(500.0, UnitSystem::Imperial, DistanceStyle::Abbreviated),
+            "0.3 mi"
+        );
+        assert_eq!(
+            format(
+                1_609.0,
+                UnitSystem::ImperialWithYards,
+                DistanceStyle::Spoken
+            ),
+            "1 mile"
+        );
+    }
+
+    #[test]
+    fn format_localized_distances() {
+        let language = InstructionLanguage {
+            phrases: HashMap::from([
+                (
+                    "distance.short.kilometers".to_string(),
+                    "{distance} كم".to_string(),
+                ),
+                ("number.decimal_separator".to_string(), "٫".to_string()),
+            ]),
+        };
+
+        assert_eq!(
+            format_distance(
+                1_540.0,
+                UnitSystem::Metric,
+                DistanceStyle::Abbreviated,
+                Some(language.clone())
+            ),
+            "1٫5 كم"
+        );
+        // Phrases which aren't localized fall back to English
+        assert_eq!(
+            format_distance(
+                152.0,
+                UnitSystem::Metric,
+                DistanceStyle::Abbreviated,
+                Some(language)
+            ),
+            "150 m"
+        );
+    }
+}
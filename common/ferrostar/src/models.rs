@@ -13,7 +13,6 @@ use geo::{Coord, LineString, Point, Rect};
 #[cfg(feature = "uniffi")]
 use polyline::encode_coordinates;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 #[cfg(all(feature = "std", not(feature = "web-time")))]
 use std::time::SystemTime;
@@ -28,7 +27,8 @@ use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::algorithms::get_linestring;
+use crate::algorithms::{get_linestring, is_instruction_triggered};
+use crate::navigation_controller::models::InstructionTriggerMode;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
@@ -148,12 +148,17 @@ impl From<Rect> for BoundingBox {
 /// The heading of the user/device.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
 pub struct Heading {
     /// The heading in degrees relative to true north.
     pub true_heading: u16,
     /// The platform specific accuracy of the heading value.
     pub accuracy: u16,
     /// The time at which the heading was recorded.
+    #[cfg_attr(feature = "wasm-bindgen", serde(with = "system_time_format"))]
     pub timestamp: SystemTime,
 }
 
@@ -175,11 +180,11 @@ impl CourseOverGround {
     /// # Arguments
     ///
     /// - degrees: The direction in which the user's device is traveling, measured in clockwise degrees from
-    ///            true north (N = 0, E = 90, S = 180, W = 270).
-    ///            NOTE: Input values must lie in the range [0, 360).
+    ///   true north (N = 0, E = 90, S = 180, W = 270).
+    ///   NOTE: Input values must lie in the range [0, 360).
     /// - accuracy: the accuracy of the course value, measured in degrees.
     pub fn new(degrees: f64, accuracy: Option<u16>) -> Self {
-        debug_assert!(degrees >= 0.0 && degrees < 360.0);
+        debug_assert!((0.0..360.0).contains(&degrees));
         Self {
             degrees: degrees.round() as u16,
             accuracy,
@@ -279,6 +284,31 @@ pub struct Route {
     /// A waypoint represents a start/end point for a route leg.
     pub waypoints: Vec<Waypoint>,
     pub steps: Vec<RouteStep>,
+    /// The legs of the route, in order.
+    ///
+    /// The [`RouteStep::leg_index`] of each step is an index into this list.
+    /// This may be empty if the route was built from a source without leg information.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    pub legs: Vec<RouteLeg>,
+}
+
+/// A portion of a route which ends at a waypoint where the user stops
+/// (ex: an intermediate stop or the final destination).
+///
+/// [`WaypointKind::Via`] waypoints are passed through, and do not split a route into legs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct RouteLeg {
+    /// The distance of the leg, in meters.
+    pub distance: f64,
+    /// The estimated duration of the leg, in seconds.
+    pub duration: f64,
+    /// The index of the waypoint in [`Route::waypoints`] where this leg ends.
+    pub destination_waypoint_index: u64,
 }
 
 /// Helper function for getting the route as an encoded polyline.
@@ -323,10 +353,19 @@ pub struct RouteStep {
     pub visual_instructions: Vec<VisualInstruction>,
     /// A list of prompts to announce (via speech synthesis) at specific points along the step.
     pub spoken_instructions: Vec<SpokenInstruction>,
-    /// A list of json encoded strings representing annotations between each coordinate along the step.
-    pub annotations: Option<Vec<String>>,
+    /// Annotations for each segment (between successive coordinates) along the step.
+    pub annotations: Option<Vec<SegmentAnnotation>>,
     /// A list of incidents that occur along the step.
     pub incidents: Vec<Incident>,
+    /// The maneuver at the start of the step (if known).
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub maneuver: Option<Maneuver>,
+    /// The intersections along the step, starting with the one where the maneuver takes place.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    pub intersections: Vec<Intersection>,
+    /// The index of the leg in [`Route::legs`] which this step is a part of.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0))]
+    pub leg_index: u64,
 }
 
 impl RouteStep {
@@ -335,19 +374,39 @@ impl RouteStep {
     }
 
     /// Gets the active visual instruction at a specific point along the step.
+    ///
+    /// This always uses the default [`InstructionTriggerMode`].
+    #[deprecated(
+        note = "This ignores the configured instruction trigger mode; use the visual instruction in the trip state instead."
+    )]
     pub fn get_active_visual_instruction(
         &self,
         distance_to_end_of_step: f64,
     ) -> Option<&VisualInstruction> {
-        // Plain English: finds the *last* instruction where we are past the trigger distance.
-        //
-        // We have a fudge factor to account for imprecision in calculation methodologies from different engines and CPUs,
-        // particularly at the start of a step.
-        self.visual_instructions.iter().rev().find(|instruction| {
-            distance_to_end_of_step - instruction.trigger_distance_before_maneuver <= 5.0
+        self.get_triggered_visual_instruction(|trigger_distance_before_maneuver| {
+            is_instruction_triggered(
+                trigger_distance_before_maneuver,
+                distance_to_end_of_step,
+                None,
+                None,
+                InstructionTriggerMode::default(),
+            )
         })
     }
 
+    /// Gets the active visual instruction,
+    /// given a function which determines whether an instruction's trigger distance has been reached.
+    pub(crate) fn get_triggered_visual_instruction(
+        &self,
+        is_triggered: impl Fn(f64) -> bool,
+    ) -> Option<&VisualInstruction> {
+        // Plain English: finds the *last* instruction where we are past the trigger distance.
+        self.visual_instructions
+            .iter()
+            .rev()
+            .find(|instruction| is_triggered(instruction.trigger_distance_before_maneuver))
+    }
+
     /// Gets the spoken instruction at a specific point along the step.
     ///
     /// Note to platform implementers: some care is needed with this.
@@ -357,27 +416,66 @@ impl RouteStep {
     /// including whether utterances are queued or cut off the currently playing one.
     /// You will also need some sort of check to ensure you don't make the same announcement
     /// more times than necessary.
+    ///
+    /// This always uses the default [`InstructionTriggerMode`].
+    #[deprecated(
+        note = "This ignores the configured instruction trigger mode; use the spoken instruction in the trip state instead."
+    )]
     pub fn get_current_spoken_instruction(
         &self,
         distance_to_end_of_step: f64,
     ) -> Option<&SpokenInstruction> {
-        // Plain English: finds the *last* instruction where we are past the trigger distance.
-        //
-        // We have a fudge factor to account for imprecision in calculation methodologies from different engines and CPUs,
-        // particularly at the start of a step.
-        self.spoken_instructions.iter().rev().find(|instruction| {
-            distance_to_end_of_step - instruction.trigger_distance_before_maneuver <= 5.0
+        self.get_triggered_spoken_instruction(|trigger_distance_before_maneuver| {
+            is_instruction_triggered(
+                trigger_distance_before_maneuver,
+                distance_to_end_of_step,
+                None,
+                None,
+                InstructionTriggerMode::default(),
+            )
         })
     }
 
+    /// Gets the current spoken instruction,
+    /// given a function which determines whether an instruction's trigger distance has been reached.
+    pub(crate) fn get_triggered_spoken_instruction(
+        &self,
+        is_triggered: impl Fn(f64) -> bool,
+    ) -> Option<&SpokenInstruction> {
+        // Plain English: finds the *last* instruction where we are past the trigger distance.
+        self.spoken_instructions
+            .iter()
+            .rev()
+            .find(|instruction| is_triggered(instruction.trigger_distance_before_maneuver))
+    }
+
     /// Get the annotation data at a specific point along the step.
     ///
     /// `at_coordinate_index` is the index of the coordinate in the step geometry.
-    pub fn get_annotation_at_current_index(&self, at_coordinate_index: u64) -> Option<String> {
+    pub fn get_annotation_at_current_index(
+        &self,
+        at_coordinate_index: u64,
+    ) -> Option<SegmentAnnotation> {
         self.annotations
             .as_ref()
             .and_then(|annotations| annotations.get(at_coordinate_index as usize).cloned())
     }
+
+    /// Gets the travel time (in seconds) of each segment of the step
+    /// from the `duration` annotations.
+    ///
+    /// Returns [`None`] unless every segment of the step has a duration annotation.
+    pub(crate) fn get_annotated_segment_durations(&self) -> Option<Vec<f64>> {
+        let annotations = self.annotations.as_ref()?;
+        if annotations.len() + 1 != self.geometry.len() {
+            return None;
+        }
+
+        annotations
+            .iter()
+            .map(|annotation| annotation.duration)
+            .collect()
+    }
 }
 
 /// An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
@@ -611,6 +709,97 @@ pub struct LaneInfo {
     pub active_direction: Option<String>,
 }
 
+impl From<&IntersectionLane> for LaneInfo {
+    fn from(lane: &IntersectionLane) -> Self {
+        Self {
+            // Vanilla OSRM does not say which lane is preferred, so any valid lane will do
+            active: lane.active.unwrap_or(lane.valid),
+            directions: lane.indications.clone(),
+            active_direction: lane.valid_indication.clone(),
+        }
+    }
+}
+
+/// The maneuver at the start of a [`RouteStep`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Maneuver {
+    /// The location where the maneuver takes place.
+    pub location: GeographicCoordinate,
+    /// The clockwise angle from true north to the direction of travel immediately *before* the maneuver.
+    pub bearing_before: u16,
+    /// The clockwise angle from true north to the direction of travel immediately *after* the maneuver.
+    pub bearing_after: u16,
+    /// The broad class of maneuver.
+    pub maneuver_type: ManeuverType,
+    /// Additional information to further specify the maneuver type (if any).
+    pub maneuver_modifier: Option<ManeuverModifier>,
+}
+
+/// An intersection along a [`RouteStep`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct Intersection {
+    /// The location of the intersection.
+    pub location: GeographicCoordinate,
+    /// The clockwise angles from true north of all roads at the intersection.
+    pub bearings: Vec<u16>,
+    /// Whether each of the roads (corresponding 1:1 with `bearings`)
+    /// may be entered on a valid route.
+    pub entry: Vec<bool>,
+    /// The index into `bearings` of the road along which the route enters the intersection.
+    ///
+    /// NOTE: The bearing of this road points *away* from the intersection,
+    /// so it must be rotated by 180 degrees to get the direction of travel.
+    /// This is [`None`] for the intersection where the route departs.
+    pub in_bearing_index: Option<u64>,
+    /// The index into `bearings` of the road along which the route exits the intersection.
+    ///
+    /// This is [`None`] for the intersection where the route arrives.
+    pub out_bearing_index: Option<u64>,
+    /// The classes of the road along which the route exits the intersection
+    /// (ex: `toll`, `motorway`, `ferry`, `tunnel`, or `restricted`).
+    ///
+    /// The available classes depend on the routing engine and profile.
+    pub classes: Vec<String>,
+    /// The turn lanes at the intersection, in left-to-right order.
+    ///
+    /// This is empty if the routing engine has no lane information for the intersection.
+    pub lanes: Vec<IntersectionLane>,
+}
+
+/// A turn lane at an [`Intersection`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct IntersectionLane {
+    /// The indications (ex: markings on the road or signs) for the lane, such as `left` or `straight`.
+    ///
+    /// A lane may have multiple indications.
+    pub indications: Vec<String>,
+    /// Whether the lane can be used to complete the maneuver.
+    pub valid: bool,
+    /// Whether the lane is a preferred choice for the maneuver.
+    ///
+    /// This is an extension supported by Mapbox and Valhalla.
+    pub active: Option<bool>,
+    /// Which of the `indications` applies to the maneuver.
+    ///
+    /// This is an extension supported by Mapbox and Valhalla.
+    pub valid_indication: Option<String>,
+}
+
 /// The content of a visual instruction.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -657,12 +846,104 @@ pub struct VisualInstruction {
     pub trigger_distance_before_maneuver: f64,
 }
 
-/// A flat annotations string value map that can be used to store arbitrary
-/// annotation values.
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
-pub struct AnyAnnotationValue {
-    #[serde(flatten)]
-    pub value: HashMap<String, Value>,
+/// Annotations for a segment of a route (between two successive coordinates).
+///
+/// Routing engines vary in which annotations they support (if any),
+/// so all of the well-known values are optional.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct SegmentAnnotation {
+    /// The length of the segment, in meters.
+    pub distance: Option<f64>,
+    /// The expected travel time along the segment, in seconds.
+    pub duration: Option<f64>,
+    /// The expected speed of travel along the segment, in meters per second.
+    pub speed: Option<f64>,
+    /// The speed limit along the segment.
+    pub max_speed: Option<SpeedLimit>,
+    /// The level of traffic congestion along the segment.
+    pub congestion: Option<CongestionLevel>,
+    /// The ID of the way (ex: in OpenStreetMap) which the segment is a part of.
+    pub way_id: Option<u64>,
+    /// Any other annotations from the routing engine,
+    /// with values encoded as JSON strings.
+    pub extensions: HashMap<String, String>,
+}
+
+/// A speed limit.
+///
+/// For more information, see <https://wiki.openstreetmap.org/wiki/Key:maxspeed>.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum SpeedLimit {
+    /// There is no speed limit (ex: parts of the German Autobahn).
+    NoLimit,
+    /// The speed limit is not known.
+    Unknown,
+    /// The speed limit is a known value.
+    ///
+    /// The unit depends on the routing engine (and possibly on local conventions).
+    Limited {
+        /// The speed limit, in `unit`s.
+        value: f64,
+        /// The unit of the speed limit.
+        unit: SpeedUnit,
+    },
+}
+
+impl SpeedLimit {
+    /// The speed limit in meters per second,
+    /// or [`None`] if there is no limit or the limit is unknown.
+    pub fn meters_per_second(&self) -> Option<f64> {
+        match self {
+            SpeedLimit::NoLimit | SpeedLimit::Unknown => None,
+            SpeedLimit::Limited { value, unit } => Some(value * unit.meters_per_second()),
+        }
+    }
+}
+
+/// A unit of speed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum SpeedUnit {
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+}
+
+impl SpeedUnit {
+    /// The number of meters per second in one of this unit.
+    fn meters_per_second(self) -> f64 {
+        match self {
+            SpeedUnit::KilometersPerHour => 1000.0 / 3600.0,
+            SpeedUnit::MilesPerHour => 1609.344 / 3600.0,
+            SpeedUnit::Knots => 1852.0 / 3600.0,
+        }
+    }
+}
+
+/// The level of traffic congestion.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum CongestionLevel {
+    Unknown,
+    Low,
+    Moderate,
+    Heavy,
+    Severe,
 }
 
 #[cfg(test)]
@@ -680,6 +961,7 @@ mod tests {
             distance: 0.0,
             waypoints: vec![],
             steps: vec![],
+            legs: vec![],
         };
 
         let polyline5 = get_route_polyline(&route, 5).expect("Unable to encode polyline for route");
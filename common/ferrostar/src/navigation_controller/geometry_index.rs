@@ -0,0 +1,509 @@
+//! Precomputed geometry for a route.
+//!
+//! Location updates happen frequently, and routes can be very long.
+//! Rather than rebuilding step geometries and re-summing distances on every update,
+//! the navigation controller builds this index once per route.
+
+use crate::algorithms::{
+    calculate_trip_progress, coordinate_distances, get_linestring,
+    locate_along_with_coordinate_distances, mean_bearing, trip_progress_from_step_distance,
+    LinePosition, StepTotals,
+};
+use crate::models::{CourseOverGround, Route, RouteStep};
+use crate::navigation_controller::models::TripProgress;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec, vec::Vec};
+use geo::{Bearing, Coord, Distance, Geodesic, Haversine, LineString, Point};
+
+/// Precomputed geometry for a single route step.
+#[derive(Clone, Debug)]
+pub(crate) struct StepGeometry {
+    pub(crate) linestring: LineString,
+    /// The length of each segment of the step, in meters.
+    pub(crate) segment_lengths: Vec<f64>,
+    /// The distance along the step to each coordinate, in meters.
+    coordinate_distances: Vec<f64>,
+    /// The total length of the step, in meters.
+    pub(crate) length: f64,
+    /// The bearing of each segment of the step, in degrees.
+    pub(crate) segment_bearings: Vec<f64>,
+    /// The travel time from each coordinate to the end of the step, in seconds (if annotated).
+    coordinate_durations_to_end: Option<Vec<f64>>,
+}
+
+impl StepGeometry {
+    pub(crate) fn new(step: &RouteStep) -> Self {
+        let linestring = get_linestring(&step.geometry);
+        let segment_lengths: Vec<_> = linestring
+            .lines()
+            .map(|segment| Haversine::distance(segment.start_point(), segment.end_point()))
+            .collect();
+        let coordinate_distances = coordinate_distances(segment_lengths.iter().copied());
+        let length = coordinate_distances.last().copied().unwrap_or_default();
+        let segment_bearings = linestring
+            .lines()
+            .map(|segment| Geodesic::bearing(segment.start_point(), segment.end_point()))
+            .collect();
+        let coordinate_durations_to_end =
+            step.get_annotated_segment_durations()
+                .map(|segment_durations| {
+                    let mut durations: Vec<_> = core::iter::once(0.0)
+                        .chain(segment_durations.iter().rev().scan(0.0, |total, duration| {
+                            *total += duration;
+                            Some(*total)
+                        }))
+                        .collect();
+                    durations.reverse();
+                    durations
+                });
+
+        Self {
+            linestring,
+            segment_lengths,
+            coordinate_distances,
+            length,
+            segment_bearings,
+            coordinate_durations_to_end,
+        }
+    }
+
+    /// Locates a point (which should already be snapped to the step) along the step.
+    ///
+    /// If the index of the segment which the point was snapped to is known,
+    /// this is a constant time operation.
+    fn locate(&self, snapped_location: &Point, segment_index: Option<u64>) -> Option<LinePosition> {
+        locate_along_with_coordinate_distances(
+            snapped_location,
+            &self.linestring,
+            &self.coordinate_distances,
+            segment_index.and_then(|index| usize::try_from(index).ok()),
+        )
+    }
+
+    /// The index of the segment which lies a distance (in meters) along the step,
+    /// searching from the segment at `start_index`.
+    ///
+    /// Distances past the end of the step are on the last segment.
+    fn segment_at(&self, distance_along: f64, start_index: usize) -> Option<usize> {
+        let last_index = self.segment_lengths.len().checked_sub(1)?;
+        let segment_ends = self.coordinate_distances.get(start_index + 1..)?;
+        Some(
+            (start_index + segment_ends.partition_point(|end| *end < distance_along))
+                .min(last_index),
+        )
+    }
+
+    /// The travel distance (in meters) and time (in seconds)
+    /// from a point (which should already be snapped to the step) to the end of the step.
+    ///
+    /// The index of the segment which the point was snapped to should be given if it is known,
+    /// to save searching the step for it.
+    /// The travel time is only known if the step has duration annotations.
+    pub(crate) fn travel_to_end(
+        &self,
+        snapped_location: &Point,
+        segment_index: Option<u64>,
+    ) -> (Option<f64>, Option<f64>) {
+        let Some(position) = self.locate(snapped_location, segment_index) else {
+            return (None, None);
+        };
+
+        let duration_to_end =
+            self.coordinate_durations_to_end
+                .as_deref()
+                .and_then(|durations_to_end| {
+                    let from_segment_start = durations_to_end.get(position.segment_index)?;
+                    let from_segment_end = durations_to_end.get(position.segment_index + 1)?;
+                    Some(
+                        from_segment_end
+                            + (1.0 - position.segment_fraction)
+                                * (from_segment_start - from_segment_end),
+                    )
+                });
+        (Some(self.length - position.distance_along), duration_to_end)
+    }
+
+    /// Finds the point a distance (in meters) further along the step
+    /// from a point which is snapped to it (on the segment at `segment_index`, if known),
+    /// along with the index of the segment which the point lies on.
+    ///
+    /// The point never goes past the end of the step.
+    pub(crate) fn point_ahead(
+        &self,
+        snapped_location: &Point,
+        segment_index: Option<u64>,
+        distance: f64,
+    ) -> Option<(Point, u64)> {
+        let position = self.locate(snapped_location, segment_index)?;
+        let distance_along = position.distance_along + distance;
+        let index = self.segment_at(distance_along, position.segment_index)?;
+        let segment = self.linestring.lines().nth(index)?;
+        let length = self.segment_lengths[index];
+        let fraction = if length > 0.0 {
+            ((distance_along - self.coordinate_distances[index]) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        Some((
+            Point::from(segment.start + segment.delta() * fraction),
+            index as u64,
+        ))
+    }
+
+    /// The average bearing (in degrees) of the step between two distances along it (in meters),
+    /// weighting each segment by how much of it lies between them.
+    ///
+    /// Returns [`None`] if no part of the step lies between the distances.
+    pub(crate) fn mean_bearing(&self, start: f64, end: f64) -> Option<f64> {
+        let first_index = self.segment_at(start, 0)?;
+        mean_bearing(
+            self.segment_bearings
+                .iter()
+                .zip(self.coordinate_distances.windows(2))
+                .skip(first_index)
+                .take_while(|(_, distances)| distances[0] < end)
+                .map(|(bearing, distances)| {
+                    let overlap = (end.min(distances[1]) - start.max(distances[0])).max(0.0);
+                    (*bearing, overlap)
+                }),
+        )
+    }
+
+    /// The course of travel along the segment starting at the given coordinate index.
+    pub(crate) fn course_at(&self, index: u64) -> Option<CourseOverGround> {
+        self.segment_bearings
+            .get(usize::try_from(index).ok()?)
+            .map(|degrees| CourseOverGround::new(*degrees, None))
+    }
+}
+
+/// Precomputed geometry and cumulative totals for every step of a route.
+pub(crate) struct RouteGeometryIndex {
+    steps: Vec<StepGeometry>,
+    /// The length of all steps before the step at each index, in meters.
+    before_step: Vec<f64>,
+    /// The totals for all steps after the step at each index.
+    after_step: Vec<StepTotals>,
+    /// The totals for the steps after the step at each index
+    /// which are part of the same leg.
+    after_step_in_leg: Vec<StepTotals>,
+}
+
+impl RouteGeometryIndex {
+    pub(crate) fn new(route: &Route) -> Self {
+        let steps: Vec<StepGeometry> = route.steps.iter().map(StepGeometry::new).collect();
+        let before_step = steps
+            .iter()
+            .scan(0.0, |distance, step| {
+                let before = *distance;
+                *distance += step.length;
+                Some(before)
+            })
+            .collect();
+
+        let mut after_step = vec![StepTotals::default(); route.steps.len()];
+        let mut after_step_in_leg = vec![StepTotals::default(); route.steps.len()];
+        for (index, step) in route.steps.iter().enumerate().skip(1).rev() {
+            after_step[index - 1] = StepTotals {
+                distance: after_step[index].distance + step.distance,
+                duration: after_step[index].duration + step.duration,
+            };
+            // Totals within a leg reset at leg boundaries
+            if route.steps[index - 1].leg_index == step.leg_index {
+                after_step_in_leg[index - 1] = StepTotals {
+                    distance: after_step_in_leg[index].distance + step.distance,
+                    duration: after_step_in_leg[index].duration + step.duration,
+                };
+            }
+        }
+
+        Self {
+            steps,
+            before_step,
+            after_step,
+            after_step_in_leg,
+        }
+    }
+
+    /// Finds the index of the first of the remaining steps within the route.
+    ///
+    /// The remaining steps are always a suffix of the route's steps,
+    /// so the index follows from the number of remaining steps.
+    /// To make sure that the steps really are a suffix of the indexed route,
+    /// the geometry of the first and last remaining steps are checked against the index.
+    /// Returns [`None`] if the steps do not appear to belong to the indexed route
+    /// (ex: a trip state from a different navigation controller).
+    fn step_index(&self, remaining_steps: &[RouteStep]) -> Option<usize> {
+        let index = self.steps.len().checked_sub(remaining_steps.len())?;
+        let is_same_geometry = |indexed: &StepGeometry, step: &RouteStep| {
+            indexed.linestring.0.len() == step.geometry.len()
+                && indexed
+                    .linestring
+                    .coords()
+                    .zip(&step.geometry)
+                    .all(|(indexed, coord)| *indexed == Coord::from(*coord))
+        };
+
+        let is_suffix = is_same_geometry(self.steps.get(index)?, remaining_steps.first()?)
+            && is_same_geometry(self.steps.last()?, remaining_steps.last()?);
+        is_suffix.then_some(index)
+    }
+
+    /// Returns the geometry for the first of the remaining steps.
+    ///
+    /// This is looked up from the index when possible,
+    /// and computed on the fly otherwise.
+    pub(crate) fn step_geometry(
+        &self,
+        remaining_steps: &[RouteStep],
+    ) -> Option<Cow<'_, StepGeometry>> {
+        match self.step_index(remaining_steps) {
+            Some(index) => Some(Cow::Borrowed(&self.steps[index])),
+            None => remaining_steps
+                .first()
+                .map(|step| Cow::Owned(StepGeometry::new(step))),
+        }
+    }
+
+    /// Returns each of the remaining steps along with its geometry.
+    ///
+    /// Like [`RouteGeometryIndex::step_geometry`], geometry is looked up from the index when possible,
+    /// and computed on the fly (only for the steps which are actually visited) otherwise.
+    pub(crate) fn step_geometries<'a>(
+        &'a self,
+        remaining_steps: &'a [RouteStep],
+    ) -> impl Iterator<Item = (&'a RouteStep, Cow<'a, StepGeometry>)> {
+        let indexed = self
+            .step_index(remaining_steps)
+            .map(|index| &self.steps[index..]);
+        remaining_steps
+            .iter()
+            .enumerate()
+            .map(move |(offset, step)| match indexed {
+                Some(indexed) => (step, Cow::Borrowed(&indexed[offset])),
+                None => (step, Cow::Owned(StepGeometry::new(step))),
+            })
+    }
+
+    /// The distance along the route (by geometry) to the start of the first of the remaining steps, in meters.
+    ///
+    /// Returns [`None`] if the steps do not appear to belong to the indexed route.
+    pub(crate) fn distance_before_step(&self, remaining_steps: &[RouteStep]) -> Option<f64> {
+        self.step_index(remaining_steps)
+            .map(|index| self.before_step[index])
+    }
+
+    /// Computes the user's progress along the trip.
+    ///
+    /// The `current_step_geometry_index` is the index of the segment of the current step
+    /// which the location is snapped to (if known).
+    ///
+    /// NOTE to callers: `remaining_steps` includes the current step!
+    pub(crate) fn trip_progress(
+        &self,
+        snapped_location: &Point,
+        current_step_geometry: &StepGeometry,
+        current_step_geometry_index: Option<u64>,
+        remaining_steps: &[RouteStep],
+    ) -> TripProgress {
+        match self.step_index(remaining_steps) {
+            Some(index) => {
+                let (distance_to_end, duration_to_end) = current_step_geometry
+                    .travel_to_end(snapped_location, current_step_geometry_index);
+                trip_progress_from_step_distance(
+                    remaining_steps.first(),
+                    distance_to_end,
+                    duration_to_end,
+                    self.after_step[index],
+                    self.after_step_in_leg[index],
+                )
+            }
+            None => calculate_trip_progress(
+                snapped_location,
+                &current_step_geometry.linestring,
+                remaining_steps,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        apply_snapped_course, index_of_closest_segment_origin, snap_user_location_to_line,
+    };
+    use crate::models::{GeographicCoordinate, SegmentAnnotation};
+    use crate::navigation_controller::test_helpers::{
+        gen_dummy_route_step, gen_route_from_steps, get_extended_route,
+    };
+    use crate::simulation::{
+        advance_location_simulation, location_simulation_from_route, LocationBias,
+    };
+
+    #[test]
+    fn matches_unindexed_calculations() {
+        let route = get_extended_route();
+        let index = RouteGeometryIndex::new(&route);
+
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+        for step_index in 0..route.steps.len() {
+            let remaining_steps = &route.steps[step_index..];
+            let step_geometry = index
+                .step_geometry(remaining_steps)
+                .expect("Expected step geometry");
+            assert!(matches!(step_geometry, Cow::Borrowed(_)));
+
+            let linestring = remaining_steps[0].get_linestring();
+            assert_eq!(step_geometry.linestring, linestring);
+
+            simulation_state = advance_location_simulation(&simulation_state);
+            let snapped =
+                snap_user_location_to_line(simulation_state.current_location, &linestring);
+            let point = Point::from(snapped);
+            let segment_index = index_of_closest_segment_origin(snapped, &linestring);
+
+            let expected = calculate_trip_progress(&point, &linestring, remaining_steps);
+            let actual =
+                index.trip_progress(&point, &step_geometry, segment_index, remaining_steps);
+            assert!(
+                (expected.distance_to_next_maneuver - actual.distance_to_next_maneuver).abs()
+                    < 1e-6
+            );
+            assert!((expected.distance_remaining - actual.distance_remaining).abs() < 1e-6);
+            assert!((expected.duration_remaining - actual.duration_remaining).abs() < 1e-6);
+            assert!(
+                (expected.distance_to_next_waypoint - actual.distance_to_next_waypoint).abs()
+                    < 1e-6
+            );
+            assert!(
+                (expected.duration_to_next_waypoint - actual.duration_to_next_waypoint).abs()
+                    < 1e-6
+            );
+
+            for segment_index in 0..linestring.lines().count() as u64 {
+                assert_eq!(
+                    step_geometry.course_at(segment_index),
+                    apply_snapped_course(snapped, Some(segment_index), &linestring)
+                        .course_over_ground
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn uses_annotated_durations() {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.geometry.insert(
+            1,
+            GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.0,
+            },
+        );
+        step.duration = 30.0;
+        step.annotations = Some(vec![
+            SegmentAnnotation {
+                duration: Some(25.0),
+                ..SegmentAnnotation::default()
+            },
+            SegmentAnnotation {
+                duration: Some(5.0),
+                ..SegmentAnnotation::default()
+            },
+        ]);
+        let route = gen_route_from_steps(vec![step]);
+        let index = RouteGeometryIndex::new(&route);
+        let step_geometry = index
+            .step_geometry(&route.steps)
+            .expect("Expected step geometry");
+
+        for lng in [0.0, 0.0005, 0.001, 0.0015, 0.002] {
+            let point = Point::new(lng, 0.0);
+            let expected = calculate_trip_progress(&point, &step_geometry.linestring, &route.steps);
+            let actual = index.trip_progress(&point, &step_geometry, None, &route.steps);
+            assert!((expected.duration_remaining - actual.duration_remaining).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn averages_bearings_over_distance() {
+        // East, then north
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.001, 0.0);
+        step.geometry.push(GeographicCoordinate {
+            lat: 0.001,
+            lng: 0.001,
+        });
+        let step_geometry = StepGeometry::new(&step);
+        let first_segment_length = step_geometry.segment_lengths[0];
+
+        let mean_bearing = |start, end| {
+            step_geometry
+                .mean_bearing(start, end)
+                .expect("Expected a bearing")
+        };
+        assert!((mean_bearing(0.0, first_segment_length) - 90.0).abs() < 1e-6);
+        assert!(
+            (mean_bearing(first_segment_length / 2.0, first_segment_length * 1.5) - 45.0).abs()
+                < 0.1
+        );
+        // The range is limited to the step
+        assert!((mean_bearing(-100.0, first_segment_length) - 90.0).abs() < 1e-6);
+        assert_eq!(step_geometry.mean_bearing(1_000.0, 2_000.0), None);
+    }
+
+    #[test]
+    fn falls_back_for_unknown_steps() {
+        let route = get_extended_route();
+        let index = RouteGeometryIndex::new(&route);
+
+        // Steps from a different route (in this case, a reversed copy)
+        let mut other_steps = route.steps.clone();
+        other_steps.reverse();
+        let step_geometry = index
+            .step_geometry(&other_steps[1..])
+            .expect("Expected step geometry");
+        assert!(matches!(step_geometry, Cow::Owned(_)));
+        assert_eq!(step_geometry.linestring, other_steps[1].get_linestring());
+
+        // Steps which start like the route's, but end differently
+        let mut other_steps = route.steps.clone();
+        other_steps.last_mut().expect("Expected steps").geometry[0].lng += 0.001;
+        let step_geometry = index
+            .step_geometry(&other_steps[1..])
+            .expect("Expected step geometry");
+        assert!(matches!(step_geometry, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn finds_points_ahead() {
+        // East, then north
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.001, 0.0);
+        step.geometry.push(GeographicCoordinate {
+            lat: 0.001,
+            lng: 0.001,
+        });
+        let step_geometry = StepGeometry::new(&step);
+        let first_segment_length = step_geometry.segment_lengths[0];
+        let start = Point::new(0.0005, 0.0);
+
+        // The result is the same whether or not the segment is known
+        for segment_index in [None, Some(0)] {
+            let (point, index) = step_geometry
+                .point_ahead(&start, segment_index, first_segment_length)
+                .expect("Expected a point ahead");
+            assert!((point.x() - 0.001).abs() < 1e-9);
+            assert!((point.y() - 0.0005).abs() < 1e-6);
+            assert_eq!(index, 1);
+        }
+
+        // The point never goes past the end of the step
+        let (point, _) = step_geometry
+            .point_ahead(&start, Some(0), 1_000.0)
+            .expect("Expected a point ahead");
+        assert!((point.x() - 0.001).abs() < 1e-9);
+        assert!((point.y() - 0.001).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,174 @@
+//! Filtering of raw location updates before they are used by the navigation controller.
+//!
+//! Locations are smoothed with an alpha-beta filter,
+//! which predicts the user's position from the previous estimate and velocity,
+//! then corrects the prediction (and the velocity) by a fraction of the difference
+//! between the prediction and the measured location.
+//! Locations which are out of order, or which are too far from the previous estimate
+//! for the user to have traveled there, are rejected outright.
+
+use super::models::LocationEstimate;
+use crate::models::{Speed, UserLocation};
+use geo::{Bearing, Destination, Distance, Haversine, Point};
+
+/// Builds the initial estimate for a location, using its speed and course (if any) for the velocity.
+pub(crate) fn initial_estimate(location: UserLocation) -> LocationEstimate {
+    let (velocity_east, velocity_north) = match (location.speed, location.course_over_ground) {
+        (Some(speed), Some(course)) => {
+            let course = f64::from(course.degrees).to_radians();
+            (speed.value * course.sin(), speed.value * course.cos())
+        }
+        _ => (0.0, 0.0),
+    };
+    LocationEstimate {
+        location,
+        velocity_east,
+        velocity_north,
+    }
+}
+
+/// Updates an estimate with a new location.
+///
+/// Returns [`None`] if the location should be rejected, because its timestamp is not after
+/// the estimate's, or because it implies a speed faster than `max_speed` (in meters per second).
+/// The location's horizontal accuracy is given the benefit of the doubt when checking the speed.
+///
+/// The filtered location keeps the accuracy and course of the measured location.
+/// If the measured location has no speed, the speed of the estimated velocity is used instead.
+pub(crate) fn update_estimate(
+    location: UserLocation,
+    estimate: &LocationEstimate,
+    alpha: f64,
+    beta: f64,
+    max_speed: f64,
+) -> Option<LocationEstimate> {
+    let elapsed = location
+        .timestamp
+        .duration_since(estimate.location.timestamp)
+        .ok()
+        .filter(|elapsed| !elapsed.is_zero())?
+        .as_secs_f64();
+
+    // Work in meters east and north of the previous estimate
+    let origin = Point::from(estimate.location);
+    let measured = Point::from(location);
+    let distance = Haversine::distance(origin, measured);
+    if (distance - location.horizontal_accuracy).max(0.0) / elapsed > max_speed {
+        return None;
+    }
+    let bearing = Haversine::bearing(origin, measured).to_radians();
+    let (measured_east, measured_north) = (distance * bearing.sin(), distance * bearing.cos());
+
+    let predicted_east = estimate.velocity_east * elapsed;
+    let predicted_north = estimate.velocity_north * elapsed;
+    let residual_east = measured_east - predicted_east;
+    let residual_north = measured_north - predicted_north;
+
+    let east = predicted_east + alpha * residual_east;
+    let north = predicted_north + alpha * residual_north;
+    let velocity_east = estimate.velocity_east + beta / elapsed * residual_east;
+    let velocity_north = estimate.velocity_north + beta / elapsed * residual_north;
+
+    let filtered =
+        Haversine::destination(origin, east.atan2(north).to_degrees(), east.hypot(north));
+    Some(LocationEstimate {
+        location: UserLocation {
+            coordinates: filtered.into(),
+            speed: location.speed.or(Some(Speed {
+                value: velocity_east.hypot(velocity_north),
+                accuracy: None,
+            })),
+            ..location
+        },
+        velocity_east,
+        velocity_north,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CourseOverGround, GeographicCoordinate};
+    use std::time::{Duration, SystemTime};
+
+    /// Meters per degree of latitude (near enough for tests).
+    const METERS_PER_DEGREE: f64 = 111_195.0;
+
+    fn location_at(north: f64, seconds: u64) -> UserLocation {
+        UserLocation {
+            coordinates: GeographicCoordinate {
+                lat: north / METERS_PER_DEGREE,
+                lng: 0.0,
+            },
+            horizontal_accuracy: 5.0,
+            course_over_ground: Some(CourseOverGround::new(0.0, Some(5))),
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+        }
+    }
+
+    fn meters_north(location: &UserLocation) -> f64 {
+        location.coordinates.lat * METERS_PER_DEGREE
+    }
+
+    #[test]
+    fn smooths_noisy_locations() {
+        let estimate = initial_estimate(location_at(0.0, 0));
+        assert!((estimate.velocity_north - 10.0).abs() < 1e-9);
+        assert!(estimate.velocity_east.abs() < 1e-9);
+
+        // Predicted at 10 m; measured 20 m
+        let estimate = update_estimate(location_at(20.0, 1), &estimate, 0.5, 0.1, 50.0)
+            .expect("Location should be accepted");
+        assert!((meters_north(&estimate.location) - 15.0).abs() < 0.01);
+        assert!((estimate.velocity_north - 11.0).abs() < 0.01);
+        // The measured speed is kept
+        assert_eq!(estimate.location.speed.map(|speed| speed.value), Some(10.0));
+    }
+
+    #[test]
+    fn estimates_missing_speed() {
+        let estimate = initial_estimate(UserLocation {
+            speed: None,
+            ..location_at(0.0, 0)
+        });
+        let estimate = update_estimate(
+            UserLocation {
+                speed: None,
+                ..location_at(10.0, 1)
+            },
+            &estimate,
+            0.5,
+            0.5,
+            50.0,
+        )
+        .expect("Location should be accepted");
+        let speed = estimate.location.speed.expect("Speed should be estimated");
+        assert!((speed.value - 5.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let estimate = initial_estimate(location_at(0.0, 10));
+
+        // Out of order and duplicate timestamps
+        assert_eq!(
+            update_estimate(location_at(10.0, 9), &estimate, 0.5, 0.1, 50.0),
+            None
+        );
+        assert_eq!(
+            update_estimate(location_at(0.0, 10), &estimate, 0.5, 0.1, 50.0),
+            None
+        );
+        // 500 m in a second
+        assert_eq!(
+            update_estimate(location_at(500.0, 11), &estimate, 0.5, 0.1, 50.0),
+            None
+        );
+        // ...but plausible after a longer gap
+        assert!(update_estimate(location_at(500.0, 25), &estimate, 0.5, 0.1, 50.0).is_some());
+    }
+}
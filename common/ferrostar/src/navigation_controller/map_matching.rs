@@ -0,0 +1,366 @@
+//! Matching of the user's location to the route with a hidden Markov model.
+//!
+//! Snapping each location to the nearest point on the route works well most of the time,
+//! but it can't tell which part of the route the user is on where the route intersects itself
+//! or passes close to itself (ex: overpasses and parallel service roads).
+//! Here, each location has several candidate positions along the current step,
+//! and the most likely sequence of positions over a short window of recent locations
+//! is found with the Viterbi algorithm
+//! (loosely following Newson and Krumm, "Hidden Markov Map Matching Through Noise and Sparseness").
+
+use super::geometry_index::StepGeometry;
+use super::models::{LocationMatch, MatchCandidate};
+use crate::models::{GeographicCoordinate, UserLocation};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use geo::{Closest, Distance, Haversine, HaversineClosestPoint, Point};
+
+/// The minimum standard deviation of location measurements, in meters.
+///
+/// The accuracy reported by location providers is often optimistic.
+const MIN_LOCATION_SIGMA: f64 = 5.0;
+/// The standard deviation of the user's course relative to the route, in degrees.
+const COURSE_SIGMA: f64 = 45.0;
+/// The minimum speed (in meters per second) at which the user's course is considered meaningful.
+const MIN_SPEED_FOR_COURSE: f64 = 1.0;
+/// Candidates farther than this many standard deviations from the location are not considered
+/// (unless there are no others).
+const SEARCH_RADIUS_SIGMAS: f64 = 3.0;
+/// The maximum number of candidate positions for each location.
+const MAX_CANDIDATES: usize = 8;
+/// The scale (in meters) at which transitions become less likely
+/// as the distance traveled along the route differs from the distance between locations.
+const TRANSITION_BETA: f64 = 10.0;
+
+/// The user's position along a step, as found by [`match_location`].
+pub(crate) struct MatchedPosition {
+    /// The index of the segment of the step geometry which the user is on.
+    pub(crate) segment_index: u64,
+    /// The user's location, snapped to the matched position.
+    pub(crate) snapped_location: UserLocation,
+    /// The recent matches to carry over to the next update, including this one.
+    pub(crate) recent_matches: Vec<LocationMatch>,
+}
+
+/// A candidate position for the current location.
+#[derive(Clone, Copy)]
+struct Candidate {
+    segment_index: usize,
+    point: Point,
+    distance_from_location: f64,
+    distance_along_route: f64,
+    log_likelihood: f64,
+}
+
+/// Matches a location to a position along the current step,
+/// considering up to `window` of the `recent_matches`.
+///
+/// The step starts `step_offset` meters along the route.
+/// Positions behind the most recent match are never considered.
+///
+/// Returns [`None`] if there are no candidate positions
+/// (ex: the step has no length, or the user is already past its end).
+pub(crate) fn match_location(
+    location: UserLocation,
+    step: &StepGeometry,
+    step_offset: f64,
+    recent_matches: &[LocationMatch],
+    window: usize,
+) -> Option<MatchedPosition> {
+    let window = window.max(1);
+    let min_distance_along_route = recent_matches
+        .last()
+        .map_or(f64::NEG_INFINITY, |last_match| {
+            last_match.distance_along_route
+        });
+    let candidates = candidates(&location, step, step_offset, min_distance_along_route);
+
+    let mut observations: Vec<(UserLocation, Vec<MatchCandidate>)> = recent_matches
+        [recent_matches.len().saturating_sub(window)..]
+        .iter()
+        .map(|recent| (recent.location, recent.candidates.clone()))
+        .collect();
+    observations.push((
+        location,
+        candidates
+            .iter()
+            .map(|candidate| MatchCandidate {
+                distance_along_route: candidate.distance_along_route,
+                log_likelihood: candidate.log_likelihood,
+            })
+            .collect(),
+    ));
+
+    // Prefer the most likely path, falling back to the most likely candidate
+    // if no path is possible.
+    let (best, _) = viterbi(&observations)
+        .into_iter()
+        .zip(&candidates)
+        .enumerate()
+        .max_by(|(_, (score_1, candidate_1)), (_, (score_2, candidate_2))| {
+            score_1.total_cmp(score_2).then(
+                candidate_1
+                    .log_likelihood
+                    .total_cmp(&candidate_2.log_likelihood),
+            )
+        })?;
+    let best = candidates[best];
+
+    let matched = LocationMatch {
+        location,
+        candidates: observations.pop().map(|(_, candidates)| candidates)?,
+        distance_along_route: best.distance_along_route,
+    };
+    let recent_matches = recent_matches[recent_matches.len().saturating_sub(window - 1)..]
+        .iter()
+        .cloned()
+        .chain(vec![matched])
+        .collect();
+
+    Some(MatchedPosition {
+        segment_index: best.segment_index as u64,
+        snapped_location: UserLocation {
+            coordinates: GeographicCoordinate {
+                lng: best.point.x(),
+                lat: best.point.y(),
+            },
+            ..location
+        },
+        recent_matches,
+    })
+}
+
+/// Finds the candidate positions for a location along a step,
+/// which are the closest points on each nearby segment.
+fn candidates(
+    location: &UserLocation,
+    step: &StepGeometry,
+    step_offset: f64,
+    min_distance_along_route: f64,
+) -> Vec<Candidate> {
+    let point = Point::from(*location);
+    let sigma = location.horizontal_accuracy.max(MIN_LOCATION_SIGMA);
+    // The course is noise when the user is (nearly) stopped
+    let course = location.course_over_ground.filter(|_| {
+        location
+            .speed
+            .map_or(true, |speed| speed.value >= MIN_SPEED_FOR_COURSE)
+    });
+
+    let mut candidates = Vec::new();
+    let mut segment_start = step_offset;
+    for (segment_index, segment) in step.linestring.lines().enumerate() {
+        let length = step.segment_lengths[segment_index];
+        let distance_to_segment = segment_start;
+        segment_start += length;
+        if length <= 0.0 || segment_start < min_distance_along_route {
+            continue;
+        }
+
+        let (Closest::Intersection(closest) | Closest::SinglePoint(closest)) =
+            segment.haversine_closest_point(&point)
+        else {
+            continue;
+        };
+        let distance_along_route =
+            distance_to_segment + Haversine::distance(segment.start_point(), closest);
+        // The user can't move backward, so positions behind the last match are moved up to it
+        let (point_on_route, distance_along_route) =
+            if distance_along_route < min_distance_along_route {
+                let fraction = (min_distance_along_route - distance_to_segment) / length;
+                (
+                    Point::from(segment.start + segment.delta() * fraction),
+                    min_distance_along_route,
+                )
+            } else {
+                (closest, distance_along_route)
+            };
+
+        let distance_from_location = Haversine::distance(point, point_on_route);
+        let course_deviation = course.map_or(0.0, |course| {
+            angular_difference(
+                f64::from(course.degrees),
+                step.segment_bearings[segment_index],
+            )
+        });
+        candidates.push(Candidate {
+            segment_index,
+            point: point_on_route,
+            distance_from_location,
+            distance_along_route,
+            log_likelihood: -0.5 * (distance_from_location / sigma).powi(2)
+                - 0.5 * (course_deviation / COURSE_SIGMA).powi(2),
+        });
+    }
+
+    let search_radius = SEARCH_RADIUS_SIGMAS * sigma;
+    if candidates
+        .iter()
+        .any(|candidate| candidate.distance_from_location <= search_radius)
+    {
+        candidates.retain(|candidate| candidate.distance_from_location <= search_radius);
+    } else if let Some(nearest) = candidates.iter().copied().min_by(|a, b| {
+        a.distance_from_location
+            .total_cmp(&b.distance_from_location)
+    }) {
+        candidates = vec![nearest];
+    }
+    candidates.sort_by(|a, b| b.log_likelihood.total_cmp(&a.log_likelihood));
+    candidates.truncate(MAX_CANDIDATES);
+    candidates
+}
+
+/// Finds the log probability of the most likely sequence of positions
+/// ending at each candidate of the final observation (the Viterbi algorithm).
+fn viterbi(observations: &[(UserLocation, Vec<MatchCandidate>)]) -> Vec<f64> {
+    let Some(((first_location, first_candidates), rest)) = observations.split_first() else {
+        return vec![];
+    };
+
+    let mut scores: Vec<f64> = first_candidates
+        .iter()
+        .map(|candidate| candidate.log_likelihood)
+        .collect();
+    let (mut previous_location, mut previous_candidates) = (first_location, first_candidates);
+    for (location, candidates) in rest {
+        let distance_between_locations =
+            Haversine::distance(Point::from(*previous_location), Point::from(*location));
+        scores = candidates
+            .iter()
+            .map(|candidate| {
+                let best_transition = previous_candidates
+                    .iter()
+                    .zip(&scores)
+                    .map(|(previous, score)| {
+                        score
+                            + transition_log_probability(
+                                candidate.distance_along_route - previous.distance_along_route,
+                                distance_between_locations,
+                            )
+                    })
+                    .fold(f64::NEG_INFINITY, f64::max);
+                candidate.log_likelihood + best_transition
+            })
+            .collect();
+        (previous_location, previous_candidates) = (location, candidates);
+    }
+    scores
+}
+
+/// The log probability of moving a distance along the route between two location updates.
+///
+/// Moving backward is impossible, and otherwise, the distance along the route
+/// should be about the same as the distance between the locations.
+fn transition_log_probability(distance_along_route: f64, distance_between_locations: f64) -> f64 {
+    if distance_along_route < 0.0 {
+        f64::NEG_INFINITY
+    } else {
+        -(distance_along_route - distance_between_locations).abs() / TRANSITION_BETA
+    }
+}
+
+/// The difference between two bearings, in degrees (from 0 to 180).
+fn angular_difference(bearing_1: f64, bearing_2: f64) -> f64 {
+    let difference = (bearing_1 - bearing_2).rem_euclid(360.0);
+    difference.min(360.0 - difference)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::snap_user_location_to_line;
+    use crate::models::CourseOverGround;
+    use crate::navigation_controller::test_helpers::gen_dummy_route_step;
+
+    #[cfg(all(feature = "std", not(feature = "web-time")))]
+    use std::time::SystemTime;
+    #[cfg(feature = "web-time")]
+    use web_time::SystemTime;
+
+    /// A step which heads north, loops around to the east,
+    /// and comes back west across itself.
+    fn self_intersecting_step() -> StepGeometry {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.0, 0.002);
+        step.geometry.extend([
+            GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.002,
+            },
+            GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.001,
+            },
+            GeographicCoordinate {
+                lng: -0.001,
+                lat: 0.001,
+            },
+        ]);
+        StepGeometry::new(&step)
+    }
+
+    fn location(lng: f64, lat: f64, course: f64) -> UserLocation {
+        UserLocation {
+            coordinates: GeographicCoordinate { lat, lng },
+            horizontal_accuracy: 5.0,
+            course_over_ground: Some(CourseOverGround::new(course, None)),
+            timestamp: SystemTime::now(),
+            speed: None,
+        }
+    }
+
+    #[test]
+    fn stay_on_current_segment_at_crossing() {
+        let step = self_intersecting_step();
+        let approaching = location(0.000_02, 0.000_8, 0.0);
+        // Slightly closer to the segment which crosses back later in the step
+        let at_crossing = location(0.000_04, 0.001_02, 0.0);
+
+        let snapped = snap_user_location_to_line(at_crossing, &step.linestring);
+        assert!((snapped.coordinates.lat - 0.001).abs() < 1e-9);
+
+        let first = match_location(approaching, &step, 0.0, &[], 3).expect("Expected a match");
+        assert_eq!(first.segment_index, 0);
+        let second = match_location(at_crossing, &step, 0.0, &first.recent_matches, 3)
+            .expect("Expected a match");
+        assert_eq!(second.segment_index, 0);
+        assert!(second.snapped_location.coordinates.lng.abs() < 1e-9);
+        assert_eq!(second.recent_matches.len(), 2);
+    }
+
+    #[test]
+    fn never_move_backward() {
+        let step = self_intersecting_step();
+        let first = match_location(location(0.0, 0.001, 0.0), &step, 100.0, &[], 3)
+            .expect("Expected a match");
+        let first_distance = first.recent_matches[0].distance_along_route;
+        assert!((first_distance - 100.0 - 111.2).abs() < 1.0);
+
+        // A location which is slightly behind (ex: GPS jitter while stopped)
+        let second = match_location(
+            location(0.0, 0.000_95, 0.0),
+            &step,
+            100.0,
+            &first.recent_matches,
+            3,
+        )
+        .expect("Expected a match");
+        assert_eq!(
+            second.recent_matches[1].distance_along_route,
+            first_distance
+        );
+        assert!((second.snapped_location.coordinates.lat - 0.001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keep_matches_within_window() {
+        let step = self_intersecting_step();
+        let recent_matches = (0..5).fold(vec![], |recent_matches, index| {
+            let location = location(0.0, f64::from(index) * 0.000_1, 0.0);
+            match_location(location, &step, 0.0, &recent_matches, 3)
+                .expect("Expected a match")
+                .recent_matches
+        });
+        assert_eq!(recent_matches.len(), 3);
+        assert!((recent_matches[2].location.coordinates.lat - 0.000_4).abs() < 1e-9);
+    }
+}
@@ -1,5 +1,8 @@
 //! The navigation state machine.
 
+pub(crate) mod geometry_index;
+mod location_filter;
+mod map_matching;
 pub mod models;
 
 #[cfg(test)]
@@ -7,17 +10,37 @@ pub(crate) mod test_helpers;
 
 use crate::{
     algorithms::{
-        advance_step, apply_snapped_course, calculate_trip_progress,
-        index_of_closest_segment_origin, should_advance_to_next_step, snap_user_location_to_line,
+        advance_step, blend_duration_remaining, deviation_from_line,
+        find_upcoming_events_with_geometry, fuse_heading, index_of_closest_segment_origin,
+        is_instruction_triggered, mean_bearing, should_advance_to_next_step_with_geometry,
+        snap_user_location_to_line,
+    },
+    deviation_detection::RouteDeviation,
+    models::{
+        CourseOverGround, Heading, Route, RouteStep, SegmentAnnotation, SpeedLimit,
+        SpokenInstruction, UserLocation, VisualInstruction, Waypoint,
     },
-    models::{Route, UserLocation},
 };
 use geo::{
     algorithm::{Distance, Haversine},
-    geometry::{LineString, Point},
+    geometry::Point,
+};
+use geometry_index::{RouteGeometryIndex, StepGeometry};
+use location_filter::{initial_estimate, update_estimate};
+use map_matching::match_location;
+use models::{
+    ArrivalDetection, CourseFiltering, EtaBlending, EventLookAhead, LocationEstimate,
+    LocationFiltering, LocationMatch, LocationSnapping, NavigationControllerConfig,
+    NavigationEvent, NavigationUpdate, ProgressSample, SpeedingDetection, StepAdvanceStatus,
+    StepLookAhead, TripBookkeeping, TripProgress, TripState, TripStatistics, UpcomingEvents,
 };
-use models::{NavigationControllerConfig, StepAdvanceStatus, TripState};
 use std::clone::Clone;
+use uuid::Uuid;
+
+#[cfg(all(feature = "std", not(feature = "web-time")))]
+use std::time::SystemTime;
+#[cfg(feature = "web-time")]
+use web_time::SystemTime;
 
 #[cfg(feature = "wasm-bindgen")]
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
@@ -26,12 +49,14 @@ use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 /// returning an updated state given inputs like user location.
 ///
 /// Notes for implementing a new platform:
-/// - A controller is bound to a single route; if you want recalculation, create a new instance.
+/// - A controller is bound to a single route; if you want recalculation, create a new instance
+///   for the new route and use [`NavigationController::get_rerouted_state`] to carry over the trip context.
 /// - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
 pub struct NavigationController {
     route: Route,
     config: NavigationControllerConfig,
+    geometry_index: RouteGeometryIndex,
 }
 
 #[cfg_attr(feature = "uniffi", uniffi::export)]
@@ -39,54 +64,148 @@ impl NavigationController {
     #[cfg_attr(feature = "uniffi", uniffi::constructor)]
     /// Create a navigation controller for a route and configuration.
     pub fn new(route: Route, config: NavigationControllerConfig) -> Self {
-        Self { route, config }
+        let geometry_index = RouteGeometryIndex::new(&route);
+        Self {
+            route,
+            config,
+            geometry_index,
+        }
     }
 
     /// Returns initial trip state as if the user had just started the route with no progress.
     pub fn get_initial_state(&self, location: UserLocation) -> TripState {
-        let remaining_steps = self.route.steps.clone();
-
-        let Some(current_route_step) = remaining_steps.first() else {
-            // Bail early; if we don't have any steps, this is a useless route
-            return TripState::Complete;
-        };
-
-        // TODO: We could move this to the Route struct or NavigationController directly to only calculate it once.
-        let current_step_linestring = current_route_step.get_linestring();
-        let (current_step_geometry_index, snapped_user_location) =
-            self.snap_user_to_line(location, &current_step_linestring);
-
-        let progress = calculate_trip_progress(
-            &snapped_user_location.into(),
-            &current_step_linestring,
-            &remaining_steps,
-        );
-        let deviation = self.config.route_deviation_tracking.check_route_deviation(
+        self.start_route(
             location,
-            &self.route,
-            current_route_step,
+            TripContext {
+                // The first waypoint is the origin of the trip, so we consider it visited.
+                completed_waypoints: self.route.waypoints.first().copied().into_iter().collect(),
+                statistics: TripStatistics::default(),
+                announced_utterance_ids: vec![],
+            },
+        )
+    }
+
+    /// Returns the initial trip state for this controller's route,
+    /// carrying over the trip context from a state that was produced while navigating a previous route.
+    ///
+    /// Use this instead of [`NavigationController::get_initial_state`] when the route of an in-progress trip
+    /// is replaced (ex: after rerouting, or when the user accepts an alternative route).
+    /// Create a new controller for the replacement route,
+    /// and pass it the last state from the controller for the route being replaced.
+    ///
+    /// The completed waypoints, the [`TripStatistics`] so far
+    /// (counting this as one more reroute),
+    /// and the utterance IDs of spoken instructions which have already been triggered
+    /// are carried over from `previous_state`.
+    /// Since routes are parsed with fresh utterance IDs, spoken instructions for the first step
+    /// of the new route are also considered triggered if an instruction with the same text
+    /// and trigger distance was triggered for the current step of the previous route.
+    /// Everything else (remaining steps and waypoints, progress, instructions, etc.)
+    /// is computed fresh for the new route.
+    ///
+    /// NOTE: The first waypoint of the replacement route is assumed to be
+    /// the user's location at the time of the route request,
+    /// and is not added to the list of completed waypoints.
+    ///
+    /// If `previous_state` is not [`TripState::Navigating`] or [`TripState::Arrived`],
+    /// this is equivalent to [`NavigationController::get_initial_state`].
+    pub fn get_rerouted_state(
+        &self,
+        location: UserLocation,
+        previous_state: &TripState,
+    ) -> TripState {
+        // Time since the previous update counts as off route
+        // if the user was off route as of that update.
+        let was_off_route = matches!(
+            previous_state,
+            TripState::Navigating {
+                deviation: RouteDeviation::OffRoute { .. },
+                ..
+            }
         );
-        let visual_instruction = current_route_step
-            .get_active_visual_instruction(progress.distance_to_next_maneuver)
-            .cloned();
-        let spoken_instruction = current_route_step
-            .get_current_spoken_instruction(progress.distance_to_next_maneuver)
-            .cloned();
+        match previous_state {
+            TripState::Navigating {
+                snapped_user_location,
+                remaining_steps,
+                completed_waypoints,
+                statistics,
+                bookkeeping,
+                ..
+            }
+            | TripState::Arrived {
+                snapped_user_location,
+                remaining_steps,
+                completed_waypoints,
+                statistics,
+                bookkeeping,
+                ..
+            } => self.start_route(
+                location,
+                TripContext {
+                    completed_waypoints: completed_waypoints.clone(),
+                    statistics: TripStatistics {
+                        reroute_count: statistics.reroute_count + 1,
+                        ..statistics.record_travel(
+                            0.0,
+                            elapsed_seconds(snapped_user_location, &location),
+                            None,
+                            was_off_route,
+                        )
+                    },
+                    announced_utterance_ids: self.carry_over_announcements(
+                        remaining_steps,
+                        &bookkeeping.announced_utterance_ids,
+                    ),
+                },
+            ),
+            TripState::Idle | TripState::Complete { .. } => self.get_initial_state(location),
+        }
+    }
 
-        let annotation_json = current_step_geometry_index
-            .and_then(|index| current_route_step.get_annotation_at_current_index(index));
+    /// Resumes navigation after the user has arrived at a waypoint.
+    ///
+    /// Navigation continues from the start of the next route leg.
+    /// If the user arrived at the final destination, the trip is complete.
+    ///
+    /// If `state` is not [`TripState::Arrived`], it is returned unchanged.
+    pub fn resume_navigation(&self, location: UserLocation, state: &TripState) -> TripState {
+        match state {
+            TripState::Arrived {
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                completed_waypoints,
+                statistics,
+                bookkeeping,
+                ..
+            } => {
+                // Skip whatever is left of the leg that the user arrived at the end of
+                let current_leg_index = remaining_steps.first().map(|step| step.leg_index);
+                let next_leg_steps: Vec<_> = remaining_steps
+                    .iter()
+                    .skip_while(|step| Some(step.leg_index) == current_leg_index)
+                    .cloned()
+                    .collect();
 
-        TripState::Navigating {
-            current_step_geometry_index,
-            snapped_user_location,
-            remaining_steps,
-            // Skip the first waypoint, as it is the current one
-            remaining_waypoints: self.route.waypoints.iter().skip(1).copied().collect(),
-            progress,
-            deviation,
-            visual_instruction,
-            spoken_instruction,
-            annotation_json,
+                self.start_navigating(
+                    location,
+                    next_leg_steps,
+                    remaining_waypoints.clone(),
+                    TripContext {
+                        completed_waypoints: completed_waypoints.clone(),
+                        statistics: statistics.record_travel(
+                            0.0,
+                            elapsed_seconds(snapped_user_location, &location),
+                            None,
+                            false,
+                        ),
+                        announced_utterance_ids: bookkeeping.announced_utterance_ids.clone(),
+                    },
+                )
+            }
+            TripState::Idle | TripState::Navigating { .. } | TripState::Complete { .. } => {
+                state.clone()
+            }
         }
     }
 
@@ -99,83 +218,36 @@ impl NavigationController {
     /// This method is takes the intermediate state (e.g. from `update_user_location`) and advances if necessary.
     /// As a result, you do not to re-calculate things like deviation or the snapped user location (search this file for usage of this function).
     pub fn advance_to_next_step(&self, state: &TripState) -> TripState {
+        self.advance_state(state.clone())
+    }
+
+    /// Returns up to `max_count` of the nearest incidents (and separately, congestion spans)
+    /// on the route ahead of the user, within `max_distance` meters.
+    ///
+    /// Distances are measured along the route from the snapped user location.
+    /// See [`find_upcoming_events`](crate::algorithms::find_upcoming_events) for details.
+    ///
+    /// Returns [`None`] if `state` is not [`TripState::Navigating`].
+    pub fn upcoming_events(
+        &self,
+        state: &TripState,
+        max_count: u16,
+        max_distance: f64,
+    ) -> Option<UpcomingEvents> {
         match state {
-            TripState::Idle => TripState::Idle,
             TripState::Navigating {
                 current_step_geometry_index,
                 snapped_user_location,
-                ref remaining_steps,
-                ref remaining_waypoints,
-                deviation,
+                remaining_steps,
                 ..
-            } => {
-                // FIXME: This logic is mostly duplicated below
-                let update = advance_step(remaining_steps);
-                match update {
-                    StepAdvanceStatus::Advanced {
-                        step: current_step,
-                        linestring,
-                    } => {
-                        // Apply the updates
-                        let mut remaining_steps = remaining_steps.clone();
-                        remaining_steps.remove(0);
-
-                        // Update remaining waypoints
-                        let should_advance_waypoint = if let Some(waypoint) =
-                            remaining_waypoints.first()
-                        {
-                            let current_location: Point = snapped_user_location.coordinates.into();
-                            let next_waypoint: Point = waypoint.coordinate.into();
-                            // TODO: This is just a hard-coded threshold for the time being.
-                            // More sophisticated behavior will take some time and use cases, so punting on this for now.
-                            Haversine::distance(current_location, next_waypoint) < 100.0
-                        } else {
-                            false
-                        };
-
-                        let remaining_waypoints = if should_advance_waypoint {
-                            let mut remaining_waypoints = remaining_waypoints.clone();
-                            remaining_waypoints.remove(0);
-                            remaining_waypoints
-                        } else {
-                            remaining_waypoints.clone()
-                        };
-
-                        let progress = calculate_trip_progress(
-                            &(*snapped_user_location).into(),
-                            &linestring,
-                            &remaining_steps,
-                        );
-
-                        let visual_instruction = current_step
-                            .get_active_visual_instruction(progress.distance_to_next_maneuver)
-                            .cloned();
-                        let spoken_instruction = current_step
-                            .get_current_spoken_instruction(progress.distance_to_next_maneuver)
-                            .cloned();
-                        let annotation_json = current_step_geometry_index
-                            .and_then(|index| current_step.get_annotation_at_current_index(index));
-
-                        TripState::Navigating {
-                            current_step_geometry_index: *current_step_geometry_index,
-                            snapped_user_location: *snapped_user_location,
-                            remaining_steps,
-                            remaining_waypoints,
-                            progress,
-                            // NOTE: We *can't* run deviation calculations in this method,
-                            // as it requires a non-snapped user location.
-                            deviation: *deviation,
-                            visual_instruction,
-                            spoken_instruction,
-                            annotation_json,
-                        }
-                    }
-                    StepAdvanceStatus::EndOfRoute => TripState::Complete,
-                }
-            }
-            // It's tempting to throw an error here, since the caller should know better, but
-            // a mistake like this is technically harmless.
-            TripState::Complete => TripState::Complete,
+            } => Some(find_upcoming_events_with_geometry(
+                &(*snapped_user_location).into(),
+                *current_step_geometry_index,
+                self.geometry_index.step_geometries(remaining_steps),
+                max_count.into(),
+                max_distance,
+            )),
+            TripState::Idle | TripState::Arrived { .. } | TripState::Complete { .. } => None,
         }
     }
 
@@ -189,247 +261,1654 @@ impl NavigationController {
         match state {
             TripState::Idle => TripState::Idle,
             TripState::Navigating {
+                snapped_user_location: previous_snapped_user_location,
                 ref remaining_steps,
                 ref remaining_waypoints,
                 deviation,
-                visual_instruction,
-                spoken_instruction,
-                annotation_json,
+                ref completed_waypoints,
+                statistics,
+                ref bookkeeping,
                 ..
             } => {
-                let Some(current_step) = remaining_steps.first() else {
-                    return TripState::Complete;
+                // Clean up the location before anything else sees it
+                let Some((location, location_estimate)) =
+                    self.filter_location(location, bookkeeping.location_estimate.as_ref())
+                else {
+                    return state.clone();
+                };
+                let reported_course_over_ground = location.course_over_ground;
+                let location = self.fuse_heading(location, bookkeeping.heading.as_ref());
+
+                // Jump directly to an upcoming step if the user is clearly on it
+                let skipped_steps = self.steps_to_skip(&location, remaining_steps);
+                let remaining_steps = &remaining_steps[skipped_steps..];
+
+                let Some(current_step_geometry) =
+                    self.geometry_index.step_geometry(remaining_steps)
+                else {
+                    return TripState::Complete {
+                        summary: *statistics,
+                    };
                 };
 
                 //
                 // Core navigation logic
                 //
 
-                // Find the nearest point on the route line
-                let current_step_linestring = current_step.get_linestring();
-                let (current_step_geometry_index, snapped_user_location) =
-                    self.snap_user_to_line(location, &current_step_linestring);
+                // Find the user's position on the route line
+                let (current_step_geometry_index, snapped_user_location, recent_matches) = self
+                    .snap_user_to_route(
+                        location,
+                        remaining_steps,
+                        &current_step_geometry,
+                        &bookkeeping.recent_matches,
+                    );
+
+                let (remaining_waypoints, completed_waypoints) = if skipped_steps > 0 {
+                    self.advance_waypoints(
+                        &snapped_user_location,
+                        remaining_steps,
+                        remaining_waypoints,
+                        completed_waypoints,
+                    )
+                } else {
+                    (remaining_waypoints.clone(), completed_waypoints.clone())
+                };
 
-                let progress = calculate_trip_progress(
+                let progress = self.geometry_index.trip_progress(
                     &snapped_user_location.into(),
-                    &current_step_linestring,
+                    &current_step_geometry,
+                    current_step_geometry_index,
                     remaining_steps,
                 );
                 let intermediate_state = TripState::Navigating {
                     current_step_geometry_index,
                     snapped_user_location,
-                    remaining_steps: remaining_steps.clone(),
-                    remaining_waypoints: remaining_waypoints.clone(),
+                    // This is the only copy of the steps made during the update;
+                    // advancing to the next step reuses it.
+                    remaining_steps: remaining_steps.to_vec(),
+                    remaining_waypoints,
                     progress,
                     deviation: *deviation,
-                    visual_instruction: visual_instruction.clone(),
-                    spoken_instruction: spoken_instruction.clone(),
-                    annotation_json: annotation_json.clone(),
+                    // These are recalculated when the update is finished (after any step advance)
+                    visual_instruction: None,
+                    spoken_instruction: None,
+                    annotation: None,
+                    speed_limit: None,
+                    is_speeding: false,
+                    is_location_estimated: false,
+                    upcoming_events: None,
+                    completed_waypoints,
+                    statistics: *statistics,
+                    bookkeeping: TripBookkeeping {
+                        recent_matches,
+                        location_estimate,
+                        reported_course_over_ground,
+                        ..bookkeeping.clone()
+                    },
                 };
 
-                match if should_advance_to_next_step(
-                    &current_step_linestring,
-                    remaining_steps.get(1),
+                let updated_state = if self.should_advance_to_next_step(
                     &location,
-                    self.config.step_advance,
+                    &current_step_geometry,
+                    remaining_steps,
                 ) {
                     // Advance to the next step
-                    self.advance_to_next_step(&intermediate_state)
+                    self.advance_state(intermediate_state)
                 } else {
                     // Do not advance
                     intermediate_state
-                } {
-                    TripState::Idle => TripState::Idle,
-                    TripState::Navigating {
-                        snapped_user_location,
-                        remaining_steps,
-                        remaining_waypoints,
-                        progress,
-                        // Explicitly recalculated
-                        current_step_geometry_index: _,
-                        deviation: _,
-                        visual_instruction: _,
-                        spoken_instruction: _,
-                        annotation_json: _,
-                    } => {
-                        // Recalculate deviation. This happens later, as the current step may have changed.
-                        // The distance to the next maneuver will be updated by advance_to_next_step if needed.
-                        let current_step = remaining_steps
-                            .first()
-                            .expect("Invalid state: navigating with zero remaining steps.");
-                        let deviation = self.config.route_deviation_tracking.check_route_deviation(
-                            location,
-                            &self.route,
-                            current_step,
-                        );
+                };
 
-                        // we need to update the geometry index, since the step has changed
-                        let (updated_current_step_geometry_index, updated_snapped_user_location) =
-                            if let Some(current_route_step) = remaining_steps.first() {
-                                let current_step_linestring = current_route_step.get_linestring();
-                                self.snap_user_to_line(
-                                    snapped_user_location,
-                                    &current_step_linestring,
-                                )
-                            } else {
-                                (current_step_geometry_index, snapped_user_location)
-                            };
-
-                        let visual_instruction = current_step
-                            .get_active_visual_instruction(progress.distance_to_next_maneuver)
-                            .cloned();
-                        let spoken_instruction = current_step
-                            .get_current_spoken_instruction(progress.distance_to_next_maneuver)
-                            .cloned();
-
-                        let annotation_json = current_step_geometry_index
-                            .and_then(|index| current_step.get_annotation_at_current_index(index));
-
-                        TripState::Navigating {
-                            current_step_geometry_index: updated_current_step_geometry_index,
-                            snapped_user_location: updated_snapped_user_location,
-                            remaining_steps,
-                            remaining_waypoints,
-                            progress,
-                            deviation,
-                            visual_instruction,
-                            spoken_instruction,
-                            annotation_json,
-                        }
-                    }
-                    TripState::Complete => TripState::Complete,
-                }
+                let updated_state = self.finish_location_update(
+                    location,
+                    previous_snapped_user_location,
+                    &bookkeeping.announced_utterance_ids,
+                    updated_state,
+                );
+                self.detect_arrival(location, updated_state)
+            }
+            // The user needs to resume navigation before the location is tracked again,
+            // and a complete trip is a terminal state.
+            TripState::Arrived { .. } | TripState::Complete { .. } => state.clone(),
+        }
+    }
+
+    /// Updates the user's current location (see [`NavigationController::update_user_location`]),
+    /// and reports the [`NavigationEvent`]s which occurred as a result.
+    pub fn update_user_location_with_events(
+        &self,
+        location: UserLocation,
+        state: &TripState,
+    ) -> NavigationUpdate {
+        let trip_state = self.update_user_location(location, state);
+        let events = self.navigation_events(state, &trip_state);
+        NavigationUpdate { trip_state, events }
+    }
+
+    /// Updates the compass heading of the user's device.
+    ///
+    /// With [`CourseFiltering::FuseHeading`], the course of the snapped user location
+    /// is updated right away, so that it stays current while the user is stationary.
+    /// Otherwise, the heading is only recorded in the trip state's bookkeeping.
+    ///
+    /// Headings older than the current one are ignored.
+    pub fn update_heading(&self, heading: Heading, state: &TripState) -> TripState {
+        let mut state = state.clone();
+        if let TripState::Navigating {
+            snapped_user_location,
+            bookkeeping,
+            ..
+        } = &mut state
+        {
+            if bookkeeping
+                .heading
+                .is_some_and(|current| current.timestamp > heading.timestamp)
+            {
+                return state;
+            }
+            bookkeeping.heading = Some(heading);
+            if matches!(
+                self.config.snapped_location_course_filtering,
+                CourseFiltering::FuseHeading { .. }
+            ) {
+                *snapped_user_location = self.fuse_heading(
+                    UserLocation {
+                        course_over_ground: bookkeeping.reported_course_over_ground,
+                        ..*snapped_user_location
+                    },
+                    Some(&heading),
+                );
             }
-            // Terminal state
-            TripState::Complete => TripState::Complete,
         }
+        state
+    }
+
+    /// Predicts the trip state at a later time by dead reckoning,
+    /// for when location updates stop or degrade (ex: in tunnels or urban canyons).
+    ///
+    /// The user is assumed to keep moving along the route from the snapped location in `state`
+    /// at their last known speed
+    /// (or the speed the routing engine expects on the current step, if the user's speed is unknown).
+    /// Everything which follows from the location (progress, instructions, the speed limit, etc.)
+    /// is updated for the predicted location, and the resulting state has `is_location_estimated` set.
+    /// Spoken instructions are surfaced as usual, but not recorded as announced
+    /// (the utterance ID stays the same, so it can be used to avoid repeating them).
+    ///
+    /// The rest of the trip is left alone:
+    /// the current step never advances (the prediction stops at the end of it),
+    /// and the statistics and bookkeeping are unchanged,
+    /// as only real location updates should affect these.
+    /// Call this frequently (ex: every second) while location updates are unavailable.
+    /// Predictions can be chained by passing a predicted state back in.
+    /// The next real location update (see [`NavigationController::update_user_location`])
+    /// replaces the estimate.
+    ///
+    /// The state is returned unchanged if it is not [`TripState::Navigating`],
+    /// if the user is off route, if `timestamp` is not after the last location,
+    /// or if there is no speed to go on.
+    pub fn predict_state_at(&self, state: &TripState, timestamp: SystemTime) -> TripState {
+        let TripState::Navigating {
+            current_step_geometry_index,
+            snapped_user_location,
+            ref remaining_steps,
+            deviation: RouteDeviation::NoDeviation,
+            ref bookkeeping,
+            ..
+        } = state
+        else {
+            return state.clone();
+        };
+        let (Some(current_step), Some(current_step_geometry)) = (
+            remaining_steps.first(),
+            self.geometry_index.step_geometry(remaining_steps),
+        ) else {
+            return state.clone();
+        };
+        let Some((location, geometry_index)) = predict_location(
+            snapped_user_location,
+            *current_step_geometry_index,
+            remaining_steps,
+            &current_step_geometry,
+            timestamp,
+        ) else {
+            return state.clone();
+        };
+
+        let progress = self.blend_progress(
+            self.geometry_index.trip_progress(
+                &location.into(),
+                &current_step_geometry,
+                Some(geometry_index),
+                remaining_steps,
+            ),
+            &bookkeeping.progress_samples,
+        );
+        let (visual_instruction, spoken_instruction) = self.triggered_instructions(
+            current_step,
+            progress.distance_to_next_maneuver,
+            &location,
+            &bookkeeping.announced_utterance_ids,
+        );
+        let annotation = current_step.get_annotation_at_current_index(geometry_index);
+        let (speed_limit, is_speeding) = self.speed_limit_status(annotation.as_ref(), &location);
+        let upcoming_events = self.look_ahead(&location, Some(geometry_index), remaining_steps);
+
+        let mut predicted_state = state.clone();
+        if let TripState::Navigating {
+            current_step_geometry_index,
+            snapped_user_location,
+            progress: predicted_progress,
+            visual_instruction: predicted_visual_instruction,
+            spoken_instruction: predicted_spoken_instruction,
+            annotation: predicted_annotation,
+            speed_limit: predicted_speed_limit,
+            is_speeding: predicted_is_speeding,
+            is_location_estimated,
+            upcoming_events: predicted_upcoming_events,
+            ..
+        } = &mut predicted_state
+        {
+            *current_step_geometry_index = Some(geometry_index);
+            *snapped_user_location = location;
+            *predicted_progress = progress;
+            *predicted_visual_instruction = visual_instruction;
+            *predicted_spoken_instruction = spoken_instruction;
+            *predicted_annotation = annotation;
+            *predicted_speed_limit = speed_limit;
+            *predicted_is_speeding = is_speeding;
+            *is_location_estimated = true;
+            *predicted_upcoming_events = upcoming_events;
+        }
+        predicted_state
     }
 }
 
 /// Shared functionality for the navigation controller that is not exported by uniFFI.
 impl NavigationController {
-    /// Snaps the user's location to the route line and updates the user's course if necessary.
+    /// Determines whether to advance to the next step, according to the configured [`StepAdvanceMode`](models::StepAdvanceMode).
     ///
-    /// This bundles all work related to snapping the user's location to the route line and is not intended to be exported.
-    ///
-    /// Returns the index of the closest segment origin to the snapped user location as well as the snapped user location.
-    fn snap_user_to_line(
+    /// NOTE: The [`UserLocation`] should *not* be snapped.
+    fn should_advance_to_next_step(
         &self,
-        location: UserLocation,
-        line: &LineString,
-    ) -> (Option<u64>, UserLocation) {
-        // Snap the user's latitude and longitude to the line.
-        let snapped_user_location = snap_user_location_to_line(location, line);
+        location: &UserLocation,
+        current_step_geometry: &StepGeometry,
+        remaining_steps: &[RouteStep],
+    ) -> bool {
+        let next_step_geometry = remaining_steps
+            .get(1..)
+            .and_then(|steps| self.geometry_index.step_geometry(steps));
+        should_advance_to_next_step_with_geometry(
+            &current_step_geometry.linestring,
+            remaining_steps.get(1).zip(
+                next_step_geometry
+                    .as_ref()
+                    .map(|geometry| &geometry.linestring),
+            ),
+            location,
+            self.config.step_advance,
+        )
+    }
 
-        // Get the index of the closest segment origin to the snapped user location.
-        let current_step_geometry_index =
-            index_of_closest_segment_origin(snapped_user_location, line);
+    /// Advances navigation to the next step (see [`NavigationController::advance_to_next_step`]).
+    ///
+    /// This takes ownership of the state, so that the remaining steps can be reused rather than copied.
+    fn advance_state(&self, state: TripState) -> TripState {
+        let TripState::Navigating {
+            ref remaining_steps,
+            statistics,
+            ..
+        } = state
+        else {
+            // The user needs to resume navigation before advancing any further.
+            // For a complete trip, it's tempting to throw an error here,
+            // since the caller should know better, but a mistake like this is technically harmless.
+            return state;
+        };
 
-        // Snap the user's course to the line if the configuration specifies it.
-        let snapped_with_course: UserLocation = match &self.config.snapped_location_course_filtering
-        {
-            models::CourseFiltering::SnapToRoute => {
-                apply_snapped_course(snapped_user_location, current_step_geometry_index, line)
+        // FIXME: This logic is mostly duplicated below
+        let current_step = match advance_step(remaining_steps) {
+            StepAdvanceStatus::Advanced { step: current_step }
+                if self.should_arrive_when_passing(remaining_steps, &current_step) =>
+            {
+                return self.arrive(&state);
             }
-            models::CourseFiltering::Raw => snapped_user_location,
+            StepAdvanceStatus::Advanced { step: current_step } => current_step,
+            StepAdvanceStatus::EndOfRoute => {
+                return match self.config.arrival_detection {
+                    ArrivalDetection::Disabled => TripState::Complete {
+                        summary: statistics,
+                    },
+                    ArrivalDetection::Enabled { .. } => self.arrive(&state),
+                };
+            }
+        };
+        let TripState::Navigating {
+            current_step_geometry_index,
+            snapped_user_location,
+            mut remaining_steps,
+            remaining_waypoints,
+            deviation,
+            completed_waypoints,
+            statistics,
+            bookkeeping,
+            is_location_estimated,
+            ..
+        } = state
+        else {
+            return state;
         };
 
-        (current_step_geometry_index, snapped_with_course)
-    }
-}
+        // Apply the updates
+        remaining_steps.remove(0);
 
-/// JavaScript wrapper for `NavigationController`.
-#[cfg(feature = "wasm-bindgen")]
-#[wasm_bindgen(js_name = NavigationController)]
-pub struct JsNavigationController(NavigationController);
+        // Update remaining waypoints
+        let (remaining_waypoints, completed_waypoints) = self.advance_waypoints(
+            &snapped_user_location,
+            &remaining_steps,
+            &remaining_waypoints,
+            &completed_waypoints,
+        );
 
-#[cfg(feature = "wasm-bindgen")]
-#[wasm_bindgen(js_class = NavigationController)]
-impl JsNavigationController {
-    #[wasm_bindgen(constructor)]
-    pub fn new(route: JsValue, config: JsValue) -> Result<JsNavigationController, JsValue> {
-        let route: Route = serde_wasm_bindgen::from_value(route)?;
-        let config: NavigationControllerConfig = serde_wasm_bindgen::from_value(config)?;
+        let Some(current_step_geometry) = self.geometry_index.step_geometry(&remaining_steps)
+        else {
+            // Unreachable in practice, since we just advanced to this step
+            return TripState::Complete {
+                summary: statistics,
+            };
+        };
+        let progress = self.blend_progress(
+            self.geometry_index.trip_progress(
+                &snapped_user_location.into(),
+                &current_step_geometry,
+                // The geometry index is for the previous step
+                None,
+                &remaining_steps,
+            ),
+            &bookkeeping.progress_samples,
+        );
 
-        Ok(JsNavigationController(NavigationController::new(
-            route, config,
-        )))
+        let (visual_instruction, spoken_instruction) = self.triggered_instructions(
+            &current_step,
+            progress.distance_to_next_maneuver,
+            &snapped_user_location,
+            &bookkeeping.announced_utterance_ids,
+        );
+        // The user is at the start of the new step
+        let annotation = current_step.get_annotation_at_current_index(0);
+        let (speed_limit, is_speeding) =
+            self.speed_limit_status(annotation.as_ref(), &snapped_user_location);
+        let upcoming_events = self.look_ahead(&snapped_user_location, None, &remaining_steps);
+
+        TripState::Navigating {
+            current_step_geometry_index,
+            snapped_user_location,
+            remaining_steps,
+            remaining_waypoints,
+            progress,
+            // NOTE: We *can't* run deviation calculations in this method,
+            // as it requires a non-snapped user location.
+            deviation,
+            bookkeeping: bookkeeping.record_announcement(spoken_instruction.as_ref()),
+            visual_instruction,
+            spoken_instruction,
+            annotation,
+            speed_limit,
+            is_speeding,
+            is_location_estimated,
+            upcoming_events,
+            completed_waypoints,
+            statistics,
+        }
     }
 
-    #[wasm_bindgen(js_name = getInitialState)]
-    pub fn get_initial_state(&self, location: JsValue) -> Result<JsValue, JsValue> {
-        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+    /// Determines how many steps to skip ahead, according to the configured [`StepLookAhead`].
+    ///
+    /// Returns zero when the user is still close to the current step,
+    /// or when none of the upcoming steps in the window are a good match.
+    ///
+    /// NOTE: The [`UserLocation`] should *not* be snapped.
+    fn steps_to_skip(&self, location: &UserLocation, remaining_steps: &[RouteStep]) -> usize {
+        let StepLookAhead::Window {
+            max_steps,
+            max_distance_from_step,
+            minimum_horizontal_accuracy,
+        } = self.config.step_look_ahead
+        else {
+            return 0;
+        };
 
-        serde_wasm_bindgen::to_value(&self.0.get_initial_state(location))
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
-    }
+        if location.horizontal_accuracy > f64::from(minimum_horizontal_accuracy) {
+            return 0;
+        }
 
-    pub fn advance_to_next_step(&self, state: JsValue) -> Result<JsValue, JsValue> {
-        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+        let point = Point::from(*location);
+        let max_distance = f64::from(max_distance_from_step);
+        let distance_from_first_step = |steps: &[RouteStep]| {
+            self.geometry_index
+                .step_geometry(steps)
+                .and_then(|geometry| deviation_from_line(&point, &geometry.linestring))
+        };
 
-        serde_wasm_bindgen::to_value(&self.0.advance_to_next_step(&state))
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+        // Stay on the current step as long as the user is close to it.
+        // This is what keeps us from skipping ahead where the route intersects itself.
+        if distance_from_first_step(remaining_steps)
+            .map_or(true, |distance| distance <= max_distance)
+        {
+            return 0;
+        }
+
+        // Never skip past the end of the current leg.
+        // Crossing into the next leg always takes a regular step advance,
+        // so that waypoints are completed (and arrival detected) as usual.
+        let steps_in_leg = remaining_steps
+            .iter()
+            .take_while(|step| step.leg_index == remaining_steps[0].leg_index)
+            .count();
+        let last_offset = usize::from(max_steps).min(steps_in_leg.saturating_sub(1));
+        (1..=last_offset)
+            .filter_map(|offset| {
+                distance_from_first_step(&remaining_steps[offset..])
+                    .map(|distance| (offset, distance))
+            })
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by(|(_, distance_1), (_, distance_2)| distance_1.total_cmp(distance_2))
+            .map_or(0, |(offset, _)| offset)
     }
 
-    #[wasm_bindgen(js_name = updateUserLocation)]
-    pub fn update_user_location(
+    /// Completes a location update after the (possible) step advance,
+    /// recalculating everything which depends on the current step
+    /// and accumulating the trip statistics since the previous update.
+    ///
+    /// Only spoken instructions which were not announced before the update
+    /// (as given by `previous_announced_utterance_ids`) are surfaced.
+    fn finish_location_update(
         &self,
-        location: JsValue,
-        state: JsValue,
-    ) -> Result<JsValue, JsValue> {
-        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
-        let state: TripState = serde_wasm_bindgen::from_value(state)?;
-
-        serde_wasm_bindgen::to_value(&self.0.update_user_location(location, &state))
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
-    }
-}
+        location: UserLocation,
+        previous_snapped_user_location: &UserLocation,
+        previous_announced_utterance_ids: &[Uuid],
+        state: TripState,
+    ) -> TripState {
+        match state {
+            TripState::Idle => TripState::Idle,
+            TripState::Navigating {
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                progress,
+                completed_waypoints,
+                statistics,
+                bookkeeping,
+                is_location_estimated,
+                // Explicitly recalculated
+                current_step_geometry_index: _,
+                deviation: previous_deviation,
+                visual_instruction: _,
+                spoken_instruction: _,
+                annotation: _,
+                speed_limit: _,
+                is_speeding: _,
+                upcoming_events: _,
+            } => {
+                // Recalculate deviation. This happens later, as the current step may have changed.
+                // The distance to the next maneuver will be updated by advance_to_next_step if needed.
+                let current_step = remaining_steps
+                    .first()
+                    .expect("Invalid state: navigating with zero remaining steps.");
+                let current_step_geometry = self
+                    .geometry_index
+                    .step_geometry(&remaining_steps)
+                    .expect("Invalid state: navigating with zero remaining steps.");
+                let deviation = self.config.route_deviation_tracking.check_route_deviation(
+                    location,
+                    &self.route,
+                    current_step,
+                    &current_step_geometry.linestring,
+                );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::deviation_detection::{RouteDeviation, RouteDeviationTracking};
-    use crate::navigation_controller::models::{
-        CourseFiltering, SpecialAdvanceConditions, StepAdvanceMode,
-    };
-    use crate::navigation_controller::test_helpers::{
-        get_extended_route, get_self_intersecting_route,
-    };
-    use crate::simulation::{
-        advance_location_simulation, location_simulation_from_route, LocationBias,
-    };
+                // we need to update the geometry index, since the step has changed
+                let (updated_current_step_geometry_index, updated_snapped_user_location) = self
+                    .snap_user_to_line(
+                        snapped_user_location,
+                        &remaining_steps,
+                        &current_step_geometry,
+                    );
 
-    fn test_full_route_state_snapshot(
-        route: Route,
-        step_advance: StepAdvanceMode,
-    ) -> Vec<TripState> {
-        let mut simulation_state =
-            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
-                .expect("Unable to create simulation");
+                let (visual_instruction, spoken_instruction) = self.triggered_instructions(
+                    current_step,
+                    progress.distance_to_next_maneuver,
+                    &location,
+                    previous_announced_utterance_ids,
+                );
 
-        let controller = NavigationController::new(
-            route,
-            NavigationControllerConfig {
-                // NOTE: We will use a few varieties here via parameterized testing,
-                // but the point of this test is *not* testing the thresholds.
-                step_advance,
-                // Careful setup: if the user is ever off the route
-                // (ex: because of an improper automatic step advance),
-                // we want to know about it.
-                route_deviation_tracking: RouteDeviationTracking::StaticThreshold {
-                    minimum_horizontal_accuracy: 0,
-                    max_acceptable_deviation: 0.0,
-                },
-                snapped_location_course_filtering: CourseFiltering::Raw,
-            },
-        );
+                let annotation = updated_current_step_geometry_index
+                    .and_then(|index| current_step.get_annotation_at_current_index(index));
+                let (speed_limit, is_speeding) =
+                    self.speed_limit_status(annotation.as_ref(), &location);
 
-        let mut state = controller.get_initial_state(simulation_state.current_location);
-        let mut states = vec![state.clone()];
-        loop {
-            let new_simulation_state = advance_location_simulation(&simulation_state);
-            let new_state =
-                controller.update_user_location(new_simulation_state.current_location, &state);
+                // Time since the previous update counts as off route
+                // if the user was off route as of that update.
+                let statistics = statistics.record_travel(
+                    Haversine::distance(
+                        Point::from(*previous_snapped_user_location),
+                        Point::from(updated_snapped_user_location),
+                    ),
+                    elapsed_seconds(previous_snapped_user_location, &location),
+                    location.speed.map(|speed| speed.value),
+                    matches!(previous_deviation, RouteDeviation::OffRoute { .. }),
+                );
+                let bookkeeping = TripBookkeeping {
+                    progress_samples: self.record_progress_sample(
+                        &bookkeeping.progress_samples,
+                        &statistics,
+                        &progress,
+                    ),
+                    ..bookkeeping
+                };
+                let progress = self.blend_progress(progress, &bookkeeping.progress_samples);
+                let upcoming_events = self.look_ahead(
+                    &updated_snapped_user_location,
+                    updated_current_step_geometry_index,
+                    &remaining_steps,
+                );
+
+                TripState::Navigating {
+                    current_step_geometry_index: updated_current_step_geometry_index,
+                    snapped_user_location: updated_snapped_user_location,
+                    remaining_steps,
+                    remaining_waypoints,
+                    progress,
+                    deviation,
+                    bookkeeping: bookkeeping.record_announcement(spoken_instruction.as_ref()),
+                    visual_instruction,
+                    spoken_instruction,
+                    annotation,
+                    speed_limit,
+                    is_speeding,
+                    is_location_estimated,
+                    upcoming_events,
+                    completed_waypoints,
+                    statistics,
+                }
+            }
+            TripState::Arrived { .. } | TripState::Complete { .. } => state,
+        }
+    }
+
+    /// Returns the announced utterance IDs to carry over to this controller's route,
+    /// given the remaining steps of the previous route.
+    ///
+    /// Spoken instructions for the first step of this route are matched against those which were
+    /// announced for the current step of the previous route (ex: when the replacement route leads
+    /// to the same maneuver), so that they are not announced again.
+    fn carry_over_announcements(
+        &self,
+        previous_steps: &[RouteStep],
+        announced_utterance_ids: &[Uuid],
+    ) -> Vec<Uuid> {
+        let announced_instructions: Vec<_> = previous_steps
+            .first()
+            .into_iter()
+            .flat_map(|step| &step.spoken_instructions)
+            .filter(|instruction| announced_utterance_ids.contains(&instruction.utterance_id))
+            .collect();
+
+        let mut announced_utterance_ids = announced_utterance_ids.to_vec();
+        announced_utterance_ids.extend(
+            self.route
+                .steps
+                .first()
+                .into_iter()
+                .flat_map(|step| &step.spoken_instructions)
+                .filter(|instruction| {
+                    announced_instructions
+                        .iter()
+                        .any(|announced| is_same_announcement(announced, instruction))
+                })
+                .map(|instruction| instruction.utterance_id),
+        );
+        announced_utterance_ids
+    }
+
+    /// Builds the state for the start of this controller's route,
+    /// given the context of the trip so far.
+    fn start_route(&self, location: UserLocation, context: TripContext) -> TripState {
+        self.start_navigating(
+            location,
+            self.route.steps.clone(),
+            // Skip the first waypoint, as it is the current one
+            self.route.waypoints.iter().skip(1).copied().collect(),
+            context,
+        )
+    }
+
+    /// Builds the state for navigating the remaining steps of this controller's route,
+    /// given the context of the trip so far.
+    fn start_navigating(
+        &self,
+        location: UserLocation,
+        remaining_steps: Vec<RouteStep>,
+        remaining_waypoints: Vec<Waypoint>,
+        context: TripContext,
+    ) -> TripState {
+        let (Some(current_route_step), Some(current_step_geometry)) = (
+            remaining_steps.first(),
+            self.geometry_index.step_geometry(&remaining_steps),
+        ) else {
+            // Bail early; if we don't have any steps, this is a useless route
+            return TripState::Complete {
+                summary: context.statistics,
+            };
+        };
+
+        let (current_step_geometry_index, snapped_user_location, recent_matches) =
+            self.snap_user_to_route(location, &remaining_steps, &current_step_geometry, &[]);
+
+        let progress = self.geometry_index.trip_progress(
+            &snapped_user_location.into(),
+            &current_step_geometry,
+            current_step_geometry_index,
+            &remaining_steps,
+        );
+        let deviation = self.config.route_deviation_tracking.check_route_deviation(
+            location,
+            &self.route,
+            current_route_step,
+            &current_step_geometry.linestring,
+        );
+        let (visual_instruction, spoken_instruction) = self.triggered_instructions(
+            current_route_step,
+            progress.distance_to_next_maneuver,
+            &location,
+            &context.announced_utterance_ids,
+        );
+
+        let annotation = current_step_geometry_index
+            .and_then(|index| current_route_step.get_annotation_at_current_index(index));
+        let (speed_limit, is_speeding) = self.speed_limit_status(annotation.as_ref(), &location);
+        let progress_samples = self.record_progress_sample(&[], &context.statistics, &progress);
+        let upcoming_events = self.look_ahead(
+            &snapped_user_location,
+            current_step_geometry_index,
+            &remaining_steps,
+        );
+
+        TripState::Navigating {
+            current_step_geometry_index,
+            snapped_user_location,
+            remaining_steps,
+            remaining_waypoints,
+            progress,
+            deviation,
+            bookkeeping: TripBookkeeping {
+                announced_utterance_ids: context.announced_utterance_ids,
+                progress_samples,
+                recent_matches,
+                location_estimate: self
+                    .filter_location(location, None)
+                    .and_then(|(_, estimate)| estimate),
+                reported_course_over_ground: location.course_over_ground,
+                ..TripBookkeeping::default()
+            }
+            .record_announcement(spoken_instruction.as_ref()),
+            visual_instruction,
+            spoken_instruction,
+            annotation,
+            speed_limit,
+            is_speeding,
+            is_location_estimated: false,
+            upcoming_events,
+            completed_waypoints: context.completed_waypoints,
+            statistics: context.statistics,
+        }
+    }
+
+    /// Cleans up a raw location update according to the configured [`LocationFiltering`].
+    ///
+    /// Returns the location to use and the updated estimate (if filtering is enabled),
+    /// or [`None`] if the location should be rejected.
+    fn filter_location(
+        &self,
+        location: UserLocation,
+        estimate: Option<&LocationEstimate>,
+    ) -> Option<(UserLocation, Option<LocationEstimate>)> {
+        let LocationFiltering::AlphaBeta {
+            alpha,
+            beta,
+            max_speed,
+        } = self.config.location_filtering
+        else {
+            return Some((location, None));
+        };
+
+        let estimate = match estimate {
+            Some(estimate) => update_estimate(location, estimate, alpha, beta, max_speed)?,
+            None => initial_estimate(location),
+        };
+        Some((estimate.location, Some(estimate)))
+    }
+
+    /// Fuses the compass heading into the course of a location for [`CourseFiltering::FuseHeading`].
+    ///
+    /// Headings which are too old relative to the location are ignored.
+    /// The location is returned unchanged with other course filtering modes.
+    fn fuse_heading(&self, location: UserLocation, heading: Option<&Heading>) -> UserLocation {
+        let CourseFiltering::FuseHeading {
+            heading_only_speed,
+            course_only_speed,
+            max_heading_age,
+        } = self.config.snapped_location_course_filtering
+        else {
+            return location;
+        };
+
+        // A heading newer than the location is fine
+        let heading = heading.filter(|heading| {
+            location
+                .timestamp
+                .duration_since(heading.timestamp)
+                .map_or(true, |age| age.as_secs_f64() <= max_heading_age)
+        });
+        UserLocation {
+            course_over_ground: fuse_heading(
+                location.course_over_ground,
+                location.speed.map(|speed| speed.value),
+                heading,
+                heading_only_speed,
+                course_only_speed,
+            ),
+            ..location
+        }
+    }
+
+    /// Adds a sample of the trip progress to the sliding window for [`EtaBlending`],
+    /// dropping any samples which have fallen out of the window.
+    ///
+    /// No samples are kept when ETA blending is disabled.
+    fn record_progress_sample(
+        &self,
+        samples: &[ProgressSample],
+        statistics: &TripStatistics,
+        progress: &TripProgress,
+    ) -> Vec<ProgressSample> {
+        let EtaBlending::ObservedProgress { window, .. } = self.config.eta_blending else {
+            return vec![];
+        };
+
+        let window_start = statistics.elapsed_time - f64::from(window);
+        samples
+            .iter()
+            .filter(|sample| sample.elapsed_time >= window_start)
+            .copied()
+            .chain(core::iter::once(ProgressSample {
+                elapsed_time: statistics.elapsed_time,
+                duration_remaining: progress.duration_remaining,
+            }))
+            .collect()
+    }
+
+    /// Adjusts the remaining duration of the trip progress according to the configured [`EtaBlending`].
+    fn blend_progress(&self, progress: TripProgress, samples: &[ProgressSample]) -> TripProgress {
+        let EtaBlending::ObservedProgress {
+            observed_weight, ..
+        } = self.config.eta_blending
+        else {
+            return progress;
+        };
+
+        TripProgress {
+            adjusted_duration_remaining: blend_duration_remaining(
+                progress.duration_remaining,
+                samples,
+                observed_weight,
+            ),
+            ..progress
+        }
+    }
+
+    /// Returns a function which determines whether an instruction on the current step
+    /// (given its trigger distance) has been triggered,
+    /// according to the configured [`InstructionTriggerMode`](models::InstructionTriggerMode).
+    fn instruction_trigger(
+        &self,
+        current_step: &RouteStep,
+        distance_to_next_maneuver: f64,
+        location: &UserLocation,
+    ) -> impl Fn(f64) -> bool {
+        let mode = self.config.instruction_trigger_mode;
+        let speed = location.speed.map(|speed| speed.value);
+        let expected_speed =
+            (current_step.duration > 0.0).then(|| current_step.distance / current_step.duration);
+        move |trigger_distance_before_maneuver| {
+            is_instruction_triggered(
+                trigger_distance_before_maneuver,
+                distance_to_next_maneuver,
+                speed,
+                expected_speed,
+                mode,
+            )
+        }
+    }
+
+    /// Finds the visual and spoken instructions for the current step which are triggered
+    /// at the given distance to the next maneuver.
+    ///
+    /// Only spoken instructions which are not in `previous_announced_utterance_ids` are returned.
+    fn triggered_instructions(
+        &self,
+        current_step: &RouteStep,
+        distance_to_next_maneuver: f64,
+        location: &UserLocation,
+        previous_announced_utterance_ids: &[Uuid],
+    ) -> (Option<VisualInstruction>, Option<SpokenInstruction>) {
+        let is_triggered =
+            self.instruction_trigger(current_step, distance_to_next_maneuver, location);
+        let visual_instruction = current_step
+            .get_triggered_visual_instruction(&is_triggered)
+            .cloned();
+        let spoken_instruction = newly_triggered_spoken_instruction(
+            previous_announced_utterance_ids,
+            current_step.get_triggered_spoken_instruction(&is_triggered),
+        );
+        (visual_instruction, spoken_instruction)
+    }
+
+    /// Determines the speed limit from the annotation for the current segment,
+    /// and whether the user is exceeding it according to the configured [`SpeedingDetection`].
+    fn speed_limit_status(
+        &self,
+        annotation: Option<&SegmentAnnotation>,
+        location: &UserLocation,
+    ) -> (Option<SpeedLimit>, bool) {
+        let speed_limit = annotation.and_then(|annotation| annotation.max_speed);
+        let SpeedingDetection::Enabled { tolerance_percent } = self.config.speeding_detection
+        else {
+            return (speed_limit, false);
+        };
+
+        let tolerance = 1.0 + f64::from(tolerance_percent) / 100.0;
+        let is_speeding = speed_limit
+            .as_ref()
+            .and_then(SpeedLimit::meters_per_second)
+            .zip(location.speed)
+            .is_some_and(|(limit, speed)| speed.value > limit * tolerance);
+        (speed_limit, is_speeding)
+    }
+
+    /// Finds the upcoming events to include in the trip state according to the configured [`EventLookAhead`].
+    fn look_ahead(
+        &self,
+        snapped_user_location: &UserLocation,
+        current_step_geometry_index: Option<u64>,
+        remaining_steps: &[RouteStep],
+    ) -> Option<UpcomingEvents> {
+        let EventLookAhead::Enabled {
+            max_count,
+            max_distance,
+        } = self.config.event_look_ahead
+        else {
+            return None;
+        };
+
+        Some(find_upcoming_events_with_geometry(
+            &(*snapped_user_location).into(),
+            current_step_geometry_index,
+            self.geometry_index.step_geometries(remaining_steps),
+            max_count.into(),
+            max_distance,
+        ))
+    }
+
+    /// Snaps the user's location to the route line and updates the user's course if necessary.
+    ///
+    /// This bundles all work related to snapping the user's location to the route line and is not intended to be exported.
+    ///
+    /// Returns the index of the closest segment origin to the snapped user location as well as the snapped user location.
+    fn snap_user_to_line(
+        &self,
+        location: UserLocation,
+        remaining_steps: &[RouteStep],
+        step_geometry: &StepGeometry,
+    ) -> (Option<u64>, UserLocation) {
+        let line = &step_geometry.linestring;
+
+        // Snap the user's latitude and longitude to the line.
+        let snapped_user_location = snap_user_location_to_line(location, line);
+
+        // Get the index of the closest segment origin to the snapped user location.
+        let current_step_geometry_index =
+            index_of_closest_segment_origin(snapped_user_location, line);
+
+        (
+            current_step_geometry_index,
+            self.filter_course(
+                snapped_user_location,
+                current_step_geometry_index,
+                remaining_steps,
+                step_geometry,
+            ),
+        )
+    }
+
+    /// Snaps the user's location to the route according to the configured [`LocationSnapping`],
+    /// and updates the user's course if necessary.
+    ///
+    /// Returns the index of the segment of the current step which the user is on,
+    /// the snapped user location, and the recent matches to carry over to the next update.
+    fn snap_user_to_route(
+        &self,
+        location: UserLocation,
+        remaining_steps: &[RouteStep],
+        step_geometry: &StepGeometry,
+        recent_matches: &[LocationMatch],
+    ) -> (Option<u64>, UserLocation, Vec<LocationMatch>) {
+        let LocationSnapping::MapMatching { window } = self.config.location_snapping else {
+            let (index, snapped_user_location) =
+                self.snap_user_to_line(location, remaining_steps, step_geometry);
+            return (index, snapped_user_location, vec![]);
+        };
+
+        // Matching needs the position of the step along the route,
+        // so we fall back to the nearest point if the steps aren't from this route.
+        let Some(matched) = self
+            .geometry_index
+            .distance_before_step(remaining_steps)
+            .and_then(|step_offset| {
+                match_location(
+                    location,
+                    step_geometry,
+                    step_offset,
+                    recent_matches,
+                    window.into(),
+                )
+            })
+        else {
+            let (index, snapped_user_location) =
+                self.snap_user_to_line(location, remaining_steps, step_geometry);
+            return (index, snapped_user_location, recent_matches.to_vec());
+        };
+
+        (
+            Some(matched.segment_index),
+            self.filter_course(
+                matched.snapped_location,
+                Some(matched.segment_index),
+                remaining_steps,
+                step_geometry,
+            ),
+            matched.recent_matches,
+        )
+    }
+
+    /// Snaps the user's course to the line if the configuration specifies it.
+    fn filter_course(
+        &self,
+        snapped_user_location: UserLocation,
+        current_step_geometry_index: Option<u64>,
+        remaining_steps: &[RouteStep],
+        step_geometry: &StepGeometry,
+    ) -> UserLocation {
+        match self.config.snapped_location_course_filtering {
+            CourseFiltering::SnapToRoute => UserLocation {
+                course_over_ground: current_step_geometry_index
+                    .and_then(|index| step_geometry.course_at(index))
+                    .or(snapped_user_location.course_over_ground),
+                ..snapped_user_location
+            },
+            CourseFiltering::SnapToRouteSmoothed {
+                window,
+                maneuver_blend_distance,
+            } => UserLocation {
+                course_over_ground: self
+                    .smoothed_course(
+                        &snapped_user_location,
+                        current_step_geometry_index,
+                        remaining_steps,
+                        step_geometry,
+                        window,
+                        maneuver_blend_distance,
+                    )
+                    .or_else(|| {
+                        current_step_geometry_index.and_then(|index| step_geometry.course_at(index))
+                    })
+                    .or(snapped_user_location.course_over_ground),
+                ..snapped_user_location
+            },
+            // The heading was already fused into the course before snapping
+            CourseFiltering::Raw | CourseFiltering::FuseHeading { .. } => snapped_user_location,
+        }
+    }
+
+    /// Averages the bearing of the current step over a window around the snapped user location
+    /// for [`CourseFiltering::SnapToRouteSmoothed`],
+    /// blending toward the start of the next step when approaching the end of the current one.
+    ///
+    /// Returns [`None`] if the location can't be placed along the step,
+    /// or the step has no length within the window.
+    fn smoothed_course(
+        &self,
+        snapped_user_location: &UserLocation,
+        current_step_geometry_index: Option<u64>,
+        remaining_steps: &[RouteStep],
+        step_geometry: &StepGeometry,
+        window: f64,
+        maneuver_blend_distance: f64,
+    ) -> Option<CourseOverGround> {
+        let (distance_to_end, _) = step_geometry.travel_to_end(
+            &(*snapped_user_location).into(),
+            current_step_geometry_index,
+        );
+        let distance_to_end = distance_to_end?;
+        let distance_along = step_geometry.length - distance_to_end;
+        let bearing =
+            step_geometry.mean_bearing(distance_along - window, distance_along + window)?;
+
+        let blend = if maneuver_blend_distance > 0.0 {
+            (1.0 - distance_to_end / maneuver_blend_distance).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let next_bearing = remaining_steps
+            .get(1..)
+            .filter(|_| blend > 0.0)
+            .and_then(|steps| self.geometry_index.step_geometry(steps))
+            .and_then(|next_step_geometry| next_step_geometry.mean_bearing(0.0, window));
+        let degrees = match next_bearing {
+            Some(next_bearing) => {
+                mean_bearing([(bearing, 1.0 - blend), (next_bearing, blend)]).unwrap_or(bearing)
+            }
+            None => bearing,
+        };
+        Some(CourseOverGround::new(degrees.round() % 360.0, None))
+    }
+
+    /// Moves waypoints which the user has reached to the list of completed waypoints.
+    ///
+    /// When the route has leg information, a waypoint is reached once the user
+    /// is on the final step of the leg ending at it
+    /// (any via waypoints along the way are completed with it).
+    /// Otherwise, we fall back to checking whether the user is close enough to the next waypoint.
+    ///
+    /// Returns the updated remaining and completed waypoints.
+    fn advance_waypoints(
+        &self,
+        snapped_user_location: &UserLocation,
+        remaining_steps: &[RouteStep],
+        remaining_waypoints: &[Waypoint],
+        completed_waypoints: &[Waypoint],
+    ) -> (Vec<Waypoint>, Vec<Waypoint>) {
+        let completed_legs =
+            completed_legs(remaining_steps).filter(|_| !self.route.legs.is_empty());
+        let waypoints_to_advance = if let Some(completed_legs) = completed_legs {
+            // The origin is always visited
+            let last_visited_waypoint_index = completed_legs
+                .checked_sub(1)
+                .and_then(|leg_index| self.leg_destination_index(leg_index))
+                .unwrap_or(0);
+            self.waypoints_to_complete(last_visited_waypoint_index, remaining_waypoints)
+        } else {
+            let is_near_next_waypoint = remaining_waypoints.first().is_some_and(|waypoint| {
+                let current_location: Point = snapped_user_location.coordinates.into();
+                let next_waypoint: Point = waypoint.coordinate.into();
+                Haversine::distance(current_location, next_waypoint) < 100.0
+            });
+            usize::from(is_near_next_waypoint)
+        };
+
+        let mut remaining_waypoints = remaining_waypoints.to_vec();
+        let mut completed_waypoints = completed_waypoints.to_vec();
+        completed_waypoints.extend(remaining_waypoints.drain(..waypoints_to_advance));
+
+        (remaining_waypoints, completed_waypoints)
+    }
+
+    /// The number of remaining waypoints which need to be completed
+    /// so that all route waypoints up to and including `waypoint_index` have been visited.
+    fn waypoints_to_complete(
+        &self,
+        waypoint_index: usize,
+        remaining_waypoints: &[Waypoint],
+    ) -> usize {
+        let expected_remaining = self
+            .route
+            .waypoints
+            .len()
+            .saturating_sub(waypoint_index + 1);
+        remaining_waypoints.len().saturating_sub(expected_remaining)
+    }
+
+    /// The index (in the route's waypoints) of the waypoint at the end of a leg.
+    ///
+    /// Routes without leg information are treated as a single leg ending at the last waypoint.
+    fn leg_destination_index(&self, leg_index: usize) -> Option<usize> {
+        if self.route.legs.is_empty() {
+            self.route.waypoints.len().checked_sub(1)
+        } else {
+            self.route
+                .legs
+                .get(leg_index)
+                .and_then(|leg| usize::try_from(leg.destination_waypoint_index).ok())
+        }
+    }
+
+    /// Determines whether advancing to `next_step` passes the end of the current leg,
+    /// and [`ArrivalDetection`] is configured to arrive in that case.
+    fn should_arrive_when_passing(
+        &self,
+        remaining_steps: &[RouteStep],
+        next_step: &RouteStep,
+    ) -> bool {
+        let ArrivalDetection::Enabled {
+            arrive_when_passed, ..
+        } = self.config.arrival_detection
+        else {
+            return false;
+        };
+
+        arrive_when_passed
+            && remaining_steps
+                .first()
+                .is_some_and(|current_step| current_step.leg_index != next_step.leg_index)
+    }
+
+    /// Checks whether the user has arrived at the destination of the current leg
+    /// according to the radius and dwell time in the [`ArrivalDetection`] config.
+    ///
+    /// NOTE: The [`UserLocation`] should *not* be snapped.
+    fn detect_arrival(&self, location: UserLocation, mut state: TripState) -> TripState {
+        let ArrivalDetection::Enabled {
+            radius,
+            minimum_dwell_time,
+            ..
+        } = self.config.arrival_detection
+        else {
+            return state;
+        };
+        let TripState::Navigating {
+            ref remaining_steps,
+            statistics: TripStatistics { elapsed_time, .. },
+            ref mut bookkeeping,
+            ..
+        } = state
+        else {
+            return state;
+        };
+        let arrival_radius_entered_at = &mut bookkeeping.arrival_radius_entered_at;
+
+        let is_within_radius =
+            self.current_leg_destination(remaining_steps)
+                .is_some_and(|(_, waypoint)| {
+                    Haversine::distance(Point::from(location), Point::from(waypoint.coordinate))
+                        <= f64::from(radius)
+                });
+
+        *arrival_radius_entered_at =
+            is_within_radius.then(|| arrival_radius_entered_at.unwrap_or(elapsed_time));
+        let has_dwelled = arrival_radius_entered_at
+            .is_some_and(|entered_at| elapsed_time - entered_at >= f64::from(minimum_dwell_time));
+
+        if has_dwelled {
+            self.arrive(&state)
+        } else {
+            state
+        }
+    }
+
+    /// The index (in the route's waypoints) and value of the waypoint
+    /// at the end of the current leg.
+    fn current_leg_destination(&self, remaining_steps: &[RouteStep]) -> Option<(usize, Waypoint)> {
+        let leg_index = usize::try_from(remaining_steps.first()?.leg_index).ok()?;
+        let waypoint_index = self.leg_destination_index(leg_index)?;
+        Some((waypoint_index, *self.route.waypoints.get(waypoint_index)?))
+    }
+
+    /// Determines the events which occurred between two successive trip states.
+    fn navigation_events(
+        &self,
+        previous_state: &TripState,
+        state: &TripState,
+    ) -> Vec<NavigationEvent> {
+        let (previous_steps, previous_completed_waypoints, previous_deviation) =
+            match previous_state {
+                TripState::Navigating {
+                    remaining_steps,
+                    completed_waypoints,
+                    deviation,
+                    ..
+                } => (remaining_steps, completed_waypoints, Some(deviation)),
+                TripState::Arrived {
+                    remaining_steps,
+                    completed_waypoints,
+                    ..
+                } => (remaining_steps, completed_waypoints, None),
+                TripState::Idle | TripState::Complete { .. } => return vec![],
+            };
+
+        let mut events = vec![];
+        match state {
+            TripState::Navigating {
+                remaining_steps,
+                completed_waypoints,
+                spoken_instruction,
+                deviation,
+                ..
+            } => {
+                events.extend(self.step_advanced_events(previous_steps, remaining_steps));
+
+                events.extend(waypoints_reached(
+                    previous_completed_waypoints,
+                    completed_waypoints,
+                ));
+
+                if let Some(instruction) = spoken_instruction {
+                    events.push(NavigationEvent::SpokenInstructionTriggered {
+                        instruction: instruction.clone(),
+                    });
+                }
+
+                events.extend(
+                    previous_deviation.and_then(|previous_deviation| {
+                        deviation_event(previous_deviation, deviation)
+                    }),
+                );
+            }
+            TripState::Arrived {
+                completed_waypoints,
+                ..
+            } => events.extend(waypoints_reached(
+                previous_completed_waypoints,
+                completed_waypoints,
+            )),
+            TripState::Idle | TripState::Complete { .. } => {}
+        }
+
+        events
+    }
+
+    /// Returns the events caused by advancing to a new step (if the step changed).
+    fn step_advanced_events(
+        &self,
+        previous_steps: &[RouteStep],
+        remaining_steps: &[RouteStep],
+    ) -> Vec<NavigationEvent> {
+        let Some(current_step) = remaining_steps
+            .first()
+            .filter(|_| remaining_steps.len() < previous_steps.len())
+        else {
+            return vec![];
+        };
+
+        let mut events = vec![NavigationEvent::StepAdvanced {
+            step: current_step.clone(),
+        }];
+        events.extend(current_step.incidents.iter().map(|incident| {
+            NavigationEvent::IncidentAhead {
+                incident: incident.clone(),
+            }
+        }));
+
+        // Legs end with an arrival step, so the last maneuver begins with
+        // the second to last step of the leg.
+        let steps_left_in_leg = |steps: &[RouteStep]| {
+            steps
+                .iter()
+                .filter(|step| step.leg_index == current_step.leg_index)
+                .count()
+        };
+        if steps_left_in_leg(remaining_steps) <= 2 && steps_left_in_leg(previous_steps) > 2 {
+            if let Some((_, waypoint)) = self.current_leg_destination(remaining_steps) {
+                events.push(NavigationEvent::ArrivalImminent { waypoint });
+            }
+        }
+
+        events
+    }
+
+    /// Builds the arrival state for the destination of the current leg.
+    ///
+    /// States other than [`TripState::Navigating`] are returned unchanged.
+    fn arrive(&self, state: &TripState) -> TripState {
+        let TripState::Navigating {
+            snapped_user_location,
+            remaining_steps,
+            remaining_waypoints,
+            completed_waypoints,
+            statistics,
+            bookkeeping,
+            ..
+        } = state
+        else {
+            return state.clone();
+        };
+        let Some((waypoint_index, waypoint)) = self.current_leg_destination(remaining_steps) else {
+            return state.clone();
+        };
+
+        let mut remaining_waypoints = remaining_waypoints.clone();
+        let mut completed_waypoints = completed_waypoints.clone();
+        completed_waypoints.extend(
+            remaining_waypoints
+                .drain(..self.waypoints_to_complete(waypoint_index, &remaining_waypoints)),
+        );
+
+        TripState::Arrived {
+            waypoint,
+            is_final_destination: waypoint_index + 1 == self.route.waypoints.len(),
+            snapped_user_location: *snapped_user_location,
+            remaining_steps: remaining_steps.clone(),
+            remaining_waypoints,
+            completed_waypoints,
+            statistics: *statistics,
+            bookkeeping: bookkeeping.clone(),
+        }
+    }
+}
+
+/// The context of a trip which carries over from one route (or leg) to the next.
+struct TripContext {
+    completed_waypoints: Vec<Waypoint>,
+    statistics: TripStatistics,
+    announced_utterance_ids: Vec<Uuid>,
+}
+
+/// The number of route legs the user has completed,
+/// counting the current leg as complete once the user is on its final step.
+fn completed_legs(remaining_steps: &[RouteStep]) -> Option<usize> {
+    let current_step = remaining_steps.first()?;
+    let is_final_step_of_leg = remaining_steps.get(1).map_or(true, |next_step| {
+        next_step.leg_index != current_step.leg_index
+    });
+    let leg_index = usize::try_from(current_step.leg_index).ok()?;
+    Some(leg_index + usize::from(is_final_step_of_leg))
+}
+
+/// Whether two spoken instructions (typically from different routes) say the same thing at the same point.
+///
+/// The trigger distances are allowed to differ by up to a meter,
+/// as route providers may measure the distance to the same maneuver slightly differently.
+fn is_same_announcement(a: &SpokenInstruction, b: &SpokenInstruction) -> bool {
+    a.text == b.text
+        && (a.trigger_distance_before_maneuver - b.trigger_distance_before_maneuver).abs() < 1.0
+}
+
+/// Returns a [`NavigationEvent::WaypointReached`] for each waypoint which was newly completed.
+fn waypoints_reached<'a>(
+    previous_completed_waypoints: &[Waypoint],
+    completed_waypoints: &'a [Waypoint],
+) -> impl Iterator<Item = NavigationEvent> + 'a {
+    completed_waypoints
+        .get(previous_completed_waypoints.len()..)
+        .unwrap_or_default()
+        .iter()
+        .map(|waypoint| NavigationEvent::WaypointReached {
+            waypoint: *waypoint,
+        })
+}
+
+/// Returns a [`NavigationEvent`] if the user started or stopped deviating from the route.
+fn deviation_event(
+    previous_deviation: &RouteDeviation,
+    deviation: &RouteDeviation,
+) -> Option<NavigationEvent> {
+    match (previous_deviation, deviation) {
+        (
+            RouteDeviation::NoDeviation,
+            RouteDeviation::OffRoute {
+                deviation_from_route_line,
+            },
+        ) => Some(NavigationEvent::DeviationStarted {
+            deviation_from_route_line: *deviation_from_route_line,
+        }),
+        (RouteDeviation::OffRoute { .. }, RouteDeviation::NoDeviation) => {
+            Some(NavigationEvent::DeviationEnded)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the spoken instruction if it has not been announced before.
+fn newly_triggered_spoken_instruction(
+    announced_utterance_ids: &[Uuid],
+    spoken_instruction: Option<&SpokenInstruction>,
+) -> Option<SpokenInstruction> {
+    spoken_instruction
+        .filter(|instruction| !announced_utterance_ids.contains(&instruction.utterance_id))
+        .cloned()
+}
+
+/// Extrapolates the user's location along the route to a later time
+/// (see [`NavigationController::predict_state_at`]).
+///
+/// Returns the predicted location along with the index of the segment of the current step it lies on.
+fn predict_location(
+    snapped_user_location: &UserLocation,
+    current_step_geometry_index: Option<u64>,
+    remaining_steps: &[RouteStep],
+    current_step_geometry: &StepGeometry,
+    timestamp: SystemTime,
+) -> Option<(UserLocation, u64)> {
+    let elapsed = timestamp
+        .duration_since(snapped_user_location.timestamp)
+        .ok()
+        .filter(|elapsed| !elapsed.is_zero())?
+        .as_secs_f64();
+    let current_step = remaining_steps.first()?;
+    let speed = snapped_user_location
+        .speed
+        .map(|speed| speed.value)
+        .or_else(|| {
+            (current_step.duration > 0.0).then(|| current_step.distance / current_step.duration)
+        })?;
+
+    let (point, geometry_index) = current_step_geometry.point_ahead(
+        &Point::from(*snapped_user_location),
+        current_step_geometry_index,
+        speed * elapsed,
+    )?;
+    let location = UserLocation {
+        coordinates: point.into(),
+        course_over_ground: current_step_geometry
+            .course_at(geometry_index)
+            .or(snapped_user_location.course_over_ground),
+        timestamp,
+        ..*snapped_user_location
+    };
+    Some((location, geometry_index))
+}
+
+/// Returns the number of seconds elapsed between two user locations.
+///
+/// Locations which are out of order (ex: due to clock adjustments) count as zero elapsed time.
+fn elapsed_seconds(previous: &UserLocation, current: &UserLocation) -> f64 {
+    current
+        .timestamp
+        .duration_since(previous.timestamp)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64())
+}
+
+/// JavaScript wrapper for `NavigationController`.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = NavigationController)]
+pub struct JsNavigationController(NavigationController);
+
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_class = NavigationController)]
+impl JsNavigationController {
+    #[wasm_bindgen(constructor)]
+    pub fn new(route: JsValue, config: JsValue) -> Result<JsNavigationController, JsValue> {
+        let route: Route = serde_wasm_bindgen::from_value(route)?;
+        let config: NavigationControllerConfig = serde_wasm_bindgen::from_value(config)?;
+
+        Ok(JsNavigationController(NavigationController::new(
+            route, config,
+        )))
+    }
+
+    #[wasm_bindgen(js_name = getInitialState)]
+    pub fn get_initial_state(&self, location: JsValue) -> Result<JsValue, JsValue> {
+        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+
+        serde_wasm_bindgen::to_value(&self.0.get_initial_state(location))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = resumeNavigation)]
+    pub fn resume_navigation(&self, location: JsValue, state: JsValue) -> Result<JsValue, JsValue> {
+        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.resume_navigation(location, &state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = getReroutedState)]
+    pub fn get_rerouted_state(
+        &self,
+        location: JsValue,
+        previous_state: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+        let previous_state: TripState = serde_wasm_bindgen::from_value(previous_state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.get_rerouted_state(location, &previous_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    pub fn advance_to_next_step(&self, state: JsValue) -> Result<JsValue, JsValue> {
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.advance_to_next_step(&state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = upcomingEvents)]
+    pub fn upcoming_events(
+        &self,
+        state: JsValue,
+        max_count: u16,
+        max_distance: f64,
+    ) -> Result<JsValue, JsValue> {
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.upcoming_events(&state, max_count, max_distance))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = updateUserLocationWithEvents)]
+    pub fn update_user_location_with_events(
+        &self,
+        location: JsValue,
+        state: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.update_user_location_with_events(location, &state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = updateHeading)]
+    pub fn update_heading(&self, heading: JsValue, state: JsValue) -> Result<JsValue, JsValue> {
+        let heading: Heading = serde_wasm_bindgen::from_value(heading)?;
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.update_heading(heading, &state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = predictStateAt)]
+    pub fn predict_state_at(&self, state: JsValue, timestamp: f64) -> Result<JsValue, JsValue> {
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+        // JavaScript timestamps are in milliseconds since the epoch (ex: from `Date.now()`)
+        let timestamp = SystemTime::UNIX_EPOCH
+            + core::time::Duration::try_from_secs_f64(timestamp / 1000.0)
+                .map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
+
+        serde_wasm_bindgen::to_value(&self.0.predict_state_at(&state, timestamp))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    #[wasm_bindgen(js_name = updateUserLocation)]
+    pub fn update_user_location(
+        &self,
+        location: JsValue,
+        state: JsValue,
+    ) -> Result<JsValue, JsValue> {
+        let location: UserLocation = serde_wasm_bindgen::from_value(location)?;
+        let state: TripState = serde_wasm_bindgen::from_value(state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.update_user_location(location, &state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deviation_detection::{RouteDeviation, RouteDeviationTracking};
+    use crate::models::{
+        GeographicCoordinate, Incident, IncidentType, RouteLeg, Speed, SpeedUnit, WaypointKind,
+    };
+    use crate::navigation_controller::models::{
+        CourseFiltering, LocationSnapping, SpecialAdvanceConditions, StepAdvanceMode, StepLookAhead,
+    };
+    use crate::navigation_controller::test_helpers::{
+        gen_dummy_route_step, gen_route_from_steps, get_extended_route, get_self_intersecting_route,
+    };
+    use crate::simulation::{
+        advance_location_simulation, location_simulation_from_route, LocationBias,
+    };
+
+    use core::time::Duration;
+
+    #[cfg(all(feature = "std", not(feature = "web-time")))]
+    use std::time::SystemTime;
+
+    #[cfg(feature = "web-time")]
+    use web_time::SystemTime;
+
+    fn test_full_route_state_snapshot(
+        route: Route,
+        step_advance: StepAdvanceMode,
+    ) -> Vec<TripState> {
+        simulate_full_route(route, step_advance, StepLookAhead::Disabled)
+    }
+
+    fn simulate_full_route(
+        route: Route,
+        step_advance: StepAdvanceMode,
+        step_look_ahead: StepLookAhead,
+    ) -> Vec<TripState> {
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig {
+                step_look_ahead,
+                ..NavigationControllerConfig::new(
+                    // NOTE: We will use a few varieties here via parameterized testing,
+                    // but the point of this test is *not* testing the thresholds.
+                    step_advance,
+                    // Careful setup: if the user is ever off the route
+                    // (ex: because of an improper automatic step advance),
+                    // we want to know about it.
+                    RouteDeviationTracking::StaticThreshold {
+                        minimum_horizontal_accuracy: 0,
+                        max_acceptable_deviation: 0.0,
+                    },
+                    CourseFiltering::Raw,
+                )
+            },
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        let mut states = vec![state.clone()];
+        loop {
+            let new_simulation_state = advance_location_simulation(&simulation_state);
+            let new_state =
+                controller.update_user_location(new_simulation_state.current_location, &state);
 
             match new_state {
                 TripState::Idle => {}
@@ -448,81 +1927,1682 @@ mod tests {
                         );
                     }
 
-                    // Regression test that we are never marked as off the route.
-                    // We used to encounter this with relative step advance on self-intersecting
-                    // routes, for example.
-                    assert_eq!(deviation, &RouteDeviation::NoDeviation);
-                }
-                TripState::Complete => {
-                    states.push(new_state);
-                    break;
-                }
-            }
+                    // Regression test that we are never marked as off the route.
+                    // We used to encounter this with relative step advance on self-intersecting
+                    // routes, for example.
+                    assert_eq!(deviation, &RouteDeviation::NoDeviation);
+                }
+                TripState::Arrived { .. } => panic!("Arrival detection is disabled"),
+                TripState::Complete { .. } => {
+                    states.push(new_state);
+                    break;
+                }
+            }
+
+            simulation_state = new_simulation_state;
+            state = new_state.clone();
+            states.push(new_state);
+        }
+
+        states
+    }
+
+    /// Redacts parts of the trip state which are covered by more specific tests,
+    /// or which depend on the (wall clock) timing of the simulation.
+    fn snapshot_settings() -> insta::Settings {
+        let mut settings = insta::Settings::clone_current();
+        settings.add_redaction(".**.intersections", "redacted intersections");
+        settings.add_redaction(".**.bookkeeping", "redacted bookkeeping");
+        settings.add_redaction(".**.heading.timestamp", "redacted timestamp");
+        for statistics in [".**.statistics", ".**.summary"] {
+            for field in ["elapsedTime", "averageSpeed", "maxSpeed"] {
+                settings.add_redaction(&format!("{statistics}.{field}"), "redacted timing");
+            }
+        }
+        settings
+    }
+
+    // Full simulations for several routes with different settings
+
+    #[test]
+    fn test_extended_exact_distance() {
+        snapshot_settings().bind(|| {
+            insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
+                get_extended_route(),
+                StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 0,
+                    minimum_horizontal_accuracy: 0,
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn test_extended_relative_linestring() {
+        snapshot_settings().bind(|| {
+            insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
+                get_extended_route(),
+                StepAdvanceMode::RelativeLineStringDistance {
+                    minimum_horizontal_accuracy: 0,
+                    special_advance_conditions: None,
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn test_self_intersecting_exact_distance() {
+        snapshot_settings().bind(|| {
+            insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
+                get_self_intersecting_route(),
+                StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 0,
+                    minimum_horizontal_accuracy: 0,
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn test_self_intersecting_relative_linestring() {
+        snapshot_settings().bind(|| {
+            insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
+                get_self_intersecting_route(),
+                StepAdvanceMode::RelativeLineStringDistance {
+                    minimum_horizontal_accuracy: 0,
+                    special_advance_conditions: None,
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn test_self_intersecting_relative_linestring_min_line_distance() {
+        snapshot_settings().bind(|| {
+            insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
+                get_self_intersecting_route(),
+                StepAdvanceMode::RelativeLineStringDistance {
+                    minimum_horizontal_accuracy: 0,
+                    special_advance_conditions: Some(
+                        SpecialAdvanceConditions::MinimumDistanceFromCurrentStepLine(10)
+                    ),
+                }
+            ));
+        });
+    }
+
+    #[test]
+    fn test_rerouted_state_carries_over_trip_context() {
+        let route = get_extended_route();
+        let config = NavigationControllerConfig::new(
+            StepAdvanceMode::RelativeLineStringDistance {
+                minimum_horizontal_accuracy: 0,
+                special_advance_conditions: None,
+            },
+            RouteDeviationTracking::None,
+            CourseFiltering::Raw,
+        );
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+        let controller = NavigationController::new(route.clone(), config.clone());
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..50 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, &state);
+        }
+
+        // The test routes don't include spoken instructions, so pretend that one was announced
+        if let TripState::Navigating {
+            ref mut bookkeeping,
+            ..
+        } = state
+        {
+            bookkeeping.announced_utterance_ids.push(Uuid::new_v4());
+        }
+
+        let TripState::Navigating {
+            completed_waypoints: previous_completed_waypoints,
+            statistics: previous_statistics,
+            bookkeeping: previous_bookkeeping,
+            ..
+        } = &state
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert!(previous_statistics.distance_traveled > 0.0);
+
+        // Pretend that the "new" route is a fresh copy of the original one
+        let rerouted_controller = NavigationController::new(route, config);
+        let rerouted_state =
+            rerouted_controller.get_rerouted_state(simulation_state.current_location, &state);
+
+        let TripState::Navigating {
+            completed_waypoints,
+            statistics,
+            remaining_waypoints,
+            ..
+        } = &rerouted_state
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(completed_waypoints, previous_completed_waypoints);
+        assert!(
+            (statistics.distance_traveled - previous_statistics.distance_traveled).abs()
+                < f64::EPSILON
+        );
+        assert_eq!(
+            statistics.reroute_count,
+            previous_statistics.reroute_count + 1
+        );
+        assert_eq!(
+            bookkeeping(&rerouted_state).announced_utterance_ids,
+            previous_bookkeeping.announced_utterance_ids
+        );
+        // The origin of the new route is not a waypoint the user still needs to visit
+        assert_eq!(
+            remaining_waypoints.len(),
+            rerouted_controller.route.waypoints.len() - 1
+        );
+
+        // Rerouting from a state that isn't navigating is the same as starting fresh
+        assert_eq!(
+            rerouted_controller
+                .get_rerouted_state(simulation_state.current_location, &TripState::Idle),
+            rerouted_controller.get_initial_state(simulation_state.current_location)
+        );
+    }
+
+    #[test]
+    fn test_rerouted_state_matches_announced_instructions_by_content() {
+        let config = NavigationControllerConfig::new(
+            StepAdvanceMode::Manual,
+            RouteDeviationTracking::None,
+            CourseFiltering::Raw,
+        );
+        let controller_for_instruction = |text: &str, end_lng: f64| {
+            let mut route =
+                gen_route_from_steps(vec![gen_dummy_route_step(0.001, 0.0, end_lng, 0.0)]);
+            route.steps[0].spoken_instructions.push(SpokenInstruction {
+                text: text.to_string(),
+                ssml: None,
+                trigger_distance_before_maneuver: 1000.0,
+                utterance_id: Uuid::new_v4(),
+            });
+            NavigationController::new(route, config.clone())
+        };
+        let location = UserLocation {
+            coordinates: GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.0,
+            },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let state = controller_for_instruction("Continue", 0.002).get_initial_state(location);
+
+        // The new routes come with fresh utterance IDs, and their maneuvers are too far away
+        // for the instructions to be triggered yet
+        let was_announced = |rerouted_controller: NavigationController| {
+            let rerouted_state = rerouted_controller.get_rerouted_state(location, &state);
+            bookkeeping(&rerouted_state)
+                .announced_utterance_ids
+                .contains(&rerouted_controller.route.steps[0].spoken_instructions[0].utterance_id)
+        };
+
+        // An instruction which says the same thing was already announced
+        assert!(was_announced(controller_for_instruction("Continue", 0.05)));
+        // Instructions which say something else were not
+        assert!(!was_announced(controller_for_instruction(
+            "Turn around",
+            0.05
+        )));
+    }
+
+    fn bookkeeping(state: &TripState) -> &TripBookkeeping {
+        match state {
+            TripState::Navigating { bookkeeping, .. } | TripState::Arrived { bookkeeping, .. } => {
+                bookkeeping
+            }
+            TripState::Idle | TripState::Complete { .. } => {
+                panic!("Expected to be navigating or arrived, got {state:?}")
+            }
+        }
+    }
+
+    fn remaining_step_counts(states: &[TripState]) -> Vec<usize> {
+        states
+            .iter()
+            .map(|state| match state {
+                TripState::Navigating {
+                    remaining_steps, ..
+                }
+                | TripState::Arrived {
+                    remaining_steps, ..
+                } => remaining_steps.len(),
+                TripState::Idle | TripState::Complete { .. } => 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_look_ahead_does_not_change_normal_progress() {
+        let step_advance = StepAdvanceMode::RelativeLineStringDistance {
+            minimum_horizontal_accuracy: 0,
+            special_advance_conditions: None,
+        };
+        let step_look_ahead = StepLookAhead::Window {
+            max_steps: 3,
+            max_distance_from_step: 10,
+            minimum_horizontal_accuracy: 0,
+        };
+
+        // Following the route exactly (including where it crosses itself) should never skip steps
+        for route in [get_extended_route(), get_self_intersecting_route()] {
+            assert_eq!(
+                remaining_step_counts(&simulate_full_route(
+                    route.clone(),
+                    step_advance,
+                    StepLookAhead::Disabled
+                )),
+                remaining_step_counts(&simulate_full_route(route, step_advance, step_look_ahead))
+            );
+        }
+    }
+
+    #[test]
+    fn test_look_ahead_skips_missed_steps() {
+        let route = get_extended_route();
+        let config = NavigationControllerConfig {
+            step_look_ahead: StepLookAhead::Window {
+                max_steps: 3,
+                max_distance_from_step: 10,
+                minimum_horizontal_accuracy: 10,
+            },
+            ..NavigationControllerConfig::new(
+                StepAdvanceMode::Manual,
+                RouteDeviationTracking::None,
+                CourseFiltering::Raw,
+            )
+        };
+        let controller = NavigationController::new(route.clone(), config.clone());
+
+        let start = UserLocation {
+            coordinates: route.geometry[0],
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let initial_state = controller.get_initial_state(start);
+
+        // Jump to the middle of the fourth step
+        let fourth_step = &route.steps[3];
+        let location = UserLocation {
+            coordinates: fourth_step.geometry[fourth_step.geometry.len() / 2],
+            ..start
+        };
+
+        let state = controller.update_user_location(location, &initial_state);
+        assert_eq!(remaining_step_counts(&[state]), [route.steps.len() - 3]);
+
+        // Inaccurate locations never cause a jump
+        let inaccurate_location = UserLocation {
+            horizontal_accuracy: 20.0,
+            ..location
+        };
+        let state = controller.update_user_location(inaccurate_location, &initial_state);
+        assert_eq!(remaining_step_counts(&[state]), [route.steps.len()]);
+
+        // Steps outside the window are not considered
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                step_look_ahead: StepLookAhead::Window {
+                    max_steps: 2,
+                    max_distance_from_step: 10,
+                    minimum_horizontal_accuracy: 10,
+                },
+                ..config.clone()
+            },
+        );
+        let state = controller.update_user_location(location, &initial_state);
+        assert_eq!(remaining_step_counts(&[state]), [route.steps.len()]);
+
+        // Nothing changes when look-ahead is disabled
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                step_look_ahead: StepLookAhead::Disabled,
+                ..config
+            },
+        );
+        let state = controller.update_user_location(location, &initial_state);
+        assert_eq!(remaining_step_counts(&[state]), [route.steps.len()]);
+    }
+
+    #[test]
+    fn test_look_ahead_stays_within_leg() {
+        let route = gen_two_leg_route();
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                step_look_ahead: StepLookAhead::Window {
+                    max_steps: 3,
+                    max_distance_from_step: 10,
+                    minimum_horizontal_accuracy: 0,
+                },
+                ..manual_advance_config(ArrivalDetection::Enabled {
+                    radius: 0,
+                    minimum_dwell_time: 0,
+                    arrive_when_passed: true,
+                })
+            },
+        );
+        let location_at = |lng: f64| UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let initial_state = controller.get_initial_state(location_at(0.0));
+        let completed_waypoint_count = |state: &TripState| match state {
+            TripState::Navigating {
+                completed_waypoints,
+                ..
+            } => completed_waypoints.len(),
+            state => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        // Skipping to the last step of the first leg completes the waypoints along the leg
+        let state = controller.update_user_location(location_at(0.0015), &initial_state);
+        assert_eq!(remaining_step_counts(std::slice::from_ref(&state)), [3]);
+        assert_eq!(completed_waypoint_count(&state), 3);
+
+        // The second leg is outside the window, even though its steps are within `max_steps`.
+        // This leaves crossing into it (and arriving at the end of the first leg) to a regular advance.
+        let state = controller.update_user_location(location_at(0.0025), &initial_state);
+        assert_eq!(remaining_step_counts(std::slice::from_ref(&state)), [4]);
+        assert_eq!(completed_waypoint_count(&state), 1);
+        let state = controller.update_user_location(location_at(0.0035), &initial_state);
+        assert_eq!(remaining_step_counts(&[state]), [4]);
+    }
+
+    /// Generates a route with two legs of two steps each.
+    ///
+    /// The first leg passes a via waypoint (which is nowhere near the route)
+    /// on its way to an intermediate stop.
+    fn gen_two_leg_route() -> Route {
+        let mut steps = vec![
+            gen_dummy_route_step(0.0, 0.0, 0.001, 0.0),
+            gen_dummy_route_step(0.001, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.003, 0.0),
+            gen_dummy_route_step(0.003, 0.0, 0.004, 0.0),
+        ];
+        for step in &mut steps[2..] {
+            step.leg_index = 1;
+        }
+        let mut route = gen_route_from_steps(steps);
+        let destination = route.waypoints.pop().unwrap();
+        route.waypoints.extend([
+            Waypoint {
+                coordinate: GeographicCoordinate { lat: 1.0, lng: 1.0 },
+                kind: WaypointKind::Via,
+            },
+            Waypoint {
+                coordinate: route.steps[1].geometry[1],
+                kind: WaypointKind::Break,
+            },
+            destination,
+        ]);
+        route.legs = vec![
+            RouteLeg {
+                distance: route.steps[0].distance + route.steps[1].distance,
+                duration: 0.0,
+                destination_waypoint_index: 2,
+            },
+            RouteLeg {
+                distance: route.steps[2].distance + route.steps[3].distance,
+                duration: 0.0,
+                destination_waypoint_index: 3,
+            },
+        ];
+        route
+    }
+
+    fn manual_advance_config(arrival_detection: ArrivalDetection) -> NavigationControllerConfig {
+        NavigationControllerConfig {
+            arrival_detection,
+            ..NavigationControllerConfig::new(
+                StepAdvanceMode::Manual,
+                RouteDeviationTracking::None,
+                CourseFiltering::Raw,
+            )
+        }
+    }
+
+    #[test]
+    fn test_waypoints_advance_by_leg() {
+        let route = gen_two_leg_route();
+        let controller = NavigationController::new(
+            route.clone(),
+            manual_advance_config(ArrivalDetection::Disabled),
+        );
+        let location = UserLocation {
+            coordinates: route.geometry[0],
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+
+        let mut state = controller.get_initial_state(location);
+        let mut waypoint_counts = vec![];
+        let mut distances_to_next_waypoint = vec![];
+        while let TripState::Navigating {
+            remaining_waypoints,
+            completed_waypoints,
+            progress,
+            ..
+        } = &state
+        {
+            waypoint_counts.push((remaining_waypoints.len(), completed_waypoints.len()));
+            distances_to_next_waypoint.push(progress.distance_to_next_waypoint);
+            state = controller.advance_to_next_step(&state);
+        }
+
+        // The via and break waypoints are completed together
+        // when the user reaches the final step of the first leg
+        assert_eq!(waypoint_counts, [(3, 1), (1, 3), (1, 3), (0, 4)]);
+
+        // Progress to the next waypoint stops at the end of the current leg
+        let expected_distances = [
+            route.legs[0].distance,
+            route.steps[1].distance,
+            route.legs[1].distance,
+            route.steps[3].distance,
+        ];
+        for (actual, expected) in distances_to_next_waypoint.iter().zip(expected_distances) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_arrival_when_passing_end_of_leg() {
+        let route = gen_two_leg_route();
+        let controller = NavigationController::new(
+            route.clone(),
+            manual_advance_config(ArrivalDetection::Enabled {
+                radius: 0,
+                minimum_dwell_time: 0,
+                arrive_when_passed: true,
+            }),
+        );
+        let location = UserLocation {
+            coordinates: route.geometry[0],
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+
+        // Passing the end of the first leg arrives at the intermediate stop
+        let state = controller.get_initial_state(location);
+        let state = controller.advance_to_next_step(&state);
+        let state = controller.advance_to_next_step(&state);
+        let TripState::Arrived {
+            waypoint,
+            is_final_destination,
+            ref remaining_steps,
+            ref remaining_waypoints,
+            ref completed_waypoints,
+            ..
+        } = state
+        else {
+            panic!("Expected to arrive at the intermediate stop, got {state:?}");
+        };
+        assert_eq!(waypoint, route.waypoints[2]);
+        assert!(!is_final_destination);
+        assert_eq!(remaining_steps.len(), 3);
+        assert_eq!(remaining_waypoints, &route.waypoints[3..]);
+        assert_eq!(completed_waypoints, &route.waypoints[..3]);
+
+        // Arrival is sticky until navigation is resumed
+        assert_eq!(controller.advance_to_next_step(&state), state);
+        assert_eq!(controller.update_user_location(location, &state), state);
+
+        let state = controller.resume_navigation(location, &state);
+        let TripState::Navigating {
+            ref remaining_steps,
+            ..
+        } = state
+        else {
+            panic!("Expected to be navigating, got {state:?}");
+        };
+        assert_eq!(remaining_steps, &route.steps[2..]);
+
+        // The end of the route is always an arrival at the final destination
+        let state = controller.advance_to_next_step(&state);
+        let state = controller.advance_to_next_step(&state);
+        let TripState::Arrived {
+            waypoint,
+            is_final_destination,
+            ref remaining_waypoints,
+            ..
+        } = state
+        else {
+            panic!("Expected to arrive at the destination, got {state:?}");
+        };
+        assert_eq!(waypoint, route.waypoints[3]);
+        assert!(is_final_destination);
+        assert_eq!(remaining_waypoints, &route.waypoints[4..]);
+
+        assert!(matches!(
+            controller.resume_navigation(location, &state),
+            TripState::Complete { .. }
+        ));
+    }
+
+    #[test]
+    fn test_arrival_requires_dwell_time_within_radius() {
+        let route = gen_two_leg_route();
+        let controller = NavigationController::new(
+            route.clone(),
+            manual_advance_config(ArrivalDetection::Enabled {
+                radius: 20,
+                minimum_dwell_time: 5,
+                arrive_when_passed: false,
+            }),
+        );
+        let start = UserLocation {
+            coordinates: route.geometry[0],
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let at_stop = |seconds: u64| UserLocation {
+            coordinates: route.waypoints[2].coordinate,
+            timestamp: start.timestamp + Duration::from_secs(seconds),
+            ..start
+        };
+
+        let state = controller.get_initial_state(start);
+        assert_eq!(bookkeeping(&state).arrival_radius_entered_at, None);
+        let state = controller.update_user_location(at_stop(1), &state);
+        assert_eq!(bookkeeping(&state).arrival_radius_entered_at, Some(1.0));
+        let state = controller.update_user_location(at_stop(4), &state);
+        assert_eq!(bookkeeping(&state).arrival_radius_entered_at, Some(1.0));
+
+        // Leaving the radius resets the dwell time
+        let away = controller.update_user_location(
+            UserLocation {
+                timestamp: start.timestamp + Duration::from_secs(5),
+                ..start
+            },
+            &state,
+        );
+        assert_eq!(bookkeeping(&away).arrival_radius_entered_at, None);
+        let away = controller.update_user_location(at_stop(8), &away);
+        assert_eq!(bookkeeping(&away).arrival_radius_entered_at, Some(8.0));
+
+        let state = controller.update_user_location(at_stop(6), &state);
+        assert!(
+            matches!(state, TripState::Arrived { waypoint, .. } if waypoint == route.waypoints[2])
+        );
+
+        // Passing the end of the leg without stopping does not count as an arrival
+        let state = controller.get_initial_state(start);
+        let state = controller.advance_to_next_step(&state);
+        let state = controller.advance_to_next_step(&state);
+        assert!(matches!(state, TripState::Navigating { .. }));
+    }
+
+    fn gen_incident() -> Incident {
+        Incident {
+            id: "incident".to_string(),
+            incident_type: IncidentType::Construction,
+            description: None,
+            long_description: None,
+            creation_time: None,
+            start_time: None,
+            end_time: None,
+            impact: None,
+            lanes_blocked: vec![],
+            congestion: None,
+            closed: None,
+            geometry_index_start: 0,
+            geometry_index_end: None,
+            sub_type: None,
+            sub_type_description: None,
+            iso_3166_1_alpha2: None,
+            iso_3166_1_alpha3: None,
+            affected_road_names: vec![],
+            bbox: None,
+        }
+    }
+
+    #[test]
+    fn test_update_user_location_with_events() {
+        let mut steps = vec![
+            gen_dummy_route_step(0.0, 0.0, 0.001, 0.0),
+            gen_dummy_route_step(0.001, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.0),
+        ];
+        let incident = gen_incident();
+        let instruction = SpokenInstruction {
+            text: "Arrive at your destination".to_string(),
+            ssml: None,
+            trigger_distance_before_maneuver: 1000.0,
+            utterance_id: Uuid::new_v4(),
+        };
+        steps[1].incidents.push(incident.clone());
+        steps[1].spoken_instructions.push(instruction.clone());
+        let route = gen_route_from_steps(steps);
+
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig::new(
+                StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 10,
+                    minimum_horizontal_accuracy: 0,
+                },
+                RouteDeviationTracking::StaticThreshold {
+                    minimum_horizontal_accuracy: 5,
+                    max_acceptable_deviation: 20.0,
+                },
+                CourseFiltering::Raw,
+            ),
+        );
+        let location_at = |lng: f64, lat: f64| UserLocation {
+            coordinates: GeographicCoordinate { lat, lng },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+
+        let state = controller.get_initial_state(location_at(0.0, 0.0));
+        let update = controller.update_user_location_with_events(location_at(0.0005, 0.0), &state);
+        assert_eq!(update.events, []);
 
-            simulation_state = new_simulation_state;
-            state = new_state.clone();
-            states.push(new_state);
+        // Reaching the end of the first step advances to the last maneuver before arrival
+        let update = controller
+            .update_user_location_with_events(location_at(0.001, 0.0), &update.trip_state);
+        assert_eq!(
+            update.events,
+            [
+                NavigationEvent::StepAdvanced {
+                    step: route.steps[1].clone()
+                },
+                NavigationEvent::IncidentAhead { incident },
+                NavigationEvent::ArrivalImminent {
+                    waypoint: route.waypoints[1]
+                },
+                NavigationEvent::SpokenInstructionTriggered { instruction },
+            ]
+        );
+
+        let update = controller
+            .update_user_location_with_events(location_at(0.0015, 0.001), &update.trip_state);
+        assert!(matches!(
+            update.events.as_slice(),
+            [NavigationEvent::DeviationStarted { .. }]
+        ));
+        let update = controller
+            .update_user_location_with_events(location_at(0.0015, 0.0), &update.trip_state);
+        assert_eq!(update.events, [NavigationEvent::DeviationEnded]);
+
+        // Advancing to the arrival step completes the leg
+        let update = controller
+            .update_user_location_with_events(location_at(0.002, 0.0), &update.trip_state);
+        assert_eq!(
+            update.events,
+            [
+                NavigationEvent::StepAdvanced {
+                    step: route.steps[2].clone()
+                },
+                NavigationEvent::WaypointReached {
+                    waypoint: route.waypoints[1]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spoken_instructions_are_only_surfaced_once() {
+        let mut route = gen_route_from_steps(vec![
+            gen_dummy_route_step(0.0, 0.0, 0.001, 0.0),
+            gen_dummy_route_step(0.001, 0.0, 0.002, 0.0),
+        ]);
+        for step in &mut route.steps {
+            step.spoken_instructions.push(SpokenInstruction {
+                text: "Continue".to_string(),
+                ssml: None,
+                trigger_distance_before_maneuver: 1000.0,
+                utterance_id: Uuid::new_v4(),
+            });
         }
+        let utterance_id = |step: usize| route.steps[step].spoken_instructions[0].utterance_id;
+        let spoken_utterance_id = |state: &TripState| match state {
+            TripState::Navigating {
+                spoken_instruction, ..
+            } => spoken_instruction
+                .as_ref()
+                .map(|instruction| instruction.utterance_id),
+            _ => panic!("Expected to be navigating"),
+        };
 
-        states
+        let controller = NavigationController::new(
+            route.clone(),
+            manual_advance_config(ArrivalDetection::Disabled),
+        );
+        let location = UserLocation {
+            coordinates: route.geometry[0],
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+
+        let state = controller.get_initial_state(location);
+        assert_eq!(spoken_utterance_id(&state), Some(utterance_id(0)));
+        assert_eq!(
+            bookkeeping(&state).announced_utterance_ids,
+            [utterance_id(0)]
+        );
+        let state = controller.update_user_location(location, &state);
+        assert_eq!(spoken_utterance_id(&state), None);
+
+        // Manually advancing triggers the instruction for the next step (once)
+        let state = controller.advance_to_next_step(&state);
+        assert_eq!(spoken_utterance_id(&state), Some(utterance_id(1)));
+        let state = controller.update_user_location(location, &state);
+        assert_eq!(spoken_utterance_id(&state), None);
+        assert_eq!(
+            bookkeeping(&state).announced_utterance_ids,
+            [utterance_id(0), utterance_id(1)]
+        );
+
+        // Replacing the route does not repeat instructions which were already announced
+        let rerouted_state = controller.get_rerouted_state(location, &state);
+        assert_eq!(spoken_utterance_id(&rerouted_state), None);
     }
 
-    // Full simulations for several routes with different settings
+    #[test]
+    fn test_trip_statistics() {
+        let route = gen_route_from_steps(vec![
+            gen_dummy_route_step(0.0, 0.0, 0.001, 0.0),
+            gen_dummy_route_step(0.001, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.0),
+        ]);
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig::new(
+                StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 10,
+                    minimum_horizontal_accuracy: 0,
+                },
+                RouteDeviationTracking::StaticThreshold {
+                    minimum_horizontal_accuracy: 5,
+                    max_acceptable_deviation: 20.0,
+                },
+                CourseFiltering::Raw,
+            ),
+        );
+        let start_time = SystemTime::now();
+        let location_at = |lng: f64, lat: f64, seconds: u64, speed: Option<f64>| UserLocation {
+            coordinates: GeographicCoordinate { lat, lng },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: start_time + Duration::from_secs(seconds),
+            speed: speed.map(|value| Speed {
+                value,
+                accuracy: None,
+            }),
+        };
+        let statistics = |state: &TripState| match state {
+            TripState::Navigating { statistics, .. } => *statistics,
+            _ => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        let state = controller.get_initial_state(location_at(0.0, 0.0, 0, None));
+        assert_eq!(statistics(&state), TripStatistics::default());
+
+        // The reported speed is preferred when available
+        let state =
+            controller.update_user_location(location_at(0.0005, 0.0, 10, Some(8.0)), &state);
+        // Wander off the route without making progress along it...
+        let off_route_state =
+            controller.update_user_location(location_at(0.0005, 0.001, 20, None), &state);
+        // ... and come back
+        let state =
+            controller.update_user_location(location_at(0.001, 0.0, 30, None), &off_route_state);
+
+        let half_step_length = Haversine::distance(Point::new(0.0, 0.0), Point::new(0.0005, 0.0));
+        let trip_statistics = statistics(&state);
+        assert!((trip_statistics.distance_traveled - 2.0 * half_step_length).abs() < 1e-6);
+        assert!((trip_statistics.elapsed_time - 30.0).abs() < f64::EPSILON);
+        assert!((trip_statistics.average_speed - 2.0 * half_step_length / 30.0).abs() < 1e-6);
+        assert!((trip_statistics.max_speed - 8.0).abs() < f64::EPSILON);
+        assert!((trip_statistics.time_off_route - 10.0).abs() < f64::EPSILON);
+        assert_eq!(trip_statistics.reroute_count, 0);
+
+        // Replacing the route counts as a reroute
+        let rerouted_state =
+            controller.get_rerouted_state(location_at(0.001, 0.0, 30, None), &state);
+        assert_eq!(statistics(&rerouted_state).reroute_count, 1);
+        assert!((statistics(&rerouted_state).time_off_route - 10.0).abs() < f64::EPSILON);
+
+        // Time spent off route before a reroute counts as off route
+        let rerouted_state =
+            controller.get_rerouted_state(location_at(0.0005, 0.001, 25, None), &off_route_state);
+        assert!((statistics(&rerouted_state).time_off_route - 5.0).abs() < f64::EPSILON);
+
+        // The final statistics are summarized when the trip is complete
+        let state = controller.advance_to_next_step(&state);
+        let state = controller.advance_to_next_step(&state);
+        assert_eq!(
+            state,
+            TripState::Complete {
+                summary: trip_statistics
+            }
+        );
+    }
 
     #[test]
-    fn test_extended_exact_distance() {
-        insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
-            get_extended_route(),
-            StepAdvanceMode::DistanceToEndOfStep {
-                distance: 0,
-                minimum_horizontal_accuracy: 0,
+    fn test_eta_blending() {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.duration = 20.0;
+        let route = gen_route_from_steps(vec![step, gen_dummy_route_step(0.002, 0.0, 0.002, 0.0)]);
+        let start_time = SystemTime::now();
+        let location_at = |lng: f64, seconds: u64| UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: start_time + Duration::from_secs(seconds),
+            speed: None,
+        };
+        let progress = |state: &TripState| match state {
+            TripState::Navigating { progress, .. } => progress.clone(),
+            _ => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                eta_blending: EtaBlending::ObservedProgress {
+                    window: 10,
+                    observed_weight: 1.0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.get_initial_state(location_at(0.0, 0));
+        assert!((progress(&state).adjusted_duration_remaining - 20.0).abs() < 1e-6);
+
+        // Progressing at the expected rate
+        let state = controller.update_user_location(location_at(0.001, 10), &state);
+        let trip_progress = progress(&state);
+        assert!((trip_progress.duration_remaining - 10.0).abs() < 1e-6);
+        assert!((trip_progress.adjusted_duration_remaining - 10.0).abs() < 1e-6);
+
+        // Slowing to half the expected rate.
+        // Only this latest progress is within the window.
+        let state = controller.update_user_location(location_at(0.0015, 20), &state);
+        let trip_progress = progress(&state);
+        assert!((trip_progress.duration_remaining - 5.0).abs() < 1e-6);
+        assert!((trip_progress.adjusted_duration_remaining - 10.0).abs() < 1e-6);
+        let sample_times: Vec<_> = bookkeeping(&state)
+            .progress_samples
+            .iter()
+            .map(|sample| sample.elapsed_time)
+            .collect();
+        assert_eq!(sample_times, [10.0, 20.0]);
+
+        // Without blending, the adjusted duration is the expected one
+        let controller =
+            NavigationController::new(route, manual_advance_config(ArrivalDetection::Disabled));
+        let state = controller.get_initial_state(location_at(0.0, 0));
+        let state = controller.update_user_location(location_at(0.001, 20), &state);
+        let trip_progress = progress(&state);
+        assert!(
+            (trip_progress.adjusted_duration_remaining - trip_progress.duration_remaining).abs()
+                < 1e-6
+        );
+        assert_eq!(bookkeeping(&state).progress_samples, []);
+    }
+
+    #[test]
+    fn test_map_matching() {
+        // Heads north, loops around to the east, and comes back west across itself
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.0, 0.002);
+        step.geometry.extend([
+            GeographicCoordinate {
+                lat: 0.002,
+                lng: 0.001,
+            },
+            GeographicCoordinate {
+                lat: 0.001,
+                lng: 0.001,
+            },
+            GeographicCoordinate {
+                lat: 0.001,
+                lng: -0.001,
+            },
+        ]);
+        let route = gen_route_from_steps(vec![
+            step,
+            gen_dummy_route_step(-0.001, 0.001, -0.001, 0.001),
+        ]);
+        let location_at = |lng: f64, lat: f64| UserLocation {
+            coordinates: GeographicCoordinate { lat, lng },
+            horizontal_accuracy: 5.0,
+            course_over_ground: Some(CourseOverGround::new(0.0, None)),
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let snapped_lng = |state: &TripState| match state {
+            TripState::Navigating {
+                snapped_user_location,
+                ..
+            } => snapped_user_location.coordinates.lng,
+            _ => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        // Approaching the crossing, slightly closer to the segment which crosses back
+        let locations = [
+            location_at(0.000_02, 0.000_8),
+            location_at(0.000_04, 0.001_02),
+        ];
+        let navigate = |location_snapping| {
+            let controller = NavigationController::new(
+                route.clone(),
+                NavigationControllerConfig {
+                    location_snapping,
+                    ..manual_advance_config(ArrivalDetection::Disabled)
+                },
+            );
+            let state = controller.get_initial_state(location_at(0.0, 0.0));
+            locations.iter().fold(state, |state, location| {
+                controller.update_user_location(*location, &state)
+            })
+        };
+
+        // Snapping to the nearest point jumps ahead to the crossing segment
+        let state = navigate(LocationSnapping::Nearest);
+        assert!((snapped_lng(&state) - 0.000_04).abs() < 1e-9);
+        assert_eq!(bookkeeping(&state).recent_matches, []);
+
+        // Map matching stays on the segment the user is traveling along
+        let state = navigate(LocationSnapping::MapMatching { window: 3 });
+        assert!(snapped_lng(&state).abs() < 1e-9);
+        let TripState::Navigating {
+            current_step_geometry_index,
+            ref bookkeeping,
+            ..
+        } = state
+        else {
+            panic!("Expected to be navigating, got {state:?}");
+        };
+        assert_eq!(current_step_geometry_index, Some(0));
+        // The window includes the initial location
+        let matched_coordinates: Vec<_> = bookkeeping
+            .recent_matches
+            .iter()
+            .map(|location_match| location_match.location.coordinates)
+            .collect();
+        assert_eq!(
+            matched_coordinates,
+            [
+                location_at(0.0, 0.0).coordinates,
+                locations[0].coordinates,
+                locations[1].coordinates
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dead_reckoning() {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.duration = 20.0;
+        let route = gen_route_from_steps(vec![
+            step.clone(),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.001),
+            gen_dummy_route_step(0.002, 0.001, 0.002, 0.001),
+        ]);
+        let start_time = SystemTime::now();
+        let location = UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng: 0.0 },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: start_time,
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+        };
+        let estimated_lng = |state: &TripState| match state {
+            TripState::Navigating {
+                snapped_user_location,
+                is_location_estimated: true,
+                ..
+            } => snapped_user_location.coordinates.lng,
+            _ => panic!("Expected an estimated location, got {state:?}"),
+        };
+        let remaining_step_count = |state: &TripState| match state {
+            TripState::Navigating {
+                remaining_steps, ..
+            } => remaining_steps.len(),
+            _ => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig {
+                step_advance: StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 5,
+                    minimum_horizontal_accuracy: 0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.get_initial_state(location);
+
+        // There's nothing to predict without any time passing
+        assert_eq!(controller.predict_state_at(&state, start_time), state);
+
+        // The user keeps moving along the route at their last known speed
+        let predicted_state =
+            controller.predict_state_at(&state, start_time + Duration::from_secs(5));
+        assert!((estimated_lng(&predicted_state) - 0.000_449).abs() < 1e-6);
+        assert_eq!(remaining_step_count(&predicted_state), 3);
+
+        // Predictions stop at the end of the step, without advancing to the next one
+        let predicted_state =
+            controller.predict_state_at(&predicted_state, start_time + Duration::from_secs(30));
+        assert!((estimated_lng(&predicted_state) - 0.002).abs() < 1e-9);
+        assert_eq!(remaining_step_count(&predicted_state), 3);
+
+        // A real location update replaces the estimate (and advances the step)
+        let state = controller.update_user_location(
+            UserLocation {
+                coordinates: GeographicCoordinate {
+                    lat: 0.0,
+                    lng: 0.002,
+                },
+                timestamp: start_time + Duration::from_secs(31),
+                ..location
+            },
+            &predicted_state,
+        );
+        assert!(matches!(
+            state,
+            TripState::Navigating {
+                is_location_estimated: false,
+                ..
             }
         ));
+        assert_eq!(remaining_step_count(&state), 2);
+
+        // Without a speed, the route's expected speed for the step is used
+        let state = controller.get_initial_state(UserLocation {
+            speed: None,
+            ..location
+        });
+        let predicted_state =
+            controller.predict_state_at(&state, start_time + Duration::from_secs(2));
+        let expected_distance = step.distance / step.duration * 2.0;
+        assert!(
+            (estimated_lng(&predicted_state) - 0.002 * expected_distance / step.distance).abs()
+                < 1e-6
+        );
     }
 
     #[test]
-    fn test_extended_relative_linestring() {
-        insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
-            get_extended_route(),
-            StepAdvanceMode::RelativeLineStringDistance {
-                minimum_horizontal_accuracy: 0,
-                special_advance_conditions: None,
-            }
+    fn test_dead_reckoning_leaves_trip_alone() {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.spoken_instructions.push(SpokenInstruction {
+            text: "Turn left".to_string(),
+            ssml: None,
+            trigger_distance_before_maneuver: 100.0,
+            utterance_id: Uuid::new_v4(),
+        });
+        let route = gen_route_from_steps(vec![
+            step,
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.001),
+            gen_dummy_route_step(0.002, 0.001, 0.002, 0.001),
+        ]);
+        let start_time = SystemTime::now();
+        let location = UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng: 0.0 },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: start_time,
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+        };
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                step_advance: StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 5,
+                    minimum_horizontal_accuracy: 0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.update_user_location(
+            UserLocation {
+                coordinates: GeographicCoordinate {
+                    lat: 0.0,
+                    lng: 0.000_1,
+                },
+                timestamp: start_time + Duration::from_secs(1),
+                ..location
+            },
+            &controller.get_initial_state(location),
+        );
+
+        // Far enough to trigger the spoken instruction, and to reach the end of the step
+        let predicted_state =
+            controller.predict_state_at(&state, start_time + Duration::from_secs(30));
+        let (
+            TripState::Navigating {
+                remaining_steps,
+                remaining_waypoints,
+                completed_waypoints,
+                statistics,
+                bookkeeping,
+                deviation,
+                ..
+            },
+            TripState::Navigating {
+                remaining_steps: predicted_remaining_steps,
+                remaining_waypoints: predicted_remaining_waypoints,
+                completed_waypoints: predicted_completed_waypoints,
+                statistics: predicted_statistics,
+                bookkeeping: predicted_bookkeeping,
+                deviation: predicted_deviation,
+                spoken_instruction: Some(spoken_instruction),
+                is_location_estimated: true,
+                ..
+            },
+        ) = (&state, &predicted_state)
+        else {
+            panic!(
+                "Expected an estimated location with a spoken instruction, got {predicted_state:?}"
+            );
+        };
+        assert_eq!(
+            spoken_instruction.utterance_id,
+            route.steps[0].spoken_instructions[0].utterance_id
+        );
+
+        // The step does not advance, and nothing about the trip so far changes
+        assert_eq!(predicted_remaining_steps, remaining_steps);
+        assert_eq!(predicted_remaining_waypoints, remaining_waypoints);
+        assert_eq!(predicted_completed_waypoints, completed_waypoints);
+        assert_eq!(predicted_statistics, statistics);
+        assert_eq!(predicted_bookkeeping, bookkeeping);
+        assert_eq!(predicted_deviation, deviation);
+
+        // Apart from the spoken instruction, there's nothing to report
+        assert_eq!(
+            controller.navigation_events(&state, &predicted_state),
+            vec![NavigationEvent::SpokenInstructionTriggered {
+                instruction: spoken_instruction.clone(),
+            }]
+        );
+
+        // Chained predictions surface the same spoken instruction,
+        // since it is not recorded as announced
+        let predicted_state =
+            controller.predict_state_at(&predicted_state, start_time + Duration::from_secs(31));
+        assert!(matches!(
+            predicted_state,
+            TripState::Navigating {
+                spoken_instruction: Some(SpokenInstruction { utterance_id, .. }),
+                ..
+            } if utterance_id == spoken_instruction.utterance_id
         ));
     }
 
     #[test]
-    fn test_self_intersecting_exact_distance() {
-        insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
-            get_self_intersecting_route(),
-            StepAdvanceMode::DistanceToEndOfStep {
-                distance: 0,
-                minimum_horizontal_accuracy: 0,
+    fn test_location_filtering() {
+        let route = gen_route_from_steps(vec![
+            gen_dummy_route_step(0.0, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.0),
+        ]);
+        let start_time = SystemTime::now();
+        let location_at = |lng: f64, lat: f64, seconds: u64| UserLocation {
+            coordinates: GeographicCoordinate { lat, lng },
+            horizontal_accuracy: 5.0,
+            course_over_ground: Some(CourseOverGround::new(90.0, None)),
+            timestamp: start_time + Duration::from_secs(seconds),
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+        };
+        let controller_with = |location_filtering| {
+            NavigationController::new(
+                route.clone(),
+                NavigationControllerConfig {
+                    route_deviation_tracking: RouteDeviationTracking::StaticThreshold {
+                        minimum_horizontal_accuracy: 10,
+                        max_acceptable_deviation: 20.0,
+                    },
+                    location_filtering,
+                    ..manual_advance_config(ArrivalDetection::Disabled)
+                },
+            )
+        };
+        // About 500 m north of the route, a second after the initial location
+        let wild_location = location_at(0.000_1, 0.004_5, 1);
+
+        // Without filtering, a single wild location puts the user off route
+        let controller = controller_with(LocationFiltering::Disabled);
+        let state = controller.get_initial_state(location_at(0.0, 0.0, 0));
+        let state = controller.update_user_location(wild_location, &state);
+        assert!(matches!(
+            state,
+            TripState::Navigating {
+                deviation: RouteDeviation::OffRoute { .. },
+                ..
             }
         ));
+        assert_eq!(bookkeeping(&state).location_estimate, None);
+
+        // With filtering, it is rejected, as are locations which arrive out of order
+        let controller = controller_with(LocationFiltering::AlphaBeta {
+            alpha: 0.5,
+            beta: 0.1,
+            max_speed: 50.0,
+        });
+        let state = controller.get_initial_state(location_at(0.0, 0.0, 10));
+        let initial_estimate = bookkeeping(&state)
+            .location_estimate
+            .expect("Expected an initial location estimate");
+        assert_eq!(initial_estimate.location, location_at(0.0, 0.0, 10));
+        assert!((initial_estimate.velocity_east - 10.0).abs() < 1e-9);
+        assert!(initial_estimate.velocity_north.abs() < 1e-9);
+        assert_eq!(
+            controller.update_user_location(location_at(0.000_1, 0.004_5, 11), &state),
+            state
+        );
+        assert_eq!(
+            controller.update_user_location(location_at(0.000_1, 0.0, 9), &state),
+            state
+        );
+
+        // Plausible locations are smoothed toward the predicted position (about 10 m east)
+        let state = controller.update_user_location(location_at(0.000_1, 0.000_04, 11), &state);
+        let TripState::Navigating {
+            deviation,
+            bookkeeping:
+                TripBookkeeping {
+                    location_estimate: Some(estimate),
+                    ..
+                },
+            ..
+        } = state
+        else {
+            panic!("Expected to be navigating with a location estimate, got {state:?}");
+        };
+        assert_eq!(deviation, RouteDeviation::NoDeviation);
+        assert!((estimate.location.coordinates.lng - 0.000_095).abs() < 1e-6);
+        assert!((estimate.location.coordinates.lat - 0.000_02).abs() < 1e-6);
     }
 
     #[test]
-    fn test_self_intersecting_relative_linestring() {
-        insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
-            get_self_intersecting_route(),
-            StepAdvanceMode::RelativeLineStringDistance {
-                minimum_horizontal_accuracy: 0,
-                special_advance_conditions: None,
+    fn test_heading_fusion() {
+        let route = gen_route_from_steps(vec![
+            gen_dummy_route_step(0.0, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.0),
+        ]);
+        let start_time = SystemTime::now();
+        // Standing still, with a meaningless course
+        let location_at = |seconds: u64| UserLocation {
+            coordinates: GeographicCoordinate {
+                lat: 0.0,
+                lng: 0.000_1,
+            },
+            horizontal_accuracy: 5.0,
+            course_over_ground: Some(CourseOverGround::new(180.0, None)),
+            timestamp: start_time + Duration::from_secs(seconds),
+            speed: Some(Speed {
+                value: 0.0,
+                accuracy: None,
+            }),
+        };
+        let heading_at = |true_heading: u16, seconds: u64| Heading {
+            true_heading,
+            accuracy: 10,
+            timestamp: start_time + Duration::from_secs(seconds),
+        };
+        let course = |state: &TripState| match state {
+            TripState::Navigating {
+                snapped_user_location,
+                ..
+            } => snapped_user_location
+                .course_over_ground
+                .map(|course| course.degrees),
+            _ => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                snapped_location_course_filtering: CourseFiltering::FuseHeading {
+                    heading_only_speed: 1.0,
+                    course_only_speed: 5.0,
+                    max_heading_age: 5.0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.get_initial_state(location_at(0));
+        assert_eq!(course(&state), Some(180));
+
+        // Heading updates take effect right away, unless they are out of date
+        let state = controller.update_heading(heading_at(90, 1), &state);
+        assert_eq!(course(&state), Some(90));
+        let state = controller.update_heading(heading_at(45, 0), &state);
+        assert_eq!(course(&state), Some(90));
+        assert_eq!(bookkeeping(&state).heading, Some(heading_at(90, 1)));
+
+        // ...and are fused with location updates until they get too old
+        let state = controller.update_user_location(location_at(2), &state);
+        assert_eq!(course(&state), Some(90));
+        assert_eq!(
+            bookkeeping(&state).reported_course_over_ground,
+            location_at(2).course_over_ground
+        );
+        let state = controller.update_user_location(location_at(10), &state);
+        assert_eq!(course(&state), Some(180));
+
+        // Other course filtering modes ignore the heading
+        let controller =
+            NavigationController::new(route, manual_advance_config(ArrivalDetection::Disabled));
+        let state = controller.get_initial_state(location_at(0));
+        let state = controller.update_heading(heading_at(90, 1), &state);
+        assert_eq!(course(&state), Some(180));
+        assert_eq!(bookkeeping(&state).heading, Some(heading_at(90, 1)));
+    }
+
+    #[test]
+    fn test_course_smoothing() {
+        // Zigzags east, then turns north
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.0, 0.0);
+        step.geometry = (0..=20)
+            .map(|i| GeographicCoordinate {
+                lat: if i % 2 == 0 { 0.0 } else { 0.000_02 },
+                lng: f64::from(i) * 0.000_1,
+            })
+            .collect();
+        let route = gen_route_from_steps(vec![
+            step,
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.001),
+            gen_dummy_route_step(0.002, 0.001, 0.002, 0.001),
+        ]);
+        let location_at = |lng: f64| UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.000_01, lng },
+            horizontal_accuracy: 5.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let course = |snapped_location_course_filtering, lng| {
+            let controller = NavigationController::new(
+                route.clone(),
+                NavigationControllerConfig {
+                    snapped_location_course_filtering,
+                    ..manual_advance_config(ArrivalDetection::Disabled)
+                },
+            );
+            match controller.get_initial_state(location_at(lng)) {
+                TripState::Navigating {
+                    snapped_user_location,
+                    ..
+                } => {
+                    snapped_user_location
+                        .course_over_ground
+                        .expect("Expected a course")
+                        .degrees
+                }
+                state => panic!("Expected to be navigating, got {state:?}"),
             }
-        ));
+        };
+        let smoothed = CourseFiltering::SnapToRouteSmoothed {
+            window: 30.0,
+            maneuver_blend_distance: 20.0,
+        };
+
+        // Each segment veers off to one side or the other...
+        assert_eq!(course(CourseFiltering::SnapToRoute, 0.000_55), 101);
+        assert_eq!(course(CourseFiltering::SnapToRoute, 0.000_65), 79);
+        // ...but on average, the route goes east
+        for lng in [0.000_55, 0.000_65] {
+            let smoothed_course = course(smoothed, lng);
+            assert!((88..=92).contains(&smoothed_course), "{smoothed_course}");
+        }
+
+        // Approaching the turn (about halfway into the blend), the course blends toward the next step
+        let approaching_turn = course(smoothed, 0.001_9);
+        assert!((40..=60).contains(&approaching_turn), "{approaching_turn}");
+        assert!(course(smoothed, 0.002) <= 2);
     }
 
     #[test]
-    fn test_self_intersecting_relative_linestring_min_line_distance() {
-        insta::assert_yaml_snapshot!(test_full_route_state_snapshot(
-            get_self_intersecting_route(),
-            StepAdvanceMode::RelativeLineStringDistance {
-                minimum_horizontal_accuracy: 0,
-                special_advance_conditions: Some(
-                    SpecialAdvanceConditions::MinimumDistanceFromCurrentStepLine(10)
-                ),
+    fn test_speeding_detection() {
+        let mut step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        step.annotations = Some(vec![SegmentAnnotation {
+            max_speed: Some(SpeedLimit::Limited {
+                value: 50.0,
+                unit: SpeedUnit::KilometersPerHour,
+            }),
+            ..SegmentAnnotation::default()
+        }]);
+        let route = gen_route_from_steps(vec![step, gen_dummy_route_step(0.002, 0.0, 0.002, 0.0)]);
+        let location_with_speed = |speed: Option<f64>| UserLocation {
+            coordinates: GeographicCoordinate {
+                lat: 0.0,
+                lng: 0.0005,
+            },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: speed.map(|value| Speed {
+                value,
+                accuracy: None,
+            }),
+        };
+        let speed_limit_status =
+            |controller: &NavigationController, speed: Option<f64>| match controller
+                .get_initial_state(location_with_speed(speed))
+            {
+                TripState::Navigating {
+                    speed_limit,
+                    is_speeding,
+                    ..
+                } => (speed_limit, is_speeding),
+                state => panic!("Expected to be navigating, got {state:?}"),
+            };
+
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                speeding_detection: SpeedingDetection::Enabled {
+                    tolerance_percent: 10,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let (speed_limit, is_speeding) = speed_limit_status(&controller, Some(15.0));
+        assert_eq!(
+            speed_limit,
+            Some(SpeedLimit::Limited {
+                value: 50.0,
+                unit: SpeedUnit::KilometersPerHour,
+            })
+        );
+        // 54 km/h is within the tolerance
+        assert!(!is_speeding);
+        // 57.6 km/h is not
+        assert!(speed_limit_status(&controller, Some(16.0)).1);
+        // The user's speed must be known
+        assert!(!speed_limit_status(&controller, None).1);
+
+        // Disabled detection never flags speeding
+        let controller =
+            NavigationController::new(route, manual_advance_config(ArrivalDetection::Disabled));
+        let (speed_limit, is_speeding) = speed_limit_status(&controller, Some(30.0));
+        assert!(speed_limit.is_some());
+        assert!(!is_speeding);
+    }
+
+    #[test]
+    fn test_speed_limit_changes_across_step_boundary() {
+        let speed_limit = |value: f64| SegmentAnnotation {
+            max_speed: Some(SpeedLimit::Limited {
+                value,
+                unit: SpeedUnit::KilometersPerHour,
+            }),
+            ..SegmentAnnotation::default()
+        };
+        // The first step has two segments, so the user ends it on the second one
+        let mut first_step = gen_dummy_route_step(0.0, 0.0, 0.002, 0.0);
+        first_step.geometry.insert(
+            1,
+            GeographicCoordinate {
+                lng: 0.001,
+                lat: 0.0,
+            },
+        );
+        first_step.annotations = Some(vec![speed_limit(50.0), speed_limit(50.0)]);
+        let mut second_step = gen_dummy_route_step(0.002, 0.0, 0.004, 0.0);
+        second_step.annotations = Some(vec![speed_limit(30.0)]);
+        let route = gen_route_from_steps(vec![
+            first_step,
+            second_step,
+            gen_dummy_route_step(0.004, 0.0, 0.004, 0.0),
+        ]);
+        let location_at = |lng: f64| UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+        let current_speed_limit = |state: &TripState| match state {
+            TripState::Navigating { speed_limit, .. } => *speed_limit,
+            state => panic!("Expected to be navigating, got {state:?}"),
+        };
+
+        // Advancing manually
+        let controller = NavigationController::new(
+            route.clone(),
+            manual_advance_config(ArrivalDetection::Disabled),
+        );
+        let state = controller.get_initial_state(location_at(0.0015));
+        assert_eq!(current_speed_limit(&state), speed_limit(50.0).max_speed);
+        let state = controller.advance_to_next_step(&state);
+        assert_eq!(current_speed_limit(&state), speed_limit(30.0).max_speed);
+
+        // Advancing automatically on a location update
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig {
+                step_advance: StepAdvanceMode::DistanceToEndOfStep {
+                    distance: 10,
+                    minimum_horizontal_accuracy: 0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.get_initial_state(location_at(0.0015));
+        let state = controller.update_user_location(location_at(0.002_05), &state);
+        let TripState::Navigating {
+            remaining_steps, ..
+        } = &state
+        else {
+            panic!("Expected to be navigating, got {state:?}");
+        };
+        assert_eq!(remaining_steps.len(), 2);
+        assert_eq!(current_speed_limit(&state), speed_limit(30.0).max_speed);
+    }
+
+    #[test]
+    fn test_event_look_ahead() {
+        let mut steps = vec![
+            gen_dummy_route_step(0.0, 0.0, 0.001, 0.0),
+            gen_dummy_route_step(0.001, 0.0, 0.002, 0.0),
+            gen_dummy_route_step(0.002, 0.0, 0.002, 0.0),
+        ];
+        steps[1].incidents.push(gen_incident());
+        let route = gen_route_from_steps(steps);
+        let location = UserLocation {
+            coordinates: GeographicCoordinate {
+                lat: 0.0,
+                lng: 0.0005,
+            },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        };
+
+        let controller = NavigationController::new(
+            route.clone(),
+            NavigationControllerConfig {
+                event_look_ahead: EventLookAhead::Enabled {
+                    max_count: 3,
+                    max_distance: 1000.0,
+                },
+                ..manual_advance_config(ArrivalDetection::Disabled)
+            },
+        );
+        let state = controller.get_initial_state(location);
+        let TripState::Navigating {
+            upcoming_events: Some(ref upcoming_events),
+            ..
+        } = state
+        else {
+            panic!("Expected upcoming events, got {state:?}");
+        };
+        assert_eq!(upcoming_events.incidents.len(), 1);
+        assert_eq!(upcoming_events.incidents[0].incident, gen_incident());
+        // The incident starts at the end of the current step
+        assert!((upcoming_events.incidents[0].distance_to_start - 55.6).abs() < 0.1);
+        assert_eq!(
+            controller.upcoming_events(&state, 3, 1000.0).as_ref(),
+            Some(upcoming_events)
+        );
+
+        // Upcoming events are still available on demand when not included in the state
+        let controller =
+            NavigationController::new(route, manual_advance_config(ArrivalDetection::Disabled));
+        let state = controller.get_initial_state(location);
+        assert!(matches!(
+            state,
+            TripState::Navigating {
+                upcoming_events: None,
+                ..
             }
         ));
+        assert!(controller
+            .upcoming_events(&state, 3, 1000.0)
+            .is_some_and(|events| events.incidents.len() == 1));
+        assert_eq!(
+            controller.upcoming_events(&TripState::Idle, 3, 1000.0),
+            None
+        );
     }
 }
@@ -1,14 +1,17 @@
 //! State and configuration data models.
 
 use crate::deviation_detection::{RouteDeviation, RouteDeviationTracking};
-use crate::models::{RouteStep, SpokenInstruction, UserLocation, VisualInstruction, Waypoint};
+use crate::models::{
+    CongestionLevel, CourseOverGround, Heading, Incident, RouteStep, SegmentAnnotation, SpeedLimit,
+    SpokenInstruction, UserLocation, VisualInstruction, Waypoint,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use geo::LineString;
 #[cfg(any(feature = "wasm-bindgen", test))]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "wasm-bindgen")]
 use tsify::Tsify;
+use uuid::Uuid;
 
 /// High-level state describing progress through a route.
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +29,21 @@ pub struct TripProgress {
     pub distance_remaining: f64,
     /// The total duration remaining in the trip, in seconds.
     pub duration_remaining: f64,
+    /// The total duration remaining in the trip, in seconds,
+    /// adjusted for the rate at which the user has actually been progressing along the route.
+    ///
+    /// This is the same as `duration_remaining` unless [`EtaBlending`] is enabled
+    /// and enough location updates have been observed.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub adjusted_duration_remaining: f64,
+    /// The distance to the end of the current leg
+    /// (the next waypoint where the user stops), in meters.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub distance_to_next_waypoint: f64,
+    /// The duration remaining until the end of the current leg
+    /// (the next waypoint where the user stops), in seconds.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub duration_to_next_waypoint: f64,
 }
 
 /// The state of a navigation session.
@@ -72,25 +90,390 @@ pub enum TripState {
         deviation: RouteDeviation,
         /// The visual instruction that should be displayed in the user interface.
         visual_instruction: Option<VisualInstruction>,
-        /// The most recent spoken instruction that should be synthesized using TTS.
+        /// A spoken instruction which was triggered by this update, and should be synthesized using TTS.
         ///
-        /// Note it is the responsibility of the platform layer to ensure that utterances are not synthesized multiple times. This property simply reports the current spoken instruction.
+        /// This is only set by the update which first triggers the instruction.
+        /// Instructions which were already announced during the trip
+        /// are never surfaced again, including after manual step advances and route replacement.
         spoken_instruction: Option<SpokenInstruction>,
-        /// Annotation data at the current location.
-        /// This is represented as a json formatted byte array to allow for flexible encoding of custom annotations.
-        annotation_json: Option<String>,
+        /// Annotation data for the route segment at the current location.
+        annotation: Option<SegmentAnnotation>,
+        /// The posted speed limit at the current location (if known).
+        ///
+        /// This comes from the `max_speed` in the current segment's `annotation`.
+        speed_limit: Option<SpeedLimit>,
+        /// True if the user's speed exceeds the `speed_limit`
+        /// by more than the tolerance configured in [`SpeedingDetection`].
+        ///
+        /// This is always false if the user's speed or the speed limit is unknown.
+        is_speeding: bool,
+        /// True if the snapped location was estimated by dead reckoning
+        /// (see [`predict_state_at`](super::NavigationController::predict_state_at))
+        /// rather than matched from a location update.
+        is_location_estimated: bool,
+        /// Incidents and congestion on the route ahead.
+        ///
+        /// This is only computed when [`EventLookAhead`] is enabled,
+        /// and is [`None`] otherwise.
+        upcoming_events: Option<UpcomingEvents>,
+        /// Waypoints which have already been visited during the trip, in the order they were reached.
+        ///
+        /// Unlike `remaining_waypoints`, this list survives route replacement
+        /// (see [`get_rerouted_state`](super::NavigationController::get_rerouted_state)),
+        /// so it always describes the whole trip rather than the current route.
+        completed_waypoints: Vec<Waypoint>,
+        /// Statistics about the trip so far.
+        ///
+        /// Like `completed_waypoints`, these survive route replacement.
+        statistics: TripStatistics,
+        /// Working state which the navigation controller carries from one update to the next.
+        ///
+        /// This is only meaningful to the navigation controller;
+        /// pass it back unchanged with the rest of the trip state.
+        bookkeeping: TripBookkeeping,
+    },
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    /// The user has arrived at a waypoint where they stop
+    /// (an intermediate stop or the final destination).
+    ///
+    /// This state is only produced when [`ArrivalDetection`] is enabled.
+    /// Navigation does not continue on its own;
+    /// use [`resume_navigation`](super::NavigationController::resume_navigation)
+    /// to continue to the next leg of the route (or complete the trip at the final destination).
+    Arrived {
+        /// The waypoint which the user arrived at.
+        waypoint: Waypoint,
+        /// Whether the waypoint is the final destination of the route.
+        is_final_destination: bool,
+        /// The user's snapped location at the time of arrival.
+        snapped_user_location: UserLocation,
+        /// The steps which remained at the time of arrival, starting with the final step of the leg.
+        remaining_steps: Vec<RouteStep>,
+        /// Remaining waypoints to visit on the route.
+        ///
+        /// The waypoint that the user arrived at has already been removed from this list.
+        remaining_waypoints: Vec<Waypoint>,
+        /// Waypoints which have already been visited during the trip (including this one),
+        /// in the order they were reached.
+        completed_waypoints: Vec<Waypoint>,
+        /// Statistics about the trip up to the time of arrival.
+        statistics: TripStatistics,
+        /// Working state which the navigation controller carries from one update to the next.
+        bookkeeping: TripBookkeeping,
     },
     /// The navigation controller has reached the end of the trip.
-    Complete,
+    Complete {
+        /// Statistics about the whole trip.
+        summary: TripStatistics,
+    },
+}
+
+/// Working state which the navigation controller carries between updates of a [`TripState`].
+///
+/// None of this is intended for display;
+/// it is part of the trip state only so that the navigation controller can remain a pure type.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct TripBookkeeping {
+    /// The utterance IDs of all spoken instructions which have been triggered during the trip.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    #[cfg_attr(feature = "wasm-bindgen", tsify(type = "string[]"))]
+    pub(crate) announced_utterance_ids: Vec<Uuid>,
+    /// The elapsed trip time, in seconds, at which the user most recently came within
+    /// the arrival radius of the current leg's destination.
+    ///
+    /// This is used to measure dwell time for [`ArrivalDetection::Enabled`],
+    /// and is [`None`] while the user is outside the radius.
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub(crate) arrival_radius_entered_at: Option<f64>,
+    /// Samples of the trip progress from recent location updates, oldest first.
+    ///
+    /// This is used to observe the user's rate of progress for [`EtaBlending::ObservedProgress`],
+    /// and is empty when ETA blending is disabled.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    pub(crate) progress_samples: Vec<ProgressSample>,
+    /// Recent location updates and how they were matched to the route, oldest first.
+    ///
+    /// This is used to match the user's location with [`LocationSnapping::MapMatching`],
+    /// and is empty when snapping to the nearest point.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    pub(crate) recent_matches: Vec<LocationMatch>,
+    /// The filtered estimate of the user's location and velocity as of the last accepted update.
+    ///
+    /// This is used to clean up location updates with [`LocationFiltering::AlphaBeta`],
+    /// and is [`None`] when location filtering is disabled.
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub(crate) location_estimate: Option<LocationEstimate>,
+    /// The most recent compass heading (see [`NavigationController::update_heading`](super::NavigationController::update_heading)).
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub(crate) heading: Option<Heading>,
+    /// The course over ground reported with the most recent location update,
+    /// before it was fused with the heading for [`CourseFiltering::FuseHeading`].
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    pub(crate) reported_course_over_ground: Option<CourseOverGround>,
+}
+
+impl TripBookkeeping {
+    /// Returns the bookkeeping with the utterance ID of `spoken_instruction` recorded as announced
+    /// (if it was not announced before).
+    pub(crate) fn record_announcement(
+        &self,
+        spoken_instruction: Option<&SpokenInstruction>,
+    ) -> Self {
+        let mut announced_utterance_ids = self.announced_utterance_ids.clone();
+        if let Some(instruction) = spoken_instruction {
+            if !announced_utterance_ids.contains(&instruction.utterance_id) {
+                announced_utterance_ids.push(instruction.utterance_id);
+            }
+        }
+        Self {
+            announced_utterance_ids,
+            ..self.clone()
+        }
+    }
+}
+
+/// A sample of the user's progress along the route at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ProgressSample {
+    /// The elapsed trip time when the sample was taken, in seconds
+    /// (see [`TripStatistics::elapsed_time`]).
+    pub elapsed_time: f64,
+    /// The duration remaining in the trip according to the route, in seconds
+    /// (see [`TripProgress::duration_remaining`]).
+    pub duration_remaining: f64,
+}
+
+/// A filtered estimate of the user's location and velocity (see [`LocationFiltering`]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct LocationEstimate {
+    /// The filtered location.
+    pub location: UserLocation,
+    /// The estimated velocity toward the east, in meters per second.
+    pub velocity_east: f64,
+    /// The estimated velocity toward the north, in meters per second.
+    pub velocity_north: f64,
+}
+
+/// A location update which was matched to the route with [`LocationSnapping::MapMatching`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct LocationMatch {
+    /// The (raw) location reported by the location provider.
+    pub location: UserLocation,
+    /// The positions along the route which were considered for this location.
+    pub candidates: Vec<MatchCandidate>,
+    /// The distance along the route of the position the location was matched to, in meters.
+    pub distance_along_route: f64,
+}
+
+/// A possible position of the user along the route.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct MatchCandidate {
+    /// The distance along the route from its start, in meters.
+    pub distance_along_route: f64,
+    /// The log probability of observing the location if the user were at this position.
+    pub log_likelihood: f64,
+}
+
+/// An incident on the route ahead of the user.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct UpcomingIncident {
+    /// The incident.
+    ///
+    /// NOTE: The geometry indices are relative to the step that the incident was found on.
+    pub incident: Incident,
+    /// The distance along the route from the user's snapped location to the start of the incident, in meters.
+    ///
+    /// This is zero if the user is already within the affected stretch of road.
+    pub distance_to_start: f64,
+    /// The distance along the route from the user's snapped location to the end of the incident, in meters.
+    pub distance_to_end: f64,
+}
+
+/// A continuous stretch of the route ahead of the user with the same level of traffic congestion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct CongestionSpan {
+    /// The level of congestion along the span.
+    pub level: CongestionLevel,
+    /// The distance along the route from the user's snapped location to the start of the span, in meters.
+    ///
+    /// This is zero if the user is already within the span.
+    pub distance_to_start: f64,
+    /// The length of the span ahead of the user, in meters.
+    pub length: f64,
+}
+
+/// Incidents and traffic congestion on the route ahead of the user, nearest first.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct UpcomingEvents {
+    /// Incidents which the user has not yet passed the end of.
+    pub incidents: Vec<UpcomingIncident>,
+    /// Spans of moderate or worse congestion
+    /// (see [`CongestionLevel`]) which the user has not yet passed the end of.
+    pub congestion: Vec<CongestionSpan>,
+}
+
+/// Statistics about a trip, accumulated by the [`NavigationController`](super::NavigationController)
+/// as user locations arrive.
+///
+/// These cover the whole trip, including any replaced routes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct TripStatistics {
+    /// The distance traveled since the start of the trip, in meters.
+    ///
+    /// This is accumulated from successive snapped user locations.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub distance_traveled: f64,
+    /// The time elapsed since the start of the trip, in seconds.
+    ///
+    /// This is accumulated from the timestamps of successive user locations.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub elapsed_time: f64,
+    /// The average speed over the trip, in meters per second.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub average_speed: f64,
+    /// The maximum speed reached during the trip, in meters per second.
+    ///
+    /// This uses the speed reported with each user location when available,
+    /// and the speed between successive locations otherwise.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub max_speed: f64,
+    /// The time spent off the route, in seconds.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0.0))]
+    pub time_off_route: f64,
+    /// The number of times that the route was replaced
+    /// (see [`get_rerouted_state`](super::NavigationController::get_rerouted_state)).
+    #[cfg_attr(feature = "uniffi", uniffi(default = 0))]
+    pub reroute_count: u32,
+}
+
+impl TripStatistics {
+    /// Returns the statistics after the user traveled `distance` meters over `elapsed_time` seconds.
+    ///
+    /// `speed` is the speed reported with the user's location, in meters per second (if any).
+    pub(crate) fn record_travel(
+        &self,
+        distance: f64,
+        elapsed_time: f64,
+        speed: Option<f64>,
+        is_off_route: bool,
+    ) -> Self {
+        let distance_traveled = self.distance_traveled + distance;
+        let total_elapsed_time = self.elapsed_time + elapsed_time;
+        let current_speed = speed.or_else(|| (elapsed_time > 0.0).then(|| distance / elapsed_time));
+
+        Self {
+            distance_traveled,
+            elapsed_time: total_elapsed_time,
+            average_speed: if total_elapsed_time > 0.0 {
+                distance_traveled / total_elapsed_time
+            } else {
+                0.0
+            },
+            max_speed: current_speed.map_or(self.max_speed, |speed| self.max_speed.max(speed)),
+            time_off_route: if is_off_route {
+                self.time_off_route + elapsed_time
+            } else {
+                self.time_off_route
+            },
+            reroute_count: self.reroute_count,
+        }
+    }
+}
+
+/// A discrete change which occurred during a navigation state update.
+///
+/// These are reported by
+/// [`update_user_location_with_events`](super::NavigationController::update_user_location_with_events)
+/// so that UIs, analytics, etc. do not need to compare successive [`TripState`]s.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum NavigationEvent {
+    /// Navigation advanced to a new step, which is now the current step.
+    StepAdvanced { step: RouteStep },
+    /// The user reached a waypoint, which was moved to the list of completed waypoints.
+    WaypointReached { waypoint: Waypoint },
+    /// A spoken instruction was triggered for the first time during the trip.
+    SpokenInstructionTriggered { instruction: SpokenInstruction },
+    /// The user went off the route.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    DeviationStarted {
+        /// The deviation from the route line, in meters.
+        deviation_from_route_line: f64,
+    },
+    /// The user is back on the route after a deviation.
+    DeviationEnded,
+    /// The new current step has an incident along it.
+    IncidentAhead { incident: Incident },
+    /// The user started the last maneuver before the end of a route leg
+    /// (the next maneuver is the arrival at `waypoint`).
+    ArrivalImminent { waypoint: Waypoint },
+}
+
+/// The result of a location update which also reports the [`NavigationEvent`]s that occurred.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct NavigationUpdate {
+    /// The updated trip state.
+    pub trip_state: TripState,
+    /// The events which occurred during the update, in the order they should be handled.
+    pub events: Vec<NavigationEvent>,
 }
 
 #[allow(clippy::large_enum_variant)]
 pub enum StepAdvanceStatus {
     /// Navigation has advanced, and the information on the next step is embedded.
-    Advanced {
-        step: RouteStep,
-        linestring: LineString,
-    },
+    Advanced { step: RouteStep },
     /// Navigation has reached the end of the route.
     EndOfRoute,
 }
@@ -102,12 +485,105 @@ pub enum StepAdvanceStatus {
 #[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
 pub enum CourseFiltering {
     /// Snap the user's course to the current step's linestring using the next index in the step's geometry.
-    ///
-    // TODO: We could make this more flexible by allowing the user to specify number of indices to average, etc.
     SnapToRoute,
 
+    /// Snap the user's course to the current step's linestring,
+    /// averaging the bearing of the route over a window around the snapped location.
+    ///
+    /// This keeps the course steady on densely sampled, curvy geometry,
+    /// where the bearing of individual segments can vary wildly.
+    /// Approaching the end of the step, the course also blends toward the start of the next step,
+    /// so that it doesn't jump when the step advances.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    SnapToRouteSmoothed {
+        /// The distance (in meters) behind and ahead of the snapped location to average over.
+        ///
+        /// The window doesn't extend beyond the current step.
+        window: f64,
+        /// The distance (in meters) before the end of the step at which the course
+        /// starts blending toward the start of the next step (fully reaching it at the maneuver).
+        maneuver_blend_distance: f64,
+    },
+
     /// Use the raw course as reported by the location provider with no processing.
     Raw,
+
+    /// Fuse the device's compass heading (see [`NavigationController::update_heading`](super::NavigationController::update_heading))
+    /// with the raw course, depending on the user's speed and the accuracy of each.
+    ///
+    /// Course over ground comes from the user's movement, so it is unreliable at walking speeds
+    /// and meaningless when stationary, whereas the compass doesn't depend on speed.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    FuseHeading {
+        /// The speed (in meters per second) at or below which only the heading is used.
+        heading_only_speed: f64,
+        /// The speed (in meters per second) at or above which only the course over ground is used.
+        course_only_speed: f64,
+        /// The maximum age of a heading (in seconds) relative to a location update.
+        ///
+        /// Older headings are ignored, in case the compass has stopped reporting.
+        max_heading_age: f64,
+    },
+}
+
+/// Controls how raw location updates are cleaned up before the [`NavigationController`] uses them.
+///
+/// A single wild location (ex: from a multipath reflection) can otherwise trigger
+/// a route deviation or a premature step advance.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum LocationFiltering {
+    /// Locations are used as reported by the location provider.
+    #[default]
+    Disabled,
+    /// Smooths locations with an alpha-beta filter, which tracks the user's position and velocity,
+    /// and rejects locations which are out of order or imply an impossible speed.
+    ///
+    /// Rejected locations leave the trip state unchanged.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    AlphaBeta {
+        /// How far to correct the predicted position toward each new location,
+        /// from 0 (ignore the location) to 1 (use the location as reported).
+        alpha: f64,
+        /// How far to correct the estimated velocity for the difference
+        /// between the predicted position and each new location (typically less than `alpha`).
+        beta: f64,
+        /// The fastest plausible speed of the user, in meters per second.
+        ///
+        /// Locations which are farther from the previous estimate than the user could have traveled
+        /// (allowing for the location's accuracy) are rejected.
+        max_speed: f64,
+    },
+}
+
+/// Controls how the user's location is snapped to the route by the [`NavigationController`].
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum LocationSnapping {
+    /// Snap each location to the nearest point on the current step's linestring.
+    #[default]
+    Nearest,
+    /// Match recent locations to the current step with a hidden Markov model.
+    ///
+    /// Each location has several candidate positions along the step.
+    /// The most likely sequence of positions is chosen based on
+    /// the distance of each candidate from the location,
+    /// how well the user's course agrees with the route at the candidate,
+    /// and how well the distance traveled along the route agrees with the distance between locations.
+    ///
+    /// This keeps the snapped location from jumping to a crossing segment
+    /// (ex: where a route intersects itself, or passes over or next to another part of the route).
+    /// The snapped location never moves backward along the route.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    MapMatching {
+        /// The number of previous location updates which are considered along with the current one
+        /// (at least one).
+        window: u16,
+    },
 }
 
 /// The step advance mode describes when the current maneuver has been successfully completed,
@@ -169,6 +645,204 @@ pub enum SpecialAdvanceConditions {
     MinimumDistanceFromCurrentStepLine(u16),
 }
 
+/// Controls whether the [`NavigationController`](super::NavigationController)
+/// considers upcoming steps (and not just the current one) when matching the user's location to the route.
+///
+/// Looking ahead lets navigation recover when the user skips past one or more steps
+/// (ex: a short step which was missed entirely, or a jump in the GPS signal)
+/// by jumping directly to the best matching step.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum StepLookAhead {
+    /// Only the current step is considered;
+    /// navigation proceeds one step at a time according to the [`StepAdvanceMode`].
+    #[default]
+    Disabled,
+    /// Searches a window of upcoming steps for a better match than the current step.
+    ///
+    /// The controller only jumps ahead when the user is *farther* than `max_distance_from_step`
+    /// from the current step, and *within* `max_distance_from_step` of an upcoming step.
+    /// The first condition keeps navigation on the current step in places where a route crosses itself.
+    /// When several upcoming steps qualify, the closest one wins.
+    ///
+    /// The window never extends past the end of the current route leg,
+    /// so that waypoints are still completed (and arrival detected) one leg at a time.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    Window {
+        /// The maximum number of steps after the current one to consider.
+        max_steps: u16,
+        /// The distance from a step's geometry, in meters,
+        /// within which the user is considered to be on that step.
+        max_distance_from_step: u16,
+        /// The minimum required horizontal accuracy of the user location, in meters.
+        /// Values larger than this will never cause navigation to jump ahead.
+        minimum_horizontal_accuracy: u16,
+    },
+}
+
+/// Controls when visual and spoken instructions are triggered as the user approaches a maneuver.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum InstructionTriggerMode {
+    /// Instructions are triggered once the user is within
+    /// the instruction's `trigger_distance_before_maneuver` of the maneuver.
+    DistanceBeforeManeuver {
+        /// Instructions are triggered up to this many meters early,
+        /// to account for imprecision in calculation methodologies from different engines and CPUs
+        /// (particularly at the start of a step).
+        tolerance: f64,
+    },
+    /// Instructions are triggered based on the time remaining until the maneuver.
+    ///
+    /// The trigger distance of each instruction is converted into a time before the maneuver
+    /// using the expected speed along the step (from the route's distance and duration).
+    /// The instruction is triggered once the user, travelling at their current speed,
+    /// is no more than that time away from the maneuver.
+    /// This means announcements come earlier when the user is faster than expected,
+    /// and later when they are slower.
+    ///
+    /// If the user's location does not include a speed
+    /// (or the step does not have a duration),
+    /// this behaves like [`InstructionTriggerMode::DistanceBeforeManeuver`].
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    TimeBeforeManeuver {
+        /// Instructions are triggered up to this many meters early,
+        /// to account for imprecision in calculation methodologies from different engines and CPUs
+        /// (particularly at the start of a step).
+        tolerance: f64,
+        /// The minimum speed, in meters per second, used to compute the time before the maneuver.
+        ///
+        /// This ensures that instructions are still triggered when the user is stopped or moving very slowly.
+        minimum_speed: f64,
+    },
+}
+
+impl Default for InstructionTriggerMode {
+    /// Triggers instructions at their trigger distance, up to 5 meters early.
+    fn default() -> Self {
+        Self::DistanceBeforeManeuver { tolerance: 5.0 }
+    }
+}
+
+/// Controls whether (and when) the [`NavigationController`](super::NavigationController)
+/// detects arrival at waypoints where the user stops.
+///
+/// These are the waypoints at the end of each route leg
+/// ([`WaypointKind::Break`](crate::models::WaypointKind::Break) waypoints, including the final destination).
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum ArrivalDetection {
+    /// Arrival is not detected.
+    ///
+    /// Waypoints are completed as the user proceeds along the route,
+    /// and the trip state changes directly to [`TripState::Complete`] at the end of the route.
+    #[default]
+    Disabled,
+    /// Enters [`TripState::Arrived`] at the destination of each route leg.
+    ///
+    /// The user arrives once they have remained within `radius` of the destination
+    /// for at least `minimum_dwell_time`.
+    /// Reaching the end of the route always counts as arriving at the final destination.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    Enabled {
+        /// The distance from the waypoint, in meters,
+        /// within which the user may be considered to have arrived.
+        radius: u16,
+        /// The minimum time, in seconds, that the user must remain within `radius`
+        /// before they are considered to have arrived.
+        minimum_dwell_time: u16,
+        /// If true, the user also arrives as soon as they pass the end of a leg,
+        /// even if they never came within `radius` of the waypoint
+        /// (ex: when the waypoint is on the other side of the street, or set back from the road).
+        ///
+        /// If false, passing the end of an intermediate leg continues navigation into the next leg
+        /// without an arrival.
+        arrive_when_passed: bool,
+    },
+}
+
+/// Controls whether the estimated remaining duration of the trip is adjusted
+/// for the user's observed rate of progress (see [`TripProgress::adjusted_duration_remaining`]).
+///
+/// Routing engines estimate durations with a model of typical travel,
+/// which may not match how the user is actually moving (ex: a slow vehicle, or heavy traffic).
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum EtaBlending {
+    /// The adjusted duration is always the same as the route's expected duration.
+    #[default]
+    Disabled,
+    /// Blends the route's expected duration with the rate of progress observed over a sliding window.
+    ///
+    /// The rate of progress is how many seconds of expected travel time the user covered
+    /// per second of actual time during the window.
+    /// For example, a user progressing at half the expected rate will (at full weight)
+    /// take twice as long as expected to finish the trip.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    ObservedProgress {
+        /// The length of the sliding window, in seconds (of elapsed trip time).
+        window: u16,
+        /// How much weight to give the observed rate of progress,
+        /// from 0 (use the route's expected duration) to 1 (use the observed rate only).
+        ///
+        /// Values less than 1 keep the estimate finite while the user is stopped.
+        observed_weight: f64,
+    },
+}
+
+/// Controls whether the user is flagged as speeding (see `TripState::Navigating::is_speeding`).
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum SpeedingDetection {
+    /// The user is never flagged as speeding.
+    #[default]
+    Disabled,
+    /// The user is flagged as speeding when their speed exceeds the posted speed limit
+    /// by more than a tolerance.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    Enabled {
+        /// How far the user's speed may exceed the speed limit before they are speeding,
+        /// as a percentage of the speed limit.
+        ///
+        /// For example, a tolerance of 10 allows up to 55 km/h in a 50 km/h zone.
+        tolerance_percent: u16,
+    },
+}
+
+/// Controls whether [`UpcomingEvents`] are included in [`TripState::Navigating`].
+///
+/// The same information is available on demand from
+/// [`NavigationController::upcoming_events`](super::NavigationController::upcoming_events).
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub enum EventLookAhead {
+    /// Upcoming events are not included in the trip state.
+    #[default]
+    Disabled,
+    /// The nearest upcoming events are included in the trip state.
+    #[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+    Enabled {
+        /// The maximum number of incidents (and separately, congestion spans) to include.
+        max_count: u16,
+        /// How far ahead to look for events, in meters along the route.
+        ///
+        /// Events which start farther ahead than this are not included.
+        max_distance: f64,
+    },
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 #[cfg_attr(feature = "wasm-bindgen", derive(Deserialize, Tsify))]
@@ -184,4 +858,55 @@ pub struct NavigationControllerConfig {
     pub route_deviation_tracking: RouteDeviationTracking,
     /// Configures how the heading component of the snapped location is reported in [`TripState`].
     pub snapped_location_course_filtering: CourseFiltering,
+    /// Configures how raw location updates are cleaned up before they are used.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub location_filtering: LocationFiltering,
+    /// Configures how the user's location is snapped to the route.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub location_snapping: LocationSnapping,
+    /// Configures whether upcoming steps are considered when matching the user's location to the route.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub step_look_ahead: StepLookAhead,
+    /// Configures whether the user's arrival at waypoints is detected.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub arrival_detection: ArrivalDetection,
+    /// Configures when visual and spoken instructions are triggered.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub instruction_trigger_mode: InstructionTriggerMode,
+    /// Configures whether the estimated remaining duration is adjusted for the user's observed progress.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub eta_blending: EtaBlending,
+    /// Configures when the user is flagged as exceeding the speed limit.
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub speeding_detection: SpeedingDetection,
+    /// Configures whether upcoming incidents and congestion are included in [`TripState`].
+    #[cfg_attr(feature = "wasm-bindgen", serde(default))]
+    pub event_look_ahead: EventLookAhead,
+}
+
+impl NavigationControllerConfig {
+    /// Creates a configuration with the given step advance, route deviation tracking,
+    /// and course filtering behavior.
+    ///
+    /// All other behaviors are left at their defaults,
+    /// which match navigation before they were configurable.
+    pub fn new(
+        step_advance: StepAdvanceMode,
+        route_deviation_tracking: RouteDeviationTracking,
+        snapped_location_course_filtering: CourseFiltering,
+    ) -> Self {
+        Self {
+            step_advance,
+            route_deviation_tracking,
+            snapped_location_course_filtering,
+            location_filtering: LocationFiltering::default(),
+            location_snapping: LocationSnapping::default(),
+            step_look_ahead: StepLookAhead::default(),
+            arrival_detection: ArrivalDetection::default(),
+            instruction_trigger_mode: InstructionTriggerMode::default(),
+            eta_blending: EtaBlending::default(),
+            speeding_detection: SpeedingDetection::default(),
+            event_look_ahead: EventLookAhead::default(),
+        }
+    }
 }
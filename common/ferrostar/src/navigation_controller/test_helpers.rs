@@ -1,7 +1,9 @@
-use crate::models::{BoundingBox, GeographicCoordinate, Route, RouteStep, Waypoint, WaypointKind};
+use crate::models::{
+    BoundingBox, GeographicCoordinate, Route, RouteLeg, RouteStep, Waypoint, WaypointKind,
+};
 use crate::routing_adapters::{osrm::OsrmResponseParser, RouteResponseParser};
 #[cfg(feature = "alloc")]
-use alloc::string::ToString;
+use alloc::string::String;
 use geo::{line_string, BoundingRect, Haversine, Length, LineString, Point};
 
 // A longer + more complex route
@@ -14,7 +16,7 @@ const VALHALLA_SELF_INTERSECTING_OSRM_RESPONSE: &str =
 
 /// Gets a longer + more complex route.
 ///
-/// The accuracy of each parser is tested separately in the routing_adapters module;
+/// The accuracy of each parser is tested separately in the `routing_adapters` module;
 /// this function simply returns a route for an extended test of the state machine.
 pub fn get_extended_route() -> Route {
     let parser = OsrmResponseParser::new(6);
@@ -27,7 +29,7 @@ pub fn get_extended_route() -> Route {
 
 /// Gets a self-intersecting route.
 ///
-/// The accuracy of each parser is tested separately in the routing_adapters module;
+/// The accuracy of each parser is tested separately in the `routing_adapters` module;
 /// this function simply returns a route for an extended test of the state machine.
 pub fn get_self_intersecting_route() -> Route {
     let parser = OsrmResponseParser::new(6);
@@ -63,11 +65,14 @@ pub fn gen_dummy_route_step(
         duration: 0.0,
         road_name: None,
         exits: vec![],
-        instruction: "".to_string(),
+        instruction: String::new(),
         visual_instructions: vec![],
         spoken_instructions: vec![],
         annotations: None,
         incidents: vec![],
+        maneuver: None,
+        intersections: vec![],
+        leg_index: 0,
     }
 }
 
@@ -78,6 +83,7 @@ pub fn gen_route_from_steps(steps: Vec<RouteStep>) -> Route {
         .collect();
     let linestring = LineString::from_iter(geometry.iter().map(|point| Point::from(*point)));
     let distance = steps.iter().fold(0.0, |acc, step| acc + step.distance);
+    let duration = steps.iter().fold(0.0, |acc, step| acc + step.duration);
     let bbox = linestring.bounding_rect().unwrap();
 
     Route {
@@ -91,14 +97,19 @@ pub fn gen_route_from_steps(steps: Vec<RouteStep>) -> Route {
             // This method cannot be used outside the test configuration,
             // so unwraps are OK.
             Waypoint {
-                coordinate: steps.first().unwrap().geometry.first().cloned().unwrap(),
+                coordinate: steps.first().unwrap().geometry.first().copied().unwrap(),
                 kind: WaypointKind::Break,
             },
             Waypoint {
-                coordinate: steps.last().unwrap().geometry.last().cloned().unwrap(),
+                coordinate: steps.last().unwrap().geometry.last().copied().unwrap(),
                 kind: WaypointKind::Break,
             },
         ],
         steps,
+        legs: vec![RouteLeg {
+            distance,
+            duration,
+            destination_waypoint_index: 1,
+        }],
     }
 }
@@ -0,0 +1,391 @@
+//! Tools for deciding when to request a new route.
+//!
+//! Detecting that the user has deviated from the route (see [`crate::deviation_detection`])
+//! and deciding to recalculate are two separate concerns.
+//! A deviation is reported on every location update while the user is off the route,
+//! but requesting a new route every time would be wasteful (and would likely get you rate limited).
+//!
+//! The [`ReroutePolicy`] in this module consumes the sequence of [`TripState`]s
+//! produced by a [`NavigationController`](crate::navigation_controller::NavigationController)
+//! and emits an explicit [`RerouteDecision`] for each one.
+//! Like the navigation controller, it is a pure type;
+//! the caller is responsible for holding on to the latest [`ReroutePolicyState`]
+//! and passing it back in along with the next trip state.
+
+use crate::deviation_detection::RouteDeviation;
+use crate::navigation_controller::models::{TripState, TripStatistics};
+
+#[cfg(any(feature = "wasm-bindgen", test))]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-bindgen")]
+use tsify::Tsify;
+#[cfg(feature = "wasm-bindgen")]
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+
+/// Configures when a [`ReroutePolicy`] decides that a new route should be requested.
+///
+/// All conditions must be met before a reroute is requested.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub struct ReroutePolicyConfig {
+    /// The minimum amount of time (in seconds) that must elapse between two reroute requests.
+    ///
+    /// Time is measured using [`TripStatistics::elapsed_time`],
+    /// so it is based on location timestamps rather than the wall clock.
+    pub minimum_time_between_reroutes: f64,
+    /// The number of consecutive location updates that must be off the route
+    /// before a reroute is requested.
+    ///
+    /// This helps to avoid rerouting due to a single noisy location fix.
+    /// Values of zero are treated the same as one.
+    pub minimum_consecutive_off_route_updates: u32,
+    /// The minimum distance (in meters) that the user must travel between two reroute requests.
+    pub minimum_distance_between_reroutes: f64,
+    /// The maximum number of reroutes to request over the course of a trip.
+    ///
+    /// Reroutes are counted by [`TripStatistics::reroute_count`],
+    /// so they only count once the new route has been applied.
+    /// When this is `None`, there is no limit.
+    pub max_reroutes_per_trip: Option<u32>,
+}
+
+impl Default for ReroutePolicyConfig {
+    /// Mirrors the default recalculation behavior of the iOS and Android SDKs.
+    fn default() -> Self {
+        Self {
+            minimum_time_between_reroutes: 5.0,
+            minimum_consecutive_off_route_updates: 1,
+            minimum_distance_between_reroutes: 0.0,
+            max_reroutes_per_trip: None,
+        }
+    }
+}
+
+/// The decision of a [`ReroutePolicy`] for a single trip state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+pub enum RerouteDecision {
+    /// The user is on the route (or is not navigating), so no action is needed.
+    OnRoute,
+    /// The user is off the route, but the conditions for requesting a new route have not been met yet.
+    Wait,
+    /// A new route should be requested now.
+    RequestReroute,
+    /// The user is off the route, but the maximum number of reroutes for the trip has been reached.
+    LimitReached,
+}
+
+/// The state of a [`ReroutePolicy`], which must be passed back in with each trip state update.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ReroutePolicyState {
+    /// The decision for the most recent trip state.
+    pub decision: RerouteDecision,
+    /// The number of consecutive trip states in which the user was off the route
+    /// since the last reroute request.
+    pub consecutive_off_route_updates: u32,
+    /// The elapsed trip time (in seconds) when the last reroute was requested.
+    pub last_reroute_elapsed_time: Option<f64>,
+    /// The distance traveled (in meters) when the last reroute was requested.
+    pub last_reroute_distance_traveled: Option<f64>,
+}
+
+/// Decides when a new route should be requested, given a sequence of trip states.
+///
+/// Create one instance per trip, and keep using it across reroutes
+/// (ex: when the trip state is carried over via
+/// [`NavigationController::get_rerouted_state`](crate::navigation_controller::NavigationController::get_rerouted_state)).
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct ReroutePolicy {
+    config: ReroutePolicyConfig,
+}
+
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+impl ReroutePolicy {
+    #[cfg_attr(feature = "uniffi", uniffi::constructor)]
+    /// Create a reroute policy with the given configuration.
+    pub fn new(config: ReroutePolicyConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns the state of the policy at the start of a trip.
+    pub fn get_initial_state(&self) -> ReroutePolicyState {
+        ReroutePolicyState {
+            decision: RerouteDecision::OnRoute,
+            consecutive_off_route_updates: 0,
+            last_reroute_elapsed_time: None,
+            last_reroute_distance_traveled: None,
+        }
+    }
+
+    /// Evaluates a new trip state, returning the updated policy state.
+    ///
+    /// The [`ReroutePolicyState::decision`] field of the result says whether a new route
+    /// should be requested now.
+    /// When it is [`RerouteDecision::RequestReroute`],
+    /// the time and distance of the request are recorded immediately,
+    /// so subsequent updates will wait for the configured time and distance
+    /// before requesting another one.
+    pub fn update(
+        &self,
+        trip_state: &TripState,
+        previous_state: &ReroutePolicyState,
+    ) -> ReroutePolicyState {
+        let TripState::Navigating {
+            deviation: RouteDeviation::OffRoute { .. },
+            statistics:
+                TripStatistics {
+                    elapsed_time,
+                    distance_traveled,
+                    reroute_count,
+                    ..
+                },
+            ..
+        } = trip_state
+        else {
+            return ReroutePolicyState {
+                decision: RerouteDecision::OnRoute,
+                consecutive_off_route_updates: 0,
+                ..*previous_state
+            };
+        };
+
+        let consecutive_off_route_updates = previous_state.consecutive_off_route_updates + 1;
+
+        let limit_reached = self
+            .config
+            .max_reroutes_per_trip
+            .is_some_and(|max| *reroute_count >= max);
+        if limit_reached {
+            return ReroutePolicyState {
+                decision: RerouteDecision::LimitReached,
+                consecutive_off_route_updates,
+                ..*previous_state
+            };
+        }
+
+        let enough_consecutive_updates =
+            consecutive_off_route_updates >= self.config.minimum_consecutive_off_route_updates;
+        let enough_time_elapsed = previous_state
+            .last_reroute_elapsed_time
+            .map_or(true, |last| {
+                elapsed_time - last >= self.config.minimum_time_between_reroutes
+            });
+        let enough_distance_traveled = previous_state
+            .last_reroute_distance_traveled
+            .map_or(true, |last| {
+                distance_traveled - last >= self.config.minimum_distance_between_reroutes
+            });
+
+        if enough_consecutive_updates && enough_time_elapsed && enough_distance_traveled {
+            ReroutePolicyState {
+                decision: RerouteDecision::RequestReroute,
+                consecutive_off_route_updates: 0,
+                last_reroute_elapsed_time: Some(*elapsed_time),
+                last_reroute_distance_traveled: Some(*distance_traveled),
+            }
+        } else {
+            ReroutePolicyState {
+                decision: RerouteDecision::Wait,
+                consecutive_off_route_updates,
+                ..*previous_state
+            }
+        }
+    }
+}
+
+/// JavaScript wrapper for `ReroutePolicy`.
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_name = ReroutePolicy)]
+pub struct JsReroutePolicy(ReroutePolicy);
+
+#[cfg(feature = "wasm-bindgen")]
+#[wasm_bindgen(js_class = ReroutePolicy)]
+impl JsReroutePolicy {
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: JsValue) -> Result<JsReroutePolicy, JsValue> {
+        let config: ReroutePolicyConfig = serde_wasm_bindgen::from_value(config)?;
+
+        Ok(JsReroutePolicy(ReroutePolicy::new(config)))
+    }
+
+    #[wasm_bindgen(js_name = getInitialState)]
+    pub fn get_initial_state(&self) -> Result<JsValue, JsValue> {
+        serde_wasm_bindgen::to_value(&self.0.get_initial_state())
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    pub fn update(&self, trip_state: JsValue, previous_state: JsValue) -> Result<JsValue, JsValue> {
+        let trip_state: TripState = serde_wasm_bindgen::from_value(trip_state)?;
+        let previous_state: ReroutePolicyState = serde_wasm_bindgen::from_value(previous_state)?;
+
+        serde_wasm_bindgen::to_value(&self.0.update(&trip_state, &previous_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deviation_detection::RouteDeviationTracking;
+    use crate::models::{GeographicCoordinate, UserLocation};
+    use crate::navigation_controller::{
+        models::{CourseFiltering, NavigationControllerConfig, StepAdvanceMode},
+        test_helpers::{gen_dummy_route_step, gen_route_from_steps},
+        NavigationController,
+    };
+
+    #[cfg(all(feature = "std", not(feature = "web-time")))]
+    use std::time::SystemTime;
+
+    #[cfg(feature = "web-time")]
+    use web_time::SystemTime;
+
+    /// Creates a navigating trip state with the given deviation and trip context.
+    fn navigating_state(off_route: bool, elapsed: f64, distance: f64) -> TripState {
+        let route = gen_route_from_steps(vec![gen_dummy_route_step(0.0, 0.0, 0.0, 1.0)]);
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig::new(
+                StepAdvanceMode::Manual,
+                RouteDeviationTracking::None,
+                CourseFiltering::Raw,
+            ),
+        );
+        let mut state = controller.get_initial_state(UserLocation {
+            coordinates: GeographicCoordinate { lat: 0.0, lng: 0.0 },
+            horizontal_accuracy: 0.0,
+            course_over_ground: None,
+            timestamp: SystemTime::now(),
+            speed: None,
+        });
+
+        if let TripState::Navigating {
+            ref mut deviation,
+            ref mut statistics,
+            ..
+        } = state
+        {
+            *deviation = if off_route {
+                RouteDeviation::OffRoute {
+                    deviation_from_route_line: 50.0,
+                }
+            } else {
+                RouteDeviation::NoDeviation
+            };
+            statistics.elapsed_time = elapsed;
+            statistics.distance_traveled = distance;
+        }
+
+        state
+    }
+
+    #[test]
+    fn on_route_never_reroutes() {
+        let policy = ReroutePolicy::new(ReroutePolicyConfig::default());
+        let state = policy.update(
+            &navigating_state(false, 0.0, 0.0),
+            &policy.get_initial_state(),
+        );
+        assert_eq!(state.decision, RerouteDecision::OnRoute);
+
+        let state = policy.update(&TripState::Idle, &state);
+        assert_eq!(state.decision, RerouteDecision::OnRoute);
+    }
+
+    #[test]
+    fn requires_consecutive_off_route_updates() {
+        let policy = ReroutePolicy::new(ReroutePolicyConfig {
+            minimum_consecutive_off_route_updates: 3,
+            ..ReroutePolicyConfig::default()
+        });
+
+        let mut state = policy.get_initial_state();
+        state = policy.update(&navigating_state(true, 1.0, 10.0), &state);
+        assert_eq!(state.decision, RerouteDecision::Wait);
+        state = policy.update(&navigating_state(true, 2.0, 20.0), &state);
+        assert_eq!(state.decision, RerouteDecision::Wait);
+
+        // A single on-route update resets the count
+        state = policy.update(&navigating_state(false, 3.0, 30.0), &state);
+        assert_eq!(state.decision, RerouteDecision::OnRoute);
+        assert_eq!(state.consecutive_off_route_updates, 0);
+
+        for i in 0..2 {
+            state = policy.update(&navigating_state(true, 4.0 + f64::from(i), 40.0), &state);
+            assert_eq!(state.decision, RerouteDecision::Wait);
+        }
+        state = policy.update(&navigating_state(true, 6.0, 60.0), &state);
+        assert_eq!(state.decision, RerouteDecision::RequestReroute);
+        assert_eq!(state.consecutive_off_route_updates, 0);
+    }
+
+    #[test]
+    fn debounces_by_time_and_distance() {
+        let policy = ReroutePolicy::new(ReroutePolicyConfig {
+            minimum_time_between_reroutes: 10.0,
+            minimum_consecutive_off_route_updates: 1,
+            minimum_distance_between_reroutes: 100.0,
+            max_reroutes_per_trip: None,
+        });
+
+        let mut state = policy.update(
+            &navigating_state(true, 0.0, 0.0),
+            &policy.get_initial_state(),
+        );
+        assert_eq!(state.decision, RerouteDecision::RequestReroute);
+
+        // Enough distance, but not enough time
+        state = policy.update(&navigating_state(true, 5.0, 200.0), &state);
+        assert_eq!(state.decision, RerouteDecision::Wait);
+
+        // Enough time, but not enough distance
+        state = policy.update(&navigating_state(true, 20.0, 0.0), &state);
+        assert_eq!(state.decision, RerouteDecision::Wait);
+
+        state = policy.update(&navigating_state(true, 20.0, 200.0), &state);
+        assert_eq!(state.decision, RerouteDecision::RequestReroute);
+        assert_eq!(state.last_reroute_elapsed_time, Some(20.0));
+        assert_eq!(state.last_reroute_distance_traveled, Some(200.0));
+    }
+
+    #[test]
+    fn stops_rerouting_at_limit() {
+        let policy = ReroutePolicy::new(ReroutePolicyConfig {
+            minimum_time_between_reroutes: 0.0,
+            minimum_consecutive_off_route_updates: 1,
+            minimum_distance_between_reroutes: 0.0,
+            max_reroutes_per_trip: Some(2),
+        });
+
+        // The reroutes are counted in the trip statistics as the new routes are applied
+        let trip_state_after_reroutes = |reroute_count: u32| {
+            let mut trip_state = navigating_state(true, 0.0, 0.0);
+            if let TripState::Navigating {
+                ref mut statistics, ..
+            } = trip_state
+            {
+                statistics.reroute_count = reroute_count;
+            }
+            trip_state
+        };
+
+        let mut state = policy.get_initial_state();
+        for reroute_count in 0..2 {
+            state = policy.update(&trip_state_after_reroutes(reroute_count), &state);
+            assert_eq!(state.decision, RerouteDecision::RequestReroute);
+        }
+        state = policy.update(&trip_state_after_reroutes(2), &state);
+        assert_eq!(state.decision, RerouteDecision::LimitReached);
+    }
+}
@@ -32,6 +32,7 @@
 //! All you need to do is convert your routes into Ferrostar [Route]s,
 //! and nothing in this module is strictly required to do that.
 
+use crate::localization::UnitSystem;
 use crate::models::Waypoint;
 use crate::models::{Route, UserLocation};
 use crate::routing_adapters::error::InstantiationError;
@@ -50,7 +51,7 @@ use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 #[cfg(feature = "alloc")]
 use alloc::{string::String, sync::Arc, vec::Vec};
 
-use crate::routing_adapters::osrm::OsrmResponseParser;
+use crate::routing_adapters::osrm::{instructions::InstructionLanguage, OsrmResponseParser};
 use crate::routing_adapters::valhalla::ValhallaHttpRequestGenerator;
 
 pub mod error;
@@ -150,6 +151,7 @@ impl RouteAdapter {
     }
 
     #[cfg_attr(feature = "uniffi", uniffi::constructor)]
+    #[allow(clippy::needless_pass_by_value)]
     pub fn new_valhalla_http(
         endpoint_url: String,
         profile: String,
@@ -164,6 +166,32 @@ impl RouteAdapter {
         Ok(Self::new(request_generator, response_parser))
     }
 
+    /// Creates a route adapter for a Valhalla server, like [`RouteAdapter::new_valhalla_http`],
+    /// which requests instructions in the given language and units.
+    #[cfg_attr(feature = "uniffi", uniffi::constructor)]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new_localized_valhalla_http(
+        endpoint_url: String,
+        profile: String,
+        options_json: Option<String>,
+        language: String,
+        units: UnitSystem,
+    ) -> Result<Self, InstantiationError> {
+        let request_generator = Arc::new(ValhallaHttpRequestGenerator::with_localization(
+            endpoint_url,
+            profile,
+            options_json.as_deref(),
+            language,
+            units,
+        )?);
+        let response_parser = Arc::new(OsrmResponseParser::with_instruction_language(
+            6,
+            InstructionLanguage::english(),
+            units,
+        ));
+        Ok(Self::new(request_generator, response_parser))
+    }
+
     //
     // Proxied implementation methods.
     //
@@ -204,6 +232,26 @@ impl JsRouteAdapter {
         // TODO: We should have a better error handling strategy here. Same for the other methods.
     }
 
+    /// Creates a new RouteAdapter like the constructor,
+    /// which requests instructions in the given language and units.
+    pub fn localized(
+        endpoint_url: String,
+        profile: String,
+        costing_options_json: Option<String>,
+        language: String,
+        units: UnitSystem,
+    ) -> Result<JsRouteAdapter, JsValue> {
+        RouteAdapter::new_localized_valhalla_http(
+            endpoint_url,
+            profile,
+            costing_options_json,
+            language,
+            units,
+        )
+        .map(JsRouteAdapter)
+        .map_err(|e| JsValue::from_str(&format!("{}", e)))
+    }
+
     #[wasm_bindgen(js_name = generateRequest)]
     pub fn generate_request(
         &self,
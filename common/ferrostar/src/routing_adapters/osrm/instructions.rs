@@ -0,0 +1,829 @@
+//! Local synthesis of instructions for routes which do not include them
+//! (ex: responses from stock OSRM servers).
+//!
+//! The phrasing is modeled after [OSRM Text Instructions](https://github.com/Project-OSRM/osrm-text-instructions).
+
+use super::models::RouteStep as OsrmRouteStep;
+use crate::localization::{round_distance, DistanceStyle, DistanceUnit, UnitSystem};
+use crate::models::{
+    LaneInfo, Maneuver, ManeuverModifier, ManeuverType, SpokenInstruction, VisualInstruction,
+    VisualInstructionContent,
+};
+use crate::ssml::{Pronunciation, SsmlHints};
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(any(feature = "wasm-bindgen", test))]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "wasm-bindgen")]
+use tsify::Tsify;
+use uuid::Uuid;
+
+/// How long before a maneuver it is announced in advance, in seconds.
+const PREPARE_TIME: f64 = 30.0;
+/// The minimum and maximum distance before a maneuver at which it is announced in advance, in meters.
+const PREPARE_DISTANCE_RANGE: (f64, f64) = (100.0, 2000.0);
+/// How long before a maneuver it is announced for the final time, in seconds.
+const FINAL_TIME: f64 = 8.0;
+/// The minimum and maximum distance before a maneuver at which it is announced for the final time, in meters.
+const FINAL_DISTANCE_RANGE: (f64, f64) = (15.0, 200.0);
+/// The assumed speed along steps without a duration, in meters per second.
+const DEFAULT_SPEED: f64 = 10.0;
+
+/// The built-in English phrases.
+///
+/// Placeholders are written in braces.
+const ENGLISH_PHRASES: &[(&str, &str)] = &[
+    // Maneuver modifiers
+    ("modifier.uturn", "U-turn"),
+    ("modifier.sharp right", "sharp right"),
+    ("modifier.right", "right"),
+    ("modifier.slight right", "slightly right"),
+    ("modifier.straight", "straight"),
+    ("modifier.slight left", "slightly left"),
+    ("modifier.left", "left"),
+    ("modifier.sharp left", "sharp left"),
+    // Compass directions
+    ("direction.north", "north"),
+    ("direction.northeast", "northeast"),
+    ("direction.east", "east"),
+    ("direction.southeast", "southeast"),
+    ("direction.south", "south"),
+    ("direction.southwest", "southwest"),
+    ("direction.west", "west"),
+    ("direction.northwest", "northwest"),
+    // Ordinal numbers (for roundabout exits)
+    ("ordinal.1", "first"),
+    ("ordinal.2", "second"),
+    ("ordinal.3", "third"),
+    ("ordinal.4", "fourth"),
+    ("ordinal.5", "fifth"),
+    ("ordinal.6", "sixth"),
+    ("ordinal.7", "seventh"),
+    ("ordinal.8", "eighth"),
+    ("ordinal.9", "ninth"),
+    ("ordinal.10", "tenth"),
+    // Distances
+    ("number.decimal_separator", "."),
+    ("distance.meters", "{distance} meters"),
+    ("distance.kilometer", "{distance} kilometer"),
+    ("distance.kilometers", "{distance} kilometers"),
+    ("distance.feet", "{distance} feet"),
+    ("distance.yards", "{distance} yards"),
+    ("distance.mile", "{distance} mile"),
+    ("distance.miles", "{distance} miles"),
+    ("distance.short.meters", "{distance} m"),
+    ("distance.short.kilometers", "{distance} km"),
+    ("distance.short.feet", "{distance} ft"),
+    ("distance.short.yards", "{distance} yd"),
+    ("distance.short.miles", "{distance} mi"),
+    (
+        "spoken.in_distance",
+        "In {distance}, {lowercase_instruction}",
+    ),
+    // Maneuvers
+    ("depart.default", "Head {direction}"),
+    ("depart.name", "Head {direction} on {way_name}"),
+    ("arrive.default", "You have arrived at your destination"),
+    ("arrive.upcoming", "You will arrive at your destination"),
+    ("arrive.left.default", "Your destination is on the left"),
+    ("arrive.right.default", "Your destination is on the right"),
+    ("turn.default", "Turn {modifier}"),
+    ("turn.name", "Turn {modifier} onto {way_name}"),
+    ("turn.destination", "Turn {modifier} towards {destination}"),
+    ("turn.straight.default", "Go straight"),
+    ("turn.straight.name", "Go straight onto {way_name}"),
+    ("turn.uturn.default", "Make a U-turn"),
+    ("turn.uturn.name", "Make a U-turn onto {way_name}"),
+    ("new name.default", "Continue {modifier}"),
+    ("new name.name", "Continue onto {way_name}"),
+    ("continue.default", "Continue {modifier}"),
+    ("continue.name", "Continue {modifier} onto {way_name}"),
+    ("continue.straight.name", "Continue onto {way_name}"),
+    ("continue.uturn.default", "Make a U-turn"),
+    ("continue.uturn.name", "Make a U-turn onto {way_name}"),
+    ("notification.default", "Continue {modifier}"),
+    ("notification.name", "Continue onto {way_name}"),
+    ("merge.default", "Merge {modifier}"),
+    ("merge.name", "Merge {modifier} onto {way_name}"),
+    ("on ramp.default", "Take the ramp"),
+    ("on ramp.name", "Take the ramp onto {way_name}"),
+    ("on ramp.destination", "Take the ramp towards {destination}"),
+    ("off ramp.default", "Take the exit"),
+    ("off ramp.name", "Take the exit onto {way_name}"),
+    (
+        "off ramp.destination",
+        "Take the exit towards {destination}",
+    ),
+    ("off ramp.exit_default", "Take exit {exit}"),
+    ("off ramp.exit_name", "Take exit {exit} onto {way_name}"),
+    (
+        "off ramp.exit_destination",
+        "Take exit {exit} towards {destination}",
+    ),
+    ("fork.default", "Keep {modifier} at the fork"),
+    ("fork.name", "Keep {modifier} onto {way_name}"),
+    ("fork.destination", "Keep {modifier} towards {destination}"),
+    (
+        "end of road.default",
+        "Turn {modifier} at the end of the road",
+    ),
+    ("end of road.name", "Turn {modifier} onto {way_name}"),
+    ("roundabout.default", "Enter the roundabout"),
+    (
+        "roundabout.name",
+        "Enter the roundabout and exit onto {way_name}",
+    ),
+    (
+        "roundabout.exit_default",
+        "Enter the roundabout and take the {ordinal} exit",
+    ),
+    (
+        "roundabout.exit_name",
+        "Enter the roundabout and take the {ordinal} exit onto {way_name}",
+    ),
+    ("rotary.default", "Enter {rotary_name}"),
+    (
+        "rotary.name",
+        "Enter {rotary_name} and exit onto {way_name}",
+    ),
+    (
+        "rotary.exit_default",
+        "Enter {rotary_name} and take the {ordinal} exit",
+    ),
+    (
+        "rotary.exit_name",
+        "Enter {rotary_name} and take the {ordinal} exit onto {way_name}",
+    ),
+    (
+        "roundabout turn.default",
+        "At the roundabout, turn {modifier}",
+    ),
+    (
+        "roundabout turn.name",
+        "At the roundabout, turn {modifier} onto {way_name}",
+    ),
+    ("exit roundabout.default", "Exit the roundabout"),
+    (
+        "exit roundabout.name",
+        "Exit the roundabout onto {way_name}",
+    ),
+    ("exit rotary.default", "Exit the rotary"),
+    ("exit rotary.name", "Exit the rotary onto {way_name}"),
+];
+
+/// A table of phrases for synthesizing instructions in a given language.
+///
+/// Phrases are looked up by key (ex: `turn.name`),
+/// and may contain placeholders in braces (ex: `Turn {modifier} onto {way_name}`).
+/// Maneuver phrases are keyed by maneuver type, an optional modifier, and a variant
+/// (`default`, `name`, `destination`, and `exit_*` for numbered exits),
+/// with more specific keys taking precedence.
+/// See [`InstructionLanguage::english`] for the full set of keys and placeholders.
+///
+/// Phrases which embed another instruction (ex: `spoken.in_distance`) can use either
+/// `{instruction}`, which is inserted as-is,
+/// or `{lowercase_instruction}`, which starts with a lowercase letter,
+/// depending on the grammar of the language.
+///
+/// Phrases which are missing from the table fall back to English,
+/// so a table only needs to contain the phrases that differ.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(any(feature = "wasm-bindgen", test), derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(from_wasm_abi))]
+pub struct InstructionLanguage {
+    /// Phrases by key.
+    pub phrases: HashMap<String, String>,
+}
+
+impl InstructionLanguage {
+    /// The built-in English phrases.
+    pub fn english() -> Self {
+        Self {
+            phrases: ENGLISH_PHRASES
+                .iter()
+                .map(|(key, phrase)| ((*key).to_string(), (*phrase).to_string()))
+                .collect(),
+        }
+    }
+
+    /// Looks up the first of the keys which has a phrase, falling back to English.
+    fn phrase<'a>(&'a self, keys: &[String]) -> &'a str {
+        self.find_phrase(keys).unwrap_or_default()
+    }
+
+    /// Like [`InstructionLanguage::phrase`], but returns [`None`] if no key has a phrase in either language.
+    fn find_phrase<'a>(&'a self, keys: &[String]) -> Option<&'a str> {
+        keys.iter()
+            .find_map(|key| self.phrases.get(key).map(String::as_str))
+            .or_else(|| {
+                keys.iter().find_map(|key| {
+                    ENGLISH_PHRASES
+                        .iter()
+                        .find(|(english_key, _)| english_key == key)
+                        .map(|(_, phrase)| *phrase)
+                })
+            })
+    }
+
+    /// Formats a distance in the given unit system, rounded with [`round_distance`].
+    pub fn format_distance(&self, meters: f64, units: UnitSystem, style: DistanceStyle) -> String {
+        let distance = round_distance(meters, units);
+        let is_one = (distance.value - 1.0).abs() < f64::EPSILON;
+        let unit = match (distance.unit, style, is_one) {
+            (DistanceUnit::Meters, _, _) => "meters",
+            (DistanceUnit::Kilometers, DistanceStyle::Spoken, true) => "kilometer",
+            (DistanceUnit::Kilometers, _, _) => "kilometers",
+            (DistanceUnit::Feet, _, _) => "feet",
+            (DistanceUnit::Yards, _, _) => "yards",
+            (DistanceUnit::Miles, DistanceStyle::Spoken, true) => "mile",
+            (DistanceUnit::Miles, _, _) => "miles",
+        };
+        let key = match style {
+            DistanceStyle::Abbreviated => format!("distance.short.{unit}"),
+            DistanceStyle::Spoken => format!("distance.{unit}"),
+        };
+        let value = format!("{}", distance.value)
+            .replace('.', self.phrase(&["number.decimal_separator".to_string()]));
+
+        fill_placeholders(self.phrase(&[key]), &[("distance", &value)])
+    }
+}
+
+/// Synthesizes instructions in a given language and unit system.
+pub(crate) struct InstructionSynthesizer<'a> {
+    pub(crate) language: &'a InstructionLanguage,
+    pub(crate) units: UnitSystem,
+    /// Whether to generate SSML for spoken instructions which lack it.
+    pub(crate) generate_ssml: bool,
+}
+
+impl InstructionSynthesizer<'_> {
+    /// Synthesizes the instruction for the maneuver at the start of a step.
+    pub(crate) fn step_instruction(&self, step: &OsrmRouteStep) -> String {
+        self.maneuver_instruction(step, false)
+    }
+
+    /// Synthesizes the instruction for a maneuver,
+    /// optionally phrased as a maneuver which is still some distance away
+    /// (this only makes a difference for arrivals).
+    fn maneuver_instruction(&self, step: &OsrmRouteStep, upcoming: bool) -> String {
+        let maneuver = Maneuver::from(&step.maneuver);
+        let way_name = way_name(step);
+        let destination = step.destinations.as_deref().map(destination);
+        let rotary_name = step
+            .rotary_name
+            .as_deref()
+            .filter(|_| maneuver.maneuver_type == ManeuverType::Rotary);
+
+        // Rotaries without a name are described like roundabouts
+        let maneuver_type = match (maneuver.maneuver_type, rotary_name) {
+            (ManeuverType::Rotary, None) => "roundabout",
+            (maneuver_type, _) => maneuver_type_key(maneuver_type),
+        };
+        let modifier = match maneuver.maneuver_type {
+            // Only the side of the road matters when arriving
+            ManeuverType::Arrive => match maneuver.maneuver_modifier {
+                Some(
+                    ManeuverModifier::SharpLeft
+                    | ManeuverModifier::Left
+                    | ManeuverModifier::SlightLeft,
+                ) => Some("left"),
+                Some(
+                    ManeuverModifier::SharpRight
+                    | ManeuverModifier::Right
+                    | ManeuverModifier::SlightRight,
+                ) => Some("right"),
+                _ => None,
+            },
+            _ => maneuver.maneuver_modifier.map(modifier_key),
+        };
+        let exit = match maneuver.maneuver_type {
+            ManeuverType::OffRamp => step
+                .exits
+                .as_deref()
+                .and_then(|exits| exits.split(';').next())
+                .map(|exit| exit.trim().to_string()),
+            ManeuverType::Roundabout | ManeuverType::Rotary => {
+                step.maneuver.exit.map(|exit| exit.to_string())
+            }
+            _ => None,
+        };
+
+        // Ramps and forks are signposted with their destinations rather than a road name
+        let prefers_destination = matches!(
+            maneuver.maneuver_type,
+            ManeuverType::OnRamp | ManeuverType::OffRamp | ManeuverType::Fork
+        );
+        let variant = match (&way_name, &destination) {
+            _ if maneuver.maneuver_type == ManeuverType::Arrive && upcoming => "upcoming",
+            _ if maneuver.maneuver_type == ManeuverType::Arrive => "default",
+            (_, Some(_)) if prefers_destination => "destination",
+            (Some(_), _) => "name",
+            (None, Some(_)) => "destination",
+            (None, None) => "default",
+        };
+        let variants: Vec<String> = exit
+            .iter()
+            .map(|_| format!("exit_{variant}"))
+            .chain([variant.to_string(), "default".to_string()])
+            .collect();
+        let keys: Vec<String> = variants
+            .iter()
+            .flat_map(|variant| {
+                modifier
+                    .map(|modifier| format!("{maneuver_type}.{modifier}.{variant}"))
+                    .into_iter()
+                    .chain([format!("{maneuver_type}.{variant}")])
+            })
+            .collect();
+
+        // Exits without an ordinal phrase (ex: the 11th) are read as numbers
+        let ordinal = exit.as_deref().map(|exit| {
+            self.language
+                .find_phrase(&[format!("ordinal.{exit}")])
+                .unwrap_or(exit)
+        });
+
+        fill_placeholders(
+            self.language.phrase(&keys),
+            &[
+                (
+                    "modifier",
+                    modifier.map_or("", |modifier| {
+                        self.language.phrase(&[format!("modifier.{modifier}")])
+                    }),
+                ),
+                ("way_name", way_name.as_deref().unwrap_or_default()),
+                ("destination", destination.as_deref().unwrap_or_default()),
+                ("exit", exit.as_deref().unwrap_or_default()),
+                ("ordinal", ordinal.unwrap_or_default()),
+                ("rotary_name", rotary_name.unwrap_or_default()),
+                (
+                    "direction",
+                    self.language.phrase(&[format!(
+                        "direction.{}",
+                        compass_direction(maneuver.bearing_after)
+                    )]),
+                ),
+            ],
+        )
+    }
+
+    /// Synthesizes a visual instruction for the maneuver at the end of a step
+    /// (the start of `next_step`), which is displayed for the length of the step.
+    pub(crate) fn visual_instructions(
+        &self,
+        step: &OsrmRouteStep,
+        next_step: Option<&OsrmRouteStep>,
+        lane_info: Option<Vec<LaneInfo>>,
+    ) -> Vec<VisualInstruction> {
+        let Some(next_step) = next_step else {
+            return vec![];
+        };
+        let maneuver = Maneuver::from(&next_step.maneuver);
+
+        // Like the banners from Mapbox and Valhalla,
+        // the primary text is where the maneuver leads rather than the full instruction.
+        let text = match maneuver.maneuver_type {
+            ManeuverType::Arrive => None,
+            _ => way_name(next_step).or_else(|| next_step.destinations.as_deref().map(destination)),
+        }
+        .unwrap_or_else(|| self.step_instruction(next_step));
+        let exit_numbers = match maneuver.maneuver_type {
+            ManeuverType::OffRamp => next_step
+                .exits
+                .as_deref()
+                .map(|exits| {
+                    exits
+                        .split(';')
+                        .map(|exit| exit.trim().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => vec![],
+        };
+
+        vec![VisualInstruction {
+            primary_content: VisualInstructionContent {
+                text,
+                maneuver_type: Some(maneuver.maneuver_type),
+                maneuver_modifier: maneuver.maneuver_modifier,
+                roundabout_exit_degrees: None,
+                lane_info,
+                exit_numbers,
+            },
+            secondary_content: None,
+            sub_content: None,
+            trigger_distance_before_maneuver: step.distance,
+        }]
+    }
+
+    /// Synthesizes spoken instructions for the maneuver at the end of a step
+    /// (the start of `next_step`).
+    ///
+    /// The maneuver is announced in advance and again shortly before it takes place,
+    /// at distances based on the expected speed along the step.
+    /// Departures are also announced at the start of the first step.
+    pub(crate) fn spoken_instructions(
+        &self,
+        step: &OsrmRouteStep,
+        next_step: Option<&OsrmRouteStep>,
+    ) -> Vec<SpokenInstruction> {
+        let speed = if step.duration > 0.0 {
+            step.distance / step.duration
+        } else {
+            DEFAULT_SPEED
+        };
+        let prepare_distance =
+            (speed * PREPARE_TIME).clamp(PREPARE_DISTANCE_RANGE.0, PREPARE_DISTANCE_RANGE.1);
+        let is_departure = step.maneuver.maneuver_type == "depart";
+
+        let mut instructions = vec![];
+        if is_departure {
+            instructions.push((step.distance, self.step_instruction(step)));
+        }
+        if let Some(next_step) = next_step {
+            // Arrival is announced once the user is practically there
+            let final_distance = if next_step.maneuver.maneuver_type == "arrive" {
+                FINAL_DISTANCE_RANGE.0
+            } else {
+                (speed * FINAL_TIME).clamp(FINAL_DISTANCE_RANGE.0, FINAL_DISTANCE_RANGE.1)
+            };
+
+            // Skip the advance announcement if it would come right after another one
+            let prepare_distance = prepare_distance.min(step.distance);
+            if prepare_distance > 2.0 * final_distance
+                && !(is_departure && prepare_distance >= step.distance)
+            {
+                let instruction = self.maneuver_instruction(next_step, true);
+                instructions.push((
+                    prepare_distance,
+                    fill_placeholders(
+                        self.language.phrase(&["spoken.in_distance".to_string()]),
+                        &[
+                            (
+                                "distance",
+                                &self.language.format_distance(
+                                    prepare_distance,
+                                    self.units,
+                                    DistanceStyle::Spoken,
+                                ),
+                            ),
+                            ("instruction", &instruction),
+                            ("lowercase_instruction", &lowercase_first(&instruction)),
+                        ],
+                    ),
+                ));
+            }
+            instructions.push((
+                final_distance.min(step.distance),
+                self.step_instruction(next_step),
+            ));
+        }
+
+        instructions
+            .into_iter()
+            .map(
+                |(trigger_distance_before_maneuver, text)| SpokenInstruction {
+                    text,
+                    ssml: None,
+                    trigger_distance_before_maneuver,
+                    utterance_id: Uuid::new_v4(),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Replaces the placeholders in a phrase,
+/// cleaning up any extra whitespace left by empty values.
+fn fill_placeholders(phrase: &str, values: &[(&str, &str)]) -> String {
+    let filled = values
+        .iter()
+        .fold(phrase.to_string(), |phrase, (placeholder, value)| {
+            phrase.replace(&format!("{{{placeholder}}}"), value)
+        });
+    filled.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lowercases the first character of an instruction
+/// (for languages where an embedded instruction continues the sentence).
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_lowercase().chain(chars).collect()
+    })
+}
+
+/// The name of the way for a step, combining the name and (first) reference number, if any.
+fn way_name(step: &OsrmRouteStep) -> Option<String> {
+    let name = step.name.as_deref().filter(|name| !name.is_empty());
+    let reference = step
+        .reference
+        .as_deref()
+        .and_then(|references| references.split(';').next())
+        .map(str::trim)
+        .filter(|reference| !reference.is_empty());
+    match (name, reference) {
+        (Some(name), Some(reference)) if name != reference => Some(format!("{name} ({reference})")),
+        (Some(name), _) => Some(name.to_string()),
+        (None, reference) => reference.map(ToString::to_string),
+    }
+}
+
+/// Shortens a destinations annotation (ex: `I 84 West, US 6: Hartford, Waterbury`)
+/// to the first reference and name (ex: `I 84 West, Hartford`).
+fn destination(destinations: &str) -> String {
+    destinations
+        .split(':')
+        .filter_map(|part| part.split(',').next())
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Collects hints for speaking the instructions of a step (and the maneuver onto the next step).
+pub(crate) fn ssml_hints(step: &OsrmRouteStep, next_step: Option<&OsrmRouteStep>) -> SsmlHints {
+    let steps = || core::iter::once(step).chain(next_step);
+    let split = |values: &str| -> Vec<String> {
+        values
+            .split(';')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    };
+
+    let pronunciations = steps()
+        .flat_map(|step| {
+            [
+                (&step.name, &step.pronunciation),
+                (&step.rotary_name, &step.rotary_pronunciation),
+            ]
+        })
+        .filter_map(|(text, ipa)| match (text.as_deref(), ipa.as_deref()) {
+            (Some(text), Some(ipa)) if !text.is_empty() && !ipa.is_empty() => Some(Pronunciation {
+                text: text.to_string(),
+                ipa: ipa.to_string(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    SsmlHints {
+        pronunciations,
+        road_refs: steps()
+            .filter_map(|step| step.reference.as_deref())
+            .flat_map(split)
+            .collect(),
+        exit_numbers: steps()
+            .filter_map(|step| step.exits.as_deref())
+            .flat_map(split)
+            .collect(),
+    }
+}
+
+/// The nearest of the 8 principal compass directions to a bearing.
+fn compass_direction(bearing: u16) -> &'static str {
+    const DIRECTIONS: [&str; 8] = [
+        "north",
+        "northeast",
+        "east",
+        "southeast",
+        "south",
+        "southwest",
+        "west",
+        "northwest",
+    ];
+    DIRECTIONS[usize::from((bearing % 360 + 22) / 45 % 8)]
+}
+
+fn maneuver_type_key(maneuver_type: ManeuverType) -> &'static str {
+    match maneuver_type {
+        ManeuverType::Turn => "turn",
+        ManeuverType::NewName => "new name",
+        ManeuverType::Depart => "depart",
+        ManeuverType::Arrive => "arrive",
+        ManeuverType::Merge => "merge",
+        ManeuverType::OnRamp => "on ramp",
+        ManeuverType::OffRamp => "off ramp",
+        ManeuverType::Fork => "fork",
+        ManeuverType::EndOfRoad => "end of road",
+        ManeuverType::Continue => "continue",
+        ManeuverType::Roundabout => "roundabout",
+        ManeuverType::Rotary => "rotary",
+        ManeuverType::RoundaboutTurn => "roundabout turn",
+        ManeuverType::Notification => "notification",
+        ManeuverType::ExitRoundabout => "exit roundabout",
+        ManeuverType::ExitRotary => "exit rotary",
+    }
+}
+
+fn modifier_key(modifier: ManeuverModifier) -> &'static str {
+    match modifier {
+        ManeuverModifier::UTurn => "uturn",
+        ManeuverModifier::SharpRight => "sharp right",
+        ManeuverModifier::Right => "right",
+        ManeuverModifier::SlightRight => "slight right",
+        ManeuverModifier::Straight => "straight",
+        ManeuverModifier::SlightLeft => "slight left",
+        ManeuverModifier::Left => "left",
+        ManeuverModifier::SharpLeft => "sharp left",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(maneuver: &str, name: &str) -> OsrmRouteStep {
+        serde_json::from_str(&format!(
+            r#"{{
+                "distance": 400,
+                "duration": 40,
+                "geometry": "",
+                "name": "{name}",
+                "maneuver": {maneuver},
+                "intersections": []
+            }}"#
+        ))
+        .expect("Unable to parse step")
+    }
+
+    #[test]
+    fn synthesize_roundabout_instructions() {
+        let language = InstructionLanguage::english();
+        let synthesizer = InstructionSynthesizer {
+            language: &language,
+            units: UnitSystem::Metric,
+            generate_ssml: false,
+        };
+        let roundabout = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 90, "type": "roundabout", "modifier": "right", "exit": 2}"#,
+            "Main Street",
+        );
+        assert_eq!(
+            synthesizer.step_instruction(&roundabout),
+            "Enter the roundabout and take the second exit onto Main Street"
+        );
+
+        let mut rotary = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 90, "type": "rotary", "exit": 3}"#,
+            "",
+        );
+        rotary.rotary_name = Some("Dupont Circle".to_string());
+        assert_eq!(
+            synthesizer.step_instruction(&rotary),
+            "Enter Dupont Circle and take the third exit"
+        );
+
+        // Exits without an ordinal phrase fall back to the number
+        let big_roundabout = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 90, "type": "roundabout", "exit": 11}"#,
+            "",
+        );
+        assert_eq!(
+            synthesizer.step_instruction(&big_roundabout),
+            "Enter the roundabout and take the 11 exit"
+        );
+    }
+
+    #[test]
+    fn fall_back_to_english() {
+        let language = InstructionLanguage {
+            phrases: HashMap::from([
+                (
+                    "turn.name".to_string(),
+                    "Biegen Sie {modifier} in {way_name} ab".to_string(),
+                ),
+                ("modifier.left".to_string(), "links".to_string()),
+            ]),
+        };
+        let synthesizer = InstructionSynthesizer {
+            language: &language,
+            units: UnitSystem::Metric,
+            generate_ssml: false,
+        };
+
+        let turn = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 270, "type": "turn", "modifier": "left"}"#,
+            "Hauptstraße",
+        );
+        assert_eq!(
+            synthesizer.step_instruction(&turn),
+            "Biegen Sie links in Hauptstraße ab"
+        );
+
+        // Phrases missing from the table come from English
+        let depart = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 270, "type": "depart"}"#,
+            "",
+        );
+        assert_eq!(synthesizer.step_instruction(&depart), "Head west");
+
+        // Unknown maneuver types are treated like turns
+        let unknown = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 270, "type": "teleport", "modifier": "left"}"#,
+            "Hauptstraße",
+        );
+        assert_eq!(
+            synthesizer.step_instruction(&unknown),
+            "Biegen Sie links in Hauptstraße ab"
+        );
+    }
+
+    #[test]
+    fn announce_distances_in_units() {
+        let language = InstructionLanguage::english();
+        let current = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 0, "type": "turn", "modifier": "right"}"#,
+            "First Avenue",
+        );
+        let next = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 270, "type": "turn", "modifier": "left"}"#,
+            "Main Street",
+        );
+
+        let announcements = |units| {
+            InstructionSynthesizer {
+                language: &language,
+                units,
+                generate_ssml: false,
+            }
+            .spoken_instructions(&current, Some(&next))
+            .into_iter()
+            .map(|instruction| {
+                (
+                    instruction.text,
+                    instruction.trigger_distance_before_maneuver,
+                )
+            })
+            .collect::<Vec<_>>()
+        };
+
+        // At 10 m/s, the turn is announced 30 seconds and 8 seconds in advance
+        assert_eq!(
+            announcements(UnitSystem::Metric),
+            vec![
+                (
+                    "In 300 meters, turn left onto Main Street".to_string(),
+                    300.0
+                ),
+                ("Turn left onto Main Street".to_string(), 80.0),
+            ]
+        );
+        assert_eq!(
+            announcements(UnitSystem::Imperial)[0].0,
+            "In 0.2 miles, turn left onto Main Street"
+        );
+    }
+
+    #[test]
+    fn embed_instructions_as_phrased() {
+        // Languages can embed the instruction as-is, without lowercasing it
+        let language = InstructionLanguage {
+            phrases: HashMap::from([
+                (
+                    "turn.name".to_string(),
+                    "Sie biegen {modifier} in {way_name} ab".to_string(),
+                ),
+                ("modifier.left".to_string(), "links".to_string()),
+                (
+                    "spoken.in_distance".to_string(),
+                    "{instruction}, in {distance}".to_string(),
+                ),
+            ]),
+        };
+        let current = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 0, "type": "turn", "modifier": "right"}"#,
+            "Erste Straße",
+        );
+        let next = step(
+            r#"{"location": [0, 0], "bearing_before": 0, "bearing_after": 270, "type": "turn", "modifier": "left"}"#,
+            "Hauptstraße",
+        );
+
+        let announcements = InstructionSynthesizer {
+            language: &language,
+            units: UnitSystem::Metric,
+            generate_ssml: false,
+        }
+        .spoken_instructions(&current, Some(&next));
+        assert_eq!(
+            announcements[0].text,
+            "Sie biegen links in Hauptstraße ab, in 300 meters"
+        );
+    }
+}
@@ -1,24 +1,30 @@
 //! Response parsing for OSRM-compatible JSON (including Stadia Maps, Valhalla, Mapbox, etc.).
 
+pub mod instructions;
 pub(crate) mod models;
 pub mod utilities;
 
 use super::RouteResponseParser;
+use crate::localization::UnitSystem;
 use crate::models::{
-    AnyAnnotationValue, GeographicCoordinate, Incident, LaneInfo, RouteStep, SpokenInstruction,
-    VisualInstruction, VisualInstructionContent, Waypoint, WaypointKind,
+    GeographicCoordinate, Incident, Intersection, IntersectionLane, LaneInfo, Maneuver, RouteLeg,
+    RouteStep, SegmentAnnotation, SpokenInstruction, VisualInstruction, VisualInstructionContent,
+    Waypoint, WaypointKind,
 };
 use crate::routing_adapters::utilities::get_coordinates_from_geometry;
 use crate::routing_adapters::{
     osrm::models::{
-        Route as OsrmRoute, RouteResponse, RouteStep as OsrmRouteStep, Waypoint as OsrmWaypoint,
+        AnyAnnotationValue, Route as OsrmRoute, RouteResponse, RouteStep as OsrmRouteStep,
+        Waypoint as OsrmWaypoint,
     },
     ParsingError, Route,
 };
+use crate::ssml::generate_ssml;
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::{string::ToString, vec, vec::Vec};
 use geo::BoundingRect;
-use models::BannerContent;
+use instructions::{InstructionLanguage, InstructionSynthesizer};
+use models::{BannerContent, BannerInstruction};
 use polyline::decode_polyline;
 use utilities::get_annotation_slice;
 use uuid::Uuid;
@@ -27,14 +33,54 @@ use uuid::Uuid;
 ///
 /// The parser is NOT limited to only the standard OSRM format; many Valhalla/Mapbox tags are also
 /// parsed and are included in the final route.
+///
+/// Responses without instructions (ex: from stock OSRM servers)
+/// get instructions synthesized in the parser's [`InstructionLanguage`] and [`UnitSystem`].
+/// The parser can optionally generate SSML for spoken instructions which don't include it
+/// (see [`OsrmResponseParser::with_ssml_generation`]).
 #[derive(Debug)]
 pub struct OsrmResponseParser {
     polyline_precision: u32,
+    instruction_language: InstructionLanguage,
+    units: UnitSystem,
+    generate_ssml: bool,
 }
 
 impl OsrmResponseParser {
     pub fn new(polyline_precision: u32) -> Self {
-        Self { polyline_precision }
+        Self::with_instruction_language(
+            polyline_precision,
+            InstructionLanguage::english(),
+            UnitSystem::Metric,
+        )
+    }
+
+    pub fn with_instruction_language(
+        polyline_precision: u32,
+        instruction_language: InstructionLanguage,
+        units: UnitSystem,
+    ) -> Self {
+        Self {
+            polyline_precision,
+            instruction_language,
+            units,
+            generate_ssml: false,
+        }
+    }
+
+    /// Configures whether SSML is generated for spoken instructions which don't include any
+    /// (see [`crate::ssml`]).
+    ///
+    /// This is disabled by default.
+    /// Clients typically prefer SSML over plain text whenever it's present,
+    /// so enabling this changes how every instruction is spoken,
+    /// not only the ones where the markup helps.
+    #[must_use]
+    pub fn with_ssml_generation(self, generate_ssml: bool) -> Self {
+        Self {
+            generate_ssml,
+            ..self
+        }
     }
 }
 
@@ -45,7 +91,16 @@ impl RouteResponseParser for OsrmResponseParser {
         if res.code == "Ok" {
             res.routes
                 .iter()
-                .map(|route| Route::from_osrm(route, &res.waypoints, self.polyline_precision))
+                .map(|route| {
+                    Route::from_osrm_with_instruction_language(
+                        route,
+                        &res.waypoints,
+                        self.polyline_precision,
+                        &self.instruction_language,
+                        self.units,
+                        self.generate_ssml,
+                    )
+                })
                 .collect::<Result<Vec<_>, _>>()
         } else {
             Err(ParsingError::InvalidStatusCode { code: res.code })
@@ -59,25 +114,54 @@ impl Route {
         waypoints: &[OsrmWaypoint],
         polyline_precision: u32,
     ) -> Result<Self, ParsingError> {
-        let via_waypoint_indices: Vec<_> = route
-            .legs
+        Self::from_osrm_with_instruction_language(
+            route,
+            waypoints,
+            polyline_precision,
+            &InstructionLanguage::english(),
+            UnitSystem::Metric,
+            false,
+        )
+    }
+
+    /// Like [`Route::from_osrm`], but synthesizes any missing instructions
+    /// in the given language and unit system.
+    ///
+    /// If `generate_ssml` is set, SSML is also generated for spoken instructions which lack it.
+    pub fn from_osrm_with_instruction_language(
+        route: &OsrmRoute,
+        waypoints: &[OsrmWaypoint],
+        polyline_precision: u32,
+        instruction_language: &InstructionLanguage,
+        units: UnitSystem,
+        generate_ssml: bool,
+    ) -> Result<Self, ParsingError> {
+        let synthesizer = &InstructionSynthesizer {
+            language: instruction_language,
+            units,
+            generate_ssml,
+        };
+        let waypoints = Self::get_waypoints(route, waypoints);
+
+        // Each leg ends at the next waypoint which is not passed through
+        let break_waypoint_indices: Vec<_> = waypoints
             .iter()
-            .flat_map(|leg| leg.via_waypoints.iter().map(|via| via.waypoint_index))
+            .enumerate()
+            .filter(|(_, waypoint)| waypoint.kind == WaypointKind::Break)
+            .map(|(idx, _)| idx)
             .collect();
-
-        let waypoints: Vec<_> = waypoints
+        let legs = route
+            .legs
             .iter()
             .enumerate()
-            .map(|(idx, waypoint)| Waypoint {
-                coordinate: GeographicCoordinate {
-                    lat: waypoint.location.latitude(),
-                    lng: waypoint.location.longitude(),
-                },
-                kind: if via_waypoint_indices.contains(&idx) {
-                    WaypointKind::Via
-                } else {
-                    WaypointKind::Break
-                },
+            .map(|(leg_index, leg)| RouteLeg {
+                distance: leg.distance,
+                duration: leg.duration,
+                destination_waypoint_index: break_waypoint_indices
+                    .get(leg_index + 1)
+                    .copied()
+                    .unwrap_or(waypoints.len().saturating_sub(1))
+                    as u64,
             })
             .collect();
 
@@ -95,7 +179,8 @@ impl Route {
             let steps = route
                 .legs
                 .iter()
-                .flat_map(|leg| {
+                .enumerate()
+                .flat_map(|(leg_index, leg)| {
                     // Converts all single value annotation vectors into a single vector witih a value object.
                     let annotations = leg
                         .annotation
@@ -112,7 +197,7 @@ impl Route {
                     // Index for the annotations slice
                     let mut start_index: usize = 0;
 
-                    leg.steps.iter().map(move |step| {
+                    leg.steps.iter().enumerate().map(move |(step_index, step)| {
                         let step_geometry =
                             get_coordinates_from_geometry(&step.geometry, polyline_precision)?;
 
@@ -128,53 +213,23 @@ impl Route {
                         let annotation_slice =
                             get_annotation_slice(annotations.clone(), start_index, end_index).ok();
 
-                        let relevant_incidents_slice = incident_items
-                            .iter()
-                            .filter(|incident| {
-                                let incident_start = incident.geometry_index_start as usize;
-
-                                match incident.geometry_index_end {
-                                    Some(end) => {
-                                        let incident_end = end as usize;
-                                        incident_start >= start_index && incident_end <= end_index
-                                    }
-                                    None => {
-                                        incident_start >= start_index && incident_start <= end_index
-                                    }
-                                }
-                            })
-                            .map(|incident| {
-                                let mut adjusted_incident = incident.clone();
-                                if adjusted_incident.geometry_index_start - start_index as u64 > 0 {
-                                    adjusted_incident.geometry_index_start -= start_index as u64;
-                                } else {
-                                    adjusted_incident.geometry_index_start = 0;
-                                }
-
-                                if let Some(end) = adjusted_incident.geometry_index_end {
-                                    let adjusted_end = end - start_index as u64;
-                                    adjusted_incident.geometry_index_end =
-                                        Some(if adjusted_end > end_index as u64 {
-                                            end_index as u64
-                                        } else {
-                                            adjusted_end
-                                        });
-                                }
-                                adjusted_incident
-                            })
-                            .collect::<Vec<Incident>>();
+                        let relevant_incidents_slice =
+                            Self::get_incidents_slice(&incident_items, start_index, end_index);
 
                         start_index = end_index;
 
-                        RouteStep::from_osrm_and_geom(
+                        Ok(RouteStep::from_osrm_and_geom(
                             step,
+                            leg.steps.get(step_index + 1),
                             step_geometry,
                             annotation_slice,
                             relevant_incidents_slice,
-                        )
+                            leg_index as u64,
+                            synthesizer,
+                        ))
                     })
                 })
-                .collect::<Result<Vec<_>, _>>()?;
+                .collect::<Result<Vec<_>, ParsingError>>()?;
 
             Ok(Route {
                 geometry,
@@ -182,6 +237,7 @@ impl Route {
                 distance: route.distance,
                 waypoints: waypoints.clone(),
                 steps,
+                legs,
             })
         } else {
             Err(ParsingError::InvalidGeometry {
@@ -191,6 +247,76 @@ impl Route {
     }
 }
 
+impl Route {
+    /// Converts the waypoints of an OSRM response,
+    /// marking those which the route passes through without stopping as vias.
+    fn get_waypoints(route: &OsrmRoute, waypoints: &[OsrmWaypoint]) -> Vec<Waypoint> {
+        let via_waypoint_indices: Vec<_> = route
+            .legs
+            .iter()
+            .flat_map(|leg| leg.via_waypoints.iter().map(|via| via.waypoint_index))
+            .collect();
+
+        waypoints
+            .iter()
+            .enumerate()
+            .map(|(idx, waypoint)| Waypoint {
+                coordinate: GeographicCoordinate {
+                    lat: waypoint.location.latitude(),
+                    lng: waypoint.location.longitude(),
+                },
+                kind: if via_waypoint_indices.contains(&idx) {
+                    WaypointKind::Via
+                } else {
+                    WaypointKind::Break
+                },
+            })
+            .collect()
+    }
+
+    /// Gets the incidents which occur between the given geometry indices of a leg,
+    /// with indices adjusted so that they are relative to the start of the step.
+    fn get_incidents_slice(
+        incidents: &[Incident],
+        start_index: usize,
+        end_index: usize,
+    ) -> Vec<Incident> {
+        incidents
+            .iter()
+            .filter(|incident| {
+                let incident_start = incident.geometry_index_start as usize;
+
+                match incident.geometry_index_end {
+                    Some(end) => {
+                        let incident_end = end as usize;
+                        incident_start >= start_index && incident_end <= end_index
+                    }
+                    None => incident_start >= start_index && incident_start <= end_index,
+                }
+            })
+            .map(|incident| {
+                let mut adjusted_incident = incident.clone();
+                if adjusted_incident.geometry_index_start - start_index as u64 > 0 {
+                    adjusted_incident.geometry_index_start -= start_index as u64;
+                } else {
+                    adjusted_incident.geometry_index_start = 0;
+                }
+
+                if let Some(end) = adjusted_incident.geometry_index_end {
+                    let adjusted_end = end - start_index as u64;
+                    adjusted_incident.geometry_index_end =
+                        Some(if adjusted_end > end_index as u64 {
+                            end_index as u64
+                        } else {
+                            adjusted_end
+                        });
+                }
+                adjusted_incident
+            })
+            .collect()
+    }
+}
+
 impl RouteStep {
     fn extract_exit_numbers(banner_content: &BannerContent) -> Vec<String> {
         banner_content
@@ -201,64 +327,101 @@ impl RouteStep {
             .collect()
     }
 
+    /// Converts a banner instruction, using lanes from the maneuver's intersection
+    /// when the banner itself doesn't include any.
+    fn visual_instruction_from_banner(
+        banner: &BannerInstruction,
+        maneuver_lane_info: Option<&Vec<LaneInfo>>,
+    ) -> VisualInstruction {
+        VisualInstruction {
+            primary_content: VisualInstructionContent {
+                text: banner.primary.text.clone(),
+                maneuver_type: banner.primary.maneuver_type,
+                maneuver_modifier: banner.primary.maneuver_modifier,
+                roundabout_exit_degrees: banner.primary.roundabout_exit_degrees,
+                // Lane info from the banner belongs in the sub content (see below),
+                // so intersection lanes only go here when there is no sub content.
+                lane_info: if banner.sub.is_none() {
+                    maneuver_lane_info.cloned()
+                } else {
+                    None
+                },
+                exit_numbers: Self::extract_exit_numbers(&banner.primary),
+            },
+            secondary_content: banner.secondary.as_ref().map(|secondary| {
+                VisualInstructionContent {
+                    text: secondary.text.clone(),
+                    maneuver_type: secondary.maneuver_type,
+                    maneuver_modifier: secondary.maneuver_modifier,
+                    roundabout_exit_degrees: banner.primary.roundabout_exit_degrees,
+                    lane_info: None,
+                    exit_numbers: Self::extract_exit_numbers(secondary),
+                }
+            }),
+            sub_content: banner.sub.as_ref().map(|sub| VisualInstructionContent {
+                text: sub.text.clone(),
+                maneuver_type: sub.maneuver_type,
+                maneuver_modifier: sub.maneuver_modifier,
+                roundabout_exit_degrees: sub.roundabout_exit_degrees,
+                lane_info: {
+                    let lane_infos: Vec<LaneInfo> = sub
+                        .components
+                        .iter()
+                        .filter(|component| component.component_type.as_deref() == Some("lane"))
+                        .map(|component| LaneInfo {
+                            active: component.active.unwrap_or(false),
+                            directions: component.directions.clone().unwrap_or_default(),
+                            active_direction: component.active_direction.clone(),
+                        })
+                        .collect();
+
+                    if lane_infos.is_empty() {
+                        maneuver_lane_info.cloned()
+                    } else {
+                        Some(lane_infos)
+                    }
+                },
+                exit_numbers: Self::extract_exit_numbers(sub),
+            }),
+            trigger_distance_before_maneuver: banner.distance_along_geometry,
+        }
+    }
+
     fn from_osrm_and_geom(
         value: &OsrmRouteStep,
+        next_step: Option<&OsrmRouteStep>,
         geometry: Vec<GeographicCoordinate>,
         annotations: Option<Vec<AnyAnnotationValue>>,
         incidents: Vec<Incident>,
-    ) -> Result<Self, ParsingError> {
-        let visual_instructions = value
+        leg_index: u64,
+        synthesizer: &InstructionSynthesizer,
+    ) -> Self {
+        // The maneuver at the end of this step takes place at the first intersection of the next step,
+        // so that is where the lanes for the maneuver are found.
+        let maneuver_lane_info: Option<Vec<LaneInfo>> = next_step
+            .and_then(|next_step| next_step.intersections.first())
+            .map(|intersection| {
+                intersection
+                    .lanes
+                    .iter()
+                    .map(|lane| LaneInfo::from(&IntersectionLane::from(lane)))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|lane_infos| !lane_infos.is_empty());
+
+        let visual_instructions: Vec<VisualInstruction> = value
             .banner_instructions
             .iter()
-            .map(|banner| VisualInstruction {
-                primary_content: VisualInstructionContent {
-                    text: banner.primary.text.clone(),
-                    maneuver_type: banner.primary.maneuver_type,
-                    maneuver_modifier: banner.primary.maneuver_modifier,
-                    roundabout_exit_degrees: banner.primary.roundabout_exit_degrees,
-                    lane_info: None,
-                    exit_numbers: Self::extract_exit_numbers(&banner.primary),
-                },
-                secondary_content: banner.secondary.as_ref().map(|secondary| {
-                    VisualInstructionContent {
-                        text: secondary.text.clone(),
-                        maneuver_type: secondary.maneuver_type,
-                        maneuver_modifier: secondary.maneuver_modifier,
-                        roundabout_exit_degrees: banner.primary.roundabout_exit_degrees,
-                        lane_info: None,
-                        exit_numbers: Self::extract_exit_numbers(&secondary),
-                    }
-                }),
-                sub_content: banner.sub.as_ref().map(|sub| VisualInstructionContent {
-                    text: sub.text.clone(),
-                    maneuver_type: sub.maneuver_type,
-                    maneuver_modifier: sub.maneuver_modifier,
-                    roundabout_exit_degrees: sub.roundabout_exit_degrees,
-                    lane_info: {
-                        let lane_infos: Vec<LaneInfo> = sub
-                            .components
-                            .iter()
-                            .filter(|component| component.component_type.as_deref() == Some("lane"))
-                            .map(|component| LaneInfo {
-                                active: component.active.unwrap_or(false),
-                                directions: component.directions.clone().unwrap_or_default(),
-                                active_direction: component.active_direction.clone(),
-                            })
-                            .collect();
-
-                        if lane_infos.is_empty() {
-                            None
-                        } else {
-                            Some(lane_infos)
-                        }
-                    },
-                    exit_numbers: Self::extract_exit_numbers(&sub),
-                }),
-                trigger_distance_before_maneuver: banner.distance_along_geometry,
-            })
+            .map(|banner| Self::visual_instruction_from_banner(banner, maneuver_lane_info.as_ref()))
             .collect();
+        // Responses from stock OSRM don't include banners, so we synthesize them.
+        let visual_instructions = if visual_instructions.is_empty() {
+            synthesizer.visual_instructions(value, next_step, maneuver_lane_info)
+        } else {
+            visual_instructions
+        };
 
-        let spoken_instructions = value
+        let spoken_instructions: Vec<SpokenInstruction> = value
             .voice_instructions
             .iter()
             .map(|instruction| SpokenInstruction {
@@ -268,14 +431,33 @@ impl RouteStep {
                 utterance_id: Uuid::new_v4(),
             })
             .collect();
+        let spoken_instructions = if spoken_instructions.is_empty() {
+            synthesizer.spoken_instructions(value, next_step)
+        } else {
+            spoken_instructions
+        };
+        // Not every engine sends SSML, so we generate it from the text when it's missing (if enabled).
+        let spoken_instructions = if synthesizer.generate_ssml {
+            let ssml_hints = instructions::ssml_hints(value, next_step);
+            spoken_instructions
+                .into_iter()
+                .map(|instruction| SpokenInstruction {
+                    ssml: instruction
+                        .ssml
+                        .or_else(|| Some(generate_ssml(&instruction.text, &ssml_hints))),
+                    ..instruction
+                })
+                .collect()
+        } else {
+            spoken_instructions
+        };
 
-        // Convert the annotations to a vector of json strings.
-        // This allows us to safely pass the RouteStep through the FFI boundary.
-        // The host platform can then parse an arbitrary annotation object.
-        let annotations_as_strings: Option<Vec<String>> = annotations.map(|annotations_vec| {
+        // Convert the annotations to typed values which can be passed across the FFI boundary.
+        // Annotations which aren't well-known are preserved as extensions.
+        let annotations: Option<Vec<SegmentAnnotation>> = annotations.map(|annotations_vec| {
             annotations_vec
-                .iter()
-                .map(|annotation| serde_json::to_string(annotation).unwrap())
+                .into_iter()
+                .map(SegmentAnnotation::from)
                 .collect()
         });
 
@@ -284,7 +466,7 @@ impl RouteStep {
             None => Vec::new(),
         };
 
-        Ok(RouteStep {
+        RouteStep {
             geometry,
             // TODO: Investigate using the haversine distance or geodesics to normalize.
             // Valhalla in particular is a bit nonstandard. See https://github.com/valhalla/valhalla/issues/1717
@@ -292,18 +474,26 @@ impl RouteStep {
             duration: value.duration,
             road_name: value.name.clone(),
             exits,
-            instruction: value.maneuver.get_instruction(),
+            instruction: value
+                .maneuver
+                .instruction
+                .clone()
+                .unwrap_or_else(|| synthesizer.step_instruction(value)),
             visual_instructions,
             spoken_instructions,
-            annotations: annotations_as_strings,
+            annotations,
             incidents,
-        })
+            maneuver: Some(Maneuver::from(&value.maneuver)),
+            intersections: value.intersections.iter().map(Intersection::from).collect(),
+            leg_index,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{ManeuverModifier, ManeuverType};
 
     const STANDARD_OSRM_POLYLINE6_RESPONSE: &str =
         include_str!("fixtures/standard_osrm_polyline6_response.json");
@@ -332,8 +522,22 @@ mod tests {
             .expect("Unable to parse Valhalla OSRM response");
 
         insta::assert_yaml_snapshot!(routes, {
-            ".**.annotations" => "redacted annotations json strings vec"
+            ".**.annotations" => "redacted segment annotations",
+            ".**.intersections" => "redacted intersections"
         });
+
+        // Intersections are too verbose for the snapshot, so spot check the first step
+        let intersections = &routes[0].steps[0].intersections;
+        assert_eq!(intersections.len(), 4);
+        assert_eq!(intersections[0].bearings, vec![254]);
+        assert_eq!(intersections[1].bearings, vec![7, 82, 189, 281]);
+        assert_eq!(intersections[1].entry, vec![true, false, true, true]);
+        assert_eq!(intersections[1].in_bearing_index, Some(1));
+        assert_eq!(intersections[1].out_bearing_index, Some(3));
+        // This is a walking route, so there are no road classes or lanes
+        assert!(intersections
+            .iter()
+            .all(|intersection| intersection.classes.is_empty() && intersection.lanes.is_empty()));
     }
 
     #[test]
@@ -344,7 +548,8 @@ mod tests {
             .expect("Unable to parse Valhalla OSRM response");
 
         insta::assert_yaml_snapshot!(routes, {
-            ".**.annotations" => "redacted annotations json strings vec"
+            ".**.annotations" => "redacted segment annotations",
+            ".**.intersections" => "redacted intersections"
         });
     }
 
@@ -367,14 +572,18 @@ mod tests {
                     continue;
                 }
 
+                let segment_durations = step.get_annotated_segment_durations();
                 let step = step.clone();
                 let annotations = step.annotations.expect("No annotations");
                 assert_eq!(
                     annotations.len(),
                     step.geometry.len() - 1,
-                    "Route {}, Step {}",
-                    route_index,
-                    step_index
+                    "Route {route_index}, Step {step_index}"
+                );
+                assert_eq!(
+                    segment_durations.map(|durations| durations.len()),
+                    Some(annotations.len()),
+                    "Route {route_index}, Step {step_index}"
                 );
             }
         }
@@ -417,6 +626,94 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_maneuvers_and_intersections() {
+        let parser = OsrmResponseParser::new(6);
+        let routes = parser
+            .parse_response(VALHALLA_OSRM_RESPONSE_WITH_EXITS.into())
+            .expect("Unable to parse OSRM response");
+        let steps = &routes[0].steps;
+
+        let location = GeographicCoordinate {
+            lat: 45.375_411,
+            lng: -122.586_549,
+        };
+        assert_eq!(
+            steps[1].maneuver,
+            Some(Maneuver {
+                location,
+                bearing_before: 225,
+                bearing_after: 235,
+                maneuver_type: ManeuverType::OffRamp,
+                maneuver_modifier: Some(ManeuverModifier::SlightRight),
+            })
+        );
+
+        let intersection = &steps[1].intersections[0];
+        assert_eq!(intersection.location, location);
+        assert_eq!(intersection.bearings, vec![45, 225, 235]);
+        assert_eq!(intersection.entry, vec![false, true, true]);
+        assert_eq!(intersection.in_bearing_index, Some(0));
+        assert_eq!(intersection.out_bearing_index, Some(2));
+        assert_eq!(intersection.classes, vec!["motorway".to_string()]);
+
+        // There is no way into the intersection at departure, or out of it at arrival
+        assert_eq!(steps[0].intersections[0].in_bearing_index, None);
+        let arrival = steps.last().expect("Expected an arrival step");
+        assert_eq!(arrival.intersections[0].out_bearing_index, None);
+        assert_eq!(
+            arrival.maneuver.map(|maneuver| maneuver.maneuver_type),
+            Some(ManeuverType::Arrive)
+        );
+    }
+
+    #[test]
+    fn parse_lanes_from_intersections() {
+        let parser = OsrmResponseParser::new(6);
+        let routes = parser
+            .parse_response(VALHALLA_OSRM_RESPONSE_WITH_EXITS.into())
+            .expect("Unable to parse OSRM response");
+        let steps = &routes[0].steps;
+
+        let lanes = &steps[1].intersections[0].lanes;
+        assert_eq!(lanes.len(), 4);
+        assert_eq!(
+            lanes[2],
+            IntersectionLane {
+                indications: vec!["straight".to_string(), "slight right".to_string()],
+                valid: true,
+                active: Some(false),
+                valid_indication: Some("slight right".to_string()),
+            }
+        );
+
+        // Without lanes in the banner, the lanes at the maneuver intersection are used instead
+        let mut response: serde_json::Value =
+            serde_json::from_str(VALHALLA_OSRM_RESPONSE_WITH_EXITS).expect("Invalid JSON");
+        for banner in response["routes"][0]["legs"][0]["steps"][0]["bannerInstructions"]
+            .as_array_mut()
+            .expect("Expected banner instructions")
+        {
+            banner
+                .as_object_mut()
+                .expect("Expected banner instruction")
+                .remove("sub");
+        }
+        let routes = parser
+            .parse_response(response.to_string().into())
+            .expect("Unable to parse OSRM response");
+        let lane_info = routes[0].steps[0].visual_instructions[0]
+            .primary_content
+            .lane_info
+            .as_ref()
+            .expect("Expected lane information");
+        let expected: Vec<_> = lanes.iter().map(LaneInfo::from).collect();
+        assert_eq!(lane_info, &expected);
+        // Valid lanes are only active when they are also preferred
+        assert!(!lane_info[2].active);
+        assert!(lane_info[3].active);
+    }
+
     #[test]
     fn parse_osrm_with_exits() {
         let parser = OsrmResponseParser::new(6);
@@ -425,7 +722,145 @@ mod tests {
             .expect("Unable to parse OSRM response");
 
         insta::assert_yaml_snapshot!(routes, {
-            ".**.annotations" => "redacted annotations json strings vec"
+            ".**.annotations" => "redacted segment annotations",
+            ".**.intersections" => "redacted intersections"
         });
     }
+
+    #[test]
+    fn synthesize_missing_instructions() {
+        // Strip the instructions to make this look like a response from a stock OSRM server
+        let mut response: serde_json::Value =
+            serde_json::from_str(VALHALLA_OSRM_RESPONSE_WITH_EXITS).expect("Unable to parse JSON");
+        for step in response["routes"][0]["legs"][0]["steps"]
+            .as_array_mut()
+            .expect("Expected steps")
+        {
+            let step = step.as_object_mut().expect("Expected a step object");
+            step.remove("bannerInstructions");
+            step.remove("voiceInstructions");
+            step["maneuver"]
+                .as_object_mut()
+                .expect("Expected a maneuver object")
+                .remove("instruction");
+        }
+
+        let parser = OsrmResponseParser::new(6);
+        let routes = parser
+            .parse_response(response.to_string().into())
+            .expect("Unable to parse OSRM response");
+        let steps = &routes[0].steps;
+
+        let instructions: Vec<_> = steps.iter().map(|step| step.instruction.as_str()).collect();
+        assert_eq!(
+            instructions,
+            vec![
+                "Head southwest on I 205 South",
+                "Take exit 10 towards OR 213 South, Oregon City",
+                "Keep slightly right onto Cascade Highway (OR 213 South)",
+                "You have arrived at your destination",
+            ]
+        );
+
+        // The banner describes the maneuver at the end of the step
+        assert_eq!(steps[0].visual_instructions.len(), 1);
+        let banner = &steps[0].visual_instructions[0];
+        assert_eq!(banner.primary_content.text, "OR 213 South");
+        assert_eq!(
+            banner.primary_content.maneuver_type,
+            Some(ManeuverType::OffRamp)
+        );
+        assert_eq!(
+            banner.primary_content.maneuver_modifier,
+            Some(ManeuverModifier::SlightRight)
+        );
+        assert_eq!(banner.primary_content.exit_numbers, vec!["10"]);
+        assert_eq!(
+            banner.primary_content.lane_info.as_ref().map(Vec::len),
+            Some(4)
+        );
+        assert_eq!(banner.trigger_distance_before_maneuver, steps[0].distance);
+        assert_eq!(steps[3].visual_instructions, vec![]);
+
+        // The departure is announced, followed by the next maneuver
+        let spoken: Vec<_> = steps[0]
+            .spoken_instructions
+            .iter()
+            .map(|instruction| instruction.text.as_str())
+            .collect();
+        assert_eq!(
+            spoken,
+            vec![
+                "Head southwest on I 205 South",
+                "Take exit 10 towards OR 213 South, Oregon City"
+            ]
+        );
+
+        // Arrival is announced in advance, and again once the user is practically there
+        let spoken: Vec<_> = steps[2]
+            .spoken_instructions
+            .iter()
+            .map(|instruction| {
+                (
+                    instruction.text.as_str(),
+                    instruction.trigger_distance_before_maneuver,
+                )
+            })
+            .collect();
+        assert_eq!(
+            spoken,
+            vec![
+                (
+                    "In 500 meters, you will arrive at your destination",
+                    steps[2].distance
+                ),
+                ("You have arrived at your destination", 15.0),
+            ]
+        );
+        assert_eq!(steps[3].spoken_instructions, vec![]);
+    }
+
+    #[test]
+    fn generate_missing_ssml() {
+        let mut response: serde_json::Value =
+            serde_json::from_str(VALHALLA_OSRM_RESPONSE_WITH_EXITS).expect("Unable to parse JSON");
+        let steps = &mut response["routes"][0]["legs"][0]["steps"];
+        steps[0]["voiceInstructions"] = serde_json::json!([{
+            "announcement": "Head southwest on I 205 South",
+            "ssmlAnnouncement": "<speak>From the server</speak>",
+            "distanceAlongGeometry": 100.0,
+        }]);
+        steps[2]["pronunciation"] = "kæsˈkeɪd ˈhaɪweɪ".into();
+
+        // SSML is only generated on request
+        let routes = OsrmResponseParser::new(6)
+            .parse_response(response.to_string().into())
+            .expect("Unable to parse OSRM response");
+        assert_eq!(routes[0].steps[1].spoken_instructions[0].ssml, None);
+
+        let parser = OsrmResponseParser::new(6).with_ssml_generation(true);
+        let routes = parser
+            .parse_response(response.to_string().into())
+            .expect("Unable to parse OSRM response");
+        let steps = &routes[0].steps;
+
+        // SSML from the server is used as-is
+        assert_eq!(
+            steps[0].spoken_instructions[0].ssml.as_deref(),
+            Some("<speak>From the server</speak>")
+        );
+
+        // Otherwise, it's generated with the pronunciation of the next road
+        let ssml: Vec<_> = steps[1]
+            .spoken_instructions
+            .iter()
+            .map(|instruction| instruction.ssml.as_deref())
+            .collect();
+        assert_eq!(
+            ssml,
+            vec![Some(
+                r#"<speak>Keep slightly right onto <phoneme alphabet="ipa" ph="kæsˈkeɪd ˈhaɪweɪ">Cascade Highway</phoneme> (<say-as interpret-as="address">OR 213 South</say-as>)</speak>"#
+            )]
+        );
+    }
 }
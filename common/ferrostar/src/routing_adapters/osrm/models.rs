@@ -83,6 +83,15 @@ pub struct AnyAnnotation {
     pub values: HashMap<String, Vec<Value>>,
 }
 
+/// A flat annotations string value map that can be used to store arbitrary
+/// annotation values.
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct AnyAnnotationValue {
+    #[serde(flatten)]
+    pub value: HashMap<String, Value>,
+}
+
 /// A traffic incident, as modeled in the Mapbox OSRM extensions.
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Deserialize, Debug)]
@@ -132,11 +141,13 @@ pub struct RouteStep {
     pub reference: Option<String>,
     /// A pronunciation hint for the name of the way.
     pub pronunciation: Option<String>,
+    /// The destinations of the way (ex: from signposts on a highway ramp).
+    pub destinations: Option<String>,
     /// The mode of transportation.
     pub mode: Option<String>,
     /// The maneuver for this step
     pub maneuver: StepManeuver,
-    /// TODO: docs
+    /// The intersections along the step, starting with the one where the maneuver takes place.
     pub intersections: Vec<Intersections>,
 
     /// A list of exits (name or number), separated by semicolons.
@@ -150,9 +161,10 @@ pub struct RouteStep {
     /// NOTE: This annotation is not in the official spec, but is a common extension used by Mapbox
     /// and Valhalla.
     pub driving_side: Option<String>,
-    // Mapbox and Valhalla extensions that might be useful later
-    // pub rotary_name: Option<String>,
-    // pub rotary_pronunciation: Option<String>,
+    /// The name of the rotary (for `rotary` maneuvers through a named rotary).
+    pub rotary_name: Option<String>,
+    /// A pronunciation hint for the name of the rotary.
+    pub rotary_pronunciation: Option<String>,
     /// Textual instructions that are displayed as a banner; supported by Mapbox and Valhalla
     #[serde(default, rename = "bannerInstructions")]
     pub banner_instructions: Vec<BannerInstruction>,
@@ -235,24 +247,10 @@ pub struct StepManeuver {
     /// An optional string indicating the direction change of the maneuver.
     /// TODO: Model this as an enum.
     pub modifier: Option<String>,
+    /// The number of the exit to take, for `roundabout` and `rotary` maneuvers.
+    pub exit: Option<u32>,
     /// Non-standard extension in Mapbox and Valhalla where the instruction is computed server-side
-    instruction: Option<String>,
-}
-
-impl StepManeuver {
-    // TODO: This is a placeholder implementation.
-    // Most commercial offerings offer server-side synthesis of voice instructions.
-    // However, we might consider synthesizing these locally too.
-    // This will be rather cumbersome with localization though.
-    fn synthesize_instruction(&self, _locale: &str) -> String {
-        String::from("TODO: OSRM instruction synthesis")
-    }
-
-    pub fn get_instruction(&self) -> String {
-        self.instruction
-            .clone()
-            .unwrap_or_else(|| self.synthesize_instruction("en-US"))
-    }
+    pub instruction: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -280,16 +278,14 @@ pub struct Intersections {
     /// maneuver/passing the intersection.
     /// To get the bearing in the direction of driving, the bearing has to be rotated by
     /// 180 degrees. Not supplied for `depart` maneuvers.
-    #[serde(default)]
     #[serde(rename = "in")]
-    pub intersection_in: usize,
+    pub intersection_in: Option<usize>,
     /// An index into the bearings/entry array used to calculate the bearing just before the turn.
     ///
     /// The clockwise angle from true north to the direction of travel immediately after the
     /// maneuver/passing the intersection.  Not supplied for `arrive` maneuvers.
-    #[serde(default)]
     #[serde(rename = "out")]
-    pub intersection_out: usize,
+    pub intersection_out: Option<usize>,
     /// A list of turn [`Lane`]s available at the intersection (if info is available).
     ///
     /// Lanes are listed in left-to-right order.
@@ -306,7 +302,16 @@ pub struct Lane {
     pub indications: Vec<String>,
     /// Whether the lane is a valid choice for the current maneuver
     pub valid: bool,
-    // TODO: Mapbox and Valhalla extensions: `active` and `valid_indication`
+    /// Whether the lane is a preferred choice for the current maneuver.
+    ///
+    /// NOTE: This annotation is not in the official spec, but is a common extension used by Mapbox
+    /// and Valhalla.
+    pub active: Option<bool>,
+    /// Which of the `indications` applies to the current maneuver.
+    ///
+    /// NOTE: This annotation is not in the official spec, but is a common extension used by Mapbox
+    /// and Valhalla.
+    pub valid_indication: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -426,6 +431,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn deserialize_banner_instruction() {
         // Example from Mapbox's public docs
         let data = r#"
@@ -1,5 +1,10 @@
-use super::models::{AnyAnnotation, MapboxOsrmIncident};
-use crate::models::{AnyAnnotationValue, BoundingBox, GeographicCoordinate, Incident};
+use super::models::{
+    AnyAnnotation, AnyAnnotationValue, Intersections, Lane, MapboxOsrmIncident, StepManeuver,
+};
+use crate::models::{
+    BoundingBox, CongestionLevel, GeographicCoordinate, Incident, Intersection, IntersectionLane,
+    Maneuver, ManeuverModifier, ManeuverType, SegmentAnnotation, SpeedLimit, SpeedUnit,
+};
 use crate::routing_adapters::error::ParsingError;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -47,6 +52,88 @@ pub(crate) fn zip_annotations(annotation: AnyAnnotation) -> Vec<AnyAnnotationVal
         .collect::<Vec<AnyAnnotationValue>>()
 }
 
+impl From<AnyAnnotationValue> for SegmentAnnotation {
+    /// Converts a (zipped) OSRM-style annotation into a typed annotation.
+    ///
+    /// Well-known annotations with an unexpected format are kept as extensions
+    /// along with any other annotations, so that no information is lost.
+    fn from(annotation: AnyAnnotationValue) -> Self {
+        let mut segment_annotation = SegmentAnnotation::default();
+        for (key, value) in annotation.value {
+            if value.is_null() {
+                continue;
+            }
+
+            let parsed = match key.as_str() {
+                "distance" => value
+                    .as_f64()
+                    .map(|distance| segment_annotation.distance = Some(distance)),
+                "duration" => value
+                    .as_f64()
+                    .map(|duration| segment_annotation.duration = Some(duration)),
+                "speed" => value
+                    .as_f64()
+                    .map(|speed| segment_annotation.speed = Some(speed)),
+                // Mapbox and Valhalla use `maxspeed`, but `max_speed` also turns up in the wild.
+                "maxspeed" | "max_speed" => parse_speed_limit(&value)
+                    .map(|max_speed| segment_annotation.max_speed = Some(max_speed)),
+                "congestion" => value
+                    .as_str()
+                    .and_then(parse_congestion_level)
+                    .map(|congestion| segment_annotation.congestion = Some(congestion)),
+                "way_id" => value
+                    .as_u64()
+                    .map(|way_id| segment_annotation.way_id = Some(way_id)),
+                _ => None,
+            };
+
+            if parsed.is_none() {
+                segment_annotation.extensions.insert(key, value.to_string());
+            }
+        }
+
+        segment_annotation
+    }
+}
+
+/// Parses an OSRM-style max speed annotation.
+///
+/// These are one of `{"none": true}`, `{"unknown": true}`, or `{"speed": 56, "unit": "km/h"}`.
+fn parse_speed_limit(value: &Value) -> Option<SpeedLimit> {
+    let is_flag_set = |flag: &str| value.get(flag).and_then(Value::as_bool) == Some(true);
+    if is_flag_set("none") {
+        return Some(SpeedLimit::NoLimit);
+    }
+    if is_flag_set("unknown") {
+        return Some(SpeedLimit::Unknown);
+    }
+
+    let unit = match value.get("unit")?.as_str()? {
+        "km/h" => SpeedUnit::KilometersPerHour,
+        "mph" | "mi/h" => SpeedUnit::MilesPerHour,
+        "knots" => SpeedUnit::Knots,
+        _ => return None,
+    };
+    Some(SpeedLimit::Limited {
+        value: value.get("speed")?.as_f64()?,
+        unit,
+    })
+}
+
+/// Parses a Mapbox-style congestion annotation.
+///
+/// These are one of `unknown`, `low`, `moderate`, `heavy`, or `severe`.
+fn parse_congestion_level(congestion: &str) -> Option<CongestionLevel> {
+    match congestion {
+        "unknown" => Some(CongestionLevel::Unknown),
+        "low" => Some(CongestionLevel::Low),
+        "moderate" => Some(CongestionLevel::Moderate),
+        "heavy" => Some(CongestionLevel::Heavy),
+        "severe" => Some(CongestionLevel::Severe),
+        _ => None,
+    }
+}
+
 impl From<&MapboxOsrmIncident> for Incident {
     fn from(incident: &MapboxOsrmIncident) -> Self {
         Incident {
@@ -85,6 +172,58 @@ impl From<&MapboxOsrmIncident> for Incident {
     }
 }
 
+impl From<&Intersections> for Intersection {
+    fn from(intersection: &Intersections) -> Self {
+        Intersection {
+            location: GeographicCoordinate {
+                lat: intersection.location.latitude(),
+                lng: intersection.location.longitude(),
+            },
+            bearings: intersection.bearings.clone(),
+            entry: intersection.entry.clone(),
+            in_bearing_index: intersection.intersection_in.map(|index| index as u64),
+            out_bearing_index: intersection.intersection_out.map(|index| index as u64),
+            classes: intersection.classes.clone(),
+            lanes: intersection
+                .lanes
+                .iter()
+                .map(IntersectionLane::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&StepManeuver> for Maneuver {
+    fn from(maneuver: &StepManeuver) -> Self {
+        Maneuver {
+            location: GeographicCoordinate {
+                lat: maneuver.location.latitude(),
+                lng: maneuver.location.longitude(),
+            },
+            bearing_before: maneuver.bearing_before,
+            bearing_after: maneuver.bearing_after,
+            // New maneuver types may be introduced,
+            // and the spec says that unknown types should be handled like a turn.
+            maneuver_type: serde_json::from_value(Value::from(maneuver.maneuver_type.as_str()))
+                .unwrap_or(ManeuverType::Turn),
+            maneuver_modifier: maneuver.modifier.as_deref().and_then(|modifier| {
+                serde_json::from_value::<ManeuverModifier>(Value::from(modifier)).ok()
+            }),
+        }
+    }
+}
+
+impl From<&Lane> for IntersectionLane {
+    fn from(lane: &Lane) -> Self {
+        IntersectionLane {
+            indications: lane.indications.clone(),
+            valid: lane.valid,
+            active: lane.active,
+            valid_indication: lane.valid_indication.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -114,7 +253,7 @@ mod test {
         let json_value: Map<String, Value> = serde_json::from_str(json_str).unwrap();
         let values: HashMap<String, Vec<Value>> = json_value
             .iter()
-            .map(|(k, v)| (k.to_string(), v.as_array().unwrap().clone()))
+            .map(|(k, v)| (k.clone(), v.as_array().unwrap().clone()))
             .collect();
 
         // Construct the annotation object.
@@ -125,4 +264,66 @@ mod test {
             insta::assert_yaml_snapshot!(result);
         });
     }
+
+    #[test]
+    fn test_segment_annotation_from_zipped_annotation() {
+        let json_str = r#"[{
+            "distance": 1.2,
+            "duration": 4,
+            "speed": 10,
+            "maxspeed": {"speed": 56, "unit": "km/h"},
+            "congestion": "heavy",
+            "way_id": 1234,
+            "construction": null,
+            "congestion_numeric": 80
+        }, {
+            "maxspeed": {"none": true},
+            "congestion": "unknown"
+        }, {
+            "maxspeed": {"unknown": true},
+            "duration": "slow",
+            "congestion": "gridlock"
+        }]"#;
+
+        let annotations: Vec<AnyAnnotationValue> = serde_json::from_str(json_str).unwrap();
+        let result: Vec<SegmentAnnotation> = annotations
+            .into_iter()
+            .map(SegmentAnnotation::from)
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                SegmentAnnotation {
+                    distance: Some(1.2),
+                    duration: Some(4.0),
+                    speed: Some(10.0),
+                    max_speed: Some(SpeedLimit::Limited {
+                        value: 56.0,
+                        unit: SpeedUnit::KilometersPerHour,
+                    }),
+                    congestion: Some(CongestionLevel::Heavy),
+                    way_id: Some(1234),
+                    extensions: HashMap::from([(
+                        "congestion_numeric".to_string(),
+                        "80".to_string()
+                    )]),
+                },
+                SegmentAnnotation {
+                    max_speed: Some(SpeedLimit::NoLimit),
+                    congestion: Some(CongestionLevel::Unknown),
+                    ..SegmentAnnotation::default()
+                },
+                SegmentAnnotation {
+                    max_speed: Some(SpeedLimit::Unknown),
+                    // Well-known annotations in an unexpected format are preserved
+                    extensions: HashMap::from([
+                        ("duration".to_string(), "\"slow\"".to_string()),
+                        ("congestion".to_string(), "\"gridlock\"".to_string()),
+                    ]),
+                    ..SegmentAnnotation::default()
+                },
+            ]
+        );
+    }
 }
@@ -1,6 +1,7 @@
 //! High-level HTTP request generation for Valhalla HTTP APIs.
 
 use super::{RouteRequest, RoutingRequestGenerationError};
+use crate::localization::UnitSystem;
 use crate::models::{UserLocation, Waypoint, WaypointKind};
 use crate::routing_adapters::RouteRequestGenerator;
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
@@ -43,7 +44,14 @@ pub struct ValhallaHttpRequestGenerator {
     endpoint_url: String,
     /// The Valhalla costing model to use.
     profile: String,
-    // TODO: Language, units, and other top-level parameters
+    /// The language of the narrative instructions, as a BCP 47 tag (ex: `en-US`).
+    ///
+    /// If unspecified, the server default is used.
+    language: Option<String>,
+    /// The units for distances in the narrative instructions.
+    ///
+    /// If unspecified, the server default is used.
+    units: Option<UnitSystem>,
     /// Arbitrary key/value pairs which override the defaults.
     ///
     /// These can contain complex nested structures,
@@ -88,6 +96,8 @@ impl ValhallaHttpRequestGenerator {
         Self {
             endpoint_url,
             profile,
+            language: None,
+            units: None,
             options,
         }
     }
@@ -139,9 +149,44 @@ impl ValhallaHttpRequestGenerator {
         Ok(Self {
             endpoint_url,
             profile,
+            language: None,
+            units: None,
             options: parsed_options,
         })
     }
+
+    /// Creates a new Valhalla request generator like [`ValhallaHttpRequestGenerator::with_options_json`],
+    /// which requests narrative instructions in the given language and units.
+    ///
+    /// The `language` and `units` keys in the options, if any, take precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ferrostar::routing_adapters::valhalla::ValhallaHttpRequestGenerator;
+    /// use ferrostar::localization::UnitSystem;
+    ///
+    /// let request_generator = ValhallaHttpRequestGenerator::with_localization(
+    ///     "https://api.stadiamaps.com/route/v1?api_key=YOUR-API-KEY".to_string(),
+    ///     "auto".to_string(),
+    ///     None,
+    ///     "en-GB".to_string(),
+    ///     UnitSystem::ImperialWithYards,
+    /// );
+    /// ```
+    pub fn with_localization(
+        endpoint_url: String,
+        profile: String,
+        options_json: Option<&str>,
+        language: String,
+        units: UnitSystem,
+    ) -> Result<Self, InstantiationError> {
+        Ok(Self {
+            language: Some(language),
+            units: Some(units),
+            ..Self::with_options_json(endpoint_url, profile, options_json)?
+        })
+    }
 }
 
 impl RouteRequestGenerator for ValhallaHttpRequestGenerator {
@@ -199,6 +244,16 @@ impl RouteRequestGenerator for ValhallaHttpRequestGenerator {
                 "costing": &self.profile,
                 "locations": locations,
             });
+            if let Some(language) = &self.language {
+                args["language"] = language.as_str().into();
+            }
+            if let Some(units) = self.units {
+                args["units"] = match units {
+                    UnitSystem::Metric => "kilometers",
+                    UnitSystem::Imperial | UnitSystem::ImperialWithYards => "miles",
+                }
+                .into();
+            }
 
             for (k, v) in &self.options {
                 args[k] = v.clone();
@@ -297,7 +352,7 @@ mod tests {
                 "The Valhalla HTTP request generator currently only generates POST requests"
             ),
             Err(e) => {
-                println!("Failed to generate request: {:?}", e);
+                println!("Failed to generate request: {e:?}");
                 json!(null)
             }
         }
@@ -366,7 +421,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Unable to create request generator")]
     fn request_body_invalid_costing_options() {
         // Valid JSON, but it's not an object.
         let body_json = generate_body(
@@ -398,6 +453,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn request_body_with_localization() {
+        let generator = ValhallaHttpRequestGenerator::with_localization(
+            ENDPOINT_URL.to_string(),
+            COSTING.to_string(),
+            None,
+            "ar-SA".to_string(),
+            UnitSystem::Metric,
+        )
+        .expect("Unable to create request generator");
+
+        let Ok(RouteRequest::HttpPost { body, .. }) =
+            generator.generate_request(USER_LOCATION, WAYPOINTS.to_vec())
+        else {
+            panic!("Expected a POST request");
+        };
+        let body_json: JsonValue = from_slice(&body).expect("Failed to parse request body as JSON");
+
+        assert_json_include!(
+            actual: body_json,
+            expected: json!({
+                "language": "ar-SA",
+                "units": "kilometers",
+            })
+        );
+    }
+
+    #[test]
+    fn request_body_localization_overridden_by_options() {
+        let generator = ValhallaHttpRequestGenerator::with_localization(
+            ENDPOINT_URL.to_string(),
+            COSTING.to_string(),
+            Some(r#"{"units": "km"}"#),
+            "en-US".to_string(),
+            UnitSystem::Imperial,
+        )
+        .expect("Unable to create request generator");
+
+        let Ok(RouteRequest::HttpPost { body, .. }) =
+            generator.generate_request(USER_LOCATION, WAYPOINTS.to_vec())
+        else {
+            panic!("Expected a POST request");
+        };
+        let body_json: JsonValue = from_slice(&body).expect("Failed to parse request body as JSON");
+
+        assert_json_include!(
+            actual: body_json,
+            expected: json!({
+                "language": "en-US",
+                "units": "km",
+            })
+        );
+    }
+
     #[test]
     fn request_body_with_multiple_options() {
         let body_json = generate_body(
@@ -0,0 +1,251 @@
+//! Generation of [SSML](https://www.w3.org/TR/speech-synthesis11/) for spoken instructions.
+//!
+//! Some routing engines include SSML with their voice instructions, but many don't.
+//! Plain text is often good enough, but text-to-speech engines can stumble over
+//! road names, route numbers, and exit numbers.
+//! The generator here marks these up using hints from the route,
+//! so that they are pronounced correctly.
+//!
+//! ```
+//! use ferrostar::ssml::{generate_ssml, Pronunciation, SsmlHints};
+//!
+//! let hints = SsmlHints {
+//!     pronunciations: vec![Pronunciation {
+//!         text: "Laeva".to_string(),
+//!         ipa: "ˈlæva".to_string(),
+//!     }],
+//!     road_refs: vec![],
+//!     exit_numbers: vec![],
+//! };
+//! assert_eq!(
+//!     generate_ssml("In 200 feet, turn right onto Laeva", &hints),
+//!     r#"<speak>In <say-as interpret-as="cardinal">200</say-as> feet, turn right onto <phoneme alphabet="ipa" ph="ˈlæva">Laeva</phoneme></speak>"#
+//! );
+//! ```
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// A pronunciation for a word or phrase in an instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Pronunciation {
+    /// The text as written in the instruction.
+    pub text: String,
+    /// The pronunciation in the International Phonetic Alphabet.
+    pub ipa: String,
+}
+
+/// Hints about the parts of an instruction which need special treatment when spoken.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SsmlHints {
+    /// Pronunciations of names (typically roads).
+    pub pronunciations: Vec<Pronunciation>,
+    /// Route numbers (ex: `I 84`), which are read like an address.
+    pub road_refs: Vec<String>,
+    /// Exit numbers (ex: `10A`).
+    pub exit_numbers: Vec<String>,
+}
+
+/// How a span of the instruction is marked up.
+#[derive(Clone, Copy)]
+enum Markup<'a> {
+    Phoneme(&'a str),
+    Address,
+    ExitNumber,
+}
+
+/// Generates SSML for the plain text of an instruction.
+///
+/// Hinted names get phonemes, route numbers are read as addresses,
+/// and exit numbers and other numbers are read as numbers.
+/// Hints are only applied to whole words,
+/// and the first matching hint wins where they overlap
+/// (pronunciations come first, then route numbers, then exit numbers).
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+pub fn generate_ssml(text: &str, hints: &SsmlHints) -> String {
+    let hinted_terms = hints
+        .pronunciations
+        .iter()
+        .map(|pronunciation| {
+            (
+                pronunciation.text.as_str(),
+                Markup::Phoneme(&pronunciation.ipa),
+            )
+        })
+        .chain(
+            hints
+                .road_refs
+                .iter()
+                .map(|road_ref| (road_ref.as_str(), Markup::Address)),
+        )
+        .chain(
+            hints
+                .exit_numbers
+                .iter()
+                .map(|exit| (exit.as_str(), Markup::ExitNumber)),
+        )
+        .filter(|(term, _)| !term.trim().is_empty());
+
+    // Find the (non-overlapping) spans of the text which were hinted
+    let mut spans: Vec<(usize, usize, Markup)> = Vec::new();
+    for (term, markup) in hinted_terms {
+        for (start, _) in text.match_indices(term) {
+            let end = start + term.len();
+            let overlaps = spans
+                .iter()
+                .any(|(span_start, span_end, _)| start < *span_end && *span_start < end);
+            if is_whole_word(text, start, end) && !overlaps {
+                spans.push((start, end, markup));
+            }
+        }
+    }
+    spans.sort_by_key(|(start, _, _)| *start);
+
+    let mut body = String::new();
+    let mut position = 0;
+    for (start, end, markup) in spans {
+        body.push_str(&mark_up_numbers(&text[position..start]));
+        let span = escape(&text[start..end]);
+        let element = match markup {
+            Markup::Phoneme(ipa) => {
+                format!(
+                    r#"<phoneme alphabet="ipa" ph="{}">{span}</phoneme>"#,
+                    escape(ipa)
+                )
+            }
+            Markup::Address => say_as("address", &span),
+            // Exit numbers with letters (ex: 10A) are read character by character
+            Markup::ExitNumber if text[start..end].chars().all(|c| c.is_ascii_digit()) => {
+                say_as("cardinal", &span)
+            }
+            Markup::ExitNumber => say_as("characters", &span),
+        };
+        body.push_str(&element);
+        position = end;
+    }
+    body.push_str(&mark_up_numbers(&text[position..]));
+
+    format!("<speak>{body}</speak>")
+}
+
+/// Determines whether a span of text is bounded by non-alphanumeric characters.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+/// Escapes text and marks up any standalone numbers (ex: distances) to be read as numbers.
+fn mark_up_numbers(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.char_indices().peekable();
+    let mut previous: Option<char> = None;
+    while let Some((start, c)) = chars.next() {
+        if !c.is_ascii_digit() || previous.is_some_and(char::is_alphanumeric) {
+            result.push_str(&escape(&c.to_string()));
+            previous = Some(c);
+            continue;
+        }
+
+        // Consume the rest of the number, including a decimal part
+        let mut end = start + c.len_utf8();
+        while let Some(&(index, next)) = chars.peek() {
+            let is_decimal_point = matches!(next, '.' | ',')
+                && text[index + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit());
+            if next.is_ascii_digit() || is_decimal_point {
+                end = index + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let number = &text[start..end];
+        if chars.peek().is_some_and(|(_, next)| next.is_alphanumeric()) {
+            // Part of a word (ex: 3rd)
+            result.push_str(&escape(number));
+        } else {
+            result.push_str(&say_as("cardinal", number));
+        }
+        previous = number.chars().next_back();
+    }
+    result
+}
+
+/// Wraps (already escaped) text in a `say-as` element.
+fn say_as(interpret_as: &str, text: &str) -> String {
+    format!(r#"<say-as interpret-as="{interpret_as}">{text}</say-as>"#)
+}
+
+/// Escapes the characters which have special meaning in XML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speak(body: &str) -> String {
+        format!("<speak>{body}</speak>")
+    }
+
+    #[test]
+    fn escape_plain_text() {
+        assert_eq!(
+            generate_ssml("Turn left onto Smith & Sons' <Lane>", &SsmlHints::default()),
+            speak("Turn left onto Smith &amp; Sons&apos; &lt;Lane&gt;")
+        );
+    }
+
+    #[test]
+    fn mark_up_numbers_as_cardinals() {
+        assert_eq!(
+            generate_ssml(
+                "In 1.5 kilometers, take the 3rd exit onto A1",
+                &SsmlHints::default()
+            ),
+            speak(
+                r#"In <say-as interpret-as="cardinal">1.5</say-as> kilometers, take the 3rd exit onto A1"#
+            )
+        );
+    }
+
+    #[test]
+    fn mark_up_hints() {
+        let hints = SsmlHints {
+            pronunciations: vec![Pronunciation {
+                text: "Cascade Highway".to_string(),
+                ipa: "kæsˈkeɪd ˈhaɪweɪ".to_string(),
+            }],
+            road_refs: vec!["OR 213 South".to_string(), "OR 21".to_string()],
+            exit_numbers: vec!["10".to_string(), "10A".to_string()],
+        };
+
+        assert_eq!(
+            generate_ssml("Take exit 10 towards OR 213 South", &hints),
+            speak(
+                r#"Take exit <say-as interpret-as="cardinal">10</say-as> towards <say-as interpret-as="address">OR 213 South</say-as>"#
+            )
+        );
+        assert_eq!(
+            generate_ssml("Take exit 10A onto Cascade Highway (OR 213 South)", &hints),
+            speak(
+                r#"Take exit <say-as interpret-as="characters">10A</say-as> onto <phoneme alphabet="ipa" ph="kæsˈkeɪd ˈhaɪweɪ">Cascade Highway</phoneme> (<say-as interpret-as="address">OR 213 South</say-as>)"#
+            )
+        );
+    }
+}
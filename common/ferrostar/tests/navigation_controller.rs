@@ -3,7 +3,9 @@ extern crate ferrostar;
 use ferrostar::deviation_detection::RouteDeviationTracking;
 use ferrostar::models::{Route, UserLocation};
 use ferrostar::navigation_controller::models::{
-    CourseFiltering, NavigationControllerConfig, StepAdvanceMode, TripState,
+    ArrivalDetection, CourseFiltering, EtaBlending, EventLookAhead, InstructionTriggerMode,
+    LocationFiltering, LocationSnapping, NavigationControllerConfig, SpeedingDetection,
+    StepAdvanceMode, StepLookAhead, TripState,
 };
 use ferrostar::navigation_controller::NavigationController;
 use ferrostar::routing_adapters::osrm::OsrmResponseParser;
@@ -11,7 +13,7 @@ use ferrostar::routing_adapters::RouteResponseParser;
 
 #[cfg(all(feature = "std", not(feature = "web-time")))]
 use std::time::SystemTime;
-#[cfg(all(feature = "web-time"))]
+#[cfg(feature = "web-time")]
 use web_time::SystemTime;
 
 // A route with two steps
@@ -19,7 +21,7 @@ const TWO_STEP_RESPONSE: &str = r#"{"routes":[{"weight_name":"auto","weight":56.
 
 /// Gets a route with two steps.
 ///
-/// The accuracy of each parser is tested separately in the routing_adapters module;
+/// The accuracy of each parser is tested separately in the `routing_adapters` module;
 /// this function simply intends to return a route with two steps.
 fn get_route_with_two_steps() -> Route {
     let parser = OsrmResponseParser::new(6);
@@ -47,16 +49,36 @@ fn same_location_results_in_identical_state() {
             step_advance: StepAdvanceMode::Manual,
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            location_filtering: LocationFiltering::Disabled,
+            location_snapping: LocationSnapping::Nearest,
+            step_look_ahead: StepLookAhead::Disabled,
+            arrival_detection: ArrivalDetection::Disabled,
+            instruction_trigger_mode: InstructionTriggerMode::DistanceBeforeManeuver {
+                tolerance: 5.0,
+            },
+            eta_blending: EtaBlending::Disabled,
+            speeding_detection: SpeedingDetection::Disabled,
+            event_look_ahead: EventLookAhead::Disabled,
         },
     );
 
     let initial_state = controller.get_initial_state(initial_user_location);
     assert!(matches!(initial_state, TripState::Navigating { .. }));
 
-    // Nothing should happen if given the exact same user location update
+    // The first update marks the departure announcement as spoken
+    let state = controller.update_user_location(initial_user_location, &initial_state);
+    assert!(matches!(
+        state,
+        TripState::Navigating {
+            spoken_instruction: None,
+            ..
+        }
+    ));
+
+    // Nothing else should happen if given the exact same user location update
     assert_eq!(
-        controller.update_user_location(initial_user_location, &initial_state),
-        initial_state
+        controller.update_user_location(initial_user_location, &state),
+        state
     );
 }
 
@@ -84,6 +106,16 @@ fn simple_route_state_machine_manual_advance() {
             step_advance: StepAdvanceMode::Manual,
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            location_filtering: LocationFiltering::Disabled,
+            location_snapping: LocationSnapping::Nearest,
+            step_look_ahead: StepLookAhead::Disabled,
+            arrival_detection: ArrivalDetection::Disabled,
+            instruction_trigger_mode: InstructionTriggerMode::DistanceBeforeManeuver {
+                tolerance: 5.0,
+            },
+            eta_blending: EtaBlending::Disabled,
+            speeding_detection: SpeedingDetection::Disabled,
+            event_look_ahead: EventLookAhead::Disabled,
         },
     );
 
@@ -160,6 +192,16 @@ fn simple_route_state_machine_advances_with_location_change() {
             },
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            location_filtering: LocationFiltering::Disabled,
+            location_snapping: LocationSnapping::Nearest,
+            step_look_ahead: StepLookAhead::Disabled,
+            arrival_detection: ArrivalDetection::Disabled,
+            instruction_trigger_mode: InstructionTriggerMode::DistanceBeforeManeuver {
+                tolerance: 5.0,
+            },
+            eta_blending: EtaBlending::Disabled,
+            speeding_detection: SpeedingDetection::Disabled,
+            event_look_ahead: EventLookAhead::Disabled,
         },
     );
 